@@ -15,3 +15,22 @@
 //  You should have received a copy of the GNU General Public License
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
+//! Cartographer holds the persistent record of the Universe after generation.
+mod diplomacy;
+mod faction;
+mod knowledge;
+mod orbital;
+mod sector;
+
+pub use self::diplomacy::{
+    baseline_relation, DiplomaticEvent, DiplomaticEventKind, Reputation, Stance, ALLIED_THRESHOLD,
+    HOSTILE_THRESHOLD, RELATION_MAX, RELATION_MIN,
+};
+pub use self::faction::{
+    Faction, FactionChange, FactionError, FactionId, FactionKind, Factions, PoliticalAxis,
+};
+pub use self::knowledge::{Knowledge, SectorKnowledge};
+pub use self::orbital::Orbitals;
+pub use self::sector::Sectors;
+#[cfg(test)]
+pub(crate) use self::sector::{test_sector_row, test_sectors};
@@ -0,0 +1,258 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Relations between Factions and the Player's standing with each of them.
+use super::{Faction, FactionId, FactionKind, Factions};
+use bevy::utils::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// Lowest a relation can fall, open war.
+pub const RELATION_MIN: f32 = -100.0;
+/// Highest a relation can climb, unshakeable alliance.
+pub const RELATION_MAX: f32 = 100.0;
+/// Relation at or above which Factions are allied.
+pub const ALLIED_THRESHOLD: f32 = 50.0;
+/// Relation at or below which Factions are hostile.
+pub const HOSTILE_THRESHOLD: f32 = -50.0;
+
+/// How one Faction regards another.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Stance {
+    Allied,
+    Neutral,
+    Hostile,
+}
+
+impl Stance {
+    pub fn from_relation(relation: f32) -> Stance {
+        if relation >= ALLIED_THRESHOLD {
+            Stance::Allied
+        } else if relation <= HOSTILE_THRESHOLD {
+            Stance::Hostile
+        } else {
+            Stance::Neutral
+        }
+    }
+}
+
+/// Things that happen between Factions and shift their relations.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DiplomaticEventKind {
+    /// Open hostilities
+    War,
+    /// Hostilities ended by treaty
+    Peace,
+    /// Mutual defence pact
+    Alliance,
+    /// Trade deal struck
+    TradeAgreement,
+    /// Trade deal soured
+    TradeDispute,
+    /// Border skirmish, espionage or insult
+    Incident,
+}
+
+impl DiplomaticEventKind {
+    /// Change in relation caused by the event.
+    pub fn shift(&self) -> f32 {
+        match self {
+            DiplomaticEventKind::War => -80.0,
+            DiplomaticEventKind::Peace => 40.0,
+            DiplomaticEventKind::Alliance => 40.0,
+            DiplomaticEventKind::TradeAgreement => 10.0,
+            DiplomaticEventKind::TradeDispute => -10.0,
+            DiplomaticEventKind::Incident => -15.0,
+        }
+    }
+}
+
+/// Event between two Factions
+#[derive(Debug, Copy, Clone)]
+pub struct DiplomaticEvent {
+    pub kind: DiplomaticEventKind,
+    pub a: FactionId,
+    pub b: FactionId,
+}
+
+/// Relation Factions settle towards when left alone, from how far apart their politics are and
+/// how their kinds get along.
+pub fn baseline_relation(a: &Faction, b: &Faction) -> f32 {
+    if a.id == b.id {
+        return RELATION_MAX;
+    }
+    let politics = 50.0 - 100.0 * a.politics.distance(&b.politics) * 2.0;
+    let kinds = kind_affinity(a.kind, b.kind) + kind_affinity(b.kind, a.kind);
+    (politics + kinds).clamp(RELATION_MIN, RELATION_MAX)
+}
+
+/// How a kind of Faction regards another, applied in both directions.
+fn kind_affinity(this: FactionKind, other: FactionKind) -> f32 {
+    use FactionKind::*;
+    match (this, other) {
+        (Pirate, Pirate) => -10.0,
+        (Pirate, _) => -30.0,
+        (Rebellion, Government) | (Rebellion, House) => -40.0,
+        (Criminal, Government) => -20.0,
+        (Corporate, Government) => 10.0,
+        (Corporate, Guild) => -5.0,
+        (Religious, Religious) => -15.0,
+        (House, House) => -10.0,
+        _ => 0.0,
+    }
+}
+
+impl Factions {
+    /// Set every relation to its baseline.
+    pub fn initialize_relations(&mut self) {
+        let factions: Vec<Faction> = self.iter().cloned().collect();
+        for a in factions.iter() {
+            let relations = factions
+                .iter()
+                .filter(|b| b.id != a.id)
+                .map(|b| (b.id, baseline_relation(a, b)))
+                .collect();
+            if let Some(faction) = self.get_mut_by_id(a.id) {
+                faction.relations = relations;
+            }
+        }
+    }
+    /// How `a` regards `b`, Factions always think the world of themselves.
+    pub fn relation(&self, a: FactionId, b: FactionId) -> f32 {
+        if a == b {
+            return RELATION_MAX;
+        }
+        self.get_by_id(a)
+            .and_then(|faction| faction.relations.get(&b).copied())
+            .unwrap_or(0.0)
+    }
+    /// Shift the relation between two Factions in both directions.
+    pub fn adjust_relation(&mut self, a: FactionId, b: FactionId, delta: f32) {
+        if a == b {
+            return;
+        }
+        for (from, to) in [(a, b), (b, a)] {
+            if let Some(faction) = self.get_mut_by_id(from) {
+                let relation = faction.relations.entry(to).or_insert(0.0);
+                *relation = (*relation + delta).clamp(RELATION_MIN, RELATION_MAX);
+            }
+        }
+    }
+    /// Is the pair allied, neutral or hostile.
+    pub fn stance(&self, a: FactionId, b: FactionId) -> Stance {
+        Stance::from_relation(self.relation(a, b).min(self.relation(b, a)))
+    }
+    /// Apply a Diplomatic Event to the Factions involved.
+    pub fn apply(&mut self, event: &DiplomaticEvent) {
+        self.adjust_relation(event.a, event.b, event.kind.shift());
+    }
+    /// Ease every relation back towards its baseline by `rate` per day over `days`.
+    pub fn drift_relations(&mut self, rate: f32, days: f32) {
+        let factions: Vec<Faction> = self.iter().cloned().collect();
+        let step = rate * days;
+        for a in factions.iter() {
+            for b in factions.iter().filter(|b| b.id != a.id) {
+                let baseline = baseline_relation(a, b);
+                let current = a.relations.get(&b.id).copied().unwrap_or(baseline);
+                let next = if current < baseline {
+                    (current + step).min(baseline)
+                } else {
+                    (current - step).max(baseline)
+                };
+                if let Some(faction) = self.get_mut_by_id(a.id) {
+                    faction.relations.insert(b.id, next);
+                }
+            }
+        }
+    }
+}
+
+/// Player's standing with every Faction.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Reputation(HashMap<FactionId, f32>);
+
+impl Reputation {
+    /// Standing with `faction`, starting neutral.
+    pub fn get(&self, faction: FactionId) -> f32 {
+        self.0.get(&faction).copied().unwrap_or(0.0)
+    }
+    /// Change standing with `faction` directly.
+    pub fn adjust(&mut self, faction: FactionId, delta: f32) {
+        let reputation = self.0.entry(faction).or_insert(0.0);
+        *reputation = (*reputation + delta).clamp(RELATION_MIN, RELATION_MAX);
+    }
+    /// Change standing with `faction` for something the Player did, rippling half as far to its
+    /// allies and the opposite way to its enemies.
+    pub fn act(&mut self, factions: &Factions, faction: FactionId, delta: f32) {
+        self.adjust(faction, delta);
+        for other in factions.iter().filter(|other| other.id != faction) {
+            match factions.stance(faction, other.id) {
+                Stance::Allied => self.adjust(other.id, delta / 2.0),
+                Stance::Hostile => self.adjust(other.id, -delta / 2.0),
+                Stance::Neutral => {}
+            }
+        }
+    }
+    /// Drop standing with a Faction that is gone.
+    pub fn forget(&mut self, faction: FactionId) {
+        self.0.remove(&faction);
+    }
+    /// Start `to` off with the same standing as `from`.
+    pub fn inherit(&mut self, from: FactionId, to: FactionId) {
+        if let Some(reputation) = self.0.get(&from).copied() {
+            self.0.insert(to, reputation);
+        }
+    }
+    /// How `faction` regards the Player.
+    pub fn stance(&self, faction: FactionId) -> Stance {
+        Stance::from_relation(self.get(faction))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartographer::PoliticalAxis;
+
+    #[test]
+    fn test_relations() {
+        let mut factions = Factions::default();
+        let left = PoliticalAxis::new(-1.0, -1.0, -1.0, -1.0);
+        let right = PoliticalAxis::new(1.0, 1.0, 1.0, 1.0);
+        let a = factions
+            .create_faction("A", FactionKind::Government, left)
+            .unwrap();
+        let b = factions
+            .create_faction("B", FactionKind::Government, left)
+            .unwrap();
+        let c = factions
+            .create_faction("C", FactionKind::Government, right)
+            .unwrap();
+        factions.initialize_relations();
+        assert_eq!(factions.stance(a, b), Stance::Allied);
+        assert_eq!(factions.stance(a, c), Stance::Hostile);
+
+        factions.apply(&DiplomaticEvent {
+            kind: DiplomaticEventKind::War,
+            a,
+            b,
+        });
+        assert_eq!(factions.stance(a, b), Stance::Hostile);
+        factions.drift_relations(1.0, 1000.0);
+        assert_eq!(factions.stance(a, b), Stance::Allied);
+    }
+}
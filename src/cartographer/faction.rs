@@ -16,70 +16,381 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
 
+use super::baseline_relation;
+use crate::generation::ProtoFaction;
 use bevy::prelude::*;
 use bevy::reflect::TypeUuid;
 use bevy::utils::HashMap;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
 
-/// Faction Reference Table
+/// Faction Registry
 ///
+/// Slots are reused once a Faction is gone, every FactionId carries the generation of its slot so
+/// a stale id never finds the slot's next occupant.
 #[derive(Serialize, Deserialize, TypeUuid)]
 #[uuid = "8830af02-da6c-4a26-8308-8cac59594a95"]
-pub struct Factions(HashMap<String, FactionId>, Vec<Faction>);
+pub struct Factions {
+    labels: HashMap<String, FactionId>,
+    slots: Vec<FactionSlot>,
+}
+
+/// Registry entry, empty once its Faction is gone.
+#[derive(Serialize, Deserialize)]
+struct FactionSlot {
+    generation: u32,
+    faction: Option<Faction>,
+}
 
 impl Factions {
-    /// Add new Faction
+    /// Add new Faction, names must be unique.
     pub fn create_faction(
         &mut self,
         name: &str,
         kind: FactionKind,
         politics: PoliticalAxis,
-    ) -> FactionId {
-        let id = FactionId(self.1.len());
-        let relations = HashMap::default();
-        self.1.push(Faction {
+    ) -> Result<FactionId, FactionError> {
+        if self.labels.contains_key(name) {
+            return Err(FactionError::DuplicateName(name.to_owned()));
+        }
+        let index = match self.slots.iter().position(|slot| slot.faction.is_none()) {
+            Some(index) => index,
+            None => {
+                self.slots.push(FactionSlot {
+                    generation: 0,
+                    faction: None,
+                });
+                self.slots.len() - 1
+            }
+        };
+        let slot = &mut self.slots[index];
+        let id = FactionId {
+            index: index as u32,
+            generation: slot.generation,
+        };
+        slot.faction = Some(Faction {
             id,
             name: name.to_owned(),
             kind,
-            relations,
+            relations: HashMap::default(),
             politics,
+            color: Color::WHITE,
+            home: None,
+            territory: HashSet::default(),
         });
-        self.0.insert(name.to_owned(), id);
-        id
+        self.labels.insert(name.to_owned(), id);
+        Ok(id)
     }
     /// Get Faction by FactionId
     pub fn get_by_id(&self, id: FactionId) -> Option<&Faction> {
-        self.1.get(id.0)
+        self.slots
+            .get(id.index as usize)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.faction.as_ref())
+    }
+    /// Get mutable Faction by FactionId
+    pub fn get_mut_by_id(&mut self, id: FactionId) -> Option<&mut Faction> {
+        self.slots
+            .get_mut(id.index as usize)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.faction.as_mut())
     }
     /// Get Faction by Faction Label
     pub fn get_by_label(&self, label: &str) -> Option<&Faction> {
-        self.1.get(self.0.get(label)?.0)
+        self.get_by_id(*self.labels.get(label)?)
+    }
+    /// Does the Faction still exist
+    pub fn contains(&self, id: FactionId) -> bool {
+        self.get_by_id(id).is_some()
+    }
+    /// Iterate over all Factions
+    pub fn iter(&self) -> impl Iterator<Item = &Faction> {
+        self.slots.iter().filter_map(|slot| slot.faction.as_ref())
+    }
+    /// Number of Factions
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+    /// Faction holding a Sector as territory
+    pub fn controller(&self, sector: usize) -> Option<&Faction> {
+        self.iter()
+            .find(|faction| faction.territory.contains(&sector))
+    }
+    /// Hand a Sector to a new controller, or leave it unclaimed with `None`.
+    ///
+    /// Returns the Faction that previously held it.
+    pub fn transfer_sector(&mut self, sector: usize, to: Option<FactionId>) -> Option<FactionId> {
+        let from = self.controller(sector).map(|faction| faction.id);
+        if let Some(faction) = from.and_then(|from| self.get_mut_by_id(from)) {
+            faction.territory.remove(&sector);
+        }
+        if let Some(faction) = to.and_then(|to| self.get_mut_by_id(to)) {
+            faction.territory.insert(sector);
+        }
+        from
+    }
+    /// Give a Faction a new name.
+    ///
+    /// Returns the change for the caller to send, `None` if the name was already its own.
+    pub fn rename(
+        &mut self,
+        id: FactionId,
+        name: &str,
+    ) -> Result<Option<FactionChange>, FactionError> {
+        match self.labels.get(name) {
+            Some(other) if *other == id => return Ok(None),
+            Some(_) => return Err(FactionError::DuplicateName(name.to_owned())),
+            None => {}
+        }
+        let faction = self
+            .get_mut_by_id(id)
+            .ok_or(FactionError::UnknownFaction(id))?;
+        let old = std::mem::replace(&mut faction.name, name.to_owned());
+        self.labels.remove(&old);
+        self.labels.insert(name.to_owned(), id);
+        Ok(Some(FactionChange::Renamed { id, old }))
+    }
+    /// Remove a Faction entirely, its territory is left unclaimed and every other Faction forgets
+    /// it. Returns the change for the caller to send.
+    pub fn dissolve(&mut self, id: FactionId) -> Result<FactionChange, FactionError> {
+        let faction = self.remove(id)?;
+        Ok(FactionChange::Dissolved {
+            id,
+            name: faction.name,
+        })
+    }
+    /// Annex `absorbed` into `into`, taking over its territory and any relations `into` had no
+    /// opinion on. Returns the change for the caller to send.
+    pub fn merge(
+        &mut self,
+        absorbed: FactionId,
+        into: FactionId,
+    ) -> Result<FactionChange, FactionError> {
+        if absorbed == into {
+            return Err(FactionError::SameFaction(into));
+        }
+        if !self.contains(into) {
+            return Err(FactionError::UnknownFaction(into));
+        }
+        let removed = self.remove(absorbed)?;
+        let faction = self
+            .get_mut_by_id(into)
+            .ok_or(FactionError::UnknownFaction(into))?;
+        faction.territory.extend(removed.territory);
+        faction.home = faction.home.or(removed.home);
+        for (other, relation) in removed.relations {
+            if other != into {
+                faction.relations.entry(other).or_insert(relation);
+            }
+        }
+        Ok(FactionChange::Merged {
+            absorbed,
+            name: removed.name,
+            into,
+        })
+    }
+    /// Empty a Faction's slot and have every other Faction forget it, returning the Faction as it
+    /// was.
+    fn remove(&mut self, id: FactionId) -> Result<Faction, FactionError> {
+        if id == FactionId::PLAYER {
+            return Err(FactionError::PlayerFaction);
+        }
+        if !self.contains(id) {
+            return Err(FactionError::UnknownFaction(id));
+        }
+        let slot = &mut self.slots[id.index as usize];
+        let faction = slot
+            .faction
+            .take()
+            .ok_or(FactionError::UnknownFaction(id))?;
+        slot.generation += 1;
+        self.labels.remove(&faction.name);
+        for slot in self.slots.iter_mut() {
+            if let Some(other) = slot.faction.as_mut() {
+                other.relations.remove(&id);
+            }
+        }
+        Ok(faction)
+    }
+    /// Break `sectors` away from `from` as a new Faction of `kind`.
+    ///
+    /// The new Faction shares its parent's politics, colour and standing with everyone else, its
+    /// relation with the parent starts from the baseline.
+    pub fn split(
+        &mut self,
+        from: FactionId,
+        name: &str,
+        kind: FactionKind,
+        sectors: &HashSet<usize>,
+    ) -> Result<FactionId, FactionError> {
+        let parent = self
+            .get_by_id(from)
+            .cloned()
+            .ok_or(FactionError::UnknownFaction(from))?;
+        let mut territory: Vec<usize> = parent.territory.intersection(sectors).copied().collect();
+        if territory.is_empty() {
+            return Err(FactionError::NoTerritory(from));
+        }
+        territory.sort_unstable();
+        let id = self.create_faction(name, kind, parent.politics)?;
+        let home = parent
+            .home
+            .filter(|home| sectors.contains(home))
+            .unwrap_or(territory[0]);
+        if let Some(faction) = self.get_mut_by_id(id) {
+            faction.color = parent.color;
+            faction.home = Some(home);
+            faction.territory = territory.iter().copied().collect();
+            faction.relations = parent.relations.clone();
+        }
+        if let Some(parent) = self.get_mut_by_id(from) {
+            parent.territory.retain(|sector| !sectors.contains(sector));
+            if parent.home == Some(home) {
+                let mut remaining: Vec<usize> = parent.territory.iter().copied().collect();
+                remaining.sort_unstable();
+                parent.home = remaining.first().copied();
+            }
+        }
+        for slot in self.slots.iter_mut() {
+            if let Some(other) = slot.faction.as_mut() {
+                if let Some(relation) = other.relations.get(&from).copied() {
+                    if other.id != id {
+                        other.relations.insert(id, relation);
+                    }
+                }
+            }
+        }
+        if let (Some(parent), Some(child)) = (self.get_by_id(from), self.get_by_id(id)) {
+            let down = baseline_relation(parent, child);
+            let up = baseline_relation(child, parent);
+            if let Some(parent) = self.get_mut_by_id(from) {
+                parent.relations.insert(id, down);
+            }
+            if let Some(child) = self.get_mut_by_id(id) {
+                child.relations.insert(from, up);
+            }
+        }
+        Ok(id)
     }
 }
 
 impl Default for Factions {
     fn default() -> Factions {
-        let mut factions = Factions(HashMap::default(), Vec::default());
-        factions.create_faction(
-            "Player",
-            FactionKind::Player,
-            PoliticalAxis {
-                economic: 0.0,
-                civil: 0.0,
-                diplomatic: 0.0,
-                social: 0.0,
-            },
-        );
+        let mut factions = Factions {
+            labels: HashMap::default(),
+            slots: Vec::default(),
+        };
+        factions
+            .create_faction(
+                "Player",
+                FactionKind::Player,
+                PoliticalAxis {
+                    economic: 0.0,
+                    civil: 0.0,
+                    diplomatic: 0.0,
+                    social: 0.0,
+                },
+            )
+            .expect("Player Faction is the first created");
+        factions
+    }
+}
+
+impl From<&[ProtoFaction]> for Factions {
+    /// Build the Faction table from generated Factions, after the Player's own.
+    fn from(protos: &[ProtoFaction]) -> Factions {
+        let mut factions = Factions::default();
+        for proto in protos {
+            let id = match factions.create_faction(&proto.name, proto.kind, proto.politics) {
+                Ok(id) => id,
+                Err(error) => {
+                    warn!("Skipping generated Faction: {}", error);
+                    continue;
+                }
+            };
+            if let Some(faction) = factions.get_mut_by_id(id) {
+                faction.color = proto.color;
+                faction.home = proto.home;
+                faction.territory = proto.territory.clone();
+            }
+        }
+        factions.initialize_relations();
         factions
     }
 }
 
-/// Faction Id
+/// Change to the Faction Registry that other records keyed by FactionId must follow.
+///
+/// Registry operations return the change, whoever makes it sends it on as an event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FactionChange {
+    /// `absorbed`, once called `name`, was annexed by `into`.
+    Merged {
+        absorbed: FactionId,
+        name: String,
+        into: FactionId,
+    },
+    /// `into` broke away from `from`.
+    Split { from: FactionId, into: FactionId },
+    /// `id`, once called `name`, is gone.
+    Dissolved { id: FactionId, name: String },
+    /// `id` was called `old`.
+    Renamed { id: FactionId, old: String },
+}
+
+/// Why a change to the Faction Registry was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FactionError {
+    /// Another Faction already goes by this name.
+    DuplicateName(String),
+    /// The Faction is gone or never existed.
+    UnknownFaction(FactionId),
+    /// A Faction can't be merged into itself.
+    SameFaction(FactionId),
+    /// The Faction holds none of the Sectors asked for.
+    NoTerritory(FactionId),
+    /// The Player's Faction can't be removed.
+    PlayerFaction,
+}
+
+impl fmt::Display for FactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FactionError::DuplicateName(name) => write!(f, "faction name {:?} is taken", name),
+            FactionError::UnknownFaction(id) => write!(f, "unknown faction {}", id),
+            FactionError::SameFaction(id) => write!(f, "faction {} can't merge into itself", id),
+            FactionError::NoTerritory(id) => write!(f, "faction {} holds none of the sectors", id),
+            FactionError::PlayerFaction => write!(f, "the player faction can't be removed"),
+        }
+    }
+}
+
+impl std::error::Error for FactionError {}
+
+/// Faction Id, a registry slot and the generation of the Faction occupying it.
 #[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Hash)]
-pub struct FactionId(usize);
+pub struct FactionId {
+    index: u32,
+    generation: u32,
+}
+
+impl FactionId {
+    /// The Player's own Faction, always the first created and never removed.
+    pub const PLAYER: FactionId = FactionId {
+        index: 0,
+        generation: 0,
+    };
+}
+
+impl fmt::Display for FactionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}v{}", self.index, self.generation)
+    }
+}
 
 /// Faction Data
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Faction {
     /// Faction Id
     pub id: FactionId,
@@ -91,10 +402,16 @@ pub struct Faction {
     pub relations: HashMap<FactionId, f32>,
     /// Faction Political Axis
     pub politics: PoliticalAxis,
+    /// Colour Faction is shown in
+    pub color: Color,
+    /// Sector the Faction is headquartered in
+    pub home: Option<usize>,
+    /// Sectors the Faction controls
+    pub territory: HashSet<usize>,
 }
 
 /// Kind of Faction
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum FactionKind {
     /// Corporate Factions focus on achieving specific goals.
     Corporate,
@@ -119,7 +436,7 @@ pub enum FactionKind {
 /// Faction Axis
 ///
 /// https://8values.github.io/
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Default, Debug, Copy, Clone, Serialize, Deserialize)]
 pub struct PoliticalAxis {
     /// Economic Axis
     ///
@@ -128,7 +445,7 @@ pub struct PoliticalAxis {
     /// * -1.0 - Socialism
     /// *  0.0 - Economic Centrism
     /// * +1.0 - Capitalism
-    economic: f32,
+    pub economic: f32,
     /// Civil Axis
     ///
     /// How free are people to do as they wish.
@@ -136,18 +453,110 @@ pub struct PoliticalAxis {
     /// * -1.0 - Libertarianism
     /// *  0.0 - Civil Centrism
     /// * +1.0 - Authoritarianism
-    civil: f32,
+    pub civil: f32,
     /// Social Axis
     ///
     /// * -1.0 - Traditionalism
     /// *  0.0 - Social Centrism
     /// * +1.0 - Progressivism
     ///
-    social: f32,
+    pub social: f32,
     /// Diplomatic Axis
     ///
     /// * -1.0 - Globalism
     /// *  0.0 - Diplomatic Centrism
     /// * +1.0 - Nationalism
-    diplomatic: f32,
-}
\ No newline at end of file
+    pub diplomatic: f32,
+}
+
+impl PoliticalAxis {
+    /// Create a Political Axis, each axis is clamped between `-1.0` and `+1.0`.
+    pub fn new(economic: f32, civil: f32, social: f32, diplomatic: f32) -> PoliticalAxis {
+        PoliticalAxis {
+            economic: economic.clamp(-1.0, 1.0),
+            civil: civil.clamp(-1.0, 1.0),
+            social: social.clamp(-1.0, 1.0),
+            diplomatic: diplomatic.clamp(-1.0, 1.0),
+        }
+    }
+    /// How far apart two Political Axes are, from `0.0` for identical to `1.0` for opposite.
+    pub fn distance(&self, other: &PoliticalAxis) -> f32 {
+        let economic = self.economic - other.economic;
+        let civil = self.civil - other.civil;
+        let social = self.social - other.social;
+        let diplomatic = self.diplomatic - other.diplomatic;
+        (economic * economic + civil * civil + social * social + diplomatic * diplomatic).sqrt()
+            / 4.0
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        let mut factions = Factions::default();
+        let politics = PoliticalAxis::default();
+        let a = factions
+            .create_faction("A", FactionKind::Government, politics)
+            .unwrap();
+        let b = factions
+            .create_faction("B", FactionKind::House, politics)
+            .unwrap();
+        assert!(factions
+            .create_faction("A", FactionKind::Guild, politics)
+            .is_err());
+        factions.get_mut_by_id(a).unwrap().territory = [1, 2, 3].iter().copied().collect();
+        factions.initialize_relations();
+
+        // Rebels take Sectors 2 and 3, and are known to everyone their parent was.
+        let rebels: HashSet<usize> = [2, 3, 4].iter().copied().collect();
+        let c = factions
+            .split(a, "C", FactionKind::Rebellion, &rebels)
+            .unwrap();
+        assert_eq!(factions.controller(2).unwrap().id, c);
+        assert_eq!(factions.controller(1).unwrap().id, a);
+        assert!(factions.controller(4).is_none());
+        assert!(factions.get_by_id(b).unwrap().relations.contains_key(&c));
+
+        // Annexed Factions are gone, and their slot's next occupant isn't mistaken for them.
+        assert_eq!(
+            factions.merge(c, b).unwrap(),
+            FactionChange::Merged {
+                absorbed: c,
+                name: "C".to_owned(),
+                into: b
+            }
+        );
+        assert!(!factions.contains(c));
+        assert_eq!(factions.controller(3).unwrap().id, b);
+        assert!(!factions.get_by_id(a).unwrap().relations.contains_key(&c));
+        let d = factions
+            .create_faction("D", FactionKind::Guild, politics)
+            .unwrap();
+        assert_ne!(c, d);
+        assert!(factions.get_by_id(c).is_none());
+
+        assert_eq!(
+            factions.rename(b, "E").unwrap(),
+            Some(FactionChange::Renamed {
+                id: b,
+                old: "B".to_owned()
+            })
+        );
+        assert_eq!(factions.rename(b, "E").unwrap(), None);
+        assert_eq!(factions.get_by_label("E").unwrap().id, b);
+        assert!(factions.get_by_label("B").is_none());
+        assert_eq!(
+            factions.dissolve(FactionId::PLAYER),
+            Err(FactionError::PlayerFaction)
+        );
+        assert_eq!(
+            factions.dissolve(d).unwrap(),
+            FactionChange::Dissolved {
+                id: d,
+                name: "D".to_owned()
+            }
+        );
+    }
+}
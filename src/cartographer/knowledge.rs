@@ -0,0 +1,175 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::{Faction, FactionId};
+use bevy::utils::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// How much a Faction knows about a Sector
+#[derive(Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Hash)]
+pub enum SectorKnowledge {
+    /// Nothing is known, the Sector may as well not exist.
+    Unknown,
+    /// The Sector's Gravity Well has been detected from afar.
+    Detected,
+    /// The Sector has been visited and its contents surveyed.
+    Surveyed,
+}
+
+impl Default for SectorKnowledge {
+    fn default() -> SectorKnowledge {
+        SectorKnowledge::Unknown
+    }
+}
+
+/// What each Faction knows about each Sector, by Sector ID.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Knowledge(HashMap<FactionId, HashMap<usize, SectorKnowledge>>);
+
+impl Knowledge {
+    /// What a Faction knows about a Sector
+    pub fn level(&self, faction: FactionId, sector: usize) -> SectorKnowledge {
+        self.0
+            .get(&faction)
+            .and_then(|sectors| sectors.get(&sector))
+            .copied()
+            .unwrap_or_default()
+    }
+    /// Raise a Faction's knowledge of a Sector, knowledge is never lost.
+    /// Returns true if anything new was learned.
+    pub fn learn(&mut self, faction: FactionId, sector: usize, level: SectorKnowledge) -> bool {
+        let known = self
+            .0
+            .entry(faction)
+            .or_default()
+            .entry(sector)
+            .or_default();
+        if level > *known {
+            *known = level;
+            true
+        } else {
+            false
+        }
+    }
+    /// Mark a Sector's Gravity Well as detected by a Faction.
+    pub fn detect(&mut self, faction: FactionId, sector: usize) -> bool {
+        self.learn(faction, sector, SectorKnowledge::Detected)
+    }
+    /// Mark a Sector as surveyed by a Faction.
+    pub fn survey(&mut self, faction: FactionId, sector: usize) -> bool {
+        self.learn(faction, sector, SectorKnowledge::Surveyed)
+    }
+    /// Iterate over every Sector a Faction knows anything about.
+    pub fn known_sectors(
+        &self,
+        faction: FactionId,
+    ) -> impl Iterator<Item = (usize, SectorKnowledge)> + '_ {
+        self.0
+            .get(&faction)
+            .into_iter()
+            .flat_map(|sectors| sectors.iter().map(|(sector, level)| (*sector, *level)))
+    }
+    /// Survey a Faction's home and every Sector it holds, no Faction is a stranger to its own
+    /// territory.
+    pub fn survey_territory(&mut self, faction: &Faction) {
+        for sector in faction.home.iter().chain(faction.territory.iter()) {
+            self.survey(faction.id, *sector);
+        }
+    }
+    /// Teach `to` everything `from` knows.
+    pub fn share(&mut self, from: FactionId, to: FactionId) {
+        let known: Vec<(usize, SectorKnowledge)> = self.known_sectors(from).collect();
+        for (sector, level) in known {
+            self.learn(to, sector, level);
+        }
+    }
+    /// Drop everything a Faction knew, once it is gone.
+    pub fn forget(&mut self, faction: FactionId) {
+        self.0.remove(&faction);
+    }
+    /// Serialize for a Save Game.
+    pub fn save(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+    /// Deserialize from a Save Game.
+    pub fn load(data: &str) -> Result<Knowledge, ron::Error> {
+        ron::from_str(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Knowledge, SectorKnowledge};
+    use crate::cartographer::{FactionId, FactionKind, Factions, PoliticalAxis};
+
+    #[test]
+    fn test_knowledge_is_never_lost() {
+        let mut knowledge = Knowledge::default();
+        assert_eq!(
+            knowledge.level(FactionId::PLAYER, 3),
+            SectorKnowledge::Unknown
+        );
+        assert!(knowledge.survey(FactionId::PLAYER, 3));
+        assert!(!knowledge.detect(FactionId::PLAYER, 3));
+        assert_eq!(
+            knowledge.level(FactionId::PLAYER, 3),
+            SectorKnowledge::Surveyed
+        );
+    }
+
+    #[test]
+    fn test_knowledge_round_trip() {
+        let mut knowledge = Knowledge::default();
+        knowledge.detect(FactionId::PLAYER, 1);
+        knowledge.survey(FactionId::PLAYER, 2);
+        let loaded = Knowledge::load(&knowledge.save().unwrap()).unwrap();
+        assert_eq!(
+            loaded.level(FactionId::PLAYER, 1),
+            SectorKnowledge::Detected
+        );
+        assert_eq!(
+            loaded.level(FactionId::PLAYER, 2),
+            SectorKnowledge::Surveyed
+        );
+        assert_eq!(loaded.level(FactionId::PLAYER, 3), SectorKnowledge::Unknown);
+    }
+
+    #[test]
+    fn test_factions_know_their_territory() {
+        let mut factions = Factions::default();
+        let id = factions
+            .create_faction(
+                "Test Government",
+                FactionKind::Government,
+                PoliticalAxis::default(),
+            )
+            .unwrap();
+        let faction = factions.get_mut_by_id(id).unwrap();
+        faction.home = Some(4);
+        faction.territory = [4, 5].iter().copied().collect();
+        let mut knowledge = Knowledge::default();
+        knowledge.survey_territory(factions.get_by_id(id).unwrap());
+        assert_eq!(knowledge.level(id, 4), SectorKnowledge::Surveyed);
+        assert_eq!(knowledge.level(id, 5), SectorKnowledge::Surveyed);
+        assert_eq!(knowledge.level(id, 6), SectorKnowledge::Unknown);
+        assert_eq!(
+            knowledge.level(FactionId::PLAYER, 4),
+            SectorKnowledge::Unknown
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use crate::generation::ProtoOrbital;
+use bevy::utils::HashMap;
+
+/// Orbital Reference Table
+///
+/// Persistent record of every Orbital in the Universe, indexed by Sector.
+#[derive(Debug, Default)]
+pub struct Orbitals {
+    orbitals: Vec<ProtoOrbital>,
+    sectors: HashMap<usize, Vec<usize>>,
+}
+
+impl Orbitals {
+    /// Get Orbital by Orbital ID
+    pub fn get(&self, id: usize) -> Option<&ProtoOrbital> {
+        self.orbitals.get(id)
+    }
+    /// Get mutable Orbital by Orbital ID
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut ProtoOrbital> {
+        self.orbitals.get_mut(id)
+    }
+    /// Iterate over all Orbitals in a Sector, parents are always returned before their children.
+    pub fn in_sector(&self, sector: usize) -> impl Iterator<Item = &ProtoOrbital> {
+        self.sectors
+            .get(&sector)
+            .into_iter()
+            .flatten()
+            .filter_map(move |id| self.orbitals.get(*id))
+    }
+    /// Iterate over the direct children of an Orbital.
+    pub fn children(&self, id: usize) -> impl Iterator<Item = &ProtoOrbital> {
+        self.orbitals
+            .get(id)
+            .into_iter()
+            .flat_map(move |parent| self.in_sector(parent.sector))
+            .filter(move |orbital| orbital.parent == Some(id))
+    }
+}
+
+impl From<Vec<ProtoOrbital>> for Orbitals {
+    fn from(orbitals: Vec<ProtoOrbital>) -> Orbitals {
+        let mut sectors: HashMap<usize, Vec<usize>> = HashMap::default();
+        for orbital in orbitals.iter() {
+            sectors.entry(orbital.sector).or_default().push(orbital.id);
+        }
+        Orbitals { orbitals, sectors }
+    }
+}
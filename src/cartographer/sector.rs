@@ -0,0 +1,124 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use crate::generation::ProtoSector;
+use bevy::utils::HashMap;
+use hexgrid::Coordinate;
+use rstar::primitives::PointWithData;
+use rstar::{RTree, AABB};
+
+/// Distance between the centers of neighbouring Sector hexes.
+const HEX_SPACING: f32 = 1.732_050_8;
+
+/// Sector Reference Table
+#[derive(Default)]
+pub struct Sectors {
+    sectors: Vec<ProtoSector>,
+    locations: HashMap<Coordinate<i32>, usize>,
+    hypermap: RTree<PointWithData<usize, [f32; 2]>>,
+}
+
+impl Sectors {
+    /// Get Sector by Sector ID
+    pub fn get(&self, id: usize) -> Option<&ProtoSector> {
+        self.sectors.get(id)
+    }
+    /// Get Sector by Hex Location
+    pub fn get_by_location(&self, location: Coordinate<i32>) -> Option<&ProtoSector> {
+        self.sectors.get(*self.locations.get(&location)?)
+    }
+    /// Iterate over all Sectors
+    pub fn iter(&self) -> impl Iterator<Item = &ProtoSector> {
+        self.sectors.iter()
+    }
+    /// Number of Sectors
+    pub fn len(&self) -> usize {
+        self.sectors.len()
+    }
+    /// Iterate over Sectors within `range` hexes of `location`.
+    pub fn within(
+        &self,
+        location: Coordinate<i32>,
+        range: i32,
+    ) -> impl Iterator<Item = &ProtoSector> {
+        let (x, y) = match self.get_by_location(location) {
+            Some(sector) => (sector.position.x, sector.position.y),
+            None => {
+                let position = location.to_pixel(hexgrid::Spacing::PointyTop(1.0));
+                (position.x, position.y)
+            }
+        };
+        let reach = range as f32 * HEX_SPACING + 1.0;
+        self.hypermap
+            .locate_in_envelope(&AABB::from_corners(
+                [x - reach, y - reach],
+                [x + reach, y + reach],
+            ))
+            .filter_map(move |point| self.sectors.get(point.data))
+            .filter(move |sector| sector.location.distance(location) <= range)
+    }
+}
+
+impl From<Vec<ProtoSector>> for Sectors {
+    fn from(sectors: Vec<ProtoSector>) -> Sectors {
+        let locations = sectors
+            .iter()
+            .map(|sector| (sector.location, sector.id))
+            .collect();
+        let hypermap = RTree::bulk_load(
+            sectors
+                .iter()
+                .map(|sector| PointWithData::new(sector.id, [sector.position.x, sector.position.y]))
+                .collect(),
+        );
+        Sectors {
+            sectors,
+            locations,
+            hypermap,
+        }
+    }
+}
+
+/// Sectors at the given hex locations, named for their IDs, shared by tests across the crate.
+#[cfg(test)]
+pub(crate) fn test_sectors(locations: &[Coordinate<i32>]) -> Sectors {
+    use hexgrid::Spacing;
+    let sectors: Vec<ProtoSector> = locations
+        .iter()
+        .enumerate()
+        .map(|(id, location)| ProtoSector {
+            id,
+            cluster: 0,
+            name: format!("Sector {}", id),
+            designation: format!("S-{}", id),
+            location: *location,
+            position: location.to_pixel(Spacing::PointyTop(1.0)),
+            anchor: 0,
+        })
+        .collect();
+    Sectors::from(sectors)
+}
+
+/// Row of `count` Sectors side by side, Sector ID `n` at cubic `(n, 0)`.
+#[cfg(test)]
+pub(crate) fn test_sector_row(count: usize) -> Sectors {
+    let locations: Vec<Coordinate<i32>> = (0..count)
+        .map(|id| Coordinate::from_cubic(id as i32, 0))
+        .collect();
+    test_sectors(&locations)
+}
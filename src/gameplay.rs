@@ -16,7 +16,260 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
 
+mod combat;
+mod diplomacy;
+mod economy;
+mod hyperspace;
+mod news;
+mod quest;
+mod rebellion;
+mod sectorspace;
+mod sensors;
+mod ship;
+mod shipyard;
+mod star;
+mod strategy;
 mod tilespace;
+mod timewarp;
+mod trading;
+
+pub use self::diplomacy::ReputationEvent;
+pub use self::economy::{
+    CargoHold, Commodity, Credits, Docked, Economy, Market, PriceLedger, TradeOrder, TradeReceipt,
+};
+pub use self::hyperspace::{
+    EncounterKind, HostileSectors, HyperDrive, HyperspaceArrival, HyperspaceEncounter, JumpRequest,
+};
+pub use self::news::{NewsFeed, NewsItem};
+pub use self::quest::{Quest, QuestBoard, QuestKind};
+pub use self::rebellion::{SectorMood, Unrest};
+pub use self::sectorspace::CurrentSector;
+pub use self::ship::{PlayerShip, ShipCatalog, ShipDesign, ShipStats};
+pub use self::star::{StarAppearance, StarGlow, StarGlowTexture, StarLabel};
+pub use self::strategy::{StrategicTurn, TerritoryChange};
+pub use self::timewarp::{WarpHazard, WarpInterrupt};
+use crate::cartographer::{DiplomaticEvent, FactionChange, Knowledge, Reputation};
+use crate::utility::{world_clock_update, WorldClock};
+use crate::GameState;
 use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+/// Tag Denoting the Player's Avatar
+pub struct PlayerAvatar;
+
+/// Which space the player is currently playing in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GameplayState {
+    /// Not yet playing, while in the menus or generating a Universe
+    Inactive,
+    Hyperspace,
+    Sectorspace,
+    Tilespace,
+    Cyberspace,
+}
+
+pub fn bootstrap_gameplay(app: &mut AppBuilder) {
+    app.add_plugin(RapierPhysicsPlugin::<NoUserData>::default())
+        .add_asset::<ShipCatalog>()
+        .init_asset_loader::<ship::ShipCatalogLoader>()
+        .insert_resource(WorldClock::default())
+        .insert_resource(Knowledge::default())
+        .insert_resource(Reputation::default())
+        .insert_resource(strategy::StrategicTurn::default())
+        .insert_resource(NewsFeed::default())
+        .insert_resource(QuestBoard::default())
+        .insert_resource(Unrest::default())
+        .insert_resource(Economy::default())
+        .insert_resource(economy::EconomyClock::default())
+        .insert_resource(Credits::default())
+        .insert_resource(CargoHold::default())
+        .insert_resource(Docked::default())
+        .insert_resource(PlayerShip::default())
+        .insert_resource(PriceLedger::default())
+        .insert_resource(combat::ProjectileMaterials::default())
+        .insert_resource(sensors::Contacts::default())
+        .insert_resource(sensors::ActiveSensors::default())
+        .insert_resource(trading::TradingSelection::default())
+        .insert_resource(shipyard::ShipyardOpen::default())
+        .insert_resource(shipyard::ShipyardSelection::default())
+        .insert_resource(hyperspace::TerritoryOverlaySettings::default())
+        .insert_resource(hyperspace::SelectedSector::default())
+        .insert_resource(hyperspace::PlannedRoute::default())
+        .insert_resource(hyperspace::HostileSectors::default())
+        .insert_resource(hyperspace::ActiveEncounter::default())
+        .init_resource::<StarGlowTexture>()
+        .add_event::<WarpInterrupt>()
+        .add_event::<JumpRequest>()
+        .add_event::<HyperspaceArrival>()
+        .add_event::<HyperspaceEncounter>()
+        .add_event::<DiplomaticEvent>()
+        .add_event::<ReputationEvent>()
+        .add_event::<TerritoryChange>()
+        .add_event::<FactionChange>()
+        .add_event::<TradeOrder>()
+        .add_event::<TradeReceipt>()
+        .add_event::<ship::RefitOrder>()
+        .add_event::<ship::RefitReceipt>()
+        .add_state(GameplayState::Inactive)
+        .add_system_set(
+            SystemSet::on_enter(GameState::GameplayMode)
+                .with_system(start_gameplay.system())
+                .with_system(diplomacy::seed_faction_knowledge.system())
+                .with_system(hyperspace::initialize_hyperspace.system())
+                .with_system(hyperspace::spawn_sector_markers.system())
+                .with_system(hyperspace::spawn_territory_overlay.system())
+                .with_system(economy::initialize_economy.system())
+                .with_system(ship::load_ship_catalog.system())
+                .with_system(combat::configure_physics.system())
+                .with_system(combat::load_projectile_materials.system())
+                .with_system(timewarp::setup_time_warp_hud.system())
+                .with_system(news::setup_news_hud.system()),
+        )
+        .add_system_set(
+            SystemSet::on_update(GameState::GameplayMode)
+                .with_system(world_clock_update.system())
+                .with_system(timewarp::process_time_warp_input.system())
+                .with_system(timewarp::time_warp_safeguard_system.system())
+                .with_system(timewarp::update_time_warp_hud.system())
+                .with_system(diplomacy::apply_diplomatic_events.system())
+                .with_system(diplomacy::diplomacy_drift_system.system())
+                .with_system(diplomacy::apply_reputation_events.system())
+                .with_system(diplomacy::apply_faction_changes.system())
+                .with_system(diplomacy::apply_territory_knowledge.system())
+                .with_system(strategy::strategic_simulation_system.system())
+                .with_system(news::report_territory_changes.system())
+                .with_system(news::report_faction_changes.system())
+                .with_system(rebellion::unrest_event_system.system())
+                .with_system(rebellion::rebellion_system.system())
+                .with_system(quest::expire_quests.system())
+                .with_system(economy::economy_system.system())
+                .with_system(economy::trade_system.system())
+                .with_system(economy::record_market_prices.system())
+                .with_system(ship::initialize_player_ship.system())
+                .with_system(ship::apply_player_ship.system())
+                .with_system(news::update_news_hud.system())
+                .with_system(hyperspace::update_territory_overlay.system())
+                .with_system(hyperspace::update_hostile_sectors.system())
+                .with_system(hyperspace::refuel_on_arrival_system.system()),
+        )
+        .add_system_set(
+            SystemSet::on_exit(GameState::GameplayMode)
+                .with_system(timewarp::cleanup_time_warp_hud.system())
+                .with_system(news::cleanup_news_hud.system()),
+        )
+        .add_system_set(
+            SystemSet::on_enter(GameplayState::Hyperspace)
+                .with_system(hyperspace::setup_hyperspace.system()),
+        )
+        .add_system_set(
+            SystemSet::on_update(GameplayState::Hyperspace)
+                .with_system(hyperspace::process_keyboard_input.system())
+                .with_system(hyperspace::process_sector_selection.system())
+                .with_system(hyperspace::process_enter_sector_input.system())
+                .with_system(hyperspace::hyperspace_jump_system.system())
+                .with_system(hyperspace::hyperspace_transit_system.system())
+                .with_system(hyperspace::process_route_input.system())
+                .with_system(hyperspace::follow_route_system.system())
+                .with_system(hyperspace::draw_route_system.system())
+                .with_system(hyperspace::gravimetric_detection_system.system())
+                .with_system(hyperspace::survey_on_arrival_system.system())
+                .with_system(hyperspace::render_knowledge_system.system())
+                .with_system(hyperspace::reseed_hyperspace_background.system())
+                .with_system(hyperspace::process_territory_input.system()),
+        )
+        .add_system_set(
+            SystemSet::on_exit(GameplayState::Hyperspace)
+                .with_system(hyperspace::cleanup_hyperspace.system()),
+        )
+        .add_system_set(
+            SystemSet::on_enter(GameplayState::Sectorspace)
+                .with_system(sectorspace::setup_sectorspace.system())
+                .with_system(hyperspace::spawn_encounter_system.system())
+                .with_system(ship::spawn_player_vessel.system())
+                .with_system(ship::setup_power_hud.system())
+                .with_system(combat::setup_combat_hud.system())
+                .with_system(sensors::setup_contacts_hud.system()),
+        )
+        .add_system_set(
+            SystemSet::on_update(GameplayState::Sectorspace)
+                .with_system(sectorspace::update_sectorspace.system())
+                .with_system(timewarp::warp_hazard_system.system())
+                .with_system(sectorspace::process_leave_sector_input.system())
+                .with_system(sectorspace::process_landing_input.system())
+                .with_system(sectorspace::process_mining_input.system())
+                .with_system(sectorspace::orbital_update_system.system().label("orbits"))
+                .with_system(star::level_star_decorations.system().after("orbits"))
+                .with_system(sectorspace::ship_motion_system.system())
+                .with_system(ship::power_demand_system.system())
+                .with_system(ship::power_system.system())
+                .with_system(ship::update_power_hud.system())
+                .with_system(combat::arm_ships.system())
+                .with_system(combat::sync_ship_bodies.system())
+                .with_system(combat::process_combat_input.system())
+                .with_system(combat::hostile_ai_system.system())
+                .with_system(combat::weapon_fire_system.system())
+                .with_system(combat::guidance_system.system())
+                .with_system(combat::point_defence_system.system())
+                .with_system(combat::countermeasure_system.system())
+                .with_system(combat::decoy_system.system())
+                .with_system(combat::projectile_hit_system.system())
+                .with_system(combat::projectile_expiry_system.system())
+                .with_system(combat::shield_recharge_system.system())
+                .with_system(combat::ship_destruction_system.system())
+                .with_system(combat::combat_warp_safeguard.system())
+                .with_system(combat::follow_player_vessel.system())
+                .with_system(combat::update_combat_hud.system())
+                .with_system(sensors::body_signature_system.system())
+                .with_system(sensors::fit_sensors.system())
+                .with_system(sensors::process_sensor_input.system())
+                .with_system(sensors::sensor_power_system.system())
+                .with_system(sensors::ship_signature_system.system())
+                .with_system(sensors::sensor_system.system())
+                .with_system(sensors::contact_visibility_system.system())
+                .with_system(sensors::update_contacts_hud.system())
+                .with_system(trading::process_docking_input.system())
+                .with_system(trading::process_trading_input.system())
+                .with_system(trading::trade_receipt_system.system())
+                .with_system(trading::toggle_trading_screen.system())
+                .with_system(trading::update_trading_screen.system())
+                .with_system(quest::process_quest_input.system())
+                .with_system(quest::complete_quests_on_docking.system())
+                .with_system(shipyard::process_shipyard_input.system())
+                .with_system(ship::refit_system.system())
+                .with_system(shipyard::refit_receipt_system.system())
+                .with_system(shipyard::toggle_shipyard_screen.system())
+                .with_system(shipyard::update_shipyard_screen.system()),
+        )
+        .add_system_set(
+            SystemSet::on_exit(GameplayState::Sectorspace)
+                .with_system(sectorspace::cleanup_sectorspace.system())
+                .with_system(trading::undock_system.system())
+                .with_system(shipyard::cleanup_shipyard_screen.system())
+                .with_system(ship::cleanup_power_hud.system())
+                .with_system(combat::cleanup_combat_hud.system())
+                .with_system(combat::release_camera.system())
+                .with_system(sensors::cleanup_contacts_hud.system())
+                .with_system(sensors::clear_contacts.system()),
+        )
+        .insert_resource(tilespace::Daylight::default())
+        .add_system_set(
+            SystemSet::on_enter(GameplayState::Tilespace)
+                .with_system(tilespace::setup_tilespace.system()),
+        )
+        .add_system_set(
+            SystemSet::on_update(GameplayState::Tilespace)
+                .with_system(tilespace::update_tilespace_lighting.system())
+                .with_system(tilespace::process_liftoff_input.system()),
+        )
+        .add_system_set(
+            SystemSet::on_exit(GameplayState::Tilespace)
+                .with_system(tilespace::cleanup_tilespace.system()),
+        );
+}
 
-pub fn bootstrap_gameplay(app: &mut AppBuilder) {}
+/// Start the Player off in Hyperspace once the Universe they play in exists.
+fn start_gameplay(mut gameplay_state: ResMut<State<GameplayState>>) {
+    if let Err(error) = gameplay_state.set(GameplayState::Hyperspace) {
+        warn!("Unable to start in Hyperspace: {:?}", error);
+    }
+}
@@ -0,0 +1,46 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Real-time-with-pause ship to ship combat in Sectorspace.
+//!
+//! Shots are flown and collided by Rapier, then worn through shields, armor and the
+//! subsystems they were aimed at.
+mod control;
+mod damage;
+mod guidance;
+mod hud;
+mod weapons;
+
+pub use self::control::{
+    arm_ships, combat_warp_safeguard, configure_physics, follow_player_vessel, hostile_ai_system,
+    process_combat_input, release_camera, sync_ship_bodies, Hostile, Targeting,
+};
+pub use self::damage::{
+    armor_resistance, armor_wear, shield_deflection, ArmorLayer, Combatant, DecoyLauncher, Hit,
+    JammerMount, PointDefenceMount, Subsystem, WeaponMount,
+};
+pub use self::guidance::{
+    countermeasure_system, decoy_system, guidance_profile, guidance_system, is_jammed,
+    point_defence_system, proportional_navigation, Decoy, Guidance, GuidanceProfile,
+};
+pub use self::hud::{cleanup_combat_hud, setup_combat_hud, update_combat_hud, CombatHud};
+pub use self::weapons::{
+    load_projectile_materials, projectile_expiry_system, projectile_hit_system, projectile_speed,
+    shield_recharge_system, ship_destruction_system, weapon_fire_system, Projectile,
+    ProjectileMaterials, RANGE_SCALE,
+};
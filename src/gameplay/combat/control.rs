@@ -0,0 +1,337 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Arming ships, the Player's helm and how Hostile ships fight.
+use super::damage::Combatant;
+use super::weapons::{Projectile, RANGE_SCALE};
+use crate::gameplay::economy::Docked;
+use crate::gameplay::hyperspace::Hyperspace;
+use crate::gameplay::sectorspace::{ShipInfo, ShipMotion};
+use crate::gameplay::sensors::{ContactLevel, Contacts, SensorSuite};
+use crate::gameplay::ship::{
+    PlayerShip, PlayerVessel, PowerGrid, ShipCatalog, ShipCatalogHandle, ShipStats, SlotKind,
+};
+use crate::gameplay::timewarp::WarpInterrupt;
+use crate::gameplay::PlayerAvatar;
+use crate::utility::WorldClock;
+use bevy::prelude::*;
+use bevy::render::camera::Camera;
+use bevy_rapier2d::prelude::*;
+use bevy_rapier2d::rapier::math::{Isometry, Vector};
+
+/// Stock design flown by Hostile Encounter ships.
+const HOSTILE_DESIGN: &str = "Raider";
+/// Stock design flown by every other Encounter ship.
+const PATROL_DESIGN: &str = "Courier";
+/// Radius of a ship's collider.
+const SHIP_RADIUS: f32 = 3.0;
+/// Share of their shortest Weapon range Hostile ships close to.
+const ENGAGEMENT_RANGE: f32 = 0.7;
+/// Subsystems the Player can aim for, after the hull.
+const SUBSYSTEM_TARGETS: [SlotKind; 5] = [
+    SlotKind::Weapon,
+    SlotKind::Engine,
+    SlotKind::Reactor,
+    SlotKind::Shield,
+    SlotKind::Sensor,
+];
+
+/// Tag Denoting a ship that attacks the Player
+pub struct Hostile;
+
+/// What a ship is shooting at.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Targeting {
+    pub target: Option<Entity>,
+    /// Subsystem aimed for, the hull when None
+    pub subsystem: Option<SlotKind>,
+    /// Whether Weapons are free to fire
+    pub armed: bool,
+}
+
+/// Space has no down.
+pub fn configure_physics(mut configuration: ResMut<RapierConfiguration>) {
+    configuration.gravity = Vector::zeros();
+}
+
+/// Ready ships in Sectorspace for battle, the Player in their own design and Encounters in stock ones.
+pub fn arm_ships(
+    mut commands: Commands,
+    catalogs: Res<Assets<ShipCatalog>>,
+    catalog_handle: Res<ShipCatalogHandle>,
+    ship: Res<PlayerShip>,
+    query: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&PlayerVessel>,
+            Option<&Hostile>,
+            Option<&PowerGrid>,
+        ),
+        (With<ShipMotion>, Without<Combatant>),
+    >,
+) {
+    let catalog = match catalogs.get(&catalog_handle.0) {
+        Some(catalog) => catalog,
+        None => return,
+    };
+    for (entity, transform, player, hostile, grid) in query.iter() {
+        let design = match (player, hostile) {
+            (Some(_), _) => Some(&ship.design),
+            (None, Some(_)) => catalog.design(HOSTILE_DESIGN),
+            (None, None) => catalog.design(PATROL_DESIGN),
+        };
+        let (design, (hull, equipment)) =
+            match design.and_then(|design| Some((design, design.resolve(catalog)?))) {
+                Some(resolved) => resolved,
+                None => continue,
+            };
+        let stats = ShipStats::new(hull, equipment.iter().copied());
+        let position = transform.translation;
+        let mut entity = commands.entity(entity);
+        entity
+            .insert(Combatant::new(hull, &equipment, &stats, RANGE_SCALE))
+            .insert(Targeting {
+                armed: hostile.is_some(),
+                ..Default::default()
+            })
+            .insert_bundle(RigidBodyBundle {
+                body_type: RigidBodyType::KinematicPositionBased,
+                position: Isometry::new(Vector::new(position.x, position.y), 0.0).into(),
+                ..Default::default()
+            })
+            .insert_bundle(ColliderBundle {
+                shape: ColliderShape::ball(SHIP_RADIUS),
+                ..Default::default()
+            });
+        if grid.is_none() {
+            entity.insert(PowerGrid::new(hull, equipment.iter().copied()));
+        }
+        if player.is_none() {
+            trace!("{} armed for battle", design.name);
+            entity.insert(ShipInfo {
+                name: design.name.clone(),
+                design: design.clone(),
+            });
+        }
+    }
+}
+
+/// Ship motion is flown by [ShipMotion], Rapier only follows it along to find collisions.
+pub fn sync_ship_bodies(
+    mut query: Query<(&Transform, &mut RigidBodyPosition), (With<Combatant>, Without<Projectile>)>,
+) {
+    for (transform, mut position) in query.iter_mut() {
+        let translation = transform.translation;
+        position.next_position = Isometry::new(Vector::new(translation.x, translation.y), 0.0);
+    }
+}
+
+/// Fly the Player's ship while undocked.
+///
+/// Arrow keys thrust, `Tab` picks the next target, `G` the subsystem to aim for, `F` arms or safes
+/// Weapons and `Space` pauses the battle.
+#[allow(clippy::too_many_arguments)]
+pub fn process_combat_input(
+    keyboard: Res<Input<KeyCode>>,
+    docked: Res<Docked>,
+    contacts: Res<Contacts>,
+    mut world_clock: ResMut<WorldClock>,
+    mut configuration: ResMut<RapierConfiguration>,
+    mut player_query: Query<
+        (
+            Entity,
+            &Transform,
+            &Combatant,
+            &mut ShipMotion,
+            &mut Targeting,
+        ),
+        With<PlayerVessel>,
+    >,
+    target_query: Query<(Entity, &Transform, Option<&Hostile>), With<Combatant>>,
+) {
+    if docked.0.is_some() {
+        return;
+    }
+    if keyboard.just_pressed(KeyCode::Space) {
+        if world_clock.paused() {
+            world_clock.unpause();
+        } else {
+            world_clock.pause();
+        }
+        configuration.physics_pipeline_active = !world_clock.paused();
+    }
+    let (entity, transform, combatant, mut motion, mut targeting) =
+        match player_query.iter_mut().next() {
+            Some(player) => player,
+            None => return,
+        };
+
+    let mut direction = Vec2::ZERO;
+    if keyboard.pressed(KeyCode::Up) {
+        direction.y += 1.0;
+    }
+    if keyboard.pressed(KeyCode::Down) {
+        direction.y -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::Left) {
+        direction.x -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::Right) {
+        direction.x += 1.0;
+    }
+    motion.thrust = direction.normalize_or_zero()
+        * combatant.acceleration
+        * combatant.intact_share(SlotKind::Engine);
+
+    if keyboard.just_pressed(KeyCode::Tab) {
+        // Hostile ships first, nearest first, of those on sensors.
+        let origin = transform.translation.truncate();
+        let mut targets: Vec<(bool, f32, Entity)> = target_query
+            .iter()
+            .filter(|(target, _, _)| {
+                *target != entity && contacts.level(*target) != ContactLevel::Unknown
+            })
+            .map(|(target, target_transform, hostile)| {
+                let distance = target_transform.translation.truncate().distance(origin);
+                (hostile.is_none(), distance, target)
+            })
+            .collect();
+        targets.sort_by(|a, b| {
+            (a.0, a.1)
+                .partial_cmp(&(b.0, b.1))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let targets: Vec<Entity> = targets.into_iter().map(|(_, _, target)| target).collect();
+        targeting.target = match targeting.target {
+            Some(current) => targets
+                .iter()
+                .position(|target| *target == current)
+                .and_then(|index| targets.get(index + 1))
+                .or_else(|| targets.first())
+                .copied(),
+            None => targets.first().copied(),
+        };
+    }
+    if keyboard.just_pressed(KeyCode::G) {
+        targeting.subsystem = match targeting.subsystem {
+            None => Some(SUBSYSTEM_TARGETS[0]),
+            Some(current) => SUBSYSTEM_TARGETS
+                .iter()
+                .position(|slot| *slot == current)
+                .and_then(|index| SUBSYSTEM_TARGETS.get(index + 1))
+                .copied(),
+        };
+    }
+    if keyboard.just_pressed(KeyCode::F) {
+        targeting.armed = !targeting.armed;
+        trace!("Weapons {}", if targeting.armed { "armed" } else { "safe" });
+    }
+}
+
+/// Hostile ships close on the Player to just inside Weapon range and open fire, once they have
+/// them on sensors.
+pub fn hostile_ai_system(
+    player_query: Query<(Entity, &Transform, &ShipMotion), With<PlayerVessel>>,
+    mut hostile_query: Query<
+        (
+            &Transform,
+            &mut ShipMotion,
+            &Combatant,
+            &mut Targeting,
+            Option<&SensorSuite>,
+        ),
+        (With<Hostile>, Without<PlayerVessel>),
+    >,
+) {
+    let player = player_query.iter().next();
+    for (transform, mut motion, combatant, mut targeting, sensors) in hostile_query.iter_mut() {
+        let unseen = sensors.map_or(false, |sensors| {
+            sensors.player.level == ContactLevel::Unknown
+        });
+        let (player, player_transform, player_motion) = match player {
+            Some(player) if !unseen => player,
+            _ => {
+                targeting.target = None;
+                motion.thrust = Vec2::ZERO;
+                continue;
+            }
+        };
+        if targeting.target != Some(player) || !targeting.armed {
+            targeting.target = Some(player);
+            targeting.armed = true;
+        }
+        let offset = player_transform.translation.truncate() - transform.translation.truncate();
+        let distance = offset.length();
+        let engagement = combatant.engagement_range() * ENGAGEMENT_RANGE;
+        if engagement <= 0.0 {
+            // Nothing left to fight with.
+            motion.thrust = Vec2::ZERO;
+            continue;
+        }
+        // Match the Player's velocity, closing or opening the range as needed.
+        let desired = player_motion.velocity + offset.normalize_or_zero() * (distance - engagement);
+        let correction = desired - motion.velocity;
+        motion.thrust = correction.normalize_or_zero()
+            * combatant.acceleration
+            * combatant.intact_share(SlotKind::Engine);
+    }
+}
+
+/// Time Warp is no way to fight a battle.
+pub fn combat_warp_safeguard(
+    world_clock: Res<WorldClock>,
+    hostile_query: Query<Entity, With<Hostile>>,
+    mut interrupts: EventWriter<WarpInterrupt>,
+) {
+    if world_clock.warp_level() > 0 && hostile_query.iter().next().is_some() {
+        interrupts.send(WarpInterrupt::new("Hostile ships nearby"));
+    }
+}
+
+/// Keep the camera on the Player's ship in Sectorspace.
+pub fn follow_player_vessel(
+    vessel_query: Query<&GlobalTransform, With<PlayerVessel>>,
+    avatar_query: Query<&GlobalTransform, With<PlayerAvatar>>,
+    mut camera_query: Query<&mut Transform, (With<Camera>, With<Hyperspace>)>,
+) {
+    let (vessel, avatar) = match (vessel_query.iter().next(), avatar_query.iter().next()) {
+        (Some(vessel), Some(avatar)) => (vessel, avatar),
+        _ => return,
+    };
+    // The camera rides along with the Player's Avatar, so is offset from it.
+    let offset = vessel.translation - avatar.translation;
+    for mut transform in camera_query.iter_mut() {
+        transform.translation.x = offset.x;
+        transform.translation.y = offset.y;
+    }
+}
+
+/// Hand the camera back to the Player's Avatar and let time run again on leaving Sectorspace.
+pub fn release_camera(
+    mut world_clock: ResMut<WorldClock>,
+    mut configuration: ResMut<RapierConfiguration>,
+    mut camera_query: Query<&mut Transform, (With<Camera>, With<Hyperspace>)>,
+) {
+    for mut transform in camera_query.iter_mut() {
+        transform.translation.x = 0.0;
+        transform.translation.y = 0.0;
+    }
+    world_clock.unpause();
+    configuration.physics_pipeline_active = true;
+}
@@ -0,0 +1,429 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! How each Weapon family fares against Gravitonic Shields and each kind of Armor.
+use crate::gameplay::ship::{
+    ArmorKind, Equipment, EquipmentKind, Hull, ShipStats, SlotKind, WeaponKind,
+};
+
+/// Share of damage to a targeted subsystem that lands on it rather than the hull.
+const SUBSYSTEM_SHARE: f32 = 0.75;
+/// Subsystem integrity per tonne of Equipment.
+const INTEGRITY_PER_TONNE: f32 = 10.0;
+/// Integrity every subsystem has regardless of mass.
+const BASE_INTEGRITY: f32 = 10.0;
+
+/// Share of a hit Gravitonic Shields turn aside, heavy projectiles bend far more than light.
+pub fn shield_deflection(weapon: WeaponKind) -> f32 {
+    match weapon {
+        WeaponKind::Ballistic => 0.9,
+        WeaponKind::MassDriver => 0.8,
+        WeaponKind::Missile => 0.7,
+        WeaponKind::Nuclear => 0.5,
+        WeaponKind::Laser => 0.2,
+        WeaponKind::ParticleCannon => 0.5,
+    }
+}
+
+/// Share of what gets past the shields a layer of Armor stops.
+///
+/// Ablative Armor boils away under Lasers, Fibrous Armor catches shrapnel and slugs, and Plate
+/// stands up to heavy impacts and particle streams.
+pub fn armor_resistance(weapon: WeaponKind, armor: ArmorKind) -> f32 {
+    match (weapon, armor) {
+        (WeaponKind::Ballistic, ArmorKind::Ablative) => 0.3,
+        (WeaponKind::Ballistic, ArmorKind::Fibrous) => 0.8,
+        (WeaponKind::Ballistic, ArmorKind::Plate) => 0.6,
+        (WeaponKind::MassDriver, ArmorKind::Ablative) => 0.2,
+        (WeaponKind::MassDriver, ArmorKind::Fibrous) => 0.4,
+        (WeaponKind::MassDriver, ArmorKind::Plate) => 0.7,
+        (WeaponKind::Missile, ArmorKind::Ablative) => 0.4,
+        (WeaponKind::Missile, ArmorKind::Fibrous) => 0.6,
+        (WeaponKind::Missile, ArmorKind::Plate) => 0.5,
+        (WeaponKind::Nuclear, ArmorKind::Ablative) => 0.3,
+        (WeaponKind::Nuclear, ArmorKind::Fibrous) => 0.2,
+        (WeaponKind::Nuclear, ArmorKind::Plate) => 0.4,
+        (WeaponKind::Laser, ArmorKind::Ablative) => 0.8,
+        (WeaponKind::Laser, ArmorKind::Fibrous) => 0.2,
+        (WeaponKind::Laser, ArmorKind::Plate) => 0.4,
+        (WeaponKind::ParticleCannon, ArmorKind::Ablative) => 0.3,
+        (WeaponKind::ParticleCannon, ArmorKind::Fibrous) => 0.3,
+        (WeaponKind::ParticleCannon, ArmorKind::Plate) => 0.6,
+    }
+}
+
+/// Armor rating lost per point of damage stopped.
+pub fn armor_wear(armor: ArmorKind) -> f32 {
+    match armor {
+        ArmorKind::Ablative => 1.0,
+        ArmorKind::Fibrous => 0.6,
+        ArmorKind::Plate => 0.3,
+    }
+}
+
+/// Layer of Armor, worn down as it stops hits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArmorLayer {
+    pub equipment: usize,
+    pub kind: ArmorKind,
+    pub rating: f32,
+    pub max_rating: f32,
+}
+
+/// Fitted Equipment that can be shot out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subsystem {
+    /// Index of the Equipment in the fitted design
+    pub equipment: usize,
+    pub name: String,
+    pub slot: SlotKind,
+    pub integrity: f32,
+    pub max_integrity: f32,
+}
+
+impl Subsystem {
+    pub fn is_destroyed(&self) -> bool {
+        self.integrity <= 0.0
+    }
+}
+
+/// Weapon fitted to a ship, and how long until it can fire again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeaponMount {
+    pub equipment: usize,
+    pub name: String,
+    pub weapon: WeaponKind,
+    pub damage: f32,
+    /// Sectorspace units
+    pub range: f32,
+    /// Seconds between shots
+    pub reload: f32,
+    pub cooldown: f32,
+}
+
+/// Point Defence turret, and how long until it can fire again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointDefenceMount {
+    pub equipment: usize,
+    /// Sectorspace units
+    pub range: f32,
+    /// Seconds between shots
+    pub reload: f32,
+    /// Chance each shot hits
+    pub accuracy: f32,
+    pub cooldown: f32,
+}
+
+/// Decoy launcher and the decoys it has left.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecoyLauncher {
+    pub equipment: usize,
+    pub charges: u32,
+    /// Chance each decoy draws off an incoming munition
+    pub decoy: f32,
+    pub cooldown: f32,
+}
+
+/// Jammer blinding munition seekers about the ship.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JammerMount {
+    pub equipment: usize,
+    pub strength: f32,
+    /// Sectorspace units
+    pub range: f32,
+}
+
+/// Where the damage of a single hit went.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Hit {
+    pub shielded: f32,
+    pub armored: f32,
+    pub structural: f32,
+    pub subsystem: f32,
+    /// Equipment knocked out by the hit
+    pub destroyed: Vec<usize>,
+}
+
+/// Ship able to fight, tracking the damage it has taken.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Combatant {
+    pub structure: f32,
+    pub max_structure: f32,
+    pub shield: f32,
+    /// Shield strength with the shield generators still working
+    pub max_shield: f32,
+    /// Shield regained per second while powered
+    pub shield_recharge: f32,
+    /// Shield strength and recharge with every shield generator intact
+    pub rated_shield: (f32, f32),
+    pub armor: Vec<ArmorLayer>,
+    pub subsystems: Vec<Subsystem>,
+    pub weapons: Vec<WeaponMount>,
+    pub point_defence: Vec<PointDefenceMount>,
+    pub decoys: Vec<DecoyLauncher>,
+    pub jammers: Vec<JammerMount>,
+    /// Sectorspace units per second squared with every Drive intact
+    pub acceleration: f32,
+}
+
+impl Combatant {
+    /// Ready a Hull fitted with `equipment` for battle, ranges scaled from km to Sectorspace.
+    pub fn new(
+        hull: &Hull,
+        equipment: &[&Equipment],
+        stats: &ShipStats,
+        range_scale: f32,
+    ) -> Combatant {
+        let mut combatant = Combatant {
+            structure: hull.structure,
+            max_structure: hull.structure,
+            shield: 0.0,
+            max_shield: 0.0,
+            shield_recharge: 0.0,
+            rated_shield: (0.0, 0.0),
+            armor: Vec::new(),
+            subsystems: Vec::new(),
+            weapons: Vec::new(),
+            point_defence: Vec::new(),
+            decoys: Vec::new(),
+            jammers: Vec::new(),
+            acceleration: stats.acceleration,
+        };
+        for (index, item) in equipment.iter().enumerate() {
+            match &item.kind {
+                EquipmentKind::Armor { armor, rating } => {
+                    combatant.armor.push(ArmorLayer {
+                        equipment: index,
+                        kind: *armor,
+                        rating: *rating,
+                        max_rating: *rating,
+                    });
+                    // Armor is worn away rather than shot out.
+                    continue;
+                }
+                EquipmentKind::Shield { strength, recharge } => {
+                    combatant.rated_shield.0 += strength;
+                    combatant.rated_shield.1 += recharge;
+                }
+                EquipmentKind::Weapon {
+                    weapon,
+                    damage,
+                    range,
+                    rate,
+                } => combatant.weapons.push(WeaponMount {
+                    equipment: index,
+                    name: item.name.clone(),
+                    weapon: *weapon,
+                    damage: *damage,
+                    range: range * range_scale,
+                    reload: 1.0 / rate.max(f32::EPSILON),
+                    cooldown: 0.0,
+                }),
+                EquipmentKind::PointDefence {
+                    range,
+                    rate,
+                    accuracy,
+                } => combatant.point_defence.push(PointDefenceMount {
+                    equipment: index,
+                    range: range * range_scale,
+                    reload: 1.0 / rate.max(f32::EPSILON),
+                    accuracy: *accuracy,
+                    cooldown: 0.0,
+                }),
+                EquipmentKind::Countermeasures { charges, decoy } => {
+                    combatant.decoys.push(DecoyLauncher {
+                        equipment: index,
+                        charges: *charges,
+                        decoy: *decoy,
+                        cooldown: 0.0,
+                    })
+                }
+                EquipmentKind::Jammer { strength, range } => combatant.jammers.push(JammerMount {
+                    equipment: index,
+                    strength: *strength,
+                    range: range * range_scale,
+                }),
+                _ => {}
+            }
+            let integrity = BASE_INTEGRITY + item.mass * INTEGRITY_PER_TONNE;
+            combatant.subsystems.push(Subsystem {
+                equipment: index,
+                name: item.name.clone(),
+                slot: item.kind.slot(),
+                integrity,
+                max_integrity: integrity,
+            });
+        }
+        combatant.refresh_shield();
+        combatant.shield = combatant.max_shield;
+        combatant
+    }
+    pub fn is_destroyed(&self) -> bool {
+        self.structure <= 0.0
+    }
+    /// Whether a fitted piece of Equipment still works.
+    pub fn is_intact(&self, equipment: usize) -> bool {
+        self.subsystems
+            .iter()
+            .find(|subsystem| subsystem.equipment == equipment)
+            .map_or(true, |subsystem| !subsystem.is_destroyed())
+    }
+    /// Share of Subsystems in a slot still working, 1.0 when there are none.
+    pub fn intact_share(&self, slot: SlotKind) -> f32 {
+        let (intact, total) = self
+            .subsystems
+            .iter()
+            .filter(|subsystem| subsystem.slot == slot)
+            .fold((0, 0), |(intact, total), subsystem| {
+                (intact + u32::from(!subsystem.is_destroyed()), total + 1)
+            });
+        if total == 0 {
+            1.0
+        } else {
+            intact as f32 / total as f32
+        }
+    }
+    pub fn armor_rating(&self) -> f32 {
+        self.armor.iter().map(|layer| layer.rating).sum()
+    }
+    pub fn max_armor_rating(&self) -> f32 {
+        self.armor.iter().map(|layer| layer.max_rating).sum()
+    }
+    /// Longest reach of any working Weapon.
+    pub fn weapon_range(&self) -> f32 {
+        self.weapons
+            .iter()
+            .filter(|mount| self.is_intact(mount.equipment))
+            .map(|mount| mount.range)
+            .fold(0.0, f32::max)
+    }
+    /// Reach of the shortest ranged working Weapon, so all of them bear.
+    pub fn engagement_range(&self) -> f32 {
+        self.weapons
+            .iter()
+            .filter(|mount| self.is_intact(mount.equipment))
+            .map(|mount| mount.range)
+            .reduce(f32::min)
+            .unwrap_or(0.0)
+    }
+    /// Decoys left across every launcher still working.
+    pub fn decoys_left(&self) -> u32 {
+        self.decoys
+            .iter()
+            .filter(|launcher| self.is_intact(launcher.equipment))
+            .map(|launcher| launcher.charges)
+            .sum()
+    }
+    /// Take a hit through the shields and armor, aimed at a subsystem in `target` if any.
+    pub fn hit(&mut self, weapon: WeaponKind, damage: f32, target: Option<SlotKind>) -> Hit {
+        let mut hit = Hit::default();
+        let mut remaining = damage;
+
+        hit.shielded = (remaining * shield_deflection(weapon)).min(self.shield);
+        self.shield -= hit.shielded;
+        remaining -= hit.shielded;
+
+        for layer in self.armor.iter_mut() {
+            let wear = armor_wear(layer.kind);
+            let stopped =
+                (remaining * armor_resistance(weapon, layer.kind)).min(layer.rating / wear);
+            layer.rating = (layer.rating - stopped * wear).max(0.0);
+            hit.armored += stopped;
+            remaining -= stopped;
+        }
+
+        let subsystem = target.and_then(|slot| {
+            self.subsystems
+                .iter_mut()
+                .find(|subsystem| subsystem.slot == slot && !subsystem.is_destroyed())
+        });
+        if let Some(subsystem) = subsystem {
+            hit.subsystem = remaining * SUBSYSTEM_SHARE;
+            remaining -= hit.subsystem;
+            subsystem.integrity -= hit.subsystem;
+            if subsystem.is_destroyed() {
+                hit.destroyed.push(subsystem.equipment);
+            }
+        }
+        hit.structural = remaining;
+        self.structure -= remaining;
+
+        if !hit.destroyed.is_empty() {
+            self.refresh_shield();
+        }
+        hit
+    }
+    /// Regain shield strength over `seconds` while its generators are running.
+    pub fn recharge(&mut self, seconds: f32) {
+        self.shield = (self.shield + self.shield_recharge * seconds).min(self.max_shield);
+    }
+    /// Shield strength and recharge are lost with the shield generators.
+    fn refresh_shield(&mut self) {
+        let working = self.intact_share(SlotKind::Shield);
+        self.max_shield = self.rated_shield.0 * working;
+        self.shield_recharge = self.rated_shield.1 * working;
+        self.shield = self.shield.min(self.max_shield);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::ship::test_catalog;
+
+    #[test]
+    fn test_combatant_hit() {
+        let catalog = test_catalog();
+        let (hull, equipment) = catalog
+            .design("Brawler")
+            .unwrap()
+            .resolve(&catalog)
+            .unwrap();
+        let stats = ShipStats::new(hull, equipment.iter().copied());
+        let mut combatant = Combatant::new(hull, &equipment, &stats, 20.0);
+        assert_eq!(combatant.max_shield, 20.0);
+        assert_eq!(combatant.weapon_range(), 600.0);
+        // Armor is worn down rather than being a subsystem.
+        assert_eq!(combatant.subsystems.len(), 3);
+
+        // Slugs are mostly turned aside by the shield, but lasers cut through it.
+        let slug = combatant.clone().hit(WeaponKind::Ballistic, 10.0, None);
+        let beam = combatant.clone().hit(WeaponKind::Laser, 10.0, None);
+        assert!(slug.shielded > beam.shielded);
+
+        // Once the shield is down, Ablative Armor soaks up most of a Laser.
+        combatant.shield = 0.0;
+        let beam = combatant.clone().hit(WeaponKind::Laser, 10.0, None);
+        let slug = combatant.clone().hit(WeaponKind::MassDriver, 10.0, None);
+        assert!(beam.armored > slug.armored);
+
+        // Hammering the shield generator knocks it out along with the shield.
+        let shield = combatant
+            .subsystems
+            .iter()
+            .find(|subsystem| subsystem.slot == SlotKind::Shield)
+            .unwrap()
+            .equipment;
+        combatant.armor.clear();
+        let hit = combatant.hit(WeaponKind::MassDriver, 100.0, Some(SlotKind::Shield));
+        assert_eq!(hit.destroyed, vec![shield]);
+        assert!(!combatant.is_intact(shield));
+        assert_eq!(combatant.max_shield, 0.0);
+        assert!((combatant.structure - 275.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,413 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Guided munitions, and the Point Defence, decoys and jamming that counter them.
+use super::damage::Combatant;
+use super::weapons::{Projectile, ProjectileMaterials};
+use crate::gameplay::sectorspace::{SectorSpace, ShipMotion};
+use crate::gameplay::ship::{PowerGrid, WeaponKind};
+use crate::generation::UniverseSeed;
+use crate::utility::WorldClock;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use bevy_rapier2d::prelude::*;
+use bevy_rapier2d::rapier::math::Vector;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro128PlusPlus;
+
+/// Sectorspace units from its target an incoming munition draws out decoys.
+const DECOY_DISTANCE: f32 = 300.0;
+/// Seconds between decoys from the same launcher.
+const DECOY_RELOAD: f32 = 2.0;
+/// Seconds a decoy burns for.
+const DECOY_LIFETIME: f32 = 6.0;
+/// Sectorspace units per second decoys are thrown clear of the ship.
+const DECOY_SPEED: f32 = 30.0;
+
+/// How a guided munition flies and sees.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GuidanceProfile {
+    /// Seconds of burn
+    pub fuel: f32,
+    /// Sectorspace units per second squared
+    pub acceleration: f32,
+    /// Sectorspace units the seeker is rated to
+    pub seeker_range: f32,
+    /// Jamming the seeker sees through at its rated range
+    pub seeker_strength: f32,
+    /// Proportional Navigation constant
+    pub navigation: f32,
+    /// Point Defence hits it takes to bring down
+    pub integrity: u32,
+}
+
+/// How Missiles and Nuclear Torpedoes fly, None for the unguided Weapon families.
+pub fn guidance_profile(weapon: WeaponKind) -> Option<GuidanceProfile> {
+    match weapon {
+        WeaponKind::Missile => Some(GuidanceProfile {
+            fuel: 10.0,
+            acceleration: 200.0,
+            seeker_range: 400.0,
+            seeker_strength: 1.0,
+            navigation: 4.0,
+            integrity: 1,
+        }),
+        WeaponKind::Nuclear => Some(GuidanceProfile {
+            fuel: 20.0,
+            acceleration: 80.0,
+            seeker_range: 600.0,
+            seeker_strength: 2.0,
+            navigation: 3.0,
+            integrity: 3,
+        }),
+        _ => None,
+    }
+}
+
+/// Guided munition homing on a target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Guidance {
+    /// Ship, or decoy, the seeker is locked on to
+    pub target: Option<Entity>,
+    pub profile: GuidanceProfile,
+    /// Seconds of burn left
+    pub fuel: f32,
+    pub integrity: u32,
+    /// Whether jamming has blinded the seeker
+    pub jammed: bool,
+}
+
+impl Guidance {
+    pub fn new(target: Option<Entity>, profile: GuidanceProfile) -> Guidance {
+        Guidance {
+            target,
+            profile,
+            fuel: profile.fuel,
+            integrity: profile.integrity,
+            jammed: false,
+        }
+    }
+}
+
+/// Decoy flare drifting clear of the ship that launched it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decoy {
+    pub velocity: Vec2,
+    /// Seconds of world time before it burns out
+    pub lifetime: f32,
+}
+
+/// Steer by Proportional Navigation, turning in proportion to how fast the line of sight to the
+/// target rotates and spending whatever thrust is left closing on it.
+pub fn proportional_navigation(
+    offset: Vec2,
+    relative_velocity: Vec2,
+    navigation: f32,
+    acceleration: f32,
+) -> Vec2 {
+    let distance_squared = offset.length_squared();
+    if distance_squared <= f32::EPSILON {
+        return Vec2::ZERO;
+    }
+    let distance = distance_squared.sqrt();
+    let line_of_sight = offset / distance;
+    let rotation =
+        (offset.x * relative_velocity.y - offset.y * relative_velocity.x) / distance_squared;
+    let closing = -offset.dot(relative_velocity) / distance;
+    let lateral = (navigation * closing.max(0.0) * rotation).clamp(-acceleration, acceleration);
+    let along = (acceleration * acceleration - lateral * lateral).sqrt();
+    Vec2::new(-line_of_sight.y, line_of_sight.x) * lateral + line_of_sight * along
+}
+
+/// Whether `jamming` drowns out a seeker `distance` from its target, the closer it gets the
+/// stronger the return it burns through with.
+pub fn is_jammed(profile: &GuidanceProfile, distance: f32, jamming: f32) -> bool {
+    let burn_through = (profile.seeker_range / distance.max(1.0)).max(1.0);
+    jamming > profile.seeker_strength * burn_through
+}
+
+/// Fly guided munitions at their targets while they have fuel, unless jammed.
+pub fn guidance_system(
+    world_clock: Res<WorldClock>,
+    mut munition_query: Query<(
+        &Transform,
+        &Projectile,
+        &mut Guidance,
+        &mut RigidBodyVelocity,
+    )>,
+    target_query: Query<(&Transform, Option<&ShipMotion>, Option<&Decoy>), Without<Projectile>>,
+    jammer_query: Query<(Entity, &Transform, &Combatant, Option<&PowerGrid>)>,
+) {
+    let seconds = world_clock.delta().as_secs_f32();
+    if seconds <= 0.0 {
+        return;
+    }
+    let jammers: Vec<(Entity, Vec2, f32, f32)> = jammer_query
+        .iter()
+        .flat_map(|(entity, transform, combatant, grid)| {
+            combatant
+                .jammers
+                .iter()
+                .filter(move |jammer| {
+                    combatant.is_intact(jammer.equipment)
+                        && grid.map_or(true, |grid| grid.is_running(jammer.equipment))
+                })
+                .map(move |jammer| {
+                    let position = transform.translation.truncate();
+                    (entity, position, jammer.strength, jammer.range)
+                })
+        })
+        .collect();
+    for (transform, projectile, mut guidance, mut velocity) in munition_query.iter_mut() {
+        if guidance.fuel <= 0.0 {
+            continue;
+        }
+        guidance.fuel -= seconds;
+        let position = transform.translation.truncate();
+        let current = Vec2::new(velocity.linvel.x, velocity.linvel.y);
+        let target = guidance.target.and_then(|target| {
+            target_query
+                .get(target)
+                .ok()
+                .map(|(target_transform, motion, decoy)| {
+                    let target_velocity = motion
+                        .map(|motion| motion.velocity)
+                        .or_else(|| decoy.map(|decoy| decoy.velocity))
+                        .unwrap_or_default();
+                    (target_transform.translation.truncate(), target_velocity)
+                })
+        });
+        if target.is_none() {
+            guidance.target = None;
+        }
+        let jamming: f32 = jammers
+            .iter()
+            .filter(|(entity, jammer, _, range)| {
+                *entity != projectile.source && jammer.distance(position) <= *range
+            })
+            .map(|(_, _, strength, _)| strength)
+            .sum();
+        let profile = guidance.profile;
+        let steering = target.and_then(|(target_position, target_velocity)| {
+            let offset = target_position - position;
+            guidance.jammed = is_jammed(&profile, offset.length(), jamming);
+            if guidance.jammed {
+                None
+            } else {
+                Some(proportional_navigation(
+                    offset,
+                    target_velocity - current,
+                    profile.navigation,
+                    profile.acceleration,
+                ))
+            }
+        });
+        // Without a lock it burns on along whatever heading it has.
+        let acceleration =
+            steering.unwrap_or_else(|| current.normalize_or_zero() * profile.acceleration);
+        velocity.linvel += Vector::new(acceleration.x, acceleration.y) * seconds;
+    }
+}
+
+/// Deterministic generator for a ship's rolls this frame, the same Universe at the same moment
+/// always plays out the same way.
+fn combat_rng(universe_seed: u64, seconds: f64, ship: Entity) -> Xoshiro128PlusPlus {
+    Xoshiro128PlusPlus::seed_from_u64(universe_seed ^ seconds.to_bits() ^ ship.to_bits())
+}
+
+/// Point Defence turrets shoot down munitions homing on their ship, nearest first.
+pub fn point_defence_system(
+    mut commands: Commands,
+    world_clock: Res<WorldClock>,
+    universe_seed: Res<UniverseSeed>,
+    mut ship_query: Query<(Entity, &Transform, &mut Combatant, Option<&mut PowerGrid>)>,
+    mut munition_query: Query<(Entity, &Transform, &mut Guidance)>,
+) {
+    let seconds = world_clock.delta().as_secs_f32();
+    if seconds <= 0.0 {
+        return;
+    }
+    let now = world_clock.seconds_since_epoch();
+    let mut destroyed = HashSet::default();
+    for (ship, transform, mut combatant, mut grid) in ship_query.iter_mut() {
+        let mut rng = combat_rng(universe_seed.0, now, ship);
+        let position = transform.translation.truncate();
+        let mut threats: Vec<(f32, Entity)> = munition_query
+            .iter()
+            .filter(|(munition, _, guidance)| {
+                guidance.target == Some(ship) && !destroyed.contains(munition)
+            })
+            .map(|(munition, munition_transform, _)| {
+                let distance = munition_transform.translation.truncate().distance(position);
+                (distance, munition)
+            })
+            .collect();
+        threats.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        for index in 0..combatant.point_defence.len() {
+            let equipment = combatant.point_defence[index].equipment;
+            let intact = combatant.is_intact(equipment);
+            // Turrets only draw power while tracking something.
+            if let Some(grid) = grid.as_mut() {
+                grid.set_equipment_active(equipment, intact && !threats.is_empty());
+            }
+            let running = grid
+                .as_ref()
+                .map_or(true, |grid| grid.is_running(equipment));
+            let mount = &mut combatant.point_defence[index];
+            mount.cooldown = (mount.cooldown - seconds).max(0.0);
+            if !intact || !running || mount.cooldown > 0.0 {
+                continue;
+            }
+            let target = threats
+                .iter()
+                .find(|(distance, munition)| {
+                    *distance <= mount.range && !destroyed.contains(munition)
+                })
+                .map(|(_, munition)| *munition);
+            let munition = match target {
+                Some(munition) => munition,
+                None => continue,
+            };
+            mount.cooldown = mount.reload;
+            if rng.gen::<f32>() >= mount.accuracy {
+                continue;
+            }
+            if let Ok((_, _, mut guidance)) = munition_query.get_mut(munition) {
+                guidance.integrity = guidance.integrity.saturating_sub(1);
+                if guidance.integrity == 0 {
+                    trace!("Point Defence shot down an incoming munition");
+                    destroyed.insert(munition);
+                    commands.entity(munition).despawn();
+                }
+            }
+        }
+    }
+}
+
+/// Throw out decoys as munitions close in, each one drawing some of them off.
+pub fn countermeasure_system(
+    mut commands: Commands,
+    world_clock: Res<WorldClock>,
+    universe_seed: Res<UniverseSeed>,
+    materials: Res<ProjectileMaterials>,
+    mut ship_query: Query<(
+        Entity,
+        &Transform,
+        &ShipMotion,
+        &SectorSpace,
+        &mut Combatant,
+    )>,
+    mut munition_query: Query<(&Transform, &mut Guidance)>,
+) {
+    let seconds = world_clock.delta().as_secs_f32();
+    if seconds <= 0.0 {
+        return;
+    }
+    let now = world_clock.seconds_since_epoch();
+    for (ship, transform, motion, sector_space, mut combatant) in ship_query.iter_mut() {
+        for launcher in combatant.decoys.iter_mut() {
+            launcher.cooldown = (launcher.cooldown - seconds).max(0.0);
+        }
+        let position = transform.translation.truncate();
+        let incoming = munition_query.iter().any(|(munition, guidance)| {
+            guidance.target == Some(ship)
+                && munition.translation.truncate().distance(position) <= DECOY_DISTANCE
+        });
+        if !incoming {
+            continue;
+        }
+        let ready = (0..combatant.decoys.len()).find(|index| {
+            let launcher = &combatant.decoys[*index];
+            launcher.charges > 0
+                && launcher.cooldown <= 0.0
+                && combatant.is_intact(launcher.equipment)
+        });
+        let launcher = match ready {
+            Some(index) => &mut combatant.decoys[index],
+            None => continue,
+        };
+        launcher.charges -= 1;
+        launcher.cooldown = DECOY_RELOAD;
+        let angle = combat_rng(universe_seed.0, now, ship).gen_range(0.0..std::f32::consts::TAU);
+        let decoy = commands
+            .spawn_bundle(SpriteBundle {
+                material: materials.decoy.clone(),
+                sprite: Sprite::new(Vec2::new(3.0, 3.0)),
+                transform: Transform::from_translation(position.extend(3.0)),
+                ..Default::default()
+            })
+            .insert(SectorSpace(sector_space.0))
+            .insert(Decoy {
+                velocity: motion.velocity + Vec2::new(angle.cos(), angle.sin()) * DECOY_SPEED,
+                lifetime: DECOY_LIFETIME,
+            })
+            .id();
+        trace!("Decoy launched, {} left", launcher.charges);
+        for (munition, mut guidance) in munition_query.iter_mut() {
+            let close = munition.translation.truncate().distance(position) <= DECOY_DISTANCE;
+            if guidance.target == Some(ship) && close && rng.gen::<f32>() < launcher.decoy {
+                guidance.target = Some(decoy);
+            }
+        }
+    }
+}
+
+/// Drift decoys along until they burn out.
+pub fn decoy_system(
+    mut commands: Commands,
+    world_clock: Res<WorldClock>,
+    mut query: Query<(Entity, &mut Transform, &mut Decoy)>,
+) {
+    let seconds = world_clock.delta().as_secs_f32();
+    for (entity, mut transform, mut decoy) in query.iter_mut() {
+        transform.translation += (decoy.velocity * seconds).extend(0.0);
+        decoy.lifetime -= seconds;
+        if decoy.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proportional_navigation() {
+        // Closing straight on a target, the line of sight holds still so all thrust goes ahead.
+        let ahead =
+            proportional_navigation(Vec2::new(100.0, 0.0), Vec2::new(-50.0, 0.0), 4.0, 10.0);
+        assert!((ahead - Vec2::new(10.0, 0.0)).length() < 1e-4);
+
+        // A target crossing to the left is led by turning left.
+        let crossing =
+            proportional_navigation(Vec2::new(100.0, 0.0), Vec2::new(-50.0, 20.0), 4.0, 10.0);
+        assert!(crossing.y > 0.0);
+        assert!((crossing.length() - 10.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_jamming() {
+        let missile = guidance_profile(WeaponKind::Missile).unwrap();
+        assert!(guidance_profile(WeaponKind::Laser).is_none());
+        // An ECM Suite blinds a missile seeker at range, until it burns through up close.
+        assert!(is_jammed(&missile, 400.0, 2.0));
+        assert!(!is_jammed(&missile, 100.0, 2.0));
+        assert!(!is_jammed(&missile, 400.0, 0.0));
+    }
+}
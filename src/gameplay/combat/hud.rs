@@ -0,0 +1,153 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Heads up display of the Player's ship and whatever they have targeted.
+use super::control::{Hostile, Targeting};
+use super::damage::Combatant;
+use super::guidance::Guidance;
+use crate::gameplay::economy::Docked;
+use crate::gameplay::sectorspace::ShipInfo;
+use crate::gameplay::sensors::{ContactLevel, Contacts};
+use crate::gameplay::ship::PlayerVessel;
+use crate::utility::WorldClock;
+use bevy::prelude::*;
+
+/// Tag Denoting the Combat HUD
+pub struct CombatHud;
+
+pub fn setup_combat_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn_bundle(TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    top: Val::Px(5.0),
+                    left: Val::Px(5.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            text: Text::with_section(
+                String::new(),
+                TextStyle {
+                    font: asset_server.load("fonts/spacemono.ttf"),
+                    font_size: 14.0,
+                    color: Color::rgb(1.0, 0.7, 0.7),
+                },
+                Default::default(),
+            ),
+            ..Default::default()
+        })
+        .insert(CombatHud);
+}
+
+fn condition(combatant: &Combatant) -> String {
+    format!(
+        "Hull {:.0}/{:.0}  Shield {:.0}/{:.0}  Armor {:.0}/{:.0}",
+        combatant.structure.max(0.0),
+        combatant.max_structure,
+        combatant.shield,
+        combatant.max_shield,
+        combatant.armor_rating(),
+        combatant.max_armor_rating()
+    )
+}
+
+/// Show the Player's hull, shields and armor, and the state of their target.
+pub fn update_combat_hud(
+    world_clock: Res<WorldClock>,
+    docked: Res<Docked>,
+    contacts: Res<Contacts>,
+    player_query: Query<(Entity, &Transform, &Combatant, &Targeting), With<PlayerVessel>>,
+    target_query: Query<(&Transform, &Combatant, Option<&ShipInfo>, Option<&Hostile>)>,
+    munition_query: Query<&Guidance>,
+    mut hud_query: Query<&mut Text, With<CombatHud>>,
+) {
+    let mut lines = Vec::new();
+    match player_query.iter().next() {
+        _ if docked.0.is_some() => {}
+        None => lines.push(String::from("No ship")),
+        Some((entity, transform, combatant, targeting)) => {
+            lines.push(condition(combatant));
+            let incoming = munition_query
+                .iter()
+                .filter(|guidance| guidance.target == Some(entity))
+                .count();
+            if incoming > 0 || !combatant.decoys.is_empty() {
+                lines.push(format!(
+                    "Incoming munitions {}  Decoys {}",
+                    incoming,
+                    combatant.decoys_left()
+                ));
+            }
+            lines.push(format!(
+                "Weapons {}  Aiming at {}{}",
+                if targeting.armed { "ARMED" } else { "SAFE" },
+                targeting
+                    .subsystem
+                    .map_or(String::from("Hull"), |slot| format!("{:?}", slot)),
+                if world_clock.paused() {
+                    "  [PAUSED]"
+                } else {
+                    ""
+                }
+            ));
+            let target = targeting.target.and_then(|target| {
+                let identified = contacts.level(target) == ContactLevel::Identified;
+                target_query
+                    .get(target)
+                    .ok()
+                    .map(|target| (target, identified))
+            });
+            if let Some(((target_transform, target, info, hostile), identified)) = target {
+                let distance = target_transform
+                    .translation
+                    .truncate()
+                    .distance(transform.translation.truncate());
+                if !identified {
+                    lines.push(format!("Target: Unidentified contact at {:.0}", distance));
+                } else {
+                    lines.push(format!(
+                        "Target: {}{} at {:.0}",
+                        info.map_or("Unknown ship", |info| info.name.as_str()),
+                        if hostile.is_some() { " (Hostile)" } else { "" },
+                        distance
+                    ));
+                }
+                lines.push(format!("  {}", condition(target)));
+                for subsystem in target.subsystems.iter() {
+                    if subsystem.is_destroyed() {
+                        lines.push(format!("  {} destroyed", subsystem.name));
+                    }
+                }
+            }
+            lines.push(String::from(
+                "[Arrows] Thrust [Tab] Target [G] Subsystem [F] Arm [Space] Pause",
+            ));
+        }
+    }
+    for mut text in hud_query.iter_mut() {
+        text.sections[0].value = lines.join("\n");
+    }
+}
+
+pub fn cleanup_combat_hud(mut commands: Commands, query: Query<Entity, With<CombatHud>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
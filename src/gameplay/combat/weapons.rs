@@ -0,0 +1,336 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Firing Weapons, the shots they put out and the ships they wreck.
+use super::control::{Hostile, Targeting};
+use super::damage::Combatant;
+use super::guidance::{guidance_profile, Guidance};
+use crate::gameplay::sectorspace::{SectorSpace, ShipInfo, ShipMotion};
+use crate::gameplay::ship::{PlayerVessel, PowerGrid, SlotKind, WeaponKind};
+use crate::utility::WorldClock;
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use bevy_rapier2d::prelude::*;
+use bevy_rapier2d::rapier::math::{Isometry, Vector};
+use hexgrid::Coordinate;
+
+/// Sectorspace units per km of Weapon range.
+pub const RANGE_SCALE: f32 = 20.0;
+/// Radius of a shot's collider.
+const PROJECTILE_RADIUS: f32 = 1.0;
+/// Shots fly on a little past their Weapon's range before burning out.
+const OVERSHOOT: f32 = 1.2;
+
+/// Sectorspace units per second a Weapon family's shots travel.
+pub fn projectile_speed(weapon: WeaponKind) -> f32 {
+    match weapon {
+        WeaponKind::Ballistic => 300.0,
+        WeaponKind::MassDriver => 600.0,
+        WeaponKind::Missile => 150.0,
+        WeaponKind::Nuclear => 100.0,
+        WeaponKind::Laser => 3000.0,
+        WeaponKind::ParticleCannon => 1200.0,
+    }
+}
+
+fn projectile_color(weapon: WeaponKind) -> Color {
+    match weapon {
+        WeaponKind::Ballistic => Color::rgb(0.9, 0.9, 0.6),
+        WeaponKind::MassDriver => Color::rgb(0.7, 0.8, 1.0),
+        WeaponKind::Missile => Color::rgb(1.0, 0.6, 0.2),
+        WeaponKind::Nuclear => Color::rgb(1.0, 1.0, 0.2),
+        WeaponKind::Laser => Color::rgb(1.0, 0.2, 0.2),
+        WeaponKind::ParticleCannon => Color::rgb(0.4, 1.0, 1.0),
+    }
+}
+
+/// Shot in flight from a Weapon.
+#[derive(Debug, Clone)]
+pub struct Projectile {
+    /// Ship that fired it
+    pub source: Entity,
+    pub weapon: WeaponKind,
+    pub damage: f32,
+    /// Subsystem it was aimed at, the hull when None
+    pub subsystem: Option<SlotKind>,
+    /// Seconds of world time before it burns out
+    pub lifetime: f32,
+}
+
+/// Sprite materials for each Weapon family's shots, and for decoys.
+#[derive(Default)]
+pub struct ProjectileMaterials {
+    pub weapons: HashMap<WeaponKind, Handle<ColorMaterial>>,
+    pub decoy: Handle<ColorMaterial>,
+}
+
+pub fn load_projectile_materials(
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut projectile_materials: ResMut<ProjectileMaterials>,
+) {
+    for weapon in [
+        WeaponKind::Ballistic,
+        WeaponKind::MassDriver,
+        WeaponKind::Missile,
+        WeaponKind::Nuclear,
+        WeaponKind::Laser,
+        WeaponKind::ParticleCannon,
+    ] {
+        let material = materials.add(projectile_color(weapon).into());
+        projectile_materials.weapons.insert(weapon, material);
+    }
+    projectile_materials.decoy = materials.add(Color::rgb(1.0, 1.0, 0.9).into());
+}
+
+/// Fire every armed, powered and intact Weapon whose target is in range, leading the target.
+pub fn weapon_fire_system(
+    mut commands: Commands,
+    world_clock: Res<WorldClock>,
+    materials: Res<ProjectileMaterials>,
+    mut shooter_query: Query<(
+        Entity,
+        &Transform,
+        &ShipMotion,
+        &SectorSpace,
+        &Targeting,
+        &mut Combatant,
+        Option<&mut PowerGrid>,
+    )>,
+    target_query: Query<(&Transform, &ShipMotion), With<Combatant>>,
+) {
+    let seconds = world_clock.delta().as_secs_f32();
+    if seconds <= 0.0 {
+        return;
+    }
+    for (entity, transform, motion, sector_space, targeting, mut combatant, mut grid) in
+        shooter_query.iter_mut()
+    {
+        for mount in combatant.weapons.iter_mut() {
+            mount.cooldown = (mount.cooldown - seconds).max(0.0);
+        }
+        let target = targeting
+            .target
+            .filter(|_| targeting.armed)
+            .and_then(|target| target_query.get(target).ok());
+        // Weapons only draw power while there is something to shoot at.
+        if let Some(grid) = grid.as_mut() {
+            for mount in combatant.weapons.iter() {
+                grid.set_equipment_active(mount.equipment, target.is_some());
+            }
+        }
+        let (target_transform, target_motion) = match target {
+            Some(target) => target,
+            None => continue,
+        };
+        let origin = transform.translation.truncate();
+        let offset = target_transform.translation.truncate() - origin;
+        let distance = offset.length();
+        let ready: Vec<usize> = (0..combatant.weapons.len())
+            .filter(|index| {
+                let mount = &combatant.weapons[*index];
+                mount.cooldown <= 0.0
+                    && distance <= mount.range
+                    && combatant.is_intact(mount.equipment)
+                    && grid
+                        .as_ref()
+                        .map_or(true, |grid| grid.is_running(mount.equipment))
+            })
+            .collect();
+        for index in ready {
+            let mount = &mut combatant.weapons[index];
+            mount.cooldown = mount.reload;
+            // Aim where the target will be by the time the shot gets there.
+            let speed = projectile_speed(mount.weapon);
+            let relative = target_motion.velocity - motion.velocity;
+            let aim = offset + relative * (distance / speed);
+            let velocity = motion.velocity + aim.normalize_or_zero() * speed;
+            let projectile = Projectile {
+                source: entity,
+                weapon: mount.weapon,
+                damage: mount.damage,
+                subsystem: targeting.subsystem,
+                lifetime: mount.range / speed * OVERSHOOT,
+            };
+            trace!("{} fired at {:.0} units", mount.name, distance);
+            let shot = spawn_projectile(
+                &mut commands,
+                &materials,
+                projectile,
+                sector_space.0,
+                origin,
+                velocity,
+            );
+            // Missiles and Torpedoes home on the target from there.
+            if let Some(profile) = guidance_profile(mount.weapon) {
+                commands
+                    .entity(shot)
+                    .insert(Guidance::new(targeting.target, profile));
+            }
+        }
+    }
+}
+
+fn spawn_projectile(
+    commands: &mut Commands,
+    materials: &ProjectileMaterials,
+    projectile: Projectile,
+    location: Coordinate<i32>,
+    origin: Vec2,
+    velocity: Vec2,
+) -> Entity {
+    commands
+        .spawn_bundle(SpriteBundle {
+            material: materials
+                .weapons
+                .get(&projectile.weapon)
+                .cloned()
+                .unwrap_or_default(),
+            sprite: Sprite::new(Vec2::new(2.0, 2.0)),
+            transform: Transform::from_translation(origin.extend(3.0)),
+            ..Default::default()
+        })
+        .insert_bundle(RigidBodyBundle {
+            body_type: RigidBodyType::Dynamic,
+            position: Isometry::new(Vector::new(origin.x, origin.y), 0.0).into(),
+            velocity: RigidBodyVelocity {
+                linvel: Vector::new(velocity.x, velocity.y),
+                angvel: 0.0,
+            },
+            // Fast shots would otherwise tunnel straight through small ships.
+            ccd: RigidBodyCcd {
+                ccd_enabled: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert_bundle(ColliderBundle {
+            collider_type: ColliderType::Sensor,
+            shape: ColliderShape::ball(PROJECTILE_RADIUS),
+            flags: ColliderFlags {
+                active_events: ActiveEvents::INTERSECTION_EVENTS,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(RigidBodyPositionSync::Discrete)
+        .insert(SectorSpace(location))
+        .insert(projectile)
+        .id()
+}
+
+/// Apply hits as shots cross ships, shutting down any Equipment they knock out.
+pub fn projectile_hit_system(
+    mut commands: Commands,
+    mut intersections: EventReader<IntersectionEvent>,
+    projectile_query: Query<&Projectile>,
+    mut ship_query: Query<(&mut Combatant, Option<&mut PowerGrid>, Option<&Hostile>)>,
+    player_query: Query<Entity, With<PlayerVessel>>,
+) {
+    let mut spent = HashSet::default();
+    for event in intersections.iter().filter(|event| event.intersecting) {
+        let (first, second) = (event.collider1.entity(), event.collider2.entity());
+        let (shot, ship) = if projectile_query.get(first).is_ok() {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        let projectile = match projectile_query.get(shot) {
+            Ok(projectile) => projectile,
+            Err(_) => continue,
+        };
+        if projectile.source == ship || spent.contains(&shot) {
+            continue;
+        }
+        let (mut combatant, grid, hostile) = match ship_query.get_mut(ship) {
+            Ok(ship) => ship,
+            Err(_) => continue,
+        };
+        let hit = combatant.hit(projectile.weapon, projectile.damage, projectile.subsystem);
+        trace!(
+            "Hit for {:.1}: {:.1} shielded, {:.1} armored, {:.1} subsystem, {:.1} structural",
+            projectile.damage,
+            hit.shielded,
+            hit.armored,
+            hit.subsystem,
+            hit.structural
+        );
+        if let Some(mut grid) = grid {
+            for equipment in hit.destroyed.iter() {
+                grid.disable(*equipment);
+            }
+        }
+        // Ships the Player opens fire on fire back.
+        if hostile.is_none() && player_query.get(projectile.source).is_ok() {
+            commands.entity(ship).insert(Hostile);
+        }
+        spent.insert(shot);
+        commands.entity(shot).despawn();
+    }
+}
+
+/// Burn out shots that have flown past their range.
+pub fn projectile_expiry_system(
+    mut commands: Commands,
+    world_clock: Res<WorldClock>,
+    mut query: Query<(Entity, &mut Projectile)>,
+) {
+    let seconds = world_clock.delta().as_secs_f32();
+    for (entity, mut projectile) in query.iter_mut() {
+        projectile.lifetime -= seconds;
+        if projectile.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Shields recover while their generators are powered.
+pub fn shield_recharge_system(
+    world_clock: Res<WorldClock>,
+    mut query: Query<(&mut Combatant, Option<&PowerGrid>)>,
+) {
+    let seconds = world_clock.delta().as_secs_f32();
+    if seconds <= 0.0 {
+        return;
+    }
+    for (mut combatant, grid) in query.iter_mut() {
+        if grid.map_or(true, |grid| grid.is_powered(SlotKind::Shield)) {
+            combatant.recharge(seconds);
+        }
+    }
+}
+
+/// Break up ships whose structure has given out.
+pub fn ship_destruction_system(
+    mut commands: Commands,
+    query: Query<(Entity, &Combatant, Option<&ShipInfo>, Option<&PlayerVessel>)>,
+) {
+    for (entity, combatant, info, player) in query.iter() {
+        if !combatant.is_destroyed() {
+            continue;
+        }
+        if player.is_some() {
+            warn!("The Player's ship has been destroyed");
+        } else {
+            trace!(
+                "{} destroyed",
+                info.map_or("Unknown ship", |info| info.name.as_str())
+            );
+        }
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,122 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Keeps Faction relations and the Player's reputation moving as the game plays out.
+use super::TerritoryChange;
+use crate::cartographer::{
+    DiplomaticEvent, FactionChange, FactionId, Factions, Knowledge, Reputation,
+};
+use crate::utility::{WorldClock, SECONDS_PER_DAY};
+use bevy::prelude::*;
+
+/// Relation points per day Factions ease back towards their baseline.
+const RELATION_DRIFT_PER_DAY: f32 = 0.5;
+
+/// Something the Player did that a Faction noticed.
+#[derive(Debug, Clone)]
+pub struct ReputationEvent {
+    pub faction: FactionId,
+    pub delta: f32,
+    pub reason: String,
+}
+
+/// Shift relations for Wars, Treaties and Trade between Factions.
+pub fn apply_diplomatic_events(
+    mut events: EventReader<DiplomaticEvent>,
+    mut factions: ResMut<Factions>,
+) {
+    for event in events.iter() {
+        trace!("Diplomatic Event {:?}", event);
+        factions.apply(event);
+    }
+}
+
+/// Let old grievances and friendships fade as time passes.
+pub fn diplomacy_drift_system(world_clock: Res<WorldClock>, mut factions: ResMut<Factions>) {
+    let days = (world_clock.delta().as_secs_f64() / SECONDS_PER_DAY) as f32;
+    if days > 0.0 {
+        factions.drift_relations(RELATION_DRIFT_PER_DAY, days);
+    }
+}
+
+/// Apply the Player's actions to their standing with each Faction.
+pub fn apply_reputation_events(
+    mut events: EventReader<ReputationEvent>,
+    factions: Res<Factions>,
+    mut reputation: ResMut<Reputation>,
+) {
+    for event in events.iter() {
+        trace!(
+            "Reputation with {:?} {:+} for {}",
+            event.faction,
+            event.delta,
+            event.reason
+        );
+        reputation.act(&factions, event.faction, event.delta);
+    }
+}
+
+/// Carry the Player's standing and each Faction's knowledge through annexations, rebellions and
+/// collapses.
+pub fn apply_faction_changes(
+    mut changes: EventReader<FactionChange>,
+    mut reputation: ResMut<Reputation>,
+    mut knowledge: ResMut<Knowledge>,
+) {
+    for change in changes.iter() {
+        trace!("Faction Change {:?}", change);
+        match change {
+            FactionChange::Merged { absorbed, into, .. } => {
+                knowledge.share(*absorbed, *into);
+                knowledge.forget(*absorbed);
+                reputation.forget(*absorbed);
+            }
+            FactionChange::Split { from, into } => {
+                knowledge.share(*from, *into);
+                reputation.inherit(*from, *into);
+            }
+            FactionChange::Dissolved { id, .. } => {
+                knowledge.forget(*id);
+                reputation.forget(*id);
+            }
+            FactionChange::Renamed { .. } => {}
+        }
+    }
+}
+
+/// Start every Faction off knowing its own home and territory.
+pub fn seed_faction_knowledge(factions: Res<Factions>, mut knowledge: ResMut<Knowledge>) {
+    for faction in factions.iter() {
+        // The Player only learns what their sensors and travels reveal.
+        if faction.id != FactionId::PLAYER {
+            knowledge.survey_territory(faction);
+        }
+    }
+}
+
+/// Factions survey every Sector they take.
+pub fn apply_territory_knowledge(
+    mut changes: EventReader<TerritoryChange>,
+    mut knowledge: ResMut<Knowledge>,
+) {
+    for change in changes.iter() {
+        if let Some(to) = change.to {
+            knowledge.survey(to, change.sector);
+        }
+    }
+}
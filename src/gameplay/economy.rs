@@ -0,0 +1,36 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Markets at Stations and populated Planets, and the goods flowing between them.
+mod commodity;
+mod contraband;
+mod ledger;
+mod market;
+mod simulation;
+mod trade;
+
+pub use self::commodity::{Commodity, Recipe, RECIPES};
+pub use self::contraband::{
+    inspection_chance, is_contraband, trade_prices, SMUGGLING_FINE, SMUGGLING_PREMIUM,
+};
+pub use self::ledger::{KnownPrices, PriceLedger};
+pub use self::market::{Economy, Market, HISTORY_DAYS};
+pub use self::simulation::{economy_system, initialize_economy, EconomyClock};
+pub use self::trade::{
+    record_market_prices, trade_system, CargoHold, Credits, Docked, TradeOrder, TradeReceipt,
+};
@@ -0,0 +1,160 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use serde::{Deserialize, Serialize};
+
+/// Goods bought and sold between markets.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum Commodity {
+    Food,
+    Water,
+    Ore,
+    Gas,
+    Metals,
+    Fuel,
+    Machinery,
+    Electronics,
+    Medicine,
+    Luxuries,
+    Weapons,
+    Narcotics,
+}
+
+impl Commodity {
+    /// Every Commodity, in market listing order.
+    pub const ALL: [Commodity; 12] = [
+        Commodity::Food,
+        Commodity::Water,
+        Commodity::Ore,
+        Commodity::Gas,
+        Commodity::Metals,
+        Commodity::Fuel,
+        Commodity::Machinery,
+        Commodity::Electronics,
+        Commodity::Medicine,
+        Commodity::Luxuries,
+        Commodity::Weapons,
+        Commodity::Narcotics,
+    ];
+    /// Name shown in markets
+    pub fn name(&self) -> &'static str {
+        match self {
+            Commodity::Food => "Food",
+            Commodity::Water => "Water",
+            Commodity::Ore => "Ore",
+            Commodity::Gas => "Gas",
+            Commodity::Metals => "Metals",
+            Commodity::Fuel => "Fuel",
+            Commodity::Machinery => "Machinery",
+            Commodity::Electronics => "Electronics",
+            Commodity::Medicine => "Medicine",
+            Commodity::Luxuries => "Luxuries",
+            Commodity::Weapons => "Weapons",
+            Commodity::Narcotics => "Narcotics",
+        }
+    }
+    /// Tonnes per unit
+    pub fn mass(&self) -> f32 {
+        match self {
+            Commodity::Food => 1.0,
+            Commodity::Water => 1.0,
+            Commodity::Ore => 2.0,
+            Commodity::Gas => 0.5,
+            Commodity::Metals => 2.0,
+            Commodity::Fuel => 0.8,
+            Commodity::Machinery => 1.5,
+            Commodity::Electronics => 0.3,
+            Commodity::Medicine => 0.2,
+            Commodity::Luxuries => 0.5,
+            Commodity::Weapons => 1.0,
+            Commodity::Narcotics => 0.1,
+        }
+    }
+    /// Cubic metres per unit
+    pub fn volume(&self) -> f32 {
+        match self {
+            Commodity::Food => 1.5,
+            Commodity::Water => 1.0,
+            Commodity::Ore => 0.8,
+            Commodity::Gas => 2.0,
+            Commodity::Metals => 0.5,
+            Commodity::Fuel => 1.0,
+            Commodity::Machinery => 2.0,
+            Commodity::Electronics => 0.5,
+            Commodity::Medicine => 0.3,
+            Commodity::Luxuries => 0.8,
+            Commodity::Weapons => 1.0,
+            Commodity::Narcotics => 0.2,
+        }
+    }
+    /// Price in credits per unit when supply meets demand.
+    pub fn base_price(&self) -> f32 {
+        match self {
+            Commodity::Food => 10.0,
+            Commodity::Water => 4.0,
+            Commodity::Ore => 8.0,
+            Commodity::Gas => 6.0,
+            Commodity::Metals => 25.0,
+            Commodity::Fuel => 20.0,
+            Commodity::Machinery => 80.0,
+            Commodity::Electronics => 120.0,
+            Commodity::Medicine => 60.0,
+            Commodity::Luxuries => 150.0,
+            Commodity::Weapons => 200.0,
+            Commodity::Narcotics => 180.0,
+        }
+    }
+}
+
+/// Industry turning one set of Commodities into another.
+pub struct Recipe {
+    pub inputs: &'static [(Commodity, f32)],
+    pub output: (Commodity, f32),
+}
+
+/// Every industry a populated world may run, in the order they are worked.
+pub const RECIPES: [Recipe; 7] = [
+    Recipe {
+        inputs: &[(Commodity::Ore, 2.0)],
+        output: (Commodity::Metals, 1.0),
+    },
+    Recipe {
+        inputs: &[(Commodity::Gas, 2.0)],
+        output: (Commodity::Fuel, 1.0),
+    },
+    Recipe {
+        inputs: &[(Commodity::Metals, 2.0)],
+        output: (Commodity::Machinery, 1.0),
+    },
+    Recipe {
+        inputs: &[(Commodity::Metals, 1.0), (Commodity::Gas, 1.0)],
+        output: (Commodity::Electronics, 1.0),
+    },
+    Recipe {
+        inputs: &[(Commodity::Food, 2.0), (Commodity::Water, 2.0)],
+        output: (Commodity::Medicine, 1.0),
+    },
+    Recipe {
+        inputs: &[(Commodity::Food, 2.0), (Commodity::Metals, 1.0)],
+        output: (Commodity::Luxuries, 1.0),
+    },
+    Recipe {
+        inputs: &[(Commodity::Metals, 2.0), (Commodity::Electronics, 1.0)],
+        output: (Commodity::Weapons, 1.0),
+    },
+];
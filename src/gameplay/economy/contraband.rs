@@ -0,0 +1,112 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::commodity::Commodity;
+use super::market::Market;
+use crate::cartographer::PoliticalAxis;
+
+/// Black market mark up on goods the local authorities have banned.
+pub const SMUGGLING_PREMIUM: f32 = 2.0;
+/// Share of the goods' value charged as a fine when caught smuggling.
+pub const SMUGGLING_FINE: f32 = 0.5;
+/// Chance of a cargo inspection under the most lenient and the most authoritarian rule.
+const INSPECTION_CHANCE: (f64, f64) = (0.05, 0.5);
+
+/// Is `commodity` banned by authorities with `politics`.
+pub fn is_contraband(commodity: Commodity, politics: &PoliticalAxis) -> bool {
+    match commodity {
+        // All but the most libertarian ban Narcotics.
+        Commodity::Narcotics => politics.civil > -0.5,
+        // Authoritarians keep weapons out of private hands.
+        Commodity::Weapons => politics.civil > 0.5,
+        // Hardline socialists and traditionalists ban extravagance.
+        Commodity::Luxuries => politics.economic < -0.7 || politics.social < -0.8,
+        _ => false,
+    }
+}
+
+/// Chance a trade is inspected under authorities with `politics`.
+pub fn inspection_chance(politics: &PoliticalAxis) -> f64 {
+    let (lenient, strict) = INSPECTION_CHANCE;
+    let authority = (politics.civil as f64 + 1.0) / 2.0;
+    lenient + (strict - lenient) * authority
+}
+
+/// What a Market charges and pays for `commodity`, with banned goods going through the black
+/// market. `politics` are those of whoever controls the Sector, if anyone.
+pub fn trade_prices(
+    market: &Market,
+    commodity: Commodity,
+    politics: Option<&PoliticalAxis>,
+) -> (f32, f32) {
+    let premium = match politics {
+        Some(politics) if is_contraband(commodity, politics) => SMUGGLING_PREMIUM,
+        _ => 1.0,
+    };
+    (
+        market.buy_price(commodity) * premium,
+        market.sell_price(commodity) * premium,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_contraband() {
+        let libertarian = PoliticalAxis::new(0.0, -1.0, 0.0, 0.0);
+        let authoritarian = PoliticalAxis::new(0.0, 1.0, 0.0, 0.0);
+        let socialist = PoliticalAxis::new(-1.0, 0.0, 0.0, 0.0);
+        assert!(!is_contraband(Commodity::Narcotics, &libertarian));
+        assert!(is_contraband(Commodity::Narcotics, &authoritarian));
+        assert!(!is_contraband(Commodity::Weapons, &libertarian));
+        assert!(is_contraband(Commodity::Weapons, &authoritarian));
+        assert!(is_contraband(Commodity::Luxuries, &socialist));
+        assert!(!is_contraband(Commodity::Luxuries, &authoritarian));
+        assert!(!is_contraband(Commodity::Fuel, &authoritarian));
+    }
+
+    #[test]
+    fn test_inspection_chance() {
+        let lenient = inspection_chance(&PoliticalAxis::new(0.0, -1.0, 0.0, 0.0));
+        let central = inspection_chance(&PoliticalAxis::default());
+        let strict = inspection_chance(&PoliticalAxis::new(0.0, 1.0, 0.0, 0.0));
+        assert!((lenient - INSPECTION_CHANCE.0).abs() < 1e-6);
+        assert!((strict - INSPECTION_CHANCE.1).abs() < 1e-6);
+        assert!(lenient < central && central < strict);
+    }
+
+    #[test]
+    fn test_trade_prices() {
+        let market = Market::station(0, 0, 1.0);
+        let authoritarian = PoliticalAxis::new(0.0, 1.0, 0.0, 0.0);
+        let legal = (
+            market.buy_price(Commodity::Weapons),
+            market.sell_price(Commodity::Weapons),
+        );
+        assert_eq!(trade_prices(&market, Commodity::Weapons, None), legal);
+        assert_eq!(
+            trade_prices(&market, Commodity::Weapons, Some(&PoliticalAxis::default())),
+            legal
+        );
+        let (buy, sell) = trade_prices(&market, Commodity::Weapons, Some(&authoritarian));
+        assert!((buy - legal.0 * SMUGGLING_PREMIUM).abs() < 1e-3);
+        assert!((sell - legal.1 * SMUGGLING_PREMIUM).abs() < 1e-3);
+    }
+}
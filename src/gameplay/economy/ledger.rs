@@ -0,0 +1,102 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::commodity::Commodity;
+use super::market::Market;
+use bevy::utils::HashMap;
+
+/// Prices seen at a Market the last time the Player was there.
+#[derive(Debug, Clone)]
+pub struct KnownPrices {
+    pub sector: usize,
+    /// World time in seconds the prices were seen
+    pub seconds: f64,
+    pub buy: HashMap<Commodity, f32>,
+    pub sell: HashMap<Commodity, f32>,
+}
+
+/// Prices the Player has seen at every Market they have visited, by Orbital ID.
+#[derive(Debug, Default)]
+pub struct PriceLedger {
+    pub markets: HashMap<usize, KnownPrices>,
+}
+
+impl PriceLedger {
+    /// Note down a Market's current prices.
+    pub fn record(&mut self, market: &Market, seconds: f64) {
+        self.markets.insert(
+            market.orbital,
+            KnownPrices {
+                sector: market.sector,
+                seconds,
+                buy: Commodity::ALL
+                    .iter()
+                    .map(|commodity| (*commodity, market.buy_price(*commodity)))
+                    .collect(),
+                sell: Commodity::ALL
+                    .iter()
+                    .map(|commodity| (*commodity, market.sell_price(*commodity)))
+                    .collect(),
+            },
+        );
+    }
+    /// Known Markets paying the most for `commodity`, best first.
+    pub fn best_sales(&self, commodity: Commodity) -> Vec<(usize, &KnownPrices, f32)> {
+        let mut sales: Vec<(usize, &KnownPrices, f32)> = self
+            .markets
+            .iter()
+            .filter_map(|(orbital, known)| {
+                known
+                    .sell
+                    .get(&commodity)
+                    .map(|price| (*orbital, known, *price))
+            })
+            .collect();
+        sales.sort_by(|a, b| {
+            b.2.partial_cmp(&a.2)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.0.cmp(&b.0))
+        });
+        sales
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_best_sales() {
+        let mut ledger = PriceLedger::default();
+        let plenty = Market::station(1, 0, 1.0);
+        let mut scarce = Market::station(2, 3, 1.0);
+        scarce.take(Commodity::Fuel, scarce.stock(Commodity::Fuel) * 0.9);
+        ledger.record(&plenty, 10.0);
+        ledger.record(&scarce, 20.0);
+        let sales = ledger.best_sales(Commodity::Fuel);
+        assert_eq!(sales.len(), 2);
+        assert_eq!(sales[0].0, 2);
+        assert_eq!(sales[0].1.sector, 3);
+        assert!(sales[0].2 > sales[1].2);
+
+        // Visiting again replaces what was seen before.
+        ledger.record(&plenty, 30.0);
+        assert_eq!(ledger.markets.len(), 2);
+        assert_eq!(ledger.markets[&1].seconds, 30.0);
+    }
+}
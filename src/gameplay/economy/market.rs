@@ -0,0 +1,286 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::commodity::{Commodity, RECIPES};
+use crate::cartographer::{Orbitals, Sectors};
+use crate::generation::{OrbitalData, PlanetData};
+use bevy::utils::HashMap;
+use std::collections::VecDeque;
+
+/// Days of demand a market likes to keep in stock.
+const STOCK_DAYS: f32 = 30.0;
+/// Stock every market keeps of everything, however little it's used.
+const MIN_STOCK: f32 = 20.0;
+/// Stock past this many times the target spoils or is written off.
+const MAX_STOCK_FACTOR: f32 = 4.0;
+/// How strongly prices respond to shortage and glut.
+const ELASTICITY: f32 = 0.5;
+/// Cheapest and dearest a price can get relative to the base price.
+const PRICE_FACTOR_RANGE: (f32, f32) = (0.25, 4.0);
+/// Difference between what a market charges and what it pays, either side of the price.
+const SPREAD: f32 = 0.05;
+/// People per unit of production and consumption.
+const POPULATION_UNIT: f32 = 1_000_000.0;
+/// Ore mined per day for the square root of the minerals in a Sector.
+const ORE_PER_MINERAL: f32 = 0.05;
+/// Days of prices a Market remembers.
+pub const HISTORY_DAYS: usize = 30;
+
+/// Place goods are bought and sold, a Station or a Planet's Spaceport.
+#[derive(Debug, Clone)]
+pub struct Market {
+    /// Orbital the Market is on
+    pub orbital: usize,
+    /// Sector the Market is in
+    pub sector: usize,
+    /// Goods on hand
+    pub stock: HashMap<Commodity, f32>,
+    /// Raw goods yielded per day
+    pub output: HashMap<Commodity, f32>,
+    /// Goods used up per day
+    pub demand: HashMap<Commodity, f32>,
+    /// Industry recipe runs per day
+    pub industry: f32,
+    /// Daily prices, most recent last
+    pub history: HashMap<Commodity, VecDeque<f32>>,
+}
+
+impl Market {
+    /// Spaceport of a populated Planet, working its resources and feeding its people.
+    pub fn planet(orbital: usize, sector: usize, data: &PlanetData) -> Market {
+        let people = data.population / POPULATION_UNIT;
+        let mut market = Market::empty(orbital, sector);
+        market.output = [
+            (Commodity::Food, data.foliage * people * 2.0),
+            (Commodity::Water, data.water * people * 2.0),
+            (Commodity::Ore, data.minerals * people * 2.0),
+            (Commodity::Gas, data.gases * people * 2.0),
+            (Commodity::Narcotics, data.foliage * people * 0.05),
+        ]
+        .iter()
+        .copied()
+        .collect();
+        market.demand = [
+            (Commodity::Food, people),
+            (Commodity::Water, people),
+            (Commodity::Fuel, people * 0.2),
+            (Commodity::Machinery, people * 0.1),
+            (Commodity::Electronics, people * 0.1),
+            (Commodity::Medicine, people * 0.1),
+            (Commodity::Luxuries, people * 0.05),
+            (Commodity::Weapons, people * 0.02),
+            (Commodity::Narcotics, people * 0.02),
+        ]
+        .iter()
+        .copied()
+        .collect();
+        market.industry = people * 0.5;
+        market.restock();
+        market
+    }
+    /// Station, refining whatever is mined from the `minerals` left in its Sector and servicing
+    /// passing ships.
+    pub fn station(orbital: usize, sector: usize, minerals: f32) -> Market {
+        let mut market = Market::empty(orbital, sector);
+        let ore = minerals.max(0.0).sqrt() * ORE_PER_MINERAL;
+        market.output = std::iter::once((Commodity::Ore, ore)).collect();
+        market.demand = [
+            (Commodity::Food, 0.5),
+            (Commodity::Water, 0.5),
+            (Commodity::Fuel, 2.0),
+            (Commodity::Machinery, 0.2),
+            (Commodity::Electronics, 0.2),
+        ]
+        .iter()
+        .copied()
+        .collect();
+        market.industry = 1.0;
+        market.restock();
+        market
+    }
+    fn empty(orbital: usize, sector: usize) -> Market {
+        Market {
+            orbital,
+            sector,
+            stock: HashMap::default(),
+            output: HashMap::default(),
+            demand: HashMap::default(),
+            industry: 0.0,
+            history: HashMap::default(),
+        }
+    }
+    /// Fill every shelf to its target.
+    fn restock(&mut self) {
+        for commodity in Commodity::ALL.iter() {
+            self.stock.insert(*commodity, self.target(*commodity));
+        }
+    }
+    /// Goods on hand
+    pub fn stock(&self, commodity: Commodity) -> f32 {
+        self.stock.get(&commodity).copied().unwrap_or(0.0)
+    }
+    /// Stock the Market would like to hold.
+    pub fn target(&self, commodity: Commodity) -> f32 {
+        let flow = self.demand.get(&commodity).copied().unwrap_or(0.0)
+            + self.output.get(&commodity).copied().unwrap_or(0.0);
+        flow * STOCK_DAYS + MIN_STOCK
+    }
+    /// Going price, rising with shortage and falling with glut.
+    pub fn price(&self, commodity: Commodity) -> f32 {
+        let (cheapest, dearest) = PRICE_FACTOR_RANGE;
+        let scarcity = self.target(commodity) / (self.stock(commodity) + 1.0);
+        commodity.base_price() * scarcity.powf(ELASTICITY).clamp(cheapest, dearest)
+    }
+    /// What the Market charges per unit.
+    pub fn buy_price(&self, commodity: Commodity) -> f32 {
+        self.price(commodity) * (1.0 + SPREAD)
+    }
+    /// What the Market pays per unit.
+    pub fn sell_price(&self, commodity: Commodity) -> f32 {
+        self.price(commodity) * (1.0 - SPREAD)
+    }
+    /// Put goods on the shelves
+    pub fn add(&mut self, commodity: Commodity, amount: f32) {
+        *self.stock.entry(commodity).or_insert(0.0) += amount.max(0.0);
+    }
+    /// Take up to `amount` off the shelves, returning how much there was.
+    pub fn take(&mut self, commodity: Commodity, amount: f32) -> f32 {
+        let stock = self.stock.entry(commodity).or_insert(0.0);
+        let taken = amount.clamp(0.0, *stock);
+        *stock -= taken;
+        taken
+    }
+    /// Note down today's prices.
+    pub fn record_prices(&mut self) {
+        for commodity in Commodity::ALL.iter() {
+            let price = self.price(*commodity);
+            let history = self.history.entry(*commodity).or_default();
+            history.push_back(price);
+            if history.len() > HISTORY_DAYS {
+                history.pop_front();
+            }
+        }
+    }
+    /// Recorded daily prices, oldest first.
+    pub fn price_history(&self, commodity: Commodity) -> impl Iterator<Item = f32> + '_ {
+        self.history.get(&commodity).into_iter().flatten().copied()
+    }
+    /// Work, run industry and consume for `days`.
+    pub fn produce(&mut self, days: f32) {
+        let output: Vec<(Commodity, f32)> = self.output.iter().map(|(c, a)| (*c, *a)).collect();
+        for (commodity, amount) in output {
+            self.add(commodity, amount * days);
+        }
+        // Industry only runs as far as its inputs allow.
+        for recipe in RECIPES.iter() {
+            let runs = recipe
+                .inputs
+                .iter()
+                .map(|(commodity, amount)| self.stock(*commodity) / amount)
+                .fold(self.industry * days, f32::min);
+            if runs <= 0.0 {
+                continue;
+            }
+            for (commodity, amount) in recipe.inputs.iter() {
+                self.take(*commodity, amount * runs);
+            }
+            let (commodity, amount) = recipe.output;
+            self.add(commodity, amount * runs);
+        }
+        let demand: Vec<(Commodity, f32)> = self.demand.iter().map(|(c, a)| (*c, *a)).collect();
+        for (commodity, amount) in demand {
+            self.take(commodity, amount * days);
+        }
+        for commodity in Commodity::ALL.iter() {
+            let cap = self.target(*commodity) * MAX_STOCK_FACTOR;
+            if self.stock(*commodity) > cap {
+                self.stock.insert(*commodity, cap);
+            }
+        }
+    }
+}
+
+/// Every Market in the Universe, by Orbital ID.
+#[derive(Debug, Default)]
+pub struct Economy {
+    pub markets: HashMap<usize, Market>,
+}
+
+impl Economy {
+    /// Open a Market at every populated Planet and every Station.
+    pub fn from_universe(sectors: &Sectors, orbitals: &Orbitals) -> Economy {
+        let mut markets = HashMap::default();
+        for sector in sectors.iter() {
+            let minerals: f32 = orbitals
+                .in_sector(sector.id)
+                .map(|orbital| match &orbital.data {
+                    OrbitalData::Asteroid(data) => data.minerals,
+                    OrbitalData::Belt(data) => data.minerals,
+                    _ => 0.0,
+                })
+                .sum();
+            for orbital in orbitals.in_sector(sector.id) {
+                let market = match &orbital.data {
+                    OrbitalData::Planet(data) if data.population > 0.0 => {
+                        Market::planet(orbital.id, sector.id, data)
+                    }
+                    OrbitalData::Station(_) => Market::station(orbital.id, sector.id, minerals),
+                    _ => continue,
+                };
+                markets.insert(orbital.id, market);
+            }
+        }
+        Economy { markets }
+    }
+    /// Get Market by Orbital ID
+    pub fn get(&self, orbital: usize) -> Option<&Market> {
+        self.markets.get(&orbital)
+    }
+    /// Get mutable Market by Orbital ID
+    pub fn get_mut(&mut self, orbital: usize) -> Option<&mut Market> {
+        self.markets.get_mut(&orbital)
+    }
+    /// Iterate over every Market in a Sector
+    pub fn in_sector(&self, sector: usize) -> impl Iterator<Item = &Market> {
+        self.markets
+            .values()
+            .filter(move |market| market.sector == sector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prices_follow_supply() {
+        let mut market = Market::station(0, 0, 1.0);
+        let fuel = market.price(Commodity::Fuel);
+        assert!((fuel - Commodity::Fuel.base_price()).abs() < Commodity::Fuel.base_price() * 0.1);
+        market.take(Commodity::Fuel, market.stock(Commodity::Fuel) * 0.9);
+        assert!(market.price(Commodity::Fuel) > fuel);
+        assert!(market.buy_price(Commodity::Fuel) > market.sell_price(Commodity::Fuel));
+
+        // Industry works mined Ore up into Machinery.
+        let ore = market.stock(Commodity::Ore);
+        let machinery = market.stock(Commodity::Machinery);
+        market.produce(1.0);
+        assert!(market.stock(Commodity::Ore) < ore);
+        assert!(market.stock(Commodity::Machinery) > machinery);
+    }
+}
@@ -0,0 +1,136 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::commodity::Commodity;
+use super::market::Economy;
+use crate::cartographer::{Orbitals, Sectors};
+use crate::utility::{WorldClock, SECONDS_PER_DAY};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+/// Days between economy ticks.
+const TICK_DAYS: f64 = 1.0;
+/// Most ticks run in a single frame when Time Warp outpaces the economy.
+const MAX_TICKS_PER_FRAME: usize = 8;
+/// Hexes traders travel to find a better price.
+const TRADE_RANGE: i32 = 3;
+/// Price difference needed before traders bother hauling goods.
+const TRADE_MARGIN: f32 = 1.2;
+/// Share of a Market's surplus hauled away per day.
+const TRADE_RATE: f32 = 0.1;
+
+/// World time in seconds the economy next ticks at.
+#[derive(Debug, Clone)]
+pub struct EconomyClock {
+    pub next: f64,
+}
+
+impl Default for EconomyClock {
+    fn default() -> EconomyClock {
+        EconomyClock {
+            next: TICK_DAYS * SECONDS_PER_DAY,
+        }
+    }
+}
+
+/// Open the Universe's Markets once it has been generated.
+pub fn initialize_economy(
+    mut economy: ResMut<Economy>,
+    sectors: Res<Sectors>,
+    orbitals: Res<Orbitals>,
+) {
+    if economy.markets.is_empty() {
+        *economy = Economy::from_universe(&sectors, &orbitals);
+        trace!("Opened {} Markets", economy.markets.len());
+    }
+}
+
+/// Produce, consume and haul goods as World Time passes.
+pub fn economy_system(
+    world_clock: Res<WorldClock>,
+    sectors: Res<Sectors>,
+    mut clock: ResMut<EconomyClock>,
+    mut economy: ResMut<Economy>,
+) {
+    let seconds = world_clock.seconds_since_epoch();
+    let mut ticks = 0;
+    while seconds >= clock.next && ticks < MAX_TICKS_PER_FRAME {
+        for market in economy.markets.values_mut() {
+            market.produce(TICK_DAYS as f32);
+        }
+        haul_goods(&mut economy, &sectors, TICK_DAYS as f32);
+        for market in economy.markets.values_mut() {
+            market.record_prices();
+        }
+        clock.next += TICK_DAYS * SECONDS_PER_DAY;
+        ticks += 1;
+    }
+}
+
+/// Traders carry surplus goods to wherever nearby pays best.
+fn haul_goods(economy: &mut Economy, sectors: &Sectors, days: f32) {
+    let mut by_sector: HashMap<usize, Vec<usize>> = HashMap::default();
+    for market in economy.markets.values() {
+        by_sector
+            .entry(market.sector)
+            .or_default()
+            .push(market.orbital);
+    }
+    let mut origins: Vec<usize> = economy.markets.keys().copied().collect();
+    origins.sort_unstable();
+
+    let mut shipments = Vec::new();
+    for origin in origins {
+        let market = &economy.markets[&origin];
+        let location = match sectors.get(market.sector) {
+            Some(sector) => sector.location,
+            None => continue,
+        };
+        let nearby: Vec<usize> = sectors
+            .within(location, TRADE_RANGE)
+            .filter_map(|sector| by_sector.get(&sector.id))
+            .flatten()
+            .copied()
+            .filter(|other| *other != origin)
+            .collect();
+        for commodity in Commodity::ALL.iter() {
+            let surplus = market.stock(*commodity) - market.target(*commodity);
+            if surplus <= 0.0 {
+                continue;
+            }
+            let price = market.sell_price(*commodity);
+            let best = nearby
+                .iter()
+                .map(|other| (*other, economy.markets[other].buy_price(*commodity)))
+                .filter(|(_, offer)| *offer > price * TRADE_MARGIN)
+                .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            if let Some((destination, _)) = best {
+                shipments.push((origin, destination, *commodity, surplus * TRADE_RATE * days));
+            }
+        }
+    }
+    for (origin, destination, commodity, amount) in shipments {
+        let hauled = match economy.get_mut(origin) {
+            Some(market) => market.take(commodity, amount),
+            None => continue,
+        };
+        if let Some(market) = economy.get_mut(destination) {
+            market.add(commodity, hauled);
+        }
+    }
+}
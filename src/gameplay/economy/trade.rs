@@ -0,0 +1,339 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::commodity::Commodity;
+use super::contraband::{inspection_chance, is_contraband, trade_prices, SMUGGLING_FINE};
+use super::ledger::PriceLedger;
+use super::market::Economy;
+use crate::cartographer::Factions;
+use crate::gameplay::ReputationEvent;
+use crate::generation::UniverseSeed;
+use crate::utility::WorldClock;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro128PlusPlus;
+
+/// Credits the Player starts with.
+const STARTING_CREDITS: f32 = 1000.0;
+/// Tonnes the Player's hold carries to start with.
+const STARTING_MASS_CAPACITY: f32 = 100.0;
+/// Cubic metres the Player's hold carries to start with.
+const STARTING_VOLUME_CAPACITY: f32 = 150.0;
+/// Standing lost with the authorities when caught smuggling.
+const SMUGGLING_REPUTATION: f32 = -10.0;
+
+/// Player's money
+#[derive(Debug, Clone, Copy)]
+pub struct Credits(pub f32);
+
+impl Default for Credits {
+    fn default() -> Credits {
+        Credits(STARTING_CREDITS)
+    }
+}
+
+/// Goods carried aboard the Player's ship.
+#[derive(Debug, Clone)]
+pub struct CargoHold {
+    pub goods: HashMap<Commodity, u32>,
+    /// Tonnes the hold carries
+    pub mass_capacity: f32,
+    /// Cubic metres the hold carries
+    pub volume_capacity: f32,
+}
+
+impl Default for CargoHold {
+    fn default() -> CargoHold {
+        CargoHold {
+            goods: HashMap::default(),
+            mass_capacity: STARTING_MASS_CAPACITY,
+            volume_capacity: STARTING_VOLUME_CAPACITY,
+        }
+    }
+}
+
+impl CargoHold {
+    /// Units of a Commodity aboard
+    pub fn count(&self, commodity: Commodity) -> u32 {
+        self.goods.get(&commodity).copied().unwrap_or(0)
+    }
+    /// Tonnes aboard
+    pub fn mass(&self) -> f32 {
+        self.goods
+            .iter()
+            .map(|(commodity, units)| commodity.mass() * *units as f32)
+            .sum()
+    }
+    /// Cubic metres aboard
+    pub fn volume(&self) -> f32 {
+        self.goods
+            .iter()
+            .map(|(commodity, units)| commodity.volume() * *units as f32)
+            .sum()
+    }
+    /// Units of a Commodity there is still room for.
+    pub fn space_for(&self, commodity: Commodity) -> u32 {
+        let by_mass = (self.mass_capacity - self.mass()) / commodity.mass();
+        let by_volume = (self.volume_capacity - self.volume()) / commodity.volume();
+        by_mass.min(by_volume).max(0.0).floor() as u32
+    }
+    /// Load goods
+    pub fn load(&mut self, commodity: Commodity, units: u32) {
+        *self.goods.entry(commodity).or_insert(0) += units;
+    }
+    /// Unload up to `units`, returning how many there were.
+    pub fn unload(&mut self, commodity: Commodity, units: u32) -> u32 {
+        let held = self.goods.entry(commodity).or_insert(0);
+        let unloaded = units.min(*held);
+        *held -= unloaded;
+        if *held == 0 {
+            self.goods.remove(&commodity);
+        }
+        unloaded
+    }
+}
+
+/// Market on the Orbital the Player is docked at, if any.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct Docked(pub Option<usize>);
+
+/// Player's order to buy, or sell with negative units, at the Market on an Orbital.
+#[derive(Debug, Copy, Clone)]
+pub struct TradeOrder {
+    pub market: usize,
+    pub commodity: Commodity,
+    pub units: i32,
+}
+
+/// How a TradeOrder went.
+#[derive(Debug, Copy, Clone)]
+pub struct TradeReceipt {
+    pub market: usize,
+    pub commodity: Commodity,
+    /// Units bought, or sold when negative
+    pub units: i32,
+    /// Price per unit
+    pub price: f32,
+    /// Goods confiscated and a fine paid instead
+    pub seized: bool,
+}
+
+/// Price and units of an order that can go through, limited by the Player's credits, the
+/// Market's stock and room in the hold when buying, and what is aboard when selling.
+fn tradable_units(
+    order: &TradeOrder,
+    (buy, sell): (f32, f32),
+    credits: f32,
+    stock: f32,
+    cargo: &CargoHold,
+) -> (f32, i32) {
+    if order.units > 0 {
+        let affordable = (credits / buy).floor() as u32;
+        let available = stock.floor() as u32;
+        let units = (order.units as u32)
+            .min(affordable)
+            .min(available)
+            .min(cargo.space_for(order.commodity));
+        (buy, units as i32)
+    } else {
+        let units = order.units.unsigned_abs().min(cargo.count(order.commodity));
+        (sell, -(units as i32))
+    }
+}
+
+/// Roll whether trade number `trade` of `commodity` at `seconds` is inspected, counting trades
+/// so several at the same moment, or while the clock is paused, roll independently.
+fn inspected(
+    universe_seed: u64,
+    seconds: f64,
+    commodity: Commodity,
+    trade: u64,
+    chance: f64,
+) -> bool {
+    let seed = universe_seed ^ seconds.to_bits() ^ (trade << 8) ^ commodity as u64;
+    Xoshiro128PlusPlus::seed_from_u64(seed).gen_bool(chance)
+}
+
+/// Settle the Player's trades with the Market they are docked at, with banned goods risking
+/// inspection.
+#[allow(clippy::too_many_arguments)]
+pub fn trade_system(
+    docked: Res<Docked>,
+    world_clock: Res<WorldClock>,
+    universe_seed: Res<UniverseSeed>,
+    factions: Res<Factions>,
+    mut orders: EventReader<TradeOrder>,
+    mut economy: ResMut<Economy>,
+    mut credits: ResMut<Credits>,
+    mut cargo: ResMut<CargoHold>,
+    mut receipts: EventWriter<TradeReceipt>,
+    mut reputation: EventWriter<ReputationEvent>,
+    mut trades: Local<u64>,
+) {
+    let seconds = world_clock.seconds_since_epoch();
+    for order in orders.iter() {
+        let market = match economy.get_mut(order.market) {
+            Some(market) if docked.0 == Some(order.market) => market,
+            _ => {
+                warn!("Not docked at a Market on Orbital {}", order.market);
+                continue;
+            }
+        };
+        let authority = factions.controller(market.sector);
+        let politics = authority.map(|faction| &faction.politics);
+        let (buy, sell) = trade_prices(market, order.commodity, politics);
+        let stock = market.stock(order.commodity);
+        let (price, units) = tradable_units(order, (buy, sell), credits.0, stock, &cargo);
+        if units == 0 {
+            continue;
+        }
+
+        // Banned goods may draw an inspection.
+        let mut seized = false;
+        if let Some(authority) = authority {
+            if is_contraband(order.commodity, &authority.politics) {
+                *trades += 1;
+                seized = inspected(
+                    universe_seed.0,
+                    seconds,
+                    order.commodity,
+                    *trades,
+                    inspection_chance(&authority.politics),
+                );
+                if seized {
+                    reputation.send(ReputationEvent {
+                        faction: authority.id,
+                        delta: SMUGGLING_REPUTATION,
+                        reason: format!("Smuggling {}", order.commodity.name()),
+                    });
+                }
+            }
+        }
+
+        let value = price * units.unsigned_abs() as f32;
+        if seized {
+            // Bought goods are impounded before they are loaded, sold goods before they are paid.
+            if units > 0 {
+                market.take(order.commodity, units as f32);
+                credits.0 -= value;
+            } else {
+                cargo.unload(order.commodity, units.unsigned_abs());
+            }
+            credits.0 = (credits.0 - value * SMUGGLING_FINE).max(0.0);
+        } else if units > 0 {
+            market.take(order.commodity, units as f32);
+            cargo.load(order.commodity, units as u32);
+            credits.0 -= value;
+        } else {
+            cargo.unload(order.commodity, units.unsigned_abs());
+            market.add(order.commodity, units.unsigned_abs() as f32);
+            credits.0 += value;
+        }
+        trace!(
+            "Traded {:+} {} at {:.1}{}",
+            units,
+            order.commodity.name(),
+            price,
+            if seized { ", seized" } else { "" }
+        );
+        receipts.send(TradeReceipt {
+            market: order.market,
+            commodity: order.commodity,
+            units,
+            price,
+            seized,
+        });
+    }
+}
+
+/// Keep the Player's notes on the Market they are docked at up to date.
+pub fn record_market_prices(
+    docked: Res<Docked>,
+    world_clock: Res<WorldClock>,
+    economy: Res<Economy>,
+    mut ledger: ResMut<PriceLedger>,
+) {
+    if !docked.is_changed() && !economy.is_changed() {
+        return;
+    }
+    if let Some(market) = docked.0.and_then(|orbital| economy.get(orbital)) {
+        ledger.record(market, world_clock.seconds_since_epoch());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(commodity: Commodity, units: i32) -> TradeOrder {
+        TradeOrder {
+            market: 0,
+            commodity,
+            units,
+        }
+    }
+
+    #[test]
+    fn test_tradable_units() {
+        let mut cargo = CargoHold::default();
+        let prices = (10.0, 8.0);
+        assert_eq!(
+            tradable_units(&order(Commodity::Ore, 5), prices, 1000.0, 50.0, &cargo),
+            (10.0, 5)
+        );
+        // Limited by credits, then by stock.
+        assert_eq!(
+            tradable_units(&order(Commodity::Ore, 50), prices, 125.0, 50.0, &cargo).1,
+            12
+        );
+        assert_eq!(
+            tradable_units(&order(Commodity::Ore, 50), prices, 1000.0, 7.5, &cargo).1,
+            7
+        );
+        // Only what is aboard can be sold.
+        cargo.load(Commodity::Ore, 3);
+        assert_eq!(
+            tradable_units(&order(Commodity::Ore, -5), prices, 0.0, 0.0, &cargo),
+            (8.0, -3)
+        );
+    }
+
+    #[test]
+    fn test_full_hold_rejects_purchases() {
+        let mut cargo = CargoHold::default();
+        let room = cargo.space_for(Commodity::Fuel);
+        assert!(room > 0);
+        let (_, units) =
+            tradable_units(&order(Commodity::Fuel, 1000), (1.0, 1.0), 1e6, 1e6, &cargo);
+        assert_eq!(units as u32, room);
+        cargo.load(Commodity::Fuel, room);
+        assert_eq!(cargo.space_for(Commodity::Fuel), 0);
+        let (_, units) = tradable_units(&order(Commodity::Fuel, 1), (1.0, 1.0), 1e6, 1e6, &cargo);
+        assert_eq!(units, 0);
+    }
+
+    #[test]
+    fn test_inspections_roll_per_trade() {
+        let rolls: Vec<bool> = (0..32)
+            .map(|trade| inspected(7, 100.0, Commodity::Weapons, trade, 0.5))
+            .collect();
+        assert!(rolls.contains(&true) && rolls.contains(&false));
+        assert_eq!(inspected(7, 100.0, Commodity::Weapons, 3, 0.5), rolls[3]);
+    }
+}
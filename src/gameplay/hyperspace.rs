@@ -17,11 +17,44 @@
 //
 
 //! Hyperspace is the main method between sectors with Gravity Wells.
+mod discovery;
+mod encounter;
 mod entity;
 mod input;
+mod route;
 mod setup;
+mod territory;
+mod travel;
 
-pub use self::entity::{Hyperspace, HyperspaceSectorInfo};
-pub use self::input::{process_gamepad_input, process_keyboard_input, process_mouse_input};
-pub use self::setup::{cleanup_hyperspace, initialize_hyperspace, setup_hyperspace, update_hyperspace};
+pub use self::discovery::{
+    gravimetric_detection_system, render_knowledge_system, spawn_sector_markers,
+    survey_on_arrival_system, GravimetricSensor,
+};
+pub use self::encounter::{
+    can_leave_sector, jump_rng, roll_encounter, sector_danger, spawn_encounter_system, well_radius,
+    ActiveEncounter, EncounterEntity, EncounterKind, HyperspaceEncounter,
+};
+pub use self::entity::{
+    sector_to_world, world_to_sector, Hyperspace, HyperspaceSectorInfo, HYPERSPACE_SCALE,
+};
+pub use self::input::{
+    process_enter_sector_input, process_gamepad_input, process_keyboard_input, process_mouse_input,
+    process_sector_selection, SelectedSector,
+};
+pub use self::route::{
+    draw_route_system, follow_route_system, plan_route, process_route_input, sector_cost,
+    HostileSectors, PlannedRoute, Route, RouteMarker, SectorCost,
+};
+pub use self::setup::{
+    cleanup_hyperspace, initialize_hyperspace, reseed_hyperspace_background, setup_hyperspace,
+    update_hyperspace,
+};
+pub use self::territory::{
+    process_territory_input, spawn_territory_overlay, update_hostile_sectors,
+    update_territory_overlay, TerritoryOverlay, TerritoryOverlaySettings,
+};
+pub use self::travel::{
+    hex_line, hyperspace_jump_system, hyperspace_transit_system, refuel_on_arrival_system,
+    HyperDrive, HyperspaceArrival, HyperspaceTransit, JumpRequest,
+};
 use super::WorldClock;
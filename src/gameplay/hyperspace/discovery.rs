@@ -0,0 +1,209 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Fog of War over the Hyperspace Map, Sectors are discovered by sensors and surveyed by visiting.
+use super::{sector_to_world, Hyperspace, HyperspaceArrival, HyperspaceSectorInfo};
+use crate::cartographer::{FactionId, Knowledge, Orbitals, SectorKnowledge, Sectors};
+use crate::gameplay::star::spawn_star_decorations;
+use crate::gameplay::{
+    CurrentSector, GameplayState, PlayerAvatar, StarAppearance, StarGlow, StarGlowTexture,
+    StarLabel,
+};
+use crate::generation::OrbitalData;
+use bevy::prelude::*;
+
+/// Size in pixels of a Gravity Well that has only been detected.
+const DETECTED_SIZE: f32 = 4.0;
+/// Size in pixels of a surveyed Gravity Well per Solar Radius of its anchor.
+const SURVEYED_SCALE: f32 = 4.0;
+/// Pixels per unit of [StarAppearance] size on the Hyperspace Map.
+const STAR_MAP_SCALE: f32 = 4.0;
+/// Heaviest Gravity Well expected, bounds the area swept by Gravimetric Sensors.
+const MAX_WELL_MASS: f64 = 200.0;
+
+/// Long range sensor which picks up the Gravity Wells of nearby Sectors.
+#[derive(Debug, Copy, Clone)]
+pub struct GravimetricSensor {
+    /// Hexes a one Solar Mass Gravity Well can be detected at
+    pub range: f32,
+}
+
+impl GravimetricSensor {
+    /// Hexes a Gravity Well of `mass` Solar Masses can be detected at, heavier wells are felt
+    /// further away.
+    pub fn detection_range(&self, mass: f64) -> i32 {
+        (self.range * (1.0 + mass.max(1.0).log10() as f32)).floor() as i32
+    }
+}
+
+impl Default for GravimetricSensor {
+    fn default() -> GravimetricSensor {
+        GravimetricSensor { range: 3.0 }
+    }
+}
+
+/// Spawn a marker for every Sector on the Hyperspace Map, hidden until discovered.
+pub fn spawn_sector_markers(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    sectors: Res<Sectors>,
+    orbitals: Res<Orbitals>,
+    glow_texture: Res<StarGlowTexture>,
+    asset_server: Res<AssetServer>,
+) {
+    let font = asset_server.load("fonts/spacemono.ttf");
+    for sector in sectors.iter() {
+        let anchor = orbitals.get(sector.anchor);
+        let (radius, color) = anchor.map_or((1.0, Color::WHITE), |anchor| {
+            (anchor.data.radius() as f32, anchor.data.color())
+        });
+        let star = anchor.and_then(|anchor| match &anchor.data {
+            OrbitalData::Star(data) => Some(StarAppearance::new(data)),
+            _ => None,
+        });
+        let mut marker = commands.spawn_bundle(SpriteBundle {
+            material: materials.add(color.into()),
+            sprite: Sprite::new(Vec2::new(DETECTED_SIZE, DETECTED_SIZE)),
+            transform: Transform::from_translation(sector_to_world(sector.location).extend(0.0)),
+            visible: Visible {
+                is_visible: false,
+                is_transparent: true,
+            },
+            ..Default::default()
+        });
+        marker.insert(Hyperspace).insert(HyperspaceSectorInfo {
+            sector: sector.id,
+            location: sector.location,
+            position: sector_to_world(sector.location),
+            primary_radius: radius,
+            primary_color: color,
+            star: star.clone(),
+        });
+        if let Some(star) = star {
+            let mut decorations = None;
+            marker.with_children(|parent| {
+                decorations = Some(spawn_star_decorations(
+                    parent,
+                    &star,
+                    STAR_MAP_SCALE,
+                    false,
+                    &glow_texture,
+                    &mut materials,
+                    font.clone(),
+                ));
+            });
+            if let Some((glow, label)) = decorations {
+                commands.entity(glow).insert(Hyperspace);
+                commands.entity(label).insert(Hyperspace);
+            }
+        }
+    }
+}
+
+/// Sweep the Gravimetric Sensors whenever the Player arrives in a new Sector.
+pub fn gravimetric_detection_system(
+    current_sector: Res<CurrentSector>,
+    sectors: Res<Sectors>,
+    orbitals: Res<Orbitals>,
+    mut knowledge: ResMut<Knowledge>,
+    sensor_query: Query<&GravimetricSensor, With<PlayerAvatar>>,
+) {
+    if !current_sector.is_changed() {
+        return;
+    }
+    let origin = match sectors.get(current_sector.0) {
+        Some(sector) => sector,
+        None => return,
+    };
+    knowledge.survey(FactionId::PLAYER, origin.id);
+    for sensor in sensor_query.iter() {
+        let reach = sensor.detection_range(MAX_WELL_MASS);
+        for sector in sectors.within(origin.location, reach) {
+            let mass = orbitals
+                .get(sector.anchor)
+                .map_or(0.0, |anchor| anchor.data.mass());
+            if origin.location.distance(sector.location) <= sensor.detection_range(mass) {
+                knowledge.detect(FactionId::PLAYER, sector.id);
+            }
+        }
+    }
+}
+
+/// Survey every Sector the Player drops out of Hyperspace in.
+pub fn survey_on_arrival_system(
+    mut arrivals: EventReader<HyperspaceArrival>,
+    sectors: Res<Sectors>,
+    mut knowledge: ResMut<Knowledge>,
+) {
+    for arrival in arrivals.iter() {
+        if let Some(sector) = sectors.get_by_location(arrival.location) {
+            knowledge.survey(FactionId::PLAYER, sector.id);
+        }
+    }
+}
+
+/// Render each Sector marker according to what the Player knows about it.
+pub fn render_knowledge_system(
+    knowledge: Res<Knowledge>,
+    state: Res<State<GameplayState>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut marker_query: Query<(
+        &HyperspaceSectorInfo,
+        &Handle<ColorMaterial>,
+        &mut Sprite,
+        &mut Visible,
+        Option<&Children>,
+    )>,
+    mut decoration_query: Query<
+        &mut Visible,
+        (
+            Or<(With<StarGlow>, With<StarLabel>)>,
+            Without<HyperspaceSectorInfo>,
+        ),
+    >,
+) {
+    if !knowledge.is_changed() && !state.is_changed() {
+        return;
+    }
+    for (info, material, mut sprite, mut visible, children) in marker_query.iter_mut() {
+        let level = knowledge.level(FactionId::PLAYER, info.sector);
+        visible.is_visible = level != SectorKnowledge::Unknown;
+        // Stars only show their glow and class once someone has been there to look.
+        for child in children.iter().flat_map(|children| children.iter()) {
+            if let Ok(mut visible) = decoration_query.get_mut(*child) {
+                visible.is_visible = level == SectorKnowledge::Surveyed;
+            }
+        }
+        let (size, color) = match (level, &info.star) {
+            (SectorKnowledge::Unknown, _) => continue,
+            (SectorKnowledge::Detected, _) => (DETECTED_SIZE, Color::rgba(0.6, 0.6, 0.6, 0.6)),
+            (SectorKnowledge::Surveyed, Some(star)) => (
+                f32::max(DETECTED_SIZE, star.size * STAR_MAP_SCALE),
+                star.color,
+            ),
+            (SectorKnowledge::Surveyed, None) => (
+                f32::max(DETECTED_SIZE, info.primary_radius.sqrt() * SURVEYED_SCALE),
+                info.primary_color,
+            ),
+        };
+        sprite.size = Vec2::new(size, size);
+        if let Some(material) = materials.get_mut(material) {
+            material.color = color;
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Random Encounters that pull the Player out of Hyperspace.
+use super::HostileSectors;
+use crate::cartographer::Orbitals;
+use crate::gameplay::combat::Hostile;
+use crate::gameplay::sectorspace::{Dockable, SectorSpace, ShipMotion};
+use crate::gameplay::sensors::Signature;
+use crate::generation::{OrbitalData, ProtoSector};
+use crate::utility::Dice;
+use bevy::prelude::*;
+use hexgrid::Coordinate;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro128PlusPlus;
+
+/// Percent chance of an Encounter per Gravity Well passed, before danger.
+const BASE_ENCOUNTER_CHANCE: f32 = 5.0;
+/// Additional percent chance of an Encounter per point of danger.
+const DANGER_ENCOUNTER_CHANCE: f32 = 2.5;
+/// Hexes from the galactic core per point of danger, the frontier is lawless.
+const FRONTIER_DISTANCE: i32 = 25;
+/// Sectorspace units past the outermost orbit where a Gravity Well lets go of a ship.
+const WELL_MARGIN: f32 = 200.0;
+
+/// Kinds of Encounter found in Hyperspace
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum EncounterKind {
+    /// Abandoned wreck that can be boarded and salvaged.
+    Derelict,
+    /// Someone calling for help, or pretending to.
+    DistressBeacon,
+    /// Something strange that warrants investigation.
+    Anomaly,
+    /// Lawful patrol, hostile if the controlling faction is.
+    Patrol { hostile: bool },
+    /// Pirates lying in wait at the Gravity Well.
+    Pirates,
+}
+
+/// Encounter rolled for a Gravity Well along a jump.
+#[derive(Debug, Copy, Clone)]
+pub struct HyperspaceEncounter {
+    /// What was encountered
+    pub kind: EncounterKind,
+    /// Where it was encountered
+    pub location: Coordinate<i32>,
+    /// Seed for generating the Encounter's contents
+    pub seed: u64,
+}
+
+/// Encounter the Player is currently caught up in
+#[derive(Debug, Default)]
+pub struct ActiveEncounter(pub Option<HyperspaceEncounter>);
+
+/// Tag Denoting an Entity spawned for an Encounter
+pub struct EncounterEntity;
+
+/// Danger of a Sector, lawless frontier Sectors without stations under hostile control are the
+/// most dangerous.
+pub fn sector_danger(orbitals: &Orbitals, hostile: &HostileSectors, sector: &ProtoSector) -> i32 {
+    let mut danger = sector.location.distance(Coordinate::from_cubic(0, 0)) / FRONTIER_DISTANCE;
+    let policed = orbitals
+        .in_sector(sector.id)
+        .any(|orbital| !orbital.destroyed && matches!(orbital.data, OrbitalData::Station(_)));
+    if !policed {
+        danger += 2;
+    }
+    if hostile.0.contains(&sector.location) {
+        danger += 3;
+    }
+    danger
+}
+
+/// Roll for an Encounter at a Gravity Well.
+///
+/// Danger raises both the chance of an Encounter and shifts the 2d6 table towards Pirates.
+pub fn roll_encounter<R: Dice>(rng: &mut R, danger: i32, hostile: bool) -> Option<EncounterKind> {
+    let chance = BASE_ENCOUNTER_CHANCE + DANGER_ENCOUNTER_CHANCE * danger as f32;
+    if rng.roll_pct() >= chance {
+        return None;
+    }
+    Some(match rng.roll_sum(2, 6) as i32 + danger {
+        i32::MIN..=4 => EncounterKind::Derelict,
+        5..=6 => EncounterKind::DistressBeacon,
+        7..=8 => EncounterKind::Anomaly,
+        9..=10 => EncounterKind::Patrol { hostile },
+        _ => EncounterKind::Pirates,
+    })
+}
+
+/// Deterministic generator for rolls made along a jump, the same jump from the same Universe at
+/// the same time always plays out the same way.
+pub fn jump_rng(
+    universe_seed: u64,
+    origin: Coordinate<i32>,
+    destination: Coordinate<i32>,
+    departure: f64,
+) -> Xoshiro128PlusPlus {
+    let route = (origin.x as u64) << 48
+        ^ (origin.y as u64 & 0xFFFF) << 32
+        ^ (destination.x as u64 & 0xFFFF) << 16
+        ^ (destination.y as u64 & 0xFFFF);
+    Xoshiro128PlusPlus::seed_from_u64(universe_seed ^ route ^ departure.to_bits())
+}
+
+/// Sectorspace units from a Sector's primary to the edge of its Gravity Well.
+pub fn well_radius(orbitals: &Orbitals, sector: usize) -> f32 {
+    orbitals
+        .in_sector(sector)
+        .filter(|orbital| orbital.parent.is_some())
+        .map(|orbital| orbital.radius)
+        .fold(0.0, f32::max)
+        + WELL_MARGIN
+}
+
+/// Can the Player jump back out to Hyperspace, either with no Hostile ships left to stop them
+/// or having outrun them past the edge of the Gravity Well.
+pub fn can_leave_sector(distance: f32, well_radius: f32, hostiles: usize) -> bool {
+    hostiles == 0 || distance > well_radius
+}
+
+/// Set the scene for the active Encounter when Sectorspace is entered.
+pub fn spawn_encounter_system(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut active: ResMut<ActiveEncounter>,
+) {
+    let encounter = match active.0.take() {
+        Some(encounter) => encounter,
+        None => return,
+    };
+    let mut rng = Xoshiro128PlusPlus::seed_from_u64(encounter.seed);
+    let (count, color) = match encounter.kind {
+        EncounterKind::Derelict => (1, Color::rgb(0.4, 0.4, 0.4)),
+        EncounterKind::DistressBeacon => (1, Color::rgb(1.0, 0.8, 0.0)),
+        EncounterKind::Anomaly => (1, Color::rgb(0.6, 0.2, 1.0)),
+        EncounterKind::Patrol { hostile: false } => {
+            (rng.gen_range(2..4), Color::rgb(0.2, 0.4, 1.0))
+        }
+        EncounterKind::Patrol { hostile: true } => (rng.gen_range(2..4), Color::rgb(1.0, 0.5, 0.0)),
+        EncounterKind::Pirates => (rng.gen_range(1..5), Color::rgb(1.0, 0.1, 0.1)),
+    };
+    let material = materials.add(color.into());
+    for _ in 0..count {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let distance = rng.gen_range(50.0..150.0);
+        let mut entity = commands.spawn_bundle(SpriteBundle {
+            material: material.clone(),
+            sprite: Sprite::new(Vec2::new(6.0, 6.0)),
+            transform: Transform::from_xyz(distance * angle.cos(), distance * angle.sin(), 1.0),
+            ..Default::default()
+        });
+        entity
+            .insert(SectorSpace(encounter.location))
+            .insert(EncounterEntity);
+        match encounter.kind {
+            EncounterKind::Derelict => {
+                // Cold and silent, only its mass gives it away.
+                entity.insert(Dockable).insert(Signature {
+                    thermal: 0.1,
+                    em: 0.0,
+                    mass: 500.0,
+                });
+            }
+            EncounterKind::DistressBeacon => {
+                entity.insert(Dockable).insert(Signature {
+                    thermal: 1.0,
+                    em: 100.0,
+                    mass: 50.0,
+                });
+            }
+            EncounterKind::Patrol { hostile: false } => {
+                entity.insert(ShipMotion::default());
+            }
+            EncounterKind::Patrol { hostile: true } | EncounterKind::Pirates => {
+                entity.insert(ShipMotion::default()).insert(Hostile);
+            }
+            EncounterKind::Anomaly => {
+                entity.insert(Signature {
+                    thermal: 20.0,
+                    em: 20.0,
+                    mass: 0.0,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jumps_are_reproducible() {
+        let (origin, destination) = (Coordinate::from_cubic(0, 0), Coordinate::from_cubic(3, -1));
+        let mut a = jump_rng(42, origin, destination, 1000.0);
+        let mut b = jump_rng(42, origin, destination, 1000.0);
+        let rolls_a: Vec<_> = (0..8).map(|_| roll_encounter(&mut a, 4, false)).collect();
+        let rolls_b: Vec<_> = (0..8).map(|_| roll_encounter(&mut b, 4, false)).collect();
+        assert_eq!(rolls_a, rolls_b);
+    }
+
+    #[test]
+    fn test_lawless_space_is_pirate_infested() {
+        let mut rng = Xoshiro128PlusPlus::seed_from_u64(7);
+        for _ in 0..16 {
+            assert_eq!(
+                roll_encounter(&mut rng, 40, true),
+                Some(EncounterKind::Pirates)
+            );
+        }
+    }
+
+    #[test]
+    fn test_can_leave_sector() {
+        assert!(can_leave_sector(100.0, 500.0, 0));
+        assert!(!can_leave_sector(100.0, 500.0, 2));
+        assert!(!can_leave_sector(500.0, 500.0, 2));
+        assert!(can_leave_sector(501.0, 500.0, 2));
+    }
+}
@@ -16,14 +16,31 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
 
+use crate::gameplay::StarAppearance;
 use bevy::prelude::*;
-use hexgrid::Coordinate;
+use hexgrid::{Coordinate, Spacing};
+
+/// Size in pixels of a Sector Hex on the Hyperspace Map
+pub const HYPERSPACE_SCALE: f32 = 64.0;
+
+/// Position of a Sector's Gravity Well on the Hyperspace Map
+pub fn sector_to_world(location: Coordinate<i32>) -> Vec2 {
+    let position = location.to_pixel(Spacing::PointyTop(HYPERSPACE_SCALE));
+    Vec2::new(position.x, position.y)
+}
+
+/// Sector Hex under a point on the Hyperspace Map
+pub fn world_to_sector(position: Vec2) -> Coordinate<i32> {
+    Coordinate::from_pixel(position.x, position.y, Spacing::PointyTop(HYPERSPACE_SCALE))
+}
 
 /// Tag Denoting Hyperspace Object
 pub struct Hyperspace;
 
 /// Info about Hyperspace Gravity Well
 pub struct HyperspaceSectorInfo {
+    /// Sector ID
+    pub sector: usize,
     /// Integer Sector Location
     pub location: Coordinate<i32>,
     /// Cartesian Position
@@ -32,4 +49,6 @@ pub struct HyperspaceSectorInfo {
     pub primary_radius: f32,
     /// Primary Color
     pub primary_color: Color,
+    /// How the Primary is drawn once surveyed if it's a star
+    pub star: Option<StarAppearance>,
 }
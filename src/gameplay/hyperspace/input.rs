@@ -21,14 +21,18 @@ use bevy::input::mouse::{MouseButtonInput, MouseMotion, MouseWheel};
 use bevy::prelude::*;
 use bevy::render::camera::Camera;
 
-use super::Hyperspace;
-use crate::engine::PlayerAvatar;
+use super::{world_to_sector, Hyperspace, HyperspaceTransit, JumpRequest, PlannedRoute};
+use crate::gameplay::{GameplayState, PlayerAvatar};
+use hexgrid::Coordinate;
 
 const ZOOM_SCALE: f32 = 0.9;
 const MOVE_SCALE: f32 = 1.0;
 
 pub fn process_keyboard_input(
-    mut query: Query<(&PlayerAvatar, &mut Transform), With<Hyperspace>>,
+    mut query: Query<
+        (&PlayerAvatar, &mut Transform),
+        (With<Hyperspace>, Without<HyperspaceTransit>),
+    >,
     mut keyboard_input_events: EventReader<KeyboardInput>,
 ) {
     for event in keyboard_input_events.iter() {
@@ -116,3 +120,55 @@ pub fn process_gamepad_input(mut gamepad_event: EventReader<GamepadEvent>) {
         }
     }
 }
+
+/// Sector picked on the Hyperspace Map
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SelectedSector(pub Option<Coordinate<i32>>);
+
+/// Pick a Sector with the mouse and jump to it, or the next waypoint of a planned route, with `J`.
+pub fn process_sector_selection(
+    mouse_button_input: Res<Input<MouseButton>>,
+    keyboard_input: Res<Input<KeyCode>>,
+    windows: Res<Windows>,
+    planned: Res<PlannedRoute>,
+    camera_query: Query<&GlobalTransform, (With<Camera>, With<Hyperspace>)>,
+    mut selected: ResMut<SelectedSector>,
+    mut jump_requests: EventWriter<JumpRequest>,
+) {
+    if mouse_button_input.just_pressed(MouseButton::Left) {
+        let window = match windows.get_primary() {
+            Some(window) => window,
+            None => return,
+        };
+        if let (Some(cursor), Some(camera)) = (window.cursor_position(), camera_query.iter().next())
+        {
+            let offset = cursor - Vec2::new(window.width(), window.height()) / 2.0;
+            let world = camera.compute_matrix() * offset.extend(0.0).extend(1.0);
+            selected.0 = Some(world_to_sector(Vec2::new(world.x, world.y)));
+            trace!("Selected Sector {:?}", selected.0);
+        }
+    }
+    if keyboard_input.just_pressed(KeyCode::J) {
+        let waypoint = planned
+            .0
+            .as_ref()
+            .and_then(|route| route.waypoints.first().copied());
+        if let Some(destination) = waypoint.or(selected.0) {
+            jump_requests.send(JumpRequest { destination });
+        }
+    }
+}
+
+/// Drop into Sectorspace at the Sector the Player is in with `E`, unless they are mid jump.
+pub fn process_enter_sector_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut gameplay_state: ResMut<State<GameplayState>>,
+    transit_query: Query<Entity, (With<PlayerAvatar>, With<HyperspaceTransit>)>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::E) || transit_query.iter().next().is_some() {
+        return;
+    }
+    if let Err(error) = gameplay_state.set(GameplayState::Sectorspace) {
+        warn!("Unable to enter Sectorspace: {:?}", error);
+    }
+}
@@ -0,0 +1,406 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Multi-jump route planning across the Sector hex grid.
+use super::{sector_to_world, HyperDrive, Hyperspace, HyperspaceArrival, SelectedSector};
+use crate::cartographer::{Orbitals, Sectors};
+use crate::gameplay::{CurrentSector, PlayerAvatar};
+use crate::generation::{OrbitalData, ProtoSector};
+use crate::utility::{WorldClock, SECONDS_PER_DAY};
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use hexgrid::Coordinate;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Extra days added to a jump into a hostile Sector
+const HOSTILE_PENALTY: f32 = 10.0;
+/// Extra days added per Solar Mass of a Sector's anchor, heavy wells risk Interdiction.
+const HAZARD_PENALTY: f32 = 0.1;
+/// Anchor mass in Solar Masses above which a Sector is treated as a hazard.
+const HAZARD_MASS: f64 = 10.0;
+
+/// Sectors the Player would rather not pass through, kept up to date by faction territory.
+#[derive(Debug, Default)]
+pub struct HostileSectors(pub HashSet<Coordinate<i32>>);
+
+/// What passing through a Sector costs.
+#[derive(Debug, Copy, Clone)]
+pub struct SectorCost {
+    /// Can the tanks be refilled here
+    pub refuel: bool,
+    /// Extra days of travel this Sector is considered to cost
+    pub penalty: f32,
+}
+
+/// Planned sequence of jumps
+#[derive(Debug, Clone, Default)]
+pub struct Route {
+    /// Gravity Wells to jump to in order, not including the origin.
+    pub waypoints: Vec<Coordinate<i32>>,
+    /// Waypoints where the tanks will be refilled
+    pub refuels: Vec<Coordinate<i32>>,
+    /// Days spent travelling
+    pub days: f32,
+    /// Fuel burned over the whole route
+    pub fuel: f32,
+    /// World time in seconds the route will be complete
+    pub eta: f64,
+}
+
+/// Route currently planned by the Player
+#[derive(Debug, Default)]
+pub struct PlannedRoute(pub Option<Route>);
+
+/// Tag Denoting a piece of the drawn Route
+pub struct RouteMarker;
+
+#[derive(Copy, Clone, PartialEq)]
+struct Frontier {
+    cost: f32,
+    sector: usize,
+    fuel: i32,
+}
+
+impl Eq for Frontier {}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so the BinaryHeap pops the cheapest first.
+        other
+            .cost
+            .partial_cmp(&self.cost)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| self.fuel.cmp(&other.fuel))
+    }
+}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Find the cheapest sequence of jumps between two Sectors.
+///
+/// Search runs over (Sector, Fuel) states with fuel counted in whole hexes of range, so a route
+/// may detour through a Sector where `sector_cost` allows refueling when the tanks run dry.
+pub fn plan_route<F>(
+    sectors: &Sectors,
+    from: Coordinate<i32>,
+    to: Coordinate<i32>,
+    drive: &HyperDrive,
+    sector_cost: F,
+) -> Option<Route>
+where
+    F: Fn(&ProtoSector) -> SectorCost,
+{
+    let origin = sectors.get_by_location(from)?;
+    let destination = sectors.get_by_location(to)?;
+    let fuel_per_hex = drive.fuel_per_hex.max(f32::EPSILON);
+    let capacity = (drive.fuel_capacity / fuel_per_hex).floor() as i32;
+    let start_fuel = (drive.fuel / fuel_per_hex).floor() as i32;
+
+    let mut costs: HashMap<(usize, i32), f32> = HashMap::default();
+    let mut previous: HashMap<(usize, i32), (usize, i32)> = HashMap::default();
+    let mut frontier = BinaryHeap::new();
+    costs.insert((origin.id, start_fuel), 0.0);
+    frontier.push(Frontier {
+        cost: 0.0,
+        sector: origin.id,
+        fuel: start_fuel,
+    });
+
+    let mut goal = None;
+    // States are keyed by the fuel left on arrival, refueling only changes it on departure.
+    while let Some(Frontier { cost, sector, fuel }) = frontier.pop() {
+        let arrival = (sector, fuel);
+        if sector == destination.id {
+            goal = Some(arrival);
+            break;
+        }
+        if costs.get(&arrival).map_or(false, |best| cost > *best) {
+            continue;
+        }
+        let current = sectors.get(sector)?;
+        let fuel = if sector_cost(current).refuel {
+            capacity
+        } else {
+            fuel
+        };
+        for next in sectors.within(current.location, i32::min(drive.range, fuel)) {
+            let distance = current.location.distance(next.location);
+            if distance == 0 {
+                continue;
+            }
+            let next_cost = cost + drive.travel_days(distance) + sector_cost(next).penalty;
+            let state = (next.id, fuel - distance);
+            if costs.get(&state).map_or(true, |best| next_cost < *best) {
+                costs.insert(state, next_cost);
+                previous.insert(state, arrival);
+                frontier.push(Frontier {
+                    cost: next_cost,
+                    sector: next.id,
+                    fuel: state.1,
+                });
+            }
+        }
+    }
+
+    // Walk back from the goal to rebuild the path.
+    let mut state = goal?;
+    let mut path = vec![state.0];
+    while let Some(prior) = previous.get(&state) {
+        path.push(prior.0);
+        state = *prior;
+    }
+    path.reverse();
+
+    let mut route = Route::default();
+    for window in path.windows(2) {
+        let (from, to) = (sectors.get(window[0])?, sectors.get(window[1])?);
+        let distance = from.location.distance(to.location);
+        route.days += drive.travel_days(distance);
+        route.fuel += drive.fuel_cost(distance);
+        route.waypoints.push(to.location);
+        if window[1] != destination.id && sector_cost(to).refuel {
+            route.refuels.push(to.location);
+        }
+    }
+    Some(route)
+}
+
+/// Plan a route to the selected Sector with `R`, clear it with `Escape`.
+pub fn process_route_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    world_clock: Res<WorldClock>,
+    selected: Res<SelectedSector>,
+    current_sector: Res<CurrentSector>,
+    sectors: Res<Sectors>,
+    orbitals: Res<Orbitals>,
+    hostile: Res<HostileSectors>,
+    mut planned: ResMut<PlannedRoute>,
+    player_query: Query<&HyperDrive, With<PlayerAvatar>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        planned.0 = None;
+    }
+    if !keyboard_input.just_pressed(KeyCode::R) {
+        return;
+    }
+    let (origin, destination, drive) = match (
+        sectors.get(current_sector.0),
+        selected.0,
+        player_query.iter().next(),
+    ) {
+        (Some(origin), Some(destination), Some(drive)) => (origin, destination, drive),
+        _ => return,
+    };
+    let route = plan_route(&sectors, origin.location, destination, drive, |sector| {
+        sector_cost(&orbitals, &hostile, sector)
+    })
+    .map(|mut route| {
+        route.eta = world_clock.seconds_since_epoch() + route.days as f64 * SECONDS_PER_DAY;
+        route
+    });
+    match &route {
+        Some(route) => trace!(
+            "Planned {} jumps to {:?} arriving in {:.1} days",
+            route.waypoints.len(),
+            destination,
+            route.days
+        ),
+        None => warn!("No route to {:?}", destination),
+    }
+    planned.0 = route;
+}
+
+/// Drop waypoints from the planned route as they're reached.
+pub fn follow_route_system(
+    mut arrivals: EventReader<HyperspaceArrival>,
+    mut planned: ResMut<PlannedRoute>,
+) {
+    for arrival in arrivals.iter() {
+        if let Some(route) = &mut planned.0 {
+            if route.waypoints.first() == Some(&arrival.location) {
+                route.waypoints.remove(0);
+            }
+            if route.waypoints.is_empty() {
+                planned.0 = None;
+            }
+        }
+    }
+}
+
+/// Redraw the planned route on the Hyperspace Map whenever it changes.
+pub fn draw_route_system(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    asset_server: Res<AssetServer>,
+    planned: Res<PlannedRoute>,
+    world_clock: Res<WorldClock>,
+    current_sector: Res<CurrentSector>,
+    sectors: Res<Sectors>,
+    marker_query: Query<Entity, With<RouteMarker>>,
+) {
+    if !planned.is_changed() {
+        return;
+    }
+    for entity in marker_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    let (route, origin) = match (&planned.0, sectors.get(current_sector.0)) {
+        (Some(route), Some(origin)) => (route, origin.location),
+        _ => return,
+    };
+    let material = materials.add(Color::rgba(0.3, 0.8, 1.0, 0.8).into());
+    let mut from = sector_to_world(origin);
+    for waypoint in route.waypoints.iter() {
+        let to = sector_to_world(*waypoint);
+        let segment = to - from;
+        commands
+            .spawn_bundle(SpriteBundle {
+                material: material.clone(),
+                sprite: Sprite::new(Vec2::new(segment.length(), 2.0)),
+                transform: Transform {
+                    translation: ((from + to) / 2.0).extend(1.0),
+                    rotation: Quat::from_rotation_z(segment.y.atan2(segment.x)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(RouteMarker)
+            .insert(Hyperspace);
+        from = to;
+    }
+    let eta_days = (route.eta / SECONDS_PER_DAY).floor();
+    commands
+        .spawn_bundle(Text2dBundle {
+            text: Text::with_section(
+                format!("ETA Day {} ({:.1}d)", eta_days, route.days),
+                TextStyle {
+                    font: asset_server.load("fonts/spacemono.ttf"),
+                    font_size: 14.0,
+                    color: Color::rgb(0.3, 0.8, 1.0),
+                },
+                Default::default(),
+            ),
+            transform: Transform::from_translation((from + Vec2::new(0.0, 16.0)).extend(2.0)),
+            ..Default::default()
+        })
+        .insert(RouteMarker)
+        .insert(Hyperspace);
+}
+
+/// Cost of passing through a Sector given what's known about it.
+pub fn sector_cost(
+    orbitals: &Orbitals,
+    hostile: &HostileSectors,
+    sector: &ProtoSector,
+) -> SectorCost {
+    let refuel = orbitals
+        .in_sector(sector.id)
+        .any(|orbital| !orbital.destroyed && matches!(orbital.data, OrbitalData::Station(_)));
+    let mass = orbitals
+        .get(sector.anchor)
+        .map_or(0.0, |anchor| anchor.data.mass());
+    let mut penalty = 0.0;
+    if mass > HAZARD_MASS {
+        penalty += (mass - HAZARD_MASS) as f32 * HAZARD_PENALTY;
+    }
+    if hostile.0.contains(&sector.location) {
+        penalty += HOSTILE_PENALTY;
+    }
+    SectorCost { refuel, penalty }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartographer::test_sectors;
+
+    /// Drive with a range of 6 hexes and a 6 hex tank holding enough for 4.
+    fn drive() -> HyperDrive {
+        HyperDrive {
+            rating: 1.0,
+            range: 6,
+            fuel: 4.0,
+            fuel_capacity: 6.0,
+            fuel_per_hex: 1.0,
+        }
+    }
+
+    fn refuel_at(station: Coordinate<i32>) -> impl Fn(&ProtoSector) -> SectorCost {
+        move |sector| SectorCost {
+            refuel: sector.location == station,
+            penalty: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_plan_direct_route() {
+        let (origin, destination) = (Coordinate::from_cubic(0, 0), Coordinate::from_cubic(3, 0));
+        let sectors = test_sectors(&[origin, destination]);
+        let route = plan_route(&sectors, origin, destination, &drive(), refuel_at(origin));
+        let route = route.expect("Destination is within range");
+        assert_eq!(route.waypoints, vec![destination]);
+        assert!(route.refuels.is_empty());
+        assert_eq!(route.days, 3.0);
+        assert_eq!(route.fuel, 3.0);
+    }
+
+    #[test]
+    fn test_plan_route_out_of_range() {
+        let (origin, destination) = (Coordinate::from_cubic(0, 0), Coordinate::from_cubic(7, 0));
+        let sectors = test_sectors(&[origin, destination]);
+        let full = HyperDrive {
+            fuel: 6.0,
+            ..drive()
+        };
+        assert!(plan_route(&sectors, origin, destination, &full, refuel_at(origin)).is_none());
+    }
+
+    #[test]
+    fn test_plan_route_detours_to_refuel() {
+        let origin = Coordinate::from_cubic(0, 0);
+        let waypoint = Coordinate::from_cubic(3, 0);
+        let station = Coordinate::from_cubic(2, 2);
+        let destination = Coordinate::from_cubic(6, 0);
+        let sectors = test_sectors(&[origin, waypoint, station, destination]);
+        // Destination is in range, but the tanks only hold enough to get part way there.
+        let route = plan_route(&sectors, origin, destination, &drive(), refuel_at(station));
+        let route = route.expect("Station is on the way");
+        assert_eq!(route.waypoints, vec![station, destination]);
+        assert_eq!(route.refuels, vec![station]);
+        assert_eq!(route.days, 8.0);
+        assert_eq!(route.fuel, 8.0);
+    }
+
+    #[test]
+    fn test_plan_route_unreachable() {
+        let origin = Coordinate::from_cubic(0, 0);
+        let waypoint = Coordinate::from_cubic(3, 0);
+        let destination = Coordinate::from_cubic(6, 0);
+        let sectors = test_sectors(&[origin, waypoint, destination]);
+        let nowhere = Coordinate::from_cubic(0, 4);
+        // Without a Station the tanks run dry at the waypoint.
+        assert!(plan_route(&sectors, origin, destination, &drive(), refuel_at(nowhere)).is_none());
+        // Empty space has no Gravity Well to jump to.
+        assert!(plan_route(&sectors, origin, nowhere, &drive(), refuel_at(nowhere)).is_none());
+    }
+}
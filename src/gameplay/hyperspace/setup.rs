@@ -16,21 +16,19 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
 
-use super::Hyperspace;
-use crate::engine::PlayerAvatar;
-//use crate::plugin::parallax::ParallaxBackgroundBundle;
+use super::{GravimetricSensor, HyperDrive, Hyperspace, HyperspaceSectorInfo, TerritoryOverlay};
+use crate::gameplay::{CurrentSector, PlayerAvatar, StarGlow, StarLabel};
+use crate::plugin::parallax::ParallaxBackground;
 use bevy::prelude::*;
 use bevy::render::camera::Camera;
-use bevy::render::texture::AddressMode;
 
 pub fn initialize_hyperspace(
     mut commands: Commands,
-    mut textures: ResMut<Assets<Texture>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
-    windows: Res<Windows>,
+    current_sector: Option<Res<CurrentSector>>,
     asset_server: Res<AssetServer>,
 ) {
+    let seed = current_sector.map_or(0, |sector| sector.0 as u32);
     let texture_handle = asset_server.load("sprites/ships/Ship-001.png");
     let texture_atlas = TextureAtlas::from_grid(texture_handle, Vec2::new(32.0, 32.0), 6, 6);
     let texture_atlas_handle = texture_atlases.add(texture_atlas);
@@ -42,129 +40,63 @@ pub fn initialize_hyperspace(
             ..Default::default()
         })
         .insert(PlayerAvatar)
+        .insert(HyperDrive::default())
+        .insert(GravimetricSensor::default())
         .insert(Hyperspace)
         .with_children(|parent| {
             let mut camera_bundle = OrthographicCameraBundle::new_2d();
-            let window = windows.get(camera_bundle.camera.window).unwrap();
             camera_bundle.transform = Transform::from_xyz(0.0, 0.0, 10.0);
             parent.spawn_bundle(camera_bundle).insert(Hyperspace);
 
-            let bg1: Handle<Texture> = asset_server.load("backgrounds/title/bg1.png");
-            textures
-                .get_mut(bg1.clone())
-                .unwrap() // We got none here... why
-                .sampler
-                .set_address_mode(AddressMode::Repeat);
-            let bg2: Handle<Texture> = asset_server.load("backgrounds/title/bg2.png");
-            textures
-                .get_mut(bg2.clone())
-                .unwrap()
-                .sampler
-                .set_address_mode(AddressMode::Repeat);
-            let bg3: Handle<Texture> = asset_server.load("backgrounds/title/bg3.png");
-            textures
-                .get_mut(bg3.clone())
-                .unwrap()
-                .sampler
-                .set_address_mode(AddressMode::Repeat);
-            let bg4: Handle<Texture> = asset_server.load("backgrounds/title/bg4.png");
-            textures
-                .get_mut(bg4.clone())
-                .unwrap()
-                .sampler
-                .set_address_mode(AddressMode::Repeat);
-            let bg5: Handle<Texture> = asset_server.load("backgrounds/title/bg5.png");
-            textures
-                .get_mut(bg5.clone())
-                .unwrap()
-                .sampler
-                .set_address_mode(AddressMode::Repeat);
-            let bg6: Handle<Texture> = asset_server.load("backgrounds/title/bg6.png");
-            textures
-                .get_mut(bg6.clone())
-                .unwrap()
-                .sampler
-                .set_address_mode(AddressMode::Repeat);
-            // let hyperspace_background_texture = crate::utility::texgen::CloudTextureGenerator::new(0)
-            // .texture(window.width() as usize, window.height() as usize, 0);
-
             // Spawn Hyperspace Background
             parent
-                .spawn_bundle(ParallaxBackgroundBundle {
-                    material: materials.add(ColorMaterial::texture(bg1)),
-                    transform: Transform::from_xyz(0.0, 0.0, -1000.0),
-                    visible: Visible {
-                        is_visible: false,
-                        is_transparent: false,
-                    },
-                    ..Default::default()
-                })
-                .insert(Hyperspace);
-            parent
-                .spawn_bundle(ParallaxBackgroundBundle {
-                    material: materials.add(ColorMaterial::texture(bg2)),
-                    transform: Transform::from_xyz(0.0, 0.0, -800.0),
-                    visible: Visible {
-                        is_visible: false,
-                        is_transparent: false,
-                    },
-                    ..Default::default()
-                })
-                .insert(Hyperspace);
-            parent
-                .spawn_bundle(ParallaxBackgroundBundle {
-                    material: materials.add(ColorMaterial::texture(bg3)),
-                    transform: Transform::from_xyz(0.0, 0.0, -600.0),
-                    visible: Visible {
-                        is_visible: false,
-                        is_transparent: false,
-                    },
-                    ..Default::default()
-                })
-                .insert(Hyperspace);
-            parent
-                .spawn_bundle(ParallaxBackgroundBundle {
-                    material: materials.add(ColorMaterial::texture(bg4)),
-                    transform: Transform::from_xyz(0.0, 0.0, -400.0),
-                    visible: Visible {
-                        is_visible: false,
-                        is_transparent: false,
-                    },
-                    ..Default::default()
-                })
-                .insert(Hyperspace);
-            parent
-                .spawn_bundle(ParallaxBackgroundBundle {
-                    material: materials.add(ColorMaterial::texture(bg5)),
-                    transform: Transform::from_xyz(0.0, 0.0, -200.0),
-                    visible: Visible {
-                        is_visible: false,
-                        is_transparent: false,
-                    },
-                    ..Default::default()
-                })
-                .insert(Hyperspace);
-            parent
-                .spawn_bundle(ParallaxBackgroundBundle {
-                    material: materials.add(ColorMaterial::texture(bg6)),
-                    transform: Transform::from_xyz(0.0, 0.0, -100.0),
-                    visible: Visible {
-                        is_visible: false,
-                        is_transparent: false,
-                    },
-                    ..Default::default()
+                .spawn()
+                .insert(Transform::default())
+                .insert(GlobalTransform::default())
+                .insert(Visible {
+                    is_visible: false,
+                    is_transparent: false,
                 })
+                .insert(ParallaxBackground::new(
+                    asset_server.load("backgrounds/hyperspace.parallax"),
+                    seed,
+                ))
                 .insert(Hyperspace);
         });
 }
 
+/// Reseed the nebulae in the Hyperspace background to match the Sector the Player is in.
+pub fn reseed_hyperspace_background(
+    current_sector: Res<CurrentSector>,
+    mut background_query: Query<&mut ParallaxBackground, With<Hyperspace>>,
+) {
+    if !current_sector.is_changed() {
+        return;
+    }
+    for mut background in background_query.iter_mut() {
+        if background.seed != current_sector.0 as u32 {
+            background.seed = current_sector.0 as u32;
+        }
+    }
+}
+
 pub fn setup_hyperspace(
     mut commands: Commands,
-    mut hyperspace_query: Query<&mut Visible, With<Hyperspace>>,
+    mut hyperspace_query: Query<
+        &mut Visible,
+        (
+            With<Hyperspace>,
+            Without<HyperspaceSectorInfo>,
+            Without<StarGlow>,
+            Without<StarLabel>,
+            Without<TerritoryOverlay>,
+        ),
+    >,
     mut camera_query: Query<(Entity, &Camera, &mut Transform), With<Hyperspace>>,
 ) {
     trace!("Setup Hyperspace");
 
+    // Sector markers are shown according to what the Player knows about them.
     trace!("Making Hyperspace Objects Visible");
     for mut visible in hyperspace_query.iter_mut() {
         visible.is_visible = true;
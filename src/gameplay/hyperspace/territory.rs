@@ -0,0 +1,132 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Faction territory shown over the Hyperspace Map.
+use super::{sector_to_world, HostileSectors, Hyperspace, HYPERSPACE_SCALE};
+use crate::cartographer::{
+    FactionId, Factions, Knowledge, Reputation, SectorKnowledge, Sectors, Stance,
+};
+use crate::gameplay::GameplayState;
+use bevy::prelude::*;
+
+/// Opacity of territory tint over a Sector.
+const OVERLAY_ALPHA: f32 = 0.2;
+
+/// Tint over a Sector showing who controls it.
+pub struct TerritoryOverlay {
+    pub sector: usize,
+}
+
+/// Is the territory overlay shown
+#[derive(Debug)]
+pub struct TerritoryOverlaySettings {
+    pub visible: bool,
+}
+
+impl Default for TerritoryOverlaySettings {
+    fn default() -> TerritoryOverlaySettings {
+        TerritoryOverlaySettings { visible: true }
+    }
+}
+
+/// Spawn a tint for every Sector, hidden until known and claimed.
+pub fn spawn_territory_overlay(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    sectors: Res<Sectors>,
+) {
+    let size = HYPERSPACE_SCALE * 1.5;
+    for sector in sectors.iter() {
+        commands
+            .spawn_bundle(SpriteBundle {
+                material: materials.add(Color::NONE.into()),
+                sprite: Sprite::new(Vec2::new(size, size)),
+                transform: Transform::from_translation(
+                    sector_to_world(sector.location).extend(-1.0),
+                ),
+                visible: Visible {
+                    is_visible: false,
+                    is_transparent: true,
+                },
+                ..Default::default()
+            })
+            .insert(Hyperspace)
+            .insert(TerritoryOverlay { sector: sector.id });
+    }
+}
+
+/// Toggle the territory overlay with `T`.
+pub fn process_territory_input(
+    keyboard: Res<Input<KeyCode>>,
+    mut settings: ResMut<TerritoryOverlaySettings>,
+) {
+    if keyboard.just_pressed(KeyCode::T) {
+        settings.visible = !settings.visible;
+    }
+}
+
+/// Tint known Sectors in the colour of whoever controls them.
+pub fn update_territory_overlay(
+    factions: Res<Factions>,
+    knowledge: Res<Knowledge>,
+    settings: Res<TerritoryOverlaySettings>,
+    state: Res<State<GameplayState>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut overlay_query: Query<(&TerritoryOverlay, &Handle<ColorMaterial>, &mut Visible)>,
+) {
+    if !factions.is_changed()
+        && !knowledge.is_changed()
+        && !settings.is_changed()
+        && !state.is_changed()
+    {
+        return;
+    }
+    let shown = settings.visible && *state.current() == GameplayState::Hyperspace;
+    for (overlay, material, mut visible) in overlay_query.iter_mut() {
+        let known = knowledge.level(FactionId::PLAYER, overlay.sector) != SectorKnowledge::Unknown;
+        let owner = factions.controller(overlay.sector);
+        visible.is_visible = shown && known && owner.is_some();
+        if let (Some(owner), Some(material)) = (owner, materials.get_mut(material)) {
+            let [r, g, b, _] = owner.color.as_rgba_f32();
+            material.color = Color::rgba(r, g, b, OVERLAY_ALPHA);
+        }
+    }
+}
+
+/// Sectors held by Factions hostile to the Player are avoided when planning routes.
+pub fn update_hostile_sectors(
+    factions: Res<Factions>,
+    reputation: Res<Reputation>,
+    sectors: Res<Sectors>,
+    mut hostile: ResMut<HostileSectors>,
+) {
+    if !factions.is_changed() && !reputation.is_changed() {
+        return;
+    }
+    hostile.0 = factions
+        .iter()
+        .filter(|faction| faction.id != FactionId::PLAYER)
+        .filter(|faction| {
+            reputation.stance(faction.id) == Stance::Hostile
+                || factions.stance(FactionId::PLAYER, faction.id) == Stance::Hostile
+        })
+        .flat_map(|faction| faction.territory.iter())
+        .filter_map(|sector| sectors.get(*sector))
+        .map(|sector| sector.location)
+        .collect();
+}
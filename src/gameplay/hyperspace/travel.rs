@@ -0,0 +1,515 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Jumps between Sector Gravity Wells through Hyperspace.
+use super::{
+    jump_rng, roll_encounter, sector_danger, sector_to_world, ActiveEncounter, EncounterKind,
+    HostileSectors, HyperspaceEncounter,
+};
+use crate::cartographer::{Orbitals, Sectors};
+use crate::gameplay::economy::{Commodity, Credits, Economy};
+use crate::gameplay::{CurrentSector, GameplayState, PlayerAvatar, WarpInterrupt};
+use crate::generation::{OrbitalData, UniverseSeed};
+use crate::utility::{Dice, WorldClock, SECONDS_PER_DAY};
+use bevy::prelude::*;
+use hexgrid::Coordinate;
+use rand::RngCore;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Real time in seconds a jump takes to play out regardless of its length.
+const TRANSIT_REAL_SECONDS: f64 = 3.0;
+/// Anchor mass in Solar Masses above which a Gravity Well can pull ships out of Hyperspace.
+const INTERDICTION_MASS: f64 = 10.0;
+/// Percent chance of Interdiction per Solar Mass above [INTERDICTION_MASS].
+const INTERDICTION_CHANCE: f32 = 2.0;
+
+/// Hyperspace Drive fitted to a ship
+#[derive(Debug, Clone)]
+pub struct HyperDrive {
+    /// Hexes travelled per day
+    pub rating: f32,
+    /// Most hexes that can be covered by a single jump
+    pub range: i32,
+    /// Fuel currently in the tanks
+    pub fuel: f32,
+    /// Most fuel the tanks can hold
+    pub fuel_capacity: f32,
+    /// Fuel burned per hex travelled
+    pub fuel_per_hex: f32,
+}
+
+impl HyperDrive {
+    /// Days it takes to travel `distance` hexes.
+    pub fn travel_days(&self, distance: i32) -> f32 {
+        distance as f32 / self.rating.max(f32::EPSILON)
+    }
+    /// Fuel burned travelling `distance` hexes.
+    pub fn fuel_cost(&self, distance: i32) -> f32 {
+        distance as f32 * self.fuel_per_hex
+    }
+    /// Can this drive make a jump of `distance` hexes right now.
+    pub fn can_jump(&self, distance: i32) -> bool {
+        distance > 0 && distance <= self.range && self.fuel_cost(distance) <= self.fuel
+    }
+    /// Fill the tanks, returning how much fuel was taken on.
+    pub fn refuel(&mut self, amount: f32) -> f32 {
+        let taken = f32::min(amount.max(0.0), self.fuel_capacity - self.fuel);
+        self.fuel += taken;
+        taken
+    }
+}
+
+impl Default for HyperDrive {
+    fn default() -> HyperDrive {
+        HyperDrive {
+            rating: 1.0,
+            range: 6,
+            fuel: 20.0,
+            fuel_capacity: 20.0,
+            fuel_per_hex: 1.0,
+        }
+    }
+}
+
+/// Request for the Player to jump to another Sector.
+#[derive(Debug, Copy, Clone)]
+pub struct JumpRequest {
+    pub destination: Coordinate<i32>,
+}
+
+/// Player has dropped out of Hyperspace.
+#[derive(Debug, Copy, Clone)]
+pub struct HyperspaceArrival {
+    /// Where the Player dropped out
+    pub location: Coordinate<i32>,
+    /// Was the Player pulled out early by a Gravity Well along the way
+    pub interdicted: bool,
+}
+
+/// Jump in progress
+#[derive(Debug, Clone)]
+pub struct HyperspaceTransit {
+    /// Hexes traversed, starting at the origin
+    pub path: Vec<Coordinate<i32>>,
+    /// Index into path where the jump will end
+    pub exit: usize,
+    /// World time of departure in seconds
+    pub departure: f64,
+    /// World time of arrival in seconds
+    pub arrival: f64,
+    /// Encounter waiting at the exit and the seed to generate it from
+    pub encounter: Option<(EncounterKind, u64)>,
+}
+
+impl HyperspaceTransit {
+    /// Was this jump cut short by a Gravity Well.
+    pub fn interdicted(&self) -> bool {
+        self.exit + 1 < self.path.len()
+    }
+    /// Progress along the jump between `0.0` and `1.0`.
+    pub fn progress(&self, seconds: f64) -> f32 {
+        let duration = self.arrival - self.departure;
+        if duration <= 0.0 {
+            1.0
+        } else {
+            ((seconds - self.departure) / duration).clamp(0.0, 1.0) as f32
+        }
+    }
+}
+
+/// Start jumps, burning fuel and speeding up the World Clock for the length of the transit.
+///
+/// Every Gravity Well passed is rolled for Interdiction and Encounters up front from a seed
+/// derived from the Universe, so a jump always plays out the same way.
+#[allow(clippy::too_many_arguments)]
+pub fn hyperspace_jump_system(
+    mut commands: Commands,
+    mut jump_requests: EventReader<JumpRequest>,
+    mut world_clock: ResMut<WorldClock>,
+    universe_seed: Res<UniverseSeed>,
+    sectors: Res<Sectors>,
+    orbitals: Res<Orbitals>,
+    hostile: Res<HostileSectors>,
+    current_sector: Res<CurrentSector>,
+    mut player_query: Query<
+        (Entity, &mut HyperDrive),
+        (With<PlayerAvatar>, Without<HyperspaceTransit>),
+    >,
+) {
+    let request = match jump_requests.iter().last() {
+        Some(request) => *request,
+        None => return,
+    };
+    let origin = match sectors.get(current_sector.0) {
+        Some(sector) => sector.location,
+        None => return,
+    };
+    for (entity, mut drive) in player_query.iter_mut() {
+        let distance = match check_jump(&sectors, &drive, origin, request.destination) {
+            Ok(distance) => distance,
+            Err(error) => {
+                warn!("Unable to jump to {:?}: {}", request.destination, error);
+                continue;
+            }
+        };
+        let path = hex_line(origin, request.destination);
+        let seconds = world_clock.seconds_since_epoch();
+        let mut rng = jump_rng(universe_seed.0, origin, request.destination, seconds);
+        let mut exit = path.len() - 1;
+        let mut encounter = None;
+        for (index, location) in path.iter().enumerate().skip(1) {
+            let sector = match sectors.get_by_location(*location) {
+                Some(sector) => sector,
+                None => continue,
+            };
+            let danger = sector_danger(&orbitals, &hostile, sector);
+            let controlled = hostile.0.contains(location);
+            if let Some(kind) = roll_encounter(&mut rng, danger, controlled) {
+                exit = index;
+                encounter = Some((kind, rng.next_u64()));
+                break;
+            }
+            let interdicted = index < path.len() - 1
+                && orbitals
+                    .get(sector.anchor)
+                    .and_then(|anchor| interdiction_chance(anchor.data.mass()))
+                    .map_or(false, |chance| rng.roll_pct() < chance);
+            if interdicted {
+                exit = index;
+                break;
+            }
+        }
+        let travelled = exit as i32;
+        drive.fuel -= drive.fuel_cost(travelled);
+        let duration = drive.travel_days(travelled) as f64 * SECONDS_PER_DAY;
+        world_clock.hold_multiplier((duration / TRANSIT_REAL_SECONDS) as f32);
+        trace!("Jumping {} hexes to {:?}", travelled, path[exit]);
+        commands.entity(entity).insert(HyperspaceTransit {
+            path,
+            exit,
+            departure: seconds,
+            arrival: seconds + duration,
+            encounter,
+        });
+    }
+}
+
+/// Why a jump can't be made.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum JumpError {
+    /// No Sector at the destination to drop out at
+    Uncharted,
+    /// Beyond the drive's range or the fuel in the tanks
+    OutOfRange(i32),
+}
+
+impl fmt::Display for JumpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JumpError::Uncharted => write!(f, "no Sector to drop out at"),
+            JumpError::OutOfRange(distance) => write!(f, "{} hexes is out of range", distance),
+        }
+    }
+}
+
+impl std::error::Error for JumpError {}
+
+/// Hexes from `origin` to `destination`, if `drive` can make the jump and there's a Sector
+/// there to arrive at.
+pub fn check_jump(
+    sectors: &Sectors,
+    drive: &HyperDrive,
+    origin: Coordinate<i32>,
+    destination: Coordinate<i32>,
+) -> Result<i32, JumpError> {
+    if sectors.get_by_location(destination).is_none() {
+        return Err(JumpError::Uncharted);
+    }
+    let distance = origin.distance(destination);
+    if !drive.can_jump(distance) {
+        return Err(JumpError::OutOfRange(distance));
+    }
+    Ok(distance)
+}
+
+/// Move the Player along their jump, dropping them at the Gravity Well they exit at.
+///
+/// Arriving into an Encounter drops the Player straight into Sectorspace to deal with it.
+#[allow(clippy::too_many_arguments)]
+pub fn hyperspace_transit_system(
+    mut commands: Commands,
+    mut world_clock: ResMut<WorldClock>,
+    mut gameplay_state: ResMut<State<GameplayState>>,
+    mut active_encounter: ResMut<ActiveEncounter>,
+    mut arrivals: EventWriter<HyperspaceArrival>,
+    mut encounters: EventWriter<HyperspaceEncounter>,
+    mut interrupts: EventWriter<WarpInterrupt>,
+    mut current_sector: ResMut<CurrentSector>,
+    sectors: Res<Sectors>,
+    mut player_query: Query<(Entity, &HyperspaceTransit, &mut Transform), With<PlayerAvatar>>,
+) {
+    let seconds = world_clock.seconds_since_epoch();
+    for (entity, transit, mut transform) in player_query.iter_mut() {
+        let progress = transit.progress(seconds);
+        let hexes = progress * transit.exit as f32;
+        let from = transit.path[(hexes.floor() as usize).min(transit.exit)];
+        let to = transit.path[(hexes.ceil() as usize).min(transit.exit)];
+        let position = sector_to_world(from).lerp(sector_to_world(to), hexes.fract());
+        transform.translation = position.extend(transform.translation.z);
+
+        if progress >= 1.0 {
+            let location = transit.path[transit.exit];
+            let interdicted = transit.interdicted();
+            transform.translation = sector_to_world(location).extend(transform.translation.z);
+            if let Some(sector) = sectors.get_by_location(location) {
+                current_sector.0 = sector.id;
+            }
+            world_clock.release_multiplier();
+            world_clock.drop_warp();
+            if let Some((kind, seed)) = transit.encounter {
+                let encounter = HyperspaceEncounter {
+                    kind,
+                    location,
+                    seed,
+                };
+                info!("Encounter: {:?} at {:?}", kind, location);
+                interrupts.send(WarpInterrupt::new("Dropped out into an Encounter"));
+                encounters.send(encounter);
+                active_encounter.0 = Some(encounter);
+                if let Err(error) = gameplay_state.set(GameplayState::Sectorspace) {
+                    warn!("Unable to enter Sectorspace for Encounter: {:?}", error);
+                }
+            } else if interdicted {
+                interrupts.send(WarpInterrupt::new("Interdicted by Gravity Well"));
+            }
+            arrivals.send(HyperspaceArrival {
+                location,
+                interdicted,
+            });
+            commands.entity(entity).remove::<HyperspaceTransit>();
+        }
+    }
+}
+
+/// Top up the Player's tanks on arriving in a Sector with a Station, the same Sectors the route
+/// planner counts on refueling at.
+///
+/// Fuel is bought from the cheapest Station for as long as its stock and the Player's credits
+/// last.
+pub fn refuel_on_arrival_system(
+    mut arrivals: EventReader<HyperspaceArrival>,
+    sectors: Res<Sectors>,
+    orbitals: Res<Orbitals>,
+    mut economy: ResMut<Economy>,
+    mut credits: ResMut<Credits>,
+    mut player_query: Query<&mut HyperDrive, With<PlayerAvatar>>,
+) {
+    for arrival in arrivals.iter() {
+        let sector = match sectors.get_by_location(arrival.location) {
+            Some(sector) => sector,
+            None => continue,
+        };
+        let station = economy
+            .in_sector(sector.id)
+            .filter(|market| {
+                orbitals.get(market.orbital).map_or(false, |orbital| {
+                    !orbital.destroyed && matches!(orbital.data, OrbitalData::Station(_))
+                })
+            })
+            .min_by(|a, b| {
+                a.buy_price(Commodity::Fuel)
+                    .partial_cmp(&b.buy_price(Commodity::Fuel))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .map(|market| market.orbital);
+        let market = match station.and_then(|orbital| economy.get_mut(orbital)) {
+            Some(market) => market,
+            None => continue,
+        };
+        for mut drive in player_query.iter_mut() {
+            let price = market.buy_price(Commodity::Fuel);
+            let wanted = fuel_affordable(drive.fuel_capacity - drive.fuel, price, credits.0);
+            let bought = drive.refuel(market.take(Commodity::Fuel, wanted));
+            credits.0 -= bought * price;
+            if bought > 0.0 {
+                trace!("Refueled {:.1} at {:.0} credits", bought, bought * price);
+            }
+            if drive.fuel < drive.fuel_capacity {
+                warn!("Unable to fill the tanks at {}", sector.name);
+            }
+        }
+    }
+}
+
+/// Fuel that can be bought to fill `space` in the tanks at `price` with `credits`.
+fn fuel_affordable(space: f32, price: f32, credits: f32) -> f32 {
+    if price > 0.0 {
+        space.min(credits.max(0.0) / price)
+    } else {
+        space
+    }
+}
+
+/// Percent chance a Gravity Well anchored by `mass` Solar Masses pulls a passing ship out of
+/// Hyperspace.
+fn interdiction_chance(mass: f64) -> Option<f32> {
+    if mass > INTERDICTION_MASS {
+        Some(((mass - INTERDICTION_MASS) as f32 * INTERDICTION_CHANCE).min(100.0))
+    } else {
+        None
+    }
+}
+
+/// Hexes on the straight line between two hexes, including both ends.
+pub fn hex_line(from: Coordinate<i32>, to: Coordinate<i32>) -> Vec<Coordinate<i32>> {
+    let distance = from.distance(to);
+    let (ax, ay) = (from.x as f32, from.y as f32);
+    let (bx, by) = (to.x as f32, to.y as f32);
+    (0..=distance)
+        .map(|step| {
+            // Nudge off of hex edges so rounding is consistent.
+            let t = if distance == 0 {
+                0.0
+            } else {
+                step as f32 / distance as f32
+            };
+            let x = ax + (bx - ax) * t + 1e-6;
+            let y = ay + (by - ay) * t + 1e-6;
+            hex_round(x, y)
+        })
+        .collect()
+}
+
+/// Round fractional axial coordinates to the nearest hex.
+fn hex_round(x: f32, y: f32) -> Coordinate<i32> {
+    let z = -x - y;
+    let (mut rx, mut ry, rz) = (x.round(), y.round(), z.round());
+    let (dx, dy, dz) = ((rx - x).abs(), (ry - y).abs(), (rz - z).abs());
+    if dx > dy && dx > dz {
+        rx = -ry - rz;
+    } else if dy > dz {
+        ry = -rx - rz;
+    }
+    Coordinate::from_cubic(rx as i32, ry as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartographer::test_sector_row;
+
+    #[test]
+    fn test_hyper_drive() {
+        let mut drive = HyperDrive {
+            rating: 1.5,
+            ..Default::default()
+        };
+        assert!(!drive.can_jump(0));
+        assert!(drive.can_jump(6));
+        assert!(!drive.can_jump(7));
+        assert_eq!(drive.fuel_cost(5), 5.0);
+        assert_eq!(drive.travel_days(3), 2.0);
+        drive.fuel = 3.0;
+        assert!(drive.can_jump(3));
+        assert!(!drive.can_jump(4));
+        assert_eq!(drive.refuel(100.0), 17.0);
+        assert_eq!(drive.fuel, drive.fuel_capacity);
+    }
+
+    #[test]
+    fn test_check_jump() {
+        let origin = Coordinate::from_cubic(0, 0);
+        let sectors = test_sector_row(8);
+        let drive = HyperDrive::default();
+        assert_eq!(
+            check_jump(&sectors, &drive, origin, Coordinate::from_cubic(3, 0)),
+            Ok(3)
+        );
+        assert_eq!(
+            check_jump(&sectors, &drive, origin, Coordinate::from_cubic(7, 0)),
+            Err(JumpError::OutOfRange(7))
+        );
+        assert_eq!(
+            check_jump(&sectors, &drive, origin, origin),
+            Err(JumpError::OutOfRange(0))
+        );
+        // Empty hexes have no Gravity Well to drop out at.
+        assert_eq!(
+            check_jump(&sectors, &drive, origin, Coordinate::from_cubic(0, 2)),
+            Err(JumpError::Uncharted)
+        );
+    }
+
+    #[test]
+    fn test_fuel_affordable() {
+        assert_eq!(fuel_affordable(10.0, 2.0, 100.0), 10.0);
+        assert_eq!(fuel_affordable(10.0, 2.0, 8.0), 4.0);
+        assert_eq!(fuel_affordable(10.0, 2.0, -5.0), 0.0);
+        assert_eq!(fuel_affordable(10.0, 0.0, 0.0), 10.0);
+    }
+
+    #[test]
+    fn test_interdiction_chance() {
+        assert_eq!(interdiction_chance(1.0), None);
+        assert_eq!(interdiction_chance(INTERDICTION_MASS), None);
+        assert_eq!(interdiction_chance(15.0), Some(10.0));
+        assert_eq!(interdiction_chance(1000.0), Some(100.0));
+    }
+
+    #[test]
+    fn test_hex_round() {
+        assert_eq!(hex_round(2.0, -1.0), Coordinate::from_cubic(2, -1));
+        assert_eq!(hex_round(2.6, -1.3), Coordinate::from_cubic(2, -1));
+        assert_eq!(hex_round(1.2, -0.1), Coordinate::from_cubic(1, 0));
+        assert_eq!(hex_round(-0.2, 0.9), Coordinate::from_cubic(0, 1));
+    }
+
+    #[test]
+    fn test_hex_line() {
+        let origin = Coordinate::from_cubic(0, 0);
+        assert_eq!(hex_line(origin, origin), vec![origin]);
+        let straight = hex_line(origin, Coordinate::from_cubic(4, 0));
+        assert_eq!(straight.len(), 5);
+        assert!(straight.iter().all(|hex| hex.y == 0));
+        for destination in [Coordinate::from_cubic(3, -1), Coordinate::from_cubic(-5, 2)] {
+            let line = hex_line(origin, destination);
+            assert_eq!(line.len() as i32, origin.distance(destination) + 1);
+            assert_eq!(line.first(), Some(&origin));
+            assert_eq!(line.last(), Some(&destination));
+            for step in line.windows(2) {
+                assert_eq!(step[0].distance(step[1]), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_transit_progress() {
+        let transit = HyperspaceTransit {
+            path: hex_line(Coordinate::from_cubic(0, 0), Coordinate::from_cubic(3, 0)),
+            exit: 2,
+            departure: 100.0,
+            arrival: 300.0,
+            encounter: None,
+        };
+        assert!(transit.interdicted());
+        assert_eq!(transit.progress(0.0), 0.0);
+        assert_eq!(transit.progress(200.0), 0.5);
+        assert_eq!(transit.progress(400.0), 1.0);
+    }
+}
@@ -0,0 +1,245 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Galactic News Feed reporting what goes on beyond the Player's view.
+use super::strategy::TerritoryChange;
+use crate::cartographer::{FactionChange, FactionId, Factions, Sectors};
+use crate::utility::{WorldClock, SECONDS_PER_DAY};
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+/// Most stories kept in the feed.
+const MAX_NEWS: usize = 50;
+/// Stories shown on screen at once.
+const NEWS_LINES: usize = 5;
+
+/// A single story
+#[derive(Debug, Clone)]
+pub struct NewsItem {
+    /// World time in seconds the story broke
+    pub seconds: f64,
+    pub headline: String,
+}
+
+/// Recent stories, newest first
+#[derive(Debug, Default)]
+pub struct NewsFeed {
+    pub items: VecDeque<NewsItem>,
+}
+
+impl NewsFeed {
+    pub fn push(&mut self, seconds: f64, headline: String) {
+        self.items.push_front(NewsItem { seconds, headline });
+        self.items.truncate(MAX_NEWS);
+    }
+}
+
+/// Tag Denoting the News Feed HUD
+pub struct NewsHud;
+
+/// Turn border changes into headlines.
+pub fn report_territory_changes(
+    world_clock: Res<WorldClock>,
+    factions: Res<Factions>,
+    sectors: Res<Sectors>,
+    mut changes: EventReader<TerritoryChange>,
+    mut feed: ResMut<NewsFeed>,
+) {
+    let seconds = world_clock.seconds_since_epoch();
+    for change in changes.iter() {
+        for headline in territory_headlines(&factions, &sectors, change) {
+            feed.push(seconds, headline);
+        }
+    }
+}
+
+/// Headlines for a Sector changing hands, nobody reports on Sectors or Factions that don't exist.
+fn territory_headlines(
+    factions: &Factions,
+    sectors: &Sectors,
+    change: &TerritoryChange,
+) -> Vec<String> {
+    let sector = match sectors.get(change.sector) {
+        Some(sector) => sector.name.as_str(),
+        None => return Vec::new(),
+    };
+    let name = |id: Option<FactionId>| {
+        id.and_then(|id| factions.get_by_id(id))
+            .map(|faction| faction.name.clone())
+    };
+    let mut headlines = vec![match (name(change.from), name(change.to)) {
+        (Some(from), Some(to)) => format!("{} seizes {} from the {}", to, sector, from),
+        (None, Some(to)) => format!("{} lays claim to {}", to, sector),
+        (Some(from), None) => format!("{} abandons {}", from, sector),
+        (None, None) => return Vec::new(),
+    }];
+    // A Faction that lost its last Sector is worth a headline of its own.
+    if let Some(from) = change.from.and_then(|id| factions.get_by_id(id)) {
+        if from.territory.is_empty() && from.home.is_some() {
+            headlines.push(format!("{} has lost all its territory", from.name));
+        }
+    }
+    headlines
+}
+
+/// Turn annexations, rebellions and collapses into headlines.
+pub fn report_faction_changes(
+    world_clock: Res<WorldClock>,
+    factions: Res<Factions>,
+    mut changes: EventReader<FactionChange>,
+    mut feed: ResMut<NewsFeed>,
+) {
+    let seconds = world_clock.seconds_since_epoch();
+    let name = |id: &FactionId| factions.get_by_id(*id).map(|faction| faction.name.clone());
+    for change in changes.iter() {
+        let headline = match change {
+            FactionChange::Merged {
+                name: absorbed,
+                into,
+                ..
+            } => match name(into) {
+                Some(into) => format!("{} annexes the {}", into, absorbed),
+                None => continue,
+            },
+            FactionChange::Split { from, into } => match (name(from), name(into)) {
+                (Some(from), Some(into)) => format!("{} breaks away from the {}", into, from),
+                _ => continue,
+            },
+            FactionChange::Dissolved { name, .. } => format!("{} has collapsed", name),
+            FactionChange::Renamed { id, old } => match name(id) {
+                Some(new) => format!("{} now calls itself the {}", old, new),
+                None => continue,
+            },
+        };
+        feed.push(seconds, headline);
+    }
+}
+
+pub fn setup_news_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn_bundle(TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    bottom: Val::Px(5.0),
+                    left: Val::Px(5.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            text: Text::with_section(
+                String::new(),
+                TextStyle {
+                    font: asset_server.load("fonts/spacemono.ttf"),
+                    font_size: 12.0,
+                    color: Color::rgb(0.8, 0.8, 0.6),
+                },
+                Default::default(),
+            ),
+            ..Default::default()
+        })
+        .insert(NewsHud);
+}
+
+pub fn update_news_hud(feed: Res<NewsFeed>, mut query: Query<&mut Text, With<NewsHud>>) {
+    if !feed.is_changed() {
+        return;
+    }
+    let lines: Vec<String> = feed
+        .items
+        .iter()
+        .take(NEWS_LINES)
+        .map(|item| {
+            format!(
+                "Day {:.0}: {}",
+                item.seconds / SECONDS_PER_DAY,
+                item.headline
+            )
+        })
+        .collect();
+    for mut text in query.iter_mut() {
+        text.sections[0].value = lines.join("\n");
+    }
+}
+
+pub fn cleanup_news_hud(mut commands: Commands, query: Query<Entity, With<NewsHud>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartographer::{test_sector_row, FactionKind, PoliticalAxis};
+
+    /// Two Sectors, the first held by the Terran Union and the second by the Free Worlds.
+    fn universe() -> (Factions, Sectors, FactionId, FactionId) {
+        let mut factions = Factions::default();
+        let mut create = |name: &str, sector: usize| {
+            let id = factions
+                .create_faction(name, FactionKind::Government, PoliticalAxis::default())
+                .unwrap();
+            let faction = factions.get_mut_by_id(id).unwrap();
+            faction.home = Some(sector);
+            faction.territory.insert(sector);
+            id
+        };
+        let terran = create("Terran Union", 0);
+        let free = create("Free Worlds", 1);
+        (factions, test_sector_row(2), terran, free)
+    }
+
+    #[test]
+    fn test_territory_headlines() {
+        let (mut factions, sectors, terran, free) = universe();
+        let change = |sector, from, to| TerritoryChange { sector, from, to };
+
+        factions.transfer_sector(0, None);
+        assert_eq!(
+            territory_headlines(&factions, &sectors, &change(0, Some(terran), None)),
+            vec![
+                "Terran Union abandons Sector 0".to_string(),
+                "Terran Union has lost all its territory".to_string(),
+            ]
+        );
+        factions.transfer_sector(0, Some(free));
+        assert_eq!(
+            territory_headlines(&factions, &sectors, &change(0, None, Some(free))),
+            vec!["Free Worlds lays claim to Sector 0".to_string()]
+        );
+        factions.transfer_sector(0, Some(terran));
+        assert_eq!(
+            territory_headlines(&factions, &sectors, &change(0, Some(free), Some(terran))),
+            vec!["Terran Union seizes Sector 0 from the Free Worlds".to_string()]
+        );
+        // Nothing to say about places or people nobody has heard of.
+        assert!(territory_headlines(&factions, &sectors, &change(7, None, Some(free))).is_empty());
+        assert!(territory_headlines(&factions, &sectors, &change(1, None, None)).is_empty());
+    }
+
+    #[test]
+    fn test_news_feed() {
+        let mut feed = NewsFeed::default();
+        for story in 0..MAX_NEWS + 5 {
+            feed.push(story as f64, format!("Story {}", story));
+        }
+        assert_eq!(feed.items.len(), MAX_NEWS);
+        assert_eq!(feed.items[0].headline, format!("Story {}", MAX_NEWS + 4));
+    }
+}
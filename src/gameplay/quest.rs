@@ -0,0 +1,316 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Work Factions offer the Player, paid for in standing.
+use super::economy::{CargoHold, Commodity, Docked, Economy};
+use super::shipyard::ShipyardOpen;
+use super::ReputationEvent;
+use crate::cartographer::{FactionId, Factions, Sectors};
+use crate::utility::{WorldClock, SECONDS_PER_DAY};
+use bevy::prelude::*;
+
+/// Days a Quest stays on offer before it is withdrawn.
+pub const QUEST_OFFER_DAYS: f64 = 60.0;
+/// Offered Quests listed while docked, taken on with the number keys.
+pub const LISTED_QUESTS: usize = 3;
+/// Units of cargo a Quest asks to be delivered.
+pub const QUEST_CARGO_UNITS: u32 = 10;
+/// Share of a Quest's reward lost with its target as soon as the Quest is taken on.
+const ACCEPT_SHARE: f32 = 0.5;
+
+/// What the Quest giver wants done.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum QuestKind {
+    /// Help a Government put down a Rebellion.
+    SuppressRebellion,
+    /// Run supplies to a Rebellion.
+    SupplyRebellion,
+}
+
+impl QuestKind {
+    /// Cargo the Quest giver wants delivered.
+    pub fn cargo(self) -> Commodity {
+        match self {
+            QuestKind::SuppressRebellion => Commodity::Weapons,
+            QuestKind::SupplyRebellion => Commodity::Medicine,
+        }
+    }
+}
+
+/// Job offered by one Faction against another.
+#[derive(Debug, Clone)]
+pub struct Quest {
+    pub kind: QuestKind,
+    /// Faction offering the job
+    pub giver: FactionId,
+    /// Faction the job works against
+    pub target: FactionId,
+    /// Sector the job takes place in
+    pub sector: usize,
+    /// Standing gained with the giver, and lost with the target
+    pub reward: f32,
+    /// World time in seconds the offer is withdrawn
+    pub expires: f64,
+}
+
+impl Quest {
+    /// One line summary of the job, naming who and where.
+    pub fn describe(&self, factions: &Factions, sectors: &Sectors) -> String {
+        let name = |id: FactionId| {
+            factions
+                .get_by_id(id)
+                .map_or("Unknown", |faction| faction.name.as_str())
+        };
+        let sector = sectors
+            .get(self.sector)
+            .map_or("Unknown", |sector| sector.name.as_str());
+        let job = match self.kind {
+            QuestKind::SuppressRebellion => "to help put down",
+            QuestKind::SupplyRebellion => "to supply the fight against",
+        };
+        format!(
+            "Deliver {} {:?} {} the {} in {} for the {} ({:+.0})",
+            QUEST_CARGO_UNITS,
+            self.kind.cargo(),
+            job,
+            name(self.target),
+            sector,
+            name(self.giver),
+            self.reward
+        )
+    }
+    /// Does this Quest work for the other side of `other`
+    pub fn opposes(&self, other: &Quest) -> bool {
+        self.giver == other.target && self.target == other.giver
+    }
+    /// Standing gained with the giver and lost with the target, as shares of the reward.
+    fn standing(&self, giver_share: f32, target_share: f32) -> [ReputationEvent; 2] {
+        let reason = format!("{:?}", self.kind);
+        [
+            ReputationEvent {
+                faction: self.giver,
+                delta: self.reward * giver_share,
+                reason: reason.clone(),
+            },
+            ReputationEvent {
+                faction: self.target,
+                delta: -self.reward * target_share,
+                reason,
+            },
+        ]
+    }
+}
+
+/// Quests on offer to, and taken on by, the Player.
+#[derive(Debug, Default)]
+pub struct QuestBoard {
+    pub offered: Vec<Quest>,
+    pub accepted: Vec<Quest>,
+}
+
+impl QuestBoard {
+    /// Put a Quest on offer
+    pub fn offer(&mut self, quest: Quest) {
+        self.offered.push(quest);
+    }
+    /// Take on an offered Quest, withdrawing every offer from the other side.
+    ///
+    /// Returns the standing lost with the target, nobody works both sides of a fight.
+    pub fn accept(&mut self, index: usize) -> Option<ReputationEvent> {
+        let quest = self.offered.get(index)?;
+        if self.accepted.iter().any(|accepted| accepted.opposes(quest)) {
+            return None;
+        }
+        let quest = self.offered.remove(index);
+        self.offered.retain(|offer| !offer.opposes(&quest));
+        let [_, lost] = quest.standing(0.0, ACCEPT_SHARE);
+        self.accepted.push(quest);
+        Some(lost)
+    }
+    /// Withdraw offers past `seconds`, and any Quest naming a Faction that isn't `active`.
+    /// Returns how many Quests were withdrawn.
+    pub fn expire<F: Fn(FactionId) -> bool>(&mut self, seconds: f64, active: F) -> usize {
+        let valid = |quest: &Quest| active(quest.giver) && active(quest.target);
+        let before = self.offered.len() + self.accepted.len();
+        self.offered
+            .retain(|quest| quest.expires > seconds && valid(quest));
+        self.accepted.retain(|quest| valid(quest));
+        before - self.offered.len() - self.accepted.len()
+    }
+    /// Deliver cargo for every accepted Quest taking place in a Sector that the hold has enough
+    /// aboard for, returning the standing they earned.
+    pub fn complete_in(&mut self, sector: usize, cargo: &mut CargoHold) -> Vec<ReputationEvent> {
+        let mut events = Vec::new();
+        while let Some(index) = self.deliverable(sector, cargo) {
+            let quest = self.accepted.remove(index);
+            cargo.unload(quest.kind.cargo(), QUEST_CARGO_UNITS);
+            events.extend(quest.standing(1.0, 1.0 - ACCEPT_SHARE));
+        }
+        events
+    }
+    /// First accepted Quest taking place in a Sector that the hold has enough cargo for.
+    pub fn deliverable(&self, sector: usize, cargo: &CargoHold) -> Option<usize> {
+        self.accepted.iter().position(|quest| {
+            quest.sector == sector && cargo.count(quest.kind.cargo()) >= QUEST_CARGO_UNITS
+        })
+    }
+}
+
+/// Withdraw offers that have run out of time, and any Quest whose Factions are gone.
+pub fn expire_quests(
+    world_clock: Res<WorldClock>,
+    factions: Res<Factions>,
+    mut board: ResMut<QuestBoard>,
+) {
+    let seconds = world_clock.seconds_since_epoch();
+    let withdrawn = board.expire(seconds, |id| factions.contains(id));
+    if withdrawn > 0 {
+        trace!("Withdrew {} Quests", withdrawn);
+    }
+}
+
+/// Take on one of the listed Quests with `1` to `3` while docked.
+pub fn process_quest_input(
+    keyboard: Res<Input<KeyCode>>,
+    docked: Res<Docked>,
+    shipyard: Res<ShipyardOpen>,
+    mut board: ResMut<QuestBoard>,
+    mut reputation: EventWriter<ReputationEvent>,
+) {
+    if docked.0.is_none() || shipyard.0 {
+        return;
+    }
+    let keys = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3];
+    if let Some(index) = keys.iter().position(|key| keyboard.just_pressed(*key)) {
+        if let Some(event) = board.accept(index) {
+            trace!("Accepted {} against {:?}", event.reason, event.faction);
+            reputation.send(event);
+        }
+    }
+}
+
+/// Hand over the cargo for accepted Quests while docked in the Sector they take place in.
+pub fn complete_quests_on_docking(
+    docked: Res<Docked>,
+    economy: Res<Economy>,
+    mut cargo: ResMut<CargoHold>,
+    mut board: ResMut<QuestBoard>,
+    mut reputation: EventWriter<ReputationEvent>,
+) {
+    if !docked.is_changed() && !cargo.is_changed() {
+        return;
+    }
+    let sector = match docked.0.and_then(|orbital| economy.get(orbital)) {
+        Some(market) => market.sector,
+        None => return,
+    };
+    if board.deliverable(sector, &cargo).is_none() {
+        return;
+    }
+    for event in board.complete_in(sector, &mut cargo) {
+        reputation.send(event);
+    }
+}
+
+/// Expiry time for a Quest offered now.
+pub fn quest_expiry(seconds: f64) -> f64 {
+    seconds + QUEST_OFFER_DAYS * SECONDS_PER_DAY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartographer::{test_sector_row, FactionKind, PoliticalAxis};
+
+    /// The Player and a Faction to work for or against.
+    fn factions() -> (Factions, FactionId, FactionId) {
+        let mut factions = Factions::default();
+        let other = factions
+            .create_faction("Rebels", FactionKind::Rebellion, PoliticalAxis::default())
+            .unwrap();
+        (factions, FactionId::PLAYER, other)
+    }
+
+    fn quest(kind: QuestKind, giver: FactionId, target: FactionId, sector: usize) -> Quest {
+        Quest {
+            kind,
+            giver,
+            target,
+            sector,
+            reward: 15.0,
+            expires: quest_expiry(0.0),
+        }
+    }
+
+    #[test]
+    fn test_quest_expiry() {
+        let (_, player, other) = factions();
+        let mut board = QuestBoard::default();
+        board.offer(quest(QuestKind::SuppressRebellion, player, other, 0));
+        board.offer(quest(QuestKind::SupplyRebellion, player, other, 1));
+        board.accept(1);
+        assert_eq!(board.expire(quest_expiry(0.0) - 1.0, |_| true), 0);
+        // Offers lapse, but work already taken on doesn't.
+        assert_eq!(board.expire(quest_expiry(0.0), |_| true), 1);
+        assert!(board.offered.is_empty());
+        assert_eq!(board.accepted.len(), 1);
+        // Nobody pays for work against a Faction that is gone.
+        assert_eq!(board.expire(0.0, |id| id == player), 1);
+        assert!(board.accepted.is_empty());
+    }
+
+    #[test]
+    fn test_quest_sides() {
+        let (factions, player, other) = factions();
+        let mut board = QuestBoard::default();
+        board.offer(quest(QuestKind::SuppressRebellion, player, other, 3));
+        board.offer(quest(QuestKind::SupplyRebellion, other, player, 3));
+        let sectors = test_sector_row(4);
+        let description = board.offered[1].describe(&factions, &sectors);
+        assert!(description.contains("Medicine"));
+        assert!(description.contains("Sector 3"));
+        assert!(description.contains("for the Rebels"));
+
+        // Taking a side costs standing with the other, and their offer is withdrawn.
+        assert!(board.accept(2).is_none());
+        let lost = board.accept(0).unwrap();
+        assert_eq!((lost.faction, lost.delta), (other, -7.5));
+        assert!(board.offered.is_empty());
+        board.offer(quest(QuestKind::SupplyRebellion, other, player, 3));
+        assert!(board.accept(0).is_none());
+    }
+
+    #[test]
+    fn test_quest_completion() {
+        let (_, player, other) = factions();
+        let mut board = QuestBoard::default();
+        board.offer(quest(QuestKind::SuppressRebellion, player, other, 3));
+        board.accept(0).unwrap();
+        let mut cargo = CargoHold::default();
+        cargo.load(Commodity::Weapons, QUEST_CARGO_UNITS - 1);
+        assert!(board.complete_in(3, &mut cargo).is_empty());
+        cargo.load(Commodity::Weapons, 2);
+        assert!(board.complete_in(4, &mut cargo).is_empty());
+        let events = board.complete_in(3, &mut cargo);
+        assert_eq!(events.len(), 2);
+        assert_eq!((events[0].faction, events[0].delta), (player, 15.0));
+        assert_eq!((events[1].faction, events[1].delta), (other, -7.5));
+        assert_eq!(cargo.count(Commodity::Weapons), 1);
+        assert!(board.accepted.is_empty());
+    }
+}
@@ -0,0 +1,414 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Unrest builds in Sectors whose people disagree with their Government, until they rise up.
+use super::news::NewsFeed;
+use super::quest::{quest_expiry, Quest, QuestBoard, QuestKind};
+use super::strategy::{TerritoryChange, TURN_DAYS};
+use crate::cartographer::{
+    DiplomaticEvent, DiplomaticEventKind, FactionChange, FactionId, FactionKind, Factions,
+    PoliticalAxis, Sectors,
+};
+use crate::generation::{generate_faction, UniverseSeed};
+use crate::utility::{WorldClock, SECONDS_PER_DAY};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro128PlusPlus;
+use std::collections::HashSet;
+
+/// Unrest at which a Sector rises in revolt.
+pub const REVOLT_THRESHOLD: f32 = 100.0;
+/// How far a Sector's people lean from their Government on each axis, at most.
+const POPULATION_SPREAD: f32 = 0.6;
+/// Political distance people put up with before growing restless.
+const TOLERANCE: f32 = 0.1;
+/// Unrest per turn for each unit of political distance past tolerance.
+const UNREST_PER_TENSION: f32 = 40.0;
+/// Unrest in a Sector seized by force.
+const SEIZURE_UNREST: f32 = 20.0;
+/// Unrest across a Government's Sectors when it goes to war, or calm when it makes peace.
+const WAR_UNREST: f32 = 5.0;
+/// Hexes a revolt spreads across to restless neighbouring Sectors.
+const REVOLT_RANGE: i32 = 2;
+/// Standing a rebellion Quest is worth.
+const QUEST_REWARD: f32 = 15.0;
+
+/// Mood of the people in a Sector.
+#[derive(Debug, Copy, Clone)]
+pub struct SectorMood {
+    /// Where the people lean politically
+    pub politics: PoliticalAxis,
+    /// Built up unrest, revolt at `REVOLT_THRESHOLD`
+    pub unrest: f32,
+}
+
+/// Mood of every governed Sector, by Sector ID.
+#[derive(Debug, Clone)]
+pub struct Unrest {
+    pub sectors: HashMap<usize, SectorMood>,
+    /// World time in seconds unrest is next assessed
+    pub next: f64,
+}
+
+impl Default for Unrest {
+    fn default() -> Unrest {
+        Unrest {
+            sectors: HashMap::default(),
+            next: TURN_DAYS * SECONDS_PER_DAY,
+        }
+    }
+}
+
+impl Unrest {
+    /// Unrest in a Sector
+    pub fn level(&self, sector: usize) -> f32 {
+        self.sectors
+            .get(&sector)
+            .map(|mood| mood.unrest)
+            .unwrap_or(0.0)
+    }
+    /// Mood of a Sector, people first lean somewhere near whoever governs them.
+    fn mood(
+        &mut self,
+        universe_seed: u64,
+        sector: usize,
+        government: PoliticalAxis,
+    ) -> &mut SectorMood {
+        self.sectors.entry(sector).or_insert_with(|| {
+            let mut rng = Xoshiro128PlusPlus::seed_from_u64(universe_seed ^ sector as u64);
+            let mut lean = || rng.gen_range(-POPULATION_SPREAD..POPULATION_SPREAD);
+            SectorMood {
+                politics: PoliticalAxis::new(
+                    government.economic + lean(),
+                    government.civil + lean(),
+                    government.social + lean(),
+                    government.diplomatic + lean(),
+                ),
+                unrest: 0.0,
+            }
+        })
+    }
+    /// Grow unrest in every governed Sector by how far its people lean from their Government.
+    fn assess(&mut self, universe_seed: u64, governed: &[(FactionId, PoliticalAxis, Vec<usize>)]) {
+        for (_, politics, territory) in governed.iter() {
+            for sector in territory.iter() {
+                let mood = self.mood(universe_seed, *sector, *politics);
+                let tension = mood.politics.distance(politics) - TOLERANCE;
+                mood.unrest = (mood.unrest + tension * UNREST_PER_TENSION).max(0.0);
+            }
+        }
+    }
+    /// Add to, or calm with a negative amount, a Sector's unrest.
+    fn stir(&mut self, sector: usize, amount: f32) {
+        if let Some(mood) = self.sectors.get_mut(&sector) {
+            mood.unrest = (mood.unrest + amount).max(0.0);
+        }
+    }
+}
+
+/// Wars, Peace and Sectors taken by force stir up the people.
+pub fn unrest_event_system(
+    factions: Res<Factions>,
+    mut unrest: ResMut<Unrest>,
+    mut territory: EventReader<TerritoryChange>,
+    mut diplomacy: EventReader<DiplomaticEvent>,
+) {
+    for change in territory.iter() {
+        if change.from.is_some() {
+            unrest.stir(change.sector, SEIZURE_UNREST);
+        }
+    }
+    for event in diplomacy.iter() {
+        let amount = match event.kind {
+            DiplomaticEventKind::War => WAR_UNREST,
+            DiplomaticEventKind::Peace => -WAR_UNREST,
+            _ => continue,
+        };
+        for id in [event.a, event.b] {
+            if let Some(faction) = factions.get_by_id(id) {
+                for sector in faction.territory.iter() {
+                    unrest.stir(*sector, amount);
+                }
+            }
+        }
+    }
+}
+
+/// Grow unrest each turn from political tension, and raise Rebellions where it boils over.
+#[allow(clippy::too_many_arguments)]
+pub fn rebellion_system(
+    world_clock: Res<WorldClock>,
+    universe_seed: Res<UniverseSeed>,
+    mut unrest: ResMut<Unrest>,
+    mut factions: ResMut<Factions>,
+    sectors: Res<Sectors>,
+    mut board: ResMut<QuestBoard>,
+    mut feed: ResMut<NewsFeed>,
+    mut changes: EventWriter<FactionChange>,
+    mut transfers: EventWriter<TerritoryChange>,
+    mut diplomacy: EventWriter<DiplomaticEvent>,
+) {
+    let seconds = world_clock.seconds_since_epoch();
+    if seconds < unrest.next {
+        return;
+    }
+    unrest.next += TURN_DAYS * SECONDS_PER_DAY;
+
+    let governed = governed(&factions);
+    unrest.assess(universe_seed.0, &governed);
+
+    let mut rng = Xoshiro128PlusPlus::seed_from_u64(universe_seed.0 ^ seconds.to_bits());
+    for (government, _, territory) in governed.iter() {
+        let (rebellion, flashpoint, rising) = match raise_rebellion(
+            &mut rng,
+            &mut factions,
+            &mut unrest,
+            &sectors,
+            *government,
+            territory,
+        ) {
+            Some(rebellion) => rebellion,
+            None => continue,
+        };
+        for sector in rising.iter() {
+            transfers.send(TerritoryChange {
+                sector: *sector,
+                from: Some(*government),
+                to: Some(rebellion),
+            });
+        }
+        changes.send(FactionChange::Split {
+            from: *government,
+            into: rebellion,
+        });
+        diplomacy.send(DiplomaticEvent {
+            kind: DiplomaticEventKind::War,
+            a: rebellion,
+            b: *government,
+        });
+
+        // Both sides look for help.
+        let expires = quest_expiry(seconds);
+        for (kind, giver, target) in [
+            (QuestKind::SuppressRebellion, *government, rebellion),
+            (QuestKind::SupplyRebellion, rebellion, *government),
+        ] {
+            board.offer(Quest {
+                kind,
+                giver,
+                target,
+                sector: flashpoint,
+                reward: QUEST_REWARD,
+                expires,
+            });
+        }
+        let name = |id: FactionId| factions.get_by_id(id).map(|faction| faction.name.as_str());
+        if let (Some(government), Some(rebellion)) = (name(*government), name(rebellion)) {
+            feed.push(
+                seconds,
+                format!("Both the {} and the {} are hiring", government, rebellion),
+            );
+        }
+    }
+}
+
+/// Governments with the Sectors they govern, in order. Only Governments answer to the people.
+fn governed(factions: &Factions) -> Vec<(FactionId, PoliticalAxis, Vec<usize>)> {
+    factions
+        .iter()
+        .filter(|faction| faction.kind == FactionKind::Government)
+        .map(|faction| {
+            let mut territory: Vec<usize> = faction.territory.iter().copied().collect();
+            territory.sort_unstable();
+            (faction.id, faction.politics, territory)
+        })
+        .collect()
+}
+
+/// Split the Sectors rising around the first of a Government's Sectors to boil over off into a
+/// new Rebellion.
+///
+/// Returns the Rebellion, the flashpoint and every Sector that rose, in order.
+fn raise_rebellion<R: Rng>(
+    rng: &mut R,
+    factions: &mut Factions,
+    unrest: &mut Unrest,
+    sectors: &Sectors,
+    government: FactionId,
+    territory: &[usize],
+) -> Option<(FactionId, usize, Vec<usize>)> {
+    let flashpoint = *territory
+        .iter()
+        .find(|sector| unrest.level(**sector) >= REVOLT_THRESHOLD)?;
+    let location = sectors.get(flashpoint)?.location;
+    // Restless neighbours join the revolt.
+    let rising: HashSet<usize> = sectors
+        .within(location, REVOLT_RANGE)
+        .map(|sector| sector.id)
+        .filter(|sector| territory.binary_search(sector).is_ok())
+        .filter(|sector| *sector == flashpoint || unrest.level(*sector) >= REVOLT_THRESHOLD / 2.0)
+        .collect();
+
+    let proto = generate_faction(FactionKind::Rebellion, factions.len(), rng);
+    let rebellion = match factions.split(government, &proto.name, proto.kind, &rising) {
+        Ok(rebellion) => rebellion,
+        Err(error) => {
+            warn!("Rebellion failed to rise: {}", error);
+            return None;
+        }
+    };
+    if let Some(faction) = factions.get_mut_by_id(rebellion) {
+        faction.politics = average_politics(
+            rising
+                .iter()
+                .filter_map(|sector| unrest.sectors.get(sector).map(|mood| mood.politics)),
+        );
+        faction.color = proto.color;
+    }
+    trace!("Rebellion {} rises in {} Sectors", proto.name, rising.len());
+
+    let mut rising: Vec<usize> = rising.into_iter().collect();
+    rising.sort_unstable();
+    for sector in rising.iter() {
+        if let Some(mood) = unrest.sectors.get_mut(sector) {
+            mood.unrest = 0.0;
+        }
+    }
+    Some((rebellion, flashpoint, rising))
+}
+
+/// Political middle ground of a group of people.
+fn average_politics(politics: impl Iterator<Item = PoliticalAxis>) -> PoliticalAxis {
+    let mut count = 0.0;
+    let mut sum = PoliticalAxis::default();
+    for axis in politics {
+        sum.economic += axis.economic;
+        sum.civil += axis.civil;
+        sum.social += axis.social;
+        sum.diplomatic += axis.diplomatic;
+        count += 1.0;
+    }
+    if count == 0.0 {
+        return sum;
+    }
+    PoliticalAxis::new(
+        sum.economic / count,
+        sum.civil / count,
+        sum.social / count,
+        sum.diplomatic / count,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartographer::test_sector_row;
+
+    /// Unrest with each Sector's people sharing their Government's politics.
+    fn moods(levels: &[f32]) -> Unrest {
+        let mut unrest = Unrest::default();
+        for (sector, level) in levels.iter().enumerate() {
+            unrest.sectors.insert(
+                sector,
+                SectorMood {
+                    politics: PoliticalAxis::default(),
+                    unrest: *level,
+                },
+            );
+        }
+        unrest
+    }
+
+    #[test]
+    fn test_unrest_accumulates() {
+        let mut unrest = moods(&[0.0]);
+        // The people of Sector 1 lean as far from their Government as they can.
+        unrest.sectors.insert(
+            1,
+            SectorMood {
+                politics: PoliticalAxis::new(1.0, 1.0, 1.0, 1.0),
+                unrest: 0.0,
+            },
+        );
+        let governed = [(FactionId::PLAYER, PoliticalAxis::default(), vec![0, 1, 2])];
+        unrest.assess(7, &governed);
+        let tension = (0.5 - TOLERANCE) * UNREST_PER_TENSION;
+        assert_eq!(unrest.level(0), 0.0);
+        assert!((unrest.level(1) - tension).abs() < 1e-4);
+        unrest.assess(7, &governed);
+        assert!((unrest.level(1) - 2.0 * tension).abs() < 1e-4);
+        // Newly governed people are given a mood, and it's the same for the same Universe.
+        let mood = unrest.sectors[&2];
+        let mut again = Unrest::default();
+        again.assess(7, &governed);
+        assert_eq!(again.sectors[&2].politics.economic, mood.politics.economic);
+    }
+
+    #[test]
+    fn test_rebellion_rises_past_threshold() {
+        let sectors = test_sector_row(5);
+        let mut factions = Factions::default();
+        let empire = factions
+            .create_faction("Empire", FactionKind::Government, PoliticalAxis::default())
+            .unwrap();
+        if let Some(faction) = factions.get_mut_by_id(empire) {
+            faction.home = Some(4);
+            faction.territory = (0..5).collect();
+        }
+        let territory: Vec<usize> = (0..5).collect();
+        let mut rng = Xoshiro128PlusPlus::seed_from_u64(3);
+
+        // Restless, but nowhere has boiled over yet.
+        let calm = REVOLT_THRESHOLD - 1.0;
+        let mut unrest = moods(&[calm, calm, calm, calm, calm]);
+        let raised = raise_rebellion(
+            &mut rng,
+            &mut factions,
+            &mut unrest,
+            &sectors,
+            empire,
+            &territory,
+        );
+        assert!(raised.is_none());
+
+        // Sector 0 revolts, taking the restless within reach with it but not Sector 3 beyond.
+        let (half, full) = (REVOLT_THRESHOLD / 2.0, REVOLT_THRESHOLD);
+        let mut unrest = moods(&[full, half, half, full, 0.0]);
+        let raised = raise_rebellion(
+            &mut rng,
+            &mut factions,
+            &mut unrest,
+            &sectors,
+            empire,
+            &territory,
+        );
+        let (rebellion, flashpoint, rising) = raised.expect("Sector 0 is past the threshold");
+        assert_eq!(flashpoint, 0);
+        assert_eq!(rising, vec![0, 1, 2]);
+        let rebels = factions.get_by_id(rebellion).unwrap();
+        assert_eq!(rebels.kind, FactionKind::Rebellion);
+        assert_eq!(rebels.territory, (0..3).collect::<HashSet<usize>>());
+        assert_eq!(
+            factions.get_by_id(empire).unwrap().territory,
+            (3..5).collect::<HashSet<usize>>()
+        );
+        assert_eq!(unrest.level(0), 0.0);
+        assert_eq!(unrest.level(3), full);
+    }
+}
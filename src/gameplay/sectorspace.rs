@@ -18,11 +18,21 @@
 
 mod entity;
 mod input;
+mod motion;
 mod orbit;
 mod setup;
 
 pub use self::entity::*;
-pub use self::input::{process_gamepad_input, process_keyboard_input, process_mouse_input};
-pub use self::orbit::{OrbitalParent, OrbitalChildren, OrbitalParameters, OrbitalPosition};
-pub use self::setup::{cleanup_sectorspace, initialize_sectorspace, setup_sectorspace, update_sectorspace};
+pub use self::input::{
+    process_gamepad_input, process_keyboard_input, process_landing_input,
+    process_leave_sector_input, process_mining_input, process_mouse_input,
+};
+pub use self::motion::{ship_motion_system, GravityWell, ShipMotion, PLANETARY_MU, STELLAR_MU};
+pub use self::orbit::{
+    orbital_update_system, OrbitalChildren, OrbitalParameters, OrbitalParent, OrbitalPosition,
+};
+pub use self::setup::{
+    cleanup_sectorspace, initialize_sectorspace, setup_sectorspace, update_sectorspace,
+    CurrentSector,
+};
 use super::WorldClock;
@@ -16,16 +16,59 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
 
+use crate::gameplay::ship::ShipDesign;
 use bevy::prelude::*;
 use hexgrid::Coordinate;
 
 /// SectorSpace Tag with Sector Hex Location
 pub struct SectorSpace(pub Coordinate<i32>);
 
+/// Link from a spawned Entity back to its persistent Orbital record
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct OrbitalLink(pub usize);
+
+/// Tag Denoting an Orbital that has been destroyed
+pub struct Destroyed;
+
+/// Tag Denoting an Orbital ships can dock with
+pub struct Dockable;
+
+/// Orbital that can be mined for minerals
+pub struct Minable {
+    /// Minerals remaining
+    pub minerals: f32,
+}
+
+impl Minable {
+    /// Extract up to `amount` minerals, returning how much was actually mined.
+    pub fn mine(&mut self, amount: f32) -> f32 {
+        let mined = f32::min(self.minerals, amount.max(0.0));
+        self.minerals -= mined;
+        mined
+    }
+}
+
+/// Annular Field around an Orbital's parent, such as an Asteroid Belt or Planetary Ring.
+pub struct BeltField {
+    /// Inner radius of the annulus
+    pub inner_radius: f32,
+    /// Outer radius of the annulus
+    pub outer_radius: f32,
+}
+
+impl BeltField {
+    /// Is a point at `distance` from the field's center inside the annulus.
+    pub fn contains(&self, distance: f32) -> bool {
+        distance >= self.inner_radius && distance <= self.outer_radius
+    }
+}
+
 /// Star Information
 pub struct StarInfo {
     /// Name of Star
     pub name: String,
+    /// Spectral Classification
+    pub class: String,
     /// Mass of Primary
     pub mass: f32,
     /// How Bright is the Primary
@@ -76,4 +119,32 @@ pub struct StationInfo {
 pub struct ShipInfo {
     /// Name of Ship
     pub name: String,
+    /// Hull and Equipment the Ship is fitted with
+    pub design: ShipDesign,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mine() {
+        let mut minable = Minable { minerals: 15.0 };
+        assert_eq!(minable.mine(10.0), 10.0);
+        assert_eq!(minable.mine(-3.0), 0.0);
+        assert_eq!(minable.mine(10.0), 5.0);
+        assert_eq!(minable.minerals, 0.0);
+        assert_eq!(minable.mine(10.0), 0.0);
+    }
+
+    #[test]
+    fn test_belt_field() {
+        let belt = BeltField {
+            inner_radius: 90.0,
+            outer_radius: 110.0,
+        };
+        assert!(belt.contains(100.0));
+        assert!(!belt.contains(80.0));
+        assert!(!belt.contains(120.0));
+    }
 }
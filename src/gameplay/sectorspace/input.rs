@@ -16,12 +16,28 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
 
+use super::{BeltField, Minable, OrbitalLink, OrbitalParameters, PlanetInfo};
+use crate::cartographer::Orbitals;
+use crate::gameplay::combat::Hostile;
+use crate::gameplay::hyperspace::{can_leave_sector, well_radius};
+use crate::gameplay::ship::PlayerVessel;
+use crate::gameplay::tilespace::SurfaceLocation;
+use crate::gameplay::{CargoHold, Commodity, CurrentSector, Docked, GameplayState};
+use crate::utility::WorldClock;
 use bevy::app::EventReader;
 use bevy::input::gamepad::{GamepadEvent, GamepadEventType};
 use bevy::input::keyboard::KeyboardInput;
 use bevy::input::mouse::{MouseButtonInput, MouseMotion, MouseWheel};
+use bevy::prelude::*;
 use bevy::window::CursorMoved;
 
+/// Multiple of a body's size the Player must be within to land on it.
+const LANDING_SCALE: f32 = 2.0;
+/// Distance from an Asteroid's surface the Player must be within to mine it.
+const MINING_RANGE: f32 = 20.0;
+/// Units of Ore mined with each press of `M`, one mineral per unit.
+const MINING_YIELD: u32 = 5;
+
 pub fn process_keyboard_input(mut keyboard_input_events: EventReader<KeyboardInput>) {
     for event in keyboard_input_events.iter() {
         println!("{:?}", event);
@@ -66,3 +82,122 @@ pub fn process_gamepad_input(mut gamepad_event: EventReader<GamepadEvent>) {
         }
     }
 }
+
+/// Climb back out of the Sector's Gravity Well into Hyperspace with `H`, once undocked and clear
+/// of any Hostile ships.
+pub fn process_leave_sector_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    docked: Res<Docked>,
+    current_sector: Res<CurrentSector>,
+    orbitals: Res<Orbitals>,
+    mut gameplay_state: ResMut<State<GameplayState>>,
+    vessel_query: Query<&Transform, With<PlayerVessel>>,
+    hostile_query: Query<Entity, With<Hostile>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::H) || docked.0.is_some() {
+        return;
+    }
+    let distance = vessel_query
+        .iter()
+        .next()
+        .map_or(0.0, |transform| transform.translation.truncate().length());
+    let well = well_radius(&orbitals, current_sector.0);
+    if !can_leave_sector(distance, well, hostile_query.iter().count()) {
+        info!("Hostile ships hold us in the Gravity Well, deal with them or outrun them");
+        return;
+    }
+    if let Err(error) = gameplay_state.set(GameplayState::Hyperspace) {
+        warn!("Unable to return to Hyperspace: {:?}", error);
+    }
+}
+
+/// Land on the nearest Planet or Moon with `L`, touching down on the surface beneath the ship.
+pub fn process_landing_input(
+    mut commands: Commands,
+    keyboard_input: Res<Input<KeyCode>>,
+    world_clock: Res<WorldClock>,
+    docked: Res<Docked>,
+    mut gameplay_state: ResMut<State<GameplayState>>,
+    vessel_query: Query<&GlobalTransform, With<PlayerVessel>>,
+    body_query: Query<
+        (&GlobalTransform, &Sprite, &OrbitalLink, &OrbitalParameters),
+        With<PlanetInfo>,
+    >,
+) {
+    if !keyboard_input.just_pressed(KeyCode::L) || docked.0.is_some() {
+        return;
+    }
+    let vessel = match vessel_query.iter().next() {
+        Some(vessel) => vessel.translation.truncate(),
+        None => return,
+    };
+    let nearest = body_query
+        .iter()
+        .map(|(transform, sprite, link, parameters)| {
+            let offset = vessel - transform.translation.truncate();
+            (offset, sprite.size.x * LANDING_SCALE, link, parameters)
+        })
+        .filter(|(offset, reach, ..)| offset.length() <= *reach)
+        .min_by(|a, b| {
+            a.0.length()
+                .partial_cmp(&b.0.length())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    let (offset, _, link, parameters) = match nearest {
+        Some(nearest) => nearest,
+        None => {
+            trace!("Nothing close enough to land on");
+            return;
+        }
+    };
+    let angle = offset.y.atan2(offset.x);
+    commands.insert_resource(SurfaceLocation {
+        orbital: link.0,
+        latitude: 0.0,
+        longitude: parameters.longitude_at(world_clock.seconds_since_epoch(), angle),
+    });
+    if let Err(error) = gameplay_state.set(GameplayState::Tilespace) {
+        warn!("Unable to land: {:?}", error);
+    }
+}
+
+/// Mine a nearby Asteroid, or the Belt the ship is flying through, with `M`, loading the Ore
+/// into the hold.
+pub fn process_mining_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    docked: Res<Docked>,
+    mut cargo: ResMut<CargoHold>,
+    vessel_query: Query<&GlobalTransform, With<PlayerVessel>>,
+    mut minable_query: Query<(&GlobalTransform, &Sprite, &mut Minable, Option<&BeltField>)>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::M) || docked.0.is_some() {
+        return;
+    }
+    let vessel = match vessel_query.iter().next() {
+        Some(vessel) => vessel.translation.truncate(),
+        None => return,
+    };
+    let space = cargo.space_for(Commodity::Ore).min(MINING_YIELD);
+    if space == 0 {
+        info!("No room in the hold for more Ore");
+        return;
+    }
+    let target = minable_query
+        .iter_mut()
+        .find(|(transform, sprite, minable, belt)| {
+            let distance = (transform.translation.truncate() - vessel).length();
+            let reachable = match belt {
+                Some(belt) => belt.contains(distance),
+                None => distance <= sprite.size.x / 2.0 + MINING_RANGE,
+            };
+            reachable && minable.minerals >= 1.0
+        });
+    match target {
+        Some((_, _, mut minable, _)) => {
+            let units = minable.mine(space as f32).floor() as u32;
+            cargo.load(Commodity::Ore, units);
+            trace!("Mined {} Ore, {:.0} minerals left", units, minable.minerals);
+        }
+        None => info!("Nothing close enough to mine"),
+    }
+}
@@ -0,0 +1,168 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Newtonian motion of ships through the gravity wells of a Sector.
+use super::WorldClock;
+use crate::gameplay::WarpInterrupt;
+use bevy::prelude::*;
+
+/// Gravitational Parameter per Solar Mass
+pub const STELLAR_MU: f32 = 1000.0;
+/// Gravitational Parameter per Earth Mass
+pub const PLANETARY_MU: f32 = STELLAR_MU / 333000.0;
+/// Most physics substeps taken by a single ship in a single frame.
+const MAX_SUBSTEPS: usize = 256;
+/// Fraction of the local orbital timescale a single substep may cover and remain stable.
+const STABILITY_FACTOR: f32 = 0.01;
+
+/// Body pulling ships towards it
+pub struct GravityWell {
+    /// Gravitational Parameter (G * M)
+    pub mu: f32,
+}
+
+/// Ship Velocity and Thrust
+#[derive(Default)]
+pub struct ShipMotion {
+    /// Velocity in units per second
+    pub velocity: Vec2,
+    /// Acceleration from engines in units per second squared
+    pub thrust: Vec2,
+}
+
+/// Integrate ships through the world time elapsed this frame.
+///
+/// Time Warp can make a single frame span hours, so the step is split into substeps no longer
+/// than a fraction of the orbital timescale at the closest well. Should that need more than
+/// [MAX_SUBSTEPS] the player is dropped out of Time Warp instead of being flung out of orbit.
+pub fn ship_motion_system(
+    world_clock: Res<WorldClock>,
+    mut interrupts: EventWriter<WarpInterrupt>,
+    well_query: Query<(&GlobalTransform, &GravityWell)>,
+    mut ship_query: Query<(&mut ShipMotion, &mut Transform)>,
+) {
+    let delta = world_clock.delta().as_secs_f32();
+    if delta <= 0.0 {
+        return;
+    }
+    let wells: Vec<(Vec2, f32)> = well_query
+        .iter()
+        .map(|(transform, well)| (transform.translation.truncate(), well.mu))
+        .collect();
+    for (mut motion, mut transform) in ship_query.iter_mut() {
+        let (position, velocity, unstable) = integrate(
+            &wells,
+            transform.translation.truncate(),
+            motion.velocity,
+            motion.thrust,
+            delta,
+        );
+        if unstable {
+            interrupts.send(WarpInterrupt::new("Gravity Well too steep for Time Warp"));
+        }
+        motion.velocity = velocity;
+        transform.translation = position.extend(transform.translation.z);
+    }
+}
+
+/// Step a ship through `delta` seconds, returning its new position and velocity, and whether
+/// it needed more than [MAX_SUBSTEPS] to do so stably. When it does, the ship is only stepped
+/// through [MAX_SUBSTEPS] of the longest stable substep.
+fn integrate(
+    wells: &[(Vec2, f32)],
+    mut position: Vec2,
+    mut velocity: Vec2,
+    thrust: Vec2,
+    delta: f32,
+) -> (Vec2, Vec2, bool) {
+    let max_step = wells
+        .iter()
+        .map(|(center, mu)| {
+            let r = position.distance(*center).max(1.0);
+            STABILITY_FACTOR * (r * r * r / mu).sqrt()
+        })
+        .fold(delta, f32::min);
+    let substeps = ((delta / max_step).ceil() as usize).max(1);
+    // Past the cap only as much time as can be covered stably is integrated, the rest of the
+    // frame is lost while Time Warp drops.
+    let step = if substeps > MAX_SUBSTEPS {
+        max_step
+    } else {
+        delta / substeps as f32
+    };
+    for _ in 0..substeps.min(MAX_SUBSTEPS) {
+        // Semi-implicit Euler conserves orbital energy far better than explicit Euler.
+        velocity += (thrust + gravity(wells, position)) * step;
+        position += velocity * step;
+    }
+    (position, velocity, substeps > MAX_SUBSTEPS)
+}
+
+/// Sum of gravitational acceleration at `position`.
+fn gravity(wells: &[(Vec2, f32)], position: Vec2) -> Vec2 {
+    wells.iter().fold(Vec2::ZERO, |acceleration, (center, mu)| {
+        let offset = *center - position;
+        let r2 = offset.length_squared().max(1.0);
+        acceleration + offset * (mu / (r2 * r2.sqrt()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Position and velocity of a circular orbit `radius` from a well of `mu` at the origin.
+    fn circular_orbit(mu: f32, radius: f32) -> (Vec2, Vec2) {
+        (Vec2::new(radius, 0.0), Vec2::new(0.0, (mu / radius).sqrt()))
+    }
+
+    #[test]
+    fn test_substeps_hold_orbit_at_warp() {
+        let wells = [(Vec2::ZERO, 1000.0)];
+        let (position, velocity) = circular_orbit(1000.0, 100.0);
+        // A quarter orbit in a single frame.
+        let (position, _, unstable) = integrate(&wells, position, velocity, Vec2::ZERO, 50.0);
+        assert!(!unstable);
+        assert!((position.length() - 100.0).abs() < 1.0);
+        assert!(position.y > 90.0);
+    }
+
+    #[test]
+    fn test_steep_wells_are_unstable() {
+        let wells = [(Vec2::ZERO, 1000.0)];
+        let (position, velocity) = circular_orbit(1000.0, 100.0);
+        let (position, _, unstable) = integrate(&wells, position, velocity, Vec2::ZERO, 1000.0);
+        assert!(unstable);
+        // The ship stays in orbit rather than being flung out by oversized steps.
+        assert!((position.length() - 100.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_free_flight() {
+        let (position, velocity, unstable) = integrate(
+            &[],
+            Vec2::ZERO,
+            Vec2::new(1.0, 2.0),
+            Vec2::new(0.0, 1.0),
+            10.0,
+        );
+        assert!(!unstable);
+        assert_eq!(velocity, Vec2::new(1.0, 12.0));
+        assert_eq!(position, Vec2::new(10.0, 120.0));
+    }
+}
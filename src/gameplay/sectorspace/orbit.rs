@@ -17,7 +17,10 @@
 //
 
 use super::WorldClock;
+use crate::generation::ProtoOrbital;
+use crate::utility::SECONDS_PER_DAY;
 use bevy::prelude::*;
+use std::f32::consts::{FRAC_PI_2, TAU};
 use std::ops::Rem;
 
 /// Orbital Parent
@@ -42,20 +45,136 @@ impl From<Vec<Entity>> for OrbitalChildren {
 pub struct OrbitalParameters {
     /// Distance in kilometers object orbits from it's parent.
     pub distance: f32,
-    /// Duration in seconds it takes for a body to complete one full orbit (A Year)
+    /// Duration in days it takes for a body to complete one full orbit (A Year)
     pub period: f32,
+    /// Offset in radians along the orbit, bodies at Lagrange Points lead or trail their planet.
+    pub phase: f32,
+    /// Duration in days it takes for a body to complete one full rotation (Sidereal Day)
+    pub rotation_period: f32,
+    /// Axial Tilt in degrees relative to the orbital plane
+    pub axial_tilt: f32,
+    /// Does this body always show the same face to its parent
+    pub tidally_locked: bool,
+}
+
+impl From<&ProtoOrbital> for OrbitalParameters {
+    fn from(orbital: &ProtoOrbital) -> Self {
+        OrbitalParameters {
+            distance: orbital.radius,
+            period: orbital.period,
+            phase: orbital.phase,
+            rotation_period: orbital.rotation.period,
+            axial_tilt: orbital.rotation.axial_tilt,
+            tidally_locked: orbital.rotation.tidally_locked,
+        }
+    }
+}
+
+impl OrbitalParameters {
+    /// Angle in radians around the parent at `seconds` since epoch.
+    pub fn orbit_angle(&self, seconds: f64) -> f32 {
+        cycle_fraction(seconds, self.period) * TAU + self.phase
+    }
+    /// Angle in radians the body has turned about its own axis at `seconds` since epoch.
+    pub fn spin_angle(&self, seconds: f64) -> f32 {
+        if self.tidally_locked {
+            self.orbit_angle(seconds)
+        } else {
+            cycle_fraction(seconds, self.rotation_period) * TAU
+        }
+    }
+    /// Length of a Solar Day in days, `None` if the body is tidally locked and the parent never
+    /// moves across the sky.
+    pub fn solar_day(&self) -> Option<f32> {
+        if self.tidally_locked || self.rotation_period <= 0.0 {
+            return None;
+        }
+        if self.period <= 0.0 {
+            return Some(self.rotation_period);
+        }
+        let synodic = 1.0 / self.rotation_period - 1.0 / self.period;
+        if synodic.abs() <= f32::EPSILON {
+            None
+        } else {
+            Some(1.0 / synodic.abs())
+        }
+    }
+    /// Angle in radians of the body around the root star at `seconds` since epoch, measured like
+    /// `orbit_angle`. `parents` are the orbits of the body's parents out to, but not including,
+    /// the star, innermost first.
+    pub fn star_angle(&self, seconds: f64, parents: &[OrbitalParameters]) -> f32 {
+        if parents.is_empty() {
+            return self.orbit_angle(seconds);
+        }
+        let position = std::iter::once(self)
+            .chain(parents.iter())
+            .map(|orbit| {
+                let angle = orbit.orbit_angle(seconds);
+                Vec2::new(-angle.sin(), angle.cos()) * orbit.distance
+            })
+            .fold(Vec2::ZERO, |sum, offset| sum + offset);
+        f32::atan2(-position.x, position.y)
+    }
+    /// Local time of day at a surface longitude in degrees, as a fraction of a solar day where
+    /// `0.0` is midnight and `0.5` is noon. Days follow the root star, so a tidally locked moon
+    /// still sees it rise and set over the course of each orbit. `parents` are as for
+    /// `star_angle`.
+    pub fn time_of_day(&self, seconds: f64, longitude: f32, parents: &[OrbitalParameters]) -> f32 {
+        let hour_angle = (self.spin_angle(seconds) - self.star_angle(seconds, parents)) / TAU
+            + longitude / 360.0;
+        (hour_angle + 0.5).rem_euclid(1.0)
+    }
+    /// Longitude in degrees of the surface beneath a point at `angle` radians around the body, as
+    /// seen from above at `seconds` since epoch.
+    pub fn longitude_at(&self, seconds: f64, angle: f32) -> f32 {
+        // Parent lies a quarter turn behind the orbit angle, and noon is at 0.0 degrees.
+        (angle - self.spin_angle(seconds) + FRAC_PI_2)
+            .to_degrees()
+            .rem_euclid(360.0)
+    }
+    /// Elevation in radians of the root star above the horizon at a surface coordinate in
+    /// degrees. Axial Tilt shifts the star north and south over the course of a year (Seasons).
+    pub fn solar_elevation(
+        &self,
+        seconds: f64,
+        latitude: f32,
+        longitude: f32,
+        parents: &[OrbitalParameters],
+    ) -> f32 {
+        let declination =
+            self.axial_tilt.to_radians() * f32::sin(self.star_angle(seconds, parents));
+        let latitude = latitude.to_radians();
+        let hour_angle = (self.time_of_day(seconds, longitude, parents) - 0.5) * TAU;
+        f32::asin(
+            latitude.sin() * declination.sin()
+                + latitude.cos() * declination.cos() * hour_angle.cos(),
+        )
+    }
+}
+
+/// Fraction of the way through a repeating cycle of `period` days.
+#[inline]
+fn cycle_fraction(seconds: f64, period: f32) -> f32 {
+    if period <= 0.0 {
+        0.0
+    } else {
+        (seconds / (period as f64 * SECONDS_PER_DAY)).fract() as f32
+    }
 }
 
 /// Orbiral Position
 pub struct OrbitalPosition {
     /// Current Orbital Position
     pub rotation: f32,
+    /// Current Rotation about the body's own axis
+    pub spin: f32,
 }
 
 impl Default for OrbitalPosition {
     fn default() -> OrbitalPosition {
         OrbitalPosition {
             rotation: 0.0,
+            spin: 0.0,
         }
     }
 }
@@ -78,6 +197,10 @@ pub fn orbital_update_system(
     children_query: Query<Option<&OrbitalChildren>, (With<OrbitalParent>, With<Transform>)>,
 ) {
     for (entity, children, parameters, mut transform) in root_query.iter_mut() {
+        transform.rotation = Quat::from_axis_angle(
+            Vec3::new(0.0, 0.0, 1.0),
+            parameters.spin_angle(world_clock.seconds_since_epoch()),
+        );
         if let Some(children) = children {
             for child in children.0.iter() {
                 recursive_orbital_update(
@@ -104,15 +227,17 @@ fn recursive_orbital_update(
 ) {
     let global_matrix = {
         if let Ok((parameters, mut position, mut entity_transform)) =
-        transform_query.get_mut(entity)
+            transform_query.get_mut(entity)
         {
-            position.rotation =
-                ((world_clock.seconds_since_epoch() % parameters.period as f64) * 360.0) as f32;
-            let mut orbital_transform = Transform::identity();
-            orbital_transform.translation = Vec3::new(0.0, parameters.distance, 0.0);
-            orbital_transform.rotation =
-                Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), position.rotation);
-            *entity_transform = parent_transform.mul_transform(orbital_transform);
+            let seconds = world_clock.seconds_since_epoch();
+            position.rotation = parameters.orbit_angle(seconds);
+            position.spin = parameters.spin_angle(seconds);
+            // Orbit about the parent's position without inheriting the parent's spin.
+            let orbit = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), position.rotation);
+            entity_transform.translation = parent_transform.translation
+                + orbit.mul_vec3(Vec3::new(0.0, parameters.distance, 0.0));
+            entity_transform.rotation =
+                Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), position.spin);
             *entity_transform
         } else {
             return;
@@ -131,3 +256,92 @@ fn recursive_orbital_update(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::OrbitalParameters;
+    use crate::utility::SECONDS_PER_DAY;
+    use bevy::math::Vec2;
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    fn parameters(rotation_period: f32, tidally_locked: bool) -> OrbitalParameters {
+        OrbitalParameters {
+            distance: 100.0,
+            period: 365.0,
+            phase: 0.0,
+            rotation_period,
+            axial_tilt: 0.0,
+            tidally_locked,
+        }
+    }
+
+    #[test]
+    fn test_solar_day() {
+        let solar_day = parameters(1.0, false).solar_day().unwrap();
+        assert!((solar_day - 365.0 / 364.0).abs() < 1e-4);
+        assert!(parameters(365.0, true).solar_day().is_none());
+    }
+
+    #[test]
+    fn test_tidally_locked_time_of_day() {
+        let locked = parameters(365.0, true);
+        for day in 0..10 {
+            let seconds = day as f64 * 17.0 * SECONDS_PER_DAY;
+            assert!((locked.time_of_day(seconds, 0.0, &[]) - 0.5).abs() < 1e-4);
+            assert!(locked.time_of_day(seconds, 180.0, &[]).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_longitude_at() {
+        for locked in [false, true] {
+            let body = parameters(0.7, locked);
+            let seconds = 3.3 * SECONDS_PER_DAY;
+            // The parent is a quarter turn behind the orbit angle.
+            let noon = body.orbit_angle(seconds) - FRAC_PI_2;
+            let longitude = body.longitude_at(seconds, noon);
+            assert!((body.time_of_day(seconds, longitude, &[]) - 0.5).abs() < 1e-3);
+            let longitude = body.longitude_at(seconds, noon + PI);
+            let midnight = body.time_of_day(seconds, longitude, &[]);
+            assert!(midnight < 1e-3 || midnight > 1.0 - 1e-3);
+        }
+    }
+
+    fn moon(tidally_locked: bool) -> OrbitalParameters {
+        OrbitalParameters {
+            distance: 10.0,
+            period: 10.0,
+            rotation_period: 1.0,
+            ..parameters(1.0, tidally_locked)
+        }
+    }
+
+    #[test]
+    fn test_moon_follows_the_star() {
+        let planet = [parameters(1.0, false)];
+        let moon = moon(false);
+        for hour in 0..48 {
+            let seconds = hour as f64 * 0.37 * SECONDS_PER_DAY;
+            let position = [&moon, &planet[0]]
+                .iter()
+                .map(|orbit| {
+                    let angle = orbit.orbit_angle(seconds);
+                    Vec2::new(-angle.sin(), angle.cos()) * orbit.distance
+                })
+                .fold(Vec2::ZERO, |sum, offset| sum + offset);
+            let star = f32::atan2(-position.y, -position.x);
+            let noon = moon.longitude_at(seconds, star);
+            assert!((moon.time_of_day(seconds, noon, &planet) - 0.5).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_tidally_locked_moon_has_days() {
+        let planet = [parameters(1.0, false)];
+        let moon = moon(true);
+        // Facing the planet from the far side it is noon, from between planet and star midnight.
+        assert!((moon.time_of_day(0.0, 0.0, &planet) - 0.5).abs() < 1e-3);
+        let midnight = moon.time_of_day(5.0 * SECONDS_PER_DAY, 0.0, &planet);
+        assert!(midnight < 0.03 || midnight > 0.97);
+    }
+}
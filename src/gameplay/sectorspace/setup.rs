@@ -16,12 +16,299 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
 
+use super::{
+    AsteroidInfo, BeltField, Destroyed, Dockable, GravityWell, Minable, OrbitalChildren,
+    OrbitalLink, OrbitalParameters, OrbitalParent, OrbitalPosition, PlanetInfo, SectorSpace,
+    StarInfo, StationInfo, PLANETARY_MU, STELLAR_MU,
+};
+use crate::cartographer::{Orbitals, Sectors};
+use crate::gameplay::star::{spawn_star_decorations, StarAppearance, StarGlowTexture};
+use crate::gameplay::WarpHazard;
+use crate::generation::{BeltData, OrbitalData, ProtoOrbital};
 use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro128PlusPlus;
+use std::f32::consts::TAU;
+
+/// Scale applied to body radii when rendering Sectorspace sprites.
+const BODY_SCALE: f32 = 2.0;
+/// Pixels per unit of [StarAppearance] size.
+const STAR_SCALE: f32 = 8.0;
+/// Multiple of a body's size inside which Time Warp is dropped.
+const HAZARD_SCALE: f32 = 5.0;
+/// Most debris sprites scattered through a single Belt.
+const MAX_BELT_DEBRIS: usize = 400;
+
+/// Sector the player is currently in
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CurrentSector(pub usize);
 
 pub fn initialize_sectorspace(mut commands: Commands) {}
 
-pub fn setup_sectorspace(mut commands: Commands) {}
+/// Lazily spawn the orbital tree of the current sector.
+pub fn setup_sectorspace(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut orbitals: ResMut<Orbitals>,
+    current_sector: Res<CurrentSector>,
+    sectors: Res<Sectors>,
+    glow_texture: Res<StarGlowTexture>,
+    asset_server: Res<AssetServer>,
+) {
+    let font = asset_server.load("fonts/spacemono.ttf");
+    let sector = match sectors.get(current_sector.0) {
+        Some(sector) => sector,
+        None => {
+            warn!("Entered unknown Sector {}", current_sector.0);
+            return;
+        }
+    };
+    trace!("Setup Sectorspace for {}", sector.designation);
+
+    let mut entities: HashMap<usize, Entity> = HashMap::default();
+    let mut children: HashMap<usize, Vec<Entity>> = HashMap::default();
+    for orbital in orbitals.in_sector(sector.id) {
+        // Orbitals are ordered parent first, so a destroyed parent removes its whole subtree.
+        let parent = match orbital.parent {
+            Some(parent) => match entities.get(&parent) {
+                Some(entity) => Some((parent, *entity)),
+                None => continue,
+            },
+            None => None,
+        };
+        if orbital.destroyed {
+            continue;
+        }
+        let entity = spawn_orbital(&mut commands, &mut materials, sector.location, orbital);
+        if let OrbitalData::Star(data) = &orbital.data {
+            let appearance = StarAppearance::new(data);
+            commands.entity(entity).with_children(|parent| {
+                spawn_star_decorations(
+                    parent,
+                    &appearance,
+                    STAR_SCALE,
+                    true,
+                    &glow_texture,
+                    &mut materials,
+                    font.clone(),
+                );
+            });
+        }
+        if let Some((parent, parent_entity)) = parent {
+            commands
+                .entity(entity)
+                .insert(OrbitalParent::from(parent_entity));
+            children.entry(parent).or_default().push(entity);
+        }
+        entities.insert(orbital.id, entity);
+    }
+
+    for (id, entity) in entities {
+        if let Some(orbital) = orbitals.get_mut(id) {
+            orbital.entity = Some(entity);
+        }
+        if let Some(children) = children.remove(&id) {
+            commands
+                .entity(entity)
+                .insert(OrbitalChildren::from(children));
+        }
+    }
+}
+
+/// Write runtime changes back to the persistent Orbital record as they happen.
+pub fn update_sectorspace(
+    mut orbitals: ResMut<Orbitals>,
+    mut destroyed_query: Query<(&OrbitalLink, &mut Visible), Added<Destroyed>>,
+) {
+    for (link, mut visible) in destroyed_query.iter_mut() {
+        if let Some(orbital) = orbitals.get_mut(link.0) {
+            trace!("Orbital {} destroyed", orbital.data.designation());
+            orbital.destroyed = true;
+        }
+        visible.is_visible = false;
+    }
+}
+
+/// Despawn everything in Sectorspace and release the Orbital records.
+pub fn cleanup_sectorspace(
+    mut commands: Commands,
+    mut orbitals: ResMut<Orbitals>,
+    query: Query<
+        (
+            Entity,
+            Option<&OrbitalLink>,
+            Option<&Destroyed>,
+            Option<&Minable>,
+        ),
+        With<SectorSpace>,
+    >,
+) {
+    trace!("Cleanup Sectorspace");
+    for (entity, link, destroyed, minable) in query.iter() {
+        if let Some(orbital) = link.and_then(|link| orbitals.get_mut(link.0)) {
+            orbital.destroyed |= destroyed.is_some();
+            orbital.entity = None;
+            if let Some(minable) = minable {
+                match &mut orbital.data {
+                    OrbitalData::Asteroid(data) => data.minerals = minable.minerals,
+                    OrbitalData::Belt(data) => data.minerals = minable.minerals,
+                    _ => {}
+                }
+            }
+        }
+        commands.entity(entity).despawn_recursive();
+    }
+}
 
-pub fn update_sectorspace(mut commands: Commands) {}
+/// Spawn a single Orbital as a Sprite without any relationships.
+fn spawn_orbital(
+    commands: &mut Commands,
+    materials: &mut Assets<ColorMaterial>,
+    location: hexgrid::Coordinate<i32>,
+    orbital: &ProtoOrbital,
+) -> Entity {
+    let size = match &orbital.data {
+        OrbitalData::Star(data) => StarAppearance::new(data).size * STAR_SCALE,
+        _ => f32::max(1.0, orbital.data.radius() as f32 * BODY_SCALE),
+    };
+    let mut parameters = OrbitalParameters::from(orbital);
+    if let OrbitalData::Belt(_) = orbital.data {
+        // Belts are centered on their parent and slowly turn as a whole.
+        parameters.distance = 0.0;
+        parameters.rotation_period = orbital.period;
+    }
+    let mut entity = commands.spawn_bundle(SpriteBundle {
+        material: materials.add(orbital.data.color().into()),
+        sprite: Sprite::new(Vec2::new(size, size)),
+        visible: Visible {
+            is_visible: !matches!(orbital.data, OrbitalData::Belt(_)),
+            is_transparent: true,
+        },
+        ..Default::default()
+    });
+    entity
+        .insert(SectorSpace(location))
+        .insert(OrbitalLink(orbital.id))
+        .insert(OrbitalPosition::default())
+        .insert(parameters);
+    match &orbital.data {
+        OrbitalData::Star(_) | OrbitalData::BlackHole(_) => {
+            entity
+                .insert(GravityWell {
+                    mu: orbital.data.mass() as f32 * STELLAR_MU,
+                })
+                .insert(WarpHazard {
+                    radius: size * HAZARD_SCALE,
+                });
+        }
+        OrbitalData::Planet(_) | OrbitalData::Moon(_) => {
+            entity
+                .insert(GravityWell {
+                    mu: orbital.data.mass() as f32 * PLANETARY_MU,
+                })
+                .insert(WarpHazard {
+                    radius: size * HAZARD_SCALE,
+                });
+        }
+        _ => {}
+    }
+    match &orbital.data {
+        OrbitalData::Star(data) => {
+            entity.insert(StarInfo {
+                name: orbital.data.name().to_owned(),
+                class: data.class.clone(),
+                mass: data.mass as f32,
+                luminosity: data.luminosity as f32,
+                radius: data.radius as f32,
+                temp: data.temp as f32,
+                color: data.color,
+            });
+        }
+        OrbitalData::Planet(data) => {
+            entity.insert(PlanetInfo {
+                name: orbital.data.name().to_owned(),
+                radius: data.radius as f32,
+                temperature: data.temp as f32,
+                foliage: data.foliage,
+                minerals: data.minerals,
+                water: data.water,
+                gases: data.gases,
+                population: data.population,
+                ring: data.ring,
+            });
+            // Populated Planets have a Spaceport to dock at.
+            if data.population > 0.0 {
+                entity.insert(Dockable);
+            }
+        }
+        OrbitalData::Moon(data) => {
+            entity.insert(PlanetInfo {
+                name: orbital.data.name().to_owned(),
+                radius: data.radius as f32,
+                temperature: 0.0,
+                foliage: 0.0,
+                minerals: 0.0,
+                water: 0.0,
+                gases: 0.0,
+                population: 0.0,
+                ring: false,
+            });
+        }
+        OrbitalData::Station(data) => {
+            entity
+                .insert(StationInfo {
+                    name: orbital.data.name().to_owned(),
+                })
+                .insert(Dockable);
+        }
+        OrbitalData::Asteroid(data) => {
+            entity
+                .insert(AsteroidInfo {
+                    radius: data.radius as f32,
+                })
+                .insert(Minable {
+                    minerals: data.minerals,
+                });
+        }
+        OrbitalData::Belt(data) => {
+            let half_width = data.width as f32 / 2.0;
+            entity
+                .insert(BeltField {
+                    inner_radius: orbital.radius - half_width,
+                    outer_radius: orbital.radius + half_width,
+                })
+                .insert(Minable {
+                    minerals: data.minerals,
+                })
+                .with_children(|parent| spawn_belt_debris(parent, materials, orbital, data));
+        }
+        OrbitalData::BlackHole(_) => {}
+    }
+    entity.id()
+}
 
-pub fn cleanup_sectorspace(mut commands: Commands) {}
+/// Scatter debris sprites through a Belt's annulus, seeded by the Orbital so it's stable.
+fn spawn_belt_debris(
+    parent: &mut ChildBuilder,
+    materials: &mut Assets<ColorMaterial>,
+    orbital: &ProtoOrbital,
+    data: &BeltData,
+) {
+    let mut rng = Xoshiro128PlusPlus::seed_from_u64(orbital.id as u64);
+    let half_width = data.width as f32 / 2.0;
+    let area = TAU * orbital.radius * data.width as f32;
+    let count = usize::min(MAX_BELT_DEBRIS, (area * data.density) as usize);
+    let material = materials.add(orbital.data.color().into());
+    for _ in 0..count {
+        let angle = rng.gen_range(0.0..TAU);
+        let distance = orbital.radius + rng.gen_range(-half_width..half_width);
+        let size = rng.gen_range(0.5..1.5);
+        parent.spawn_bundle(SpriteBundle {
+            material: material.clone(),
+            sprite: Sprite::new(Vec2::new(size, size)),
+            transform: Transform::from_xyz(distance * angle.cos(), distance * angle.sin(), 0.0),
+            ..Default::default()
+        });
+    }
+}
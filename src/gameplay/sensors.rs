@@ -0,0 +1,33 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Passive Electromagnetic and Gravimetric Sensors, and Active Sensors that give the ship away.
+//!
+//! Ships and bodies give off thermal, EM and mass signatures. The Player's sensors turn them into
+//! contacts, detected then identified, which decide what shows in Sectorspace.
+mod detection;
+mod hud;
+mod signature;
+
+pub use self::detection::{
+    clear_contacts, contact_visibility_system, fit_sensors, hyperspace_sensor_range,
+    process_sensor_input, sensor_power_system, sensor_system, ActiveSensors, Contact, ContactLevel,
+    Contacts, SensorMount, SensorSuite, SENSOR_SCALE,
+};
+pub use self::hud::{cleanup_contacts_hud, setup_contacts_hud, update_contacts_hud, ContactsHud};
+pub use self::signature::{body_signature_system, clutter, ship_signature_system, Signature};
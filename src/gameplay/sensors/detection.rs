@@ -0,0 +1,404 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Sweeping sensors across signatures to build up contacts.
+use super::signature::{clutter, Signature};
+use crate::gameplay::combat::Hostile;
+use crate::gameplay::sectorspace::{OrbitalLink, ShipInfo, ShipMotion};
+use crate::gameplay::ship::{
+    Equipment, EquipmentKind, PlayerShip, PlayerVessel, PowerGrid, SensorKind, ShipCatalog,
+    ShipCatalogHandle, ShipStats,
+};
+use crate::utility::WorldClock;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+/// Sectorspace units per km of sensor range.
+pub const SENSOR_SCALE: f32 = 0.05;
+/// Share of its reach a sensor can make out what a contact is.
+const IDENTIFY_SHARE: f32 = 0.5;
+/// Seconds of world time a contact must be studied to identify it.
+const IDENTIFY_TIME: f32 = 3.0;
+/// Tonnes a Gravimetric Sensor feels at its rated range.
+const GRAVIMETRIC_REFERENCE: f32 = 1.0e6;
+/// Tonnes an Active Sensor gets a return from at its rated range.
+const ACTIVE_REFERENCE: f32 = 100.0;
+/// Hexes the Gravity Well of a one Solar Mass star is felt at without Gravimetric Sensors.
+const BASE_HYPERSPACE_RANGE: f32 = 1.0;
+/// km of Gravimetric Sensor range per extra hex felt in Hyperspace.
+const GRAVIMETRIC_KM_PER_HEX: f32 = 500_000.0;
+
+/// How much is known about a contact.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum ContactLevel {
+    Unknown,
+    /// Something is there
+    Detected,
+    /// What it is is known
+    Identified,
+}
+
+impl Default for ContactLevel {
+    fn default() -> ContactLevel {
+        ContactLevel::Unknown
+    }
+}
+
+/// Something picked up on sensors.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Contact {
+    pub level: ContactLevel,
+    /// Seconds spent studying it from close enough to identify
+    pub analysis: f32,
+}
+
+impl Contact {
+    /// Follow a contact through `seconds` of sweeps, losing it when no longer `detected`.
+    pub fn track(&mut self, detected: bool, identifying: bool, seconds: f32) {
+        if !detected {
+            *self = Contact::default();
+            return;
+        }
+        if identifying {
+            self.analysis += seconds;
+        }
+        self.level = if self.level == ContactLevel::Identified || self.analysis >= IDENTIFY_TIME {
+            ContactLevel::Identified
+        } else {
+            ContactLevel::Detected
+        };
+    }
+}
+
+/// Sensor fitted to a ship.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensorMount {
+    pub equipment: usize,
+    pub sensor: SensorKind,
+    /// Sectorspace units
+    pub range: f32,
+}
+
+impl SensorMount {
+    /// Furthest `signature` can be made out at through `clutter`.
+    pub fn reach(&self, signature: &Signature, clutter: f32) -> f32 {
+        match self.sensor {
+            SensorKind::Electromagnetic => {
+                self.range * ((signature.thermal + signature.em) / (1.0 + clutter)).sqrt()
+            }
+            SensorKind::Gravimetric => self.range * (signature.mass / GRAVIMETRIC_REFERENCE).sqrt(),
+            SensorKind::Active => self.range * (signature.mass / ACTIVE_REFERENCE).powf(1.0 / 6.0),
+        }
+    }
+}
+
+/// Every sensor fitted to a ship, and how well it has the Player.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SensorSuite {
+    pub sensors: Vec<SensorMount>,
+    /// What the ship makes of the Player
+    pub player: Contact,
+}
+
+impl SensorSuite {
+    pub fn new(equipment: &[&Equipment]) -> SensorSuite {
+        let sensors = equipment
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| match item.kind {
+                EquipmentKind::Sensor { sensor, range } => Some(SensorMount {
+                    equipment: index,
+                    sensor,
+                    range: range * SENSOR_SCALE,
+                }),
+                _ => None,
+            })
+            .collect();
+        SensorSuite {
+            sensors,
+            player: Contact::default(),
+        }
+    }
+    /// Whether a `signature` `distance` away through `clutter` is detected, and close enough to
+    /// identify, by the sensors that are `running`.
+    pub fn sweep(
+        &self,
+        distance: f32,
+        signature: &Signature,
+        clutter: f32,
+        running: impl Fn(usize) -> bool,
+    ) -> (bool, bool) {
+        let mut detected = false;
+        let mut identifying = false;
+        for mount in self.sensors.iter().filter(|mount| running(mount.equipment)) {
+            let reach = mount.reach(signature, clutter);
+            detected |= distance <= reach;
+            // A gravity well says nothing about what makes it.
+            identifying |=
+                mount.sensor != SensorKind::Gravimetric && distance <= reach * IDENTIFY_SHARE;
+        }
+        (detected, identifying)
+    }
+}
+
+/// Hexes the Gravity Well of a one Solar Mass star is felt at in Hyperspace with `equipment`.
+pub fn hyperspace_sensor_range(equipment: &[&Equipment]) -> f32 {
+    let gravimetric = equipment
+        .iter()
+        .filter_map(|item| match item.kind {
+            EquipmentKind::Sensor {
+                sensor: SensorKind::Gravimetric,
+                range,
+            } => Some(range),
+            _ => None,
+        })
+        .fold(0.0, f32::max);
+    BASE_HYPERSPACE_RANGE + gravimetric / GRAVIMETRIC_KM_PER_HEX
+}
+
+/// The Player's picture of Sectorspace.
+#[derive(Debug, Clone, Default)]
+pub struct Contacts(pub HashMap<Entity, Contact>);
+
+impl Contacts {
+    pub fn level(&self, entity: Entity) -> ContactLevel {
+        self.0
+            .get(&entity)
+            .map_or(ContactLevel::Unknown, |contact| contact.level)
+    }
+}
+
+/// Whether the Player is running their Active Sensors, giving themselves away.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct ActiveSensors(pub bool);
+
+/// Fit sensors to ships in Sectorspace, refitting the Player's whenever their ship changes.
+pub fn fit_sensors(
+    mut commands: Commands,
+    catalogs: Res<Assets<ShipCatalog>>,
+    catalog_handle: Res<ShipCatalogHandle>,
+    ship: Res<PlayerShip>,
+    query: Query<
+        (
+            Entity,
+            Option<&ShipInfo>,
+            Option<&PlayerVessel>,
+            Option<&SensorSuite>,
+        ),
+        With<ShipMotion>,
+    >,
+) {
+    let catalog = match catalogs.get(&catalog_handle.0) {
+        Some(catalog) => catalog,
+        None => return,
+    };
+    for (entity, info, player, suite) in query.iter() {
+        let design = match (player, info) {
+            (Some(_), _) if suite.is_none() || ship.is_changed() => &ship.design,
+            (None, Some(info)) if suite.is_none() => &info.design,
+            _ => continue,
+        };
+        if let Some((hull, equipment)) = design.resolve(catalog) {
+            let stats = ShipStats::new(hull, equipment.iter().copied());
+            commands
+                .entity(entity)
+                .insert(SensorSuite::new(&equipment))
+                .insert(Signature {
+                    mass: stats.mass,
+                    ..Default::default()
+                });
+        }
+    }
+}
+
+/// `P` switches the Player's Active Sensors on and off.
+pub fn process_sensor_input(keyboard: Res<Input<KeyCode>>, mut active: ResMut<ActiveSensors>) {
+    if keyboard.just_pressed(KeyCode::P) {
+        active.0 = !active.0;
+        trace!(
+            "Active Sensors {}",
+            if active.0 { "running" } else { "silent" }
+        );
+    }
+}
+
+/// Run Active Sensors on the Player's say so, Hostile ships always run theirs.
+pub fn sensor_power_system(
+    active: Res<ActiveSensors>,
+    mut query: Query<(&mut PowerGrid, Option<&PlayerVessel>, Option<&Hostile>)>,
+) {
+    for (mut grid, player, hostile) in query.iter_mut() {
+        let running = match player {
+            Some(_) => active.0,
+            None => hostile.is_some(),
+        };
+        let sensors: Vec<usize> = grid
+            .consumers
+            .iter()
+            .filter(|consumer| {
+                matches!(
+                    consumer.kind,
+                    EquipmentKind::Sensor {
+                        sensor: SensorKind::Active,
+                        ..
+                    }
+                )
+            })
+            .map(|consumer| consumer.equipment)
+            .collect();
+        for equipment in sensors {
+            grid.set_equipment_active(equipment, running);
+        }
+    }
+}
+
+/// Sweep the Player's sensors across everything with a signature, and every other ship's across
+/// the Player.
+pub fn sensor_system(
+    world_clock: Res<WorldClock>,
+    mut contacts: ResMut<Contacts>,
+    mut observer_query: Query<(
+        Entity,
+        &Transform,
+        &mut SensorSuite,
+        Option<&PowerGrid>,
+        Option<&PlayerVessel>,
+    )>,
+    target_query: Query<(Entity, &Transform, &Signature)>,
+    body_query: Query<(&Transform, &Signature), With<OrbitalLink>>,
+) {
+    let seconds = world_clock.delta().as_secs_f32();
+    let bodies: Vec<(Vec2, f32)> = body_query
+        .iter()
+        .map(|(transform, signature)| (transform.translation.truncate(), signature.thermal))
+        .collect();
+    let player = observer_query
+        .iter()
+        .find(|(.., player)| player.is_some())
+        .map(|(entity, ..)| entity);
+    for (observer, transform, mut suite, grid, is_player) in observer_query.iter_mut() {
+        let position = transform.translation.truncate();
+        let running = |equipment| grid.map_or(true, |grid| grid.is_running(equipment));
+        if is_player.is_some() {
+            let mut seen = HashMap::default();
+            for (target, target_transform, signature) in target_query.iter() {
+                if target == observer {
+                    continue;
+                }
+                let target_position = target_transform.translation.truncate();
+                let (detected, identifying) = suite.sweep(
+                    position.distance(target_position),
+                    signature,
+                    clutter(target_position, &bodies),
+                    running,
+                );
+                let mut contact = contacts.0.get(&target).copied().unwrap_or_default();
+                contact.track(detected, identifying, seconds);
+                if contact.level != ContactLevel::Unknown {
+                    seen.insert(target, contact);
+                }
+            }
+            if contacts.0 != seen {
+                contacts.0 = seen;
+            }
+        } else if let Some((_, target_transform, signature)) =
+            player.and_then(|player| target_query.get(player).ok())
+        {
+            let target_position = target_transform.translation.truncate();
+            let (detected, identifying) = suite.sweep(
+                position.distance(target_position),
+                signature,
+                clutter(target_position, &bodies),
+                running,
+            );
+            suite.player.track(detected, identifying, seconds);
+        }
+    }
+}
+
+/// Ships and Encounters only show once the Player's sensors pick them up, bodies are charted
+/// on arrival.
+pub fn contact_visibility_system(
+    contacts: Res<Contacts>,
+    mut query: Query<
+        (Entity, &mut Visible),
+        (With<Signature>, Without<OrbitalLink>, Without<PlayerVessel>),
+    >,
+) {
+    for (entity, mut visible) in query.iter_mut() {
+        let shown = contacts.level(entity) != ContactLevel::Unknown;
+        if visible.is_visible != shown {
+            visible.is_visible = shown;
+        }
+    }
+}
+
+/// Forget every contact on leaving Sectorspace.
+pub fn clear_contacts(mut contacts: ResMut<Contacts>) {
+    contacts.0.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sensor_sweep() {
+        let suite = SensorSuite {
+            sensors: vec![
+                SensorMount {
+                    equipment: 0,
+                    sensor: SensorKind::Electromagnetic,
+                    range: 500.0,
+                },
+                SensorMount {
+                    equipment: 1,
+                    sensor: SensorKind::Gravimetric,
+                    range: 50000.0,
+                },
+            ],
+            player: Contact::default(),
+        };
+        let quiet = Signature {
+            thermal: 1.0,
+            em: 0.0,
+            mass: 150.0,
+        };
+        let loud = Signature { em: 99.0, ..quiet };
+
+        // Close by, a quiet ship is picked up and studied.
+        assert_eq!(suite.sweep(200.0, &quiet, 0.0, |_| true), (true, true));
+        // Further out only its mass gives it away, which says nothing about what it is.
+        assert_eq!(suite.sweep(550.0, &quiet, 0.0, |_| true), (true, false));
+        assert_eq!(
+            suite.sweep(550.0, &quiet, 0.0, |equipment| equipment == 0),
+            (false, false)
+        );
+        // Broadcasting carries much further, unless lost in the glare of a star.
+        assert_eq!(suite.sweep(2000.0, &loud, 0.0, |_| true), (true, true));
+        assert_eq!(suite.sweep(2000.0, &loud, 99.0, |_| true), (false, false));
+
+        let mut contact = Contact::default();
+        contact.track(true, false, 1.0);
+        assert_eq!(contact.level, ContactLevel::Detected);
+        contact.track(true, true, IDENTIFY_TIME);
+        assert_eq!(contact.level, ContactLevel::Identified);
+        contact.track(false, false, 1.0);
+        assert_eq!(contact.level, ContactLevel::Unknown);
+    }
+}
@@ -0,0 +1,177 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Heads up display of the Player's contacts.
+use super::detection::{ActiveSensors, ContactLevel, Contacts};
+use super::signature::Signature;
+use crate::gameplay::combat::Hostile;
+use crate::gameplay::sectorspace::{PlanetInfo, ShipInfo, StarInfo, StationInfo};
+use crate::gameplay::ship::PlayerVessel;
+use bevy::prelude::*;
+
+/// Most contacts listed at once, nearest first.
+const LISTED_CONTACTS: usize = 8;
+
+/// Tag Denoting the Contacts HUD
+pub struct ContactsHud;
+
+pub fn setup_contacts_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn_bundle(TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    top: Val::Px(40.0),
+                    right: Val::Px(5.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            text: Text::with_section(
+                String::new(),
+                TextStyle {
+                    font: asset_server.load("fonts/spacemono.ttf"),
+                    font_size: 14.0,
+                    color: Color::rgb(0.6, 0.9, 1.0),
+                },
+                Default::default(),
+            ),
+            ..Default::default()
+        })
+        .insert(ContactsHud);
+}
+
+/// List the nearest contacts, named once identified, and how loud the Player is running.
+#[allow(clippy::type_complexity)]
+pub fn update_contacts_hud(
+    contacts: Res<Contacts>,
+    active: Res<ActiveSensors>,
+    player_query: Query<(&Transform, &Signature), With<PlayerVessel>>,
+    contact_query: Query<(
+        &Transform,
+        Option<&ShipInfo>,
+        Option<&StationInfo>,
+        Option<&PlanetInfo>,
+        Option<&StarInfo>,
+        Option<&Hostile>,
+    )>,
+    mut hud_query: Query<&mut Text, With<ContactsHud>>,
+) {
+    let (transform, signature) = match player_query.iter().next() {
+        Some(player) => player,
+        None => return,
+    };
+    let position = transform.translation.truncate();
+    let mut lines = vec![
+        format!(
+            "Sensors {}  [P] Toggle",
+            if active.0 { "ACTIVE" } else { "PASSIVE" }
+        ),
+        format!(
+            "Signature thermal {:.0} EM {:.0}",
+            signature.thermal, signature.em
+        ),
+    ];
+    let mut listed: Vec<(f32, String)> = contacts
+        .0
+        .iter()
+        .filter_map(|(entity, contact)| {
+            let (contact_transform, ship, station, planet, star, hostile) =
+                contact_query.get(*entity).ok()?;
+            let distance = contact_transform.translation.truncate().distance(position);
+            let name = ship
+                .map(|ship| ship.name.as_str())
+                .or_else(|| station.map(|station| station.name.as_str()))
+                .or_else(|| planet.map(|planet| planet.name.as_str()))
+                .or_else(|| star.map(|star| star.name.as_str()));
+            Some((
+                distance,
+                contact_name(contact.level, name, hostile.is_some()),
+            ))
+        })
+        .collect();
+    lines.extend(contact_lines(listed));
+    for mut text in hud_query.iter_mut() {
+        text.sections[0].value = lines.join("\n");
+    }
+}
+
+/// What a contact is listed as, named only once identified.
+fn contact_name(level: ContactLevel, name: Option<&str>, hostile: bool) -> String {
+    match level {
+        ContactLevel::Identified => {
+            name.unwrap_or("Unknown object").to_owned() + if hostile { " (Hostile)" } else { "" }
+        }
+        _ => String::from("Unidentified contact"),
+    }
+}
+
+/// Count of contacts followed by the nearest few and how far off they are.
+fn contact_lines(mut listed: Vec<(f32, String)>) -> Vec<String> {
+    listed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    let mut lines = vec![format!("Contacts {}", listed.len())];
+    for (distance, name) in listed.into_iter().take(LISTED_CONTACTS) {
+        lines.push(format!("  {:<28} {:>7.0}", name, distance));
+    }
+    lines
+}
+
+pub fn cleanup_contacts_hud(mut commands: Commands, query: Query<Entity, With<ContactsHud>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contact_name() {
+        assert_eq!(
+            contact_name(ContactLevel::Detected, Some("Vega Station"), false),
+            "Unidentified contact"
+        );
+        assert_eq!(
+            contact_name(ContactLevel::Identified, Some("Vega Station"), false),
+            "Vega Station"
+        );
+        assert_eq!(
+            contact_name(ContactLevel::Identified, Some("Raider"), true),
+            "Raider (Hostile)"
+        );
+        assert_eq!(
+            contact_name(ContactLevel::Identified, None, false),
+            "Unknown object"
+        );
+    }
+
+    #[test]
+    fn test_contact_lines() {
+        let listed: Vec<(f32, String)> = (0..LISTED_CONTACTS + 2)
+            .rev()
+            .map(|index| (index as f32 * 100.0, format!("Contact {}", index)))
+            .collect();
+        let lines = contact_lines(listed);
+        assert_eq!(lines.len(), LISTED_CONTACTS + 1);
+        assert_eq!(lines[0], format!("Contacts {}", LISTED_CONTACTS + 2));
+        assert!(lines[1].trim_start().starts_with("Contact 0 "));
+        assert!(lines[1].ends_with("0"));
+        assert!(lines[LISTED_CONTACTS].contains(&format!("Contact {}", LISTED_CONTACTS - 1)));
+    }
+}
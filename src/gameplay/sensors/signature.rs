@@ -0,0 +1,174 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! How loud ships and bodies are to each kind of sensor.
+use crate::cartographer::Orbitals;
+use crate::gameplay::sectorspace::OrbitalLink;
+use crate::gameplay::ship::{EquipmentKind, PowerGrid, SensorKind};
+use crate::generation::OrbitalData;
+use bevy::prelude::*;
+
+/// EM given off per MW drawn by Active Sensors and Jammers.
+const ACTIVE_EMISSION: f32 = 25.0;
+/// EM given off per MW drawn by Communications.
+const COMMS_EMISSION: f32 = 5.0;
+/// Thermal signature of a star per unit of luminosity.
+const STAR_THERMAL: f32 = 1.0e4;
+/// Bodies outweigh any ship by many orders of magnitude.
+const BODY_MASS: f32 = 1.0e20;
+
+/// How loud something is to each kind of sensor.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Signature {
+    /// Waste heat in MW, seen by Electromagnetic Sensors
+    pub thermal: f32,
+    /// Radio and radar emissions, seen by Electromagnetic Sensors
+    pub em: f32,
+    /// Tonnes, felt by Gravimetric Sensors and reflecting Active Sensors
+    pub mass: f32,
+}
+
+impl Signature {
+    /// Signature of an Orbital body, stars glare while stations chatter.
+    pub fn of_body(data: &OrbitalData) -> Signature {
+        let (thermal, em) = match data {
+            OrbitalData::Star(data) => (data.luminosity as f32 * STAR_THERMAL, 0.0),
+            OrbitalData::Station(_) => (20.0, 50.0),
+            OrbitalData::Planet(_) => (1.0, 0.0),
+            OrbitalData::Moon(_) => (0.5, 0.0),
+            OrbitalData::BlackHole(_) | OrbitalData::Asteroid(_) | OrbitalData::Belt(_) => {
+                (0.0, 0.0)
+            }
+        };
+        Signature {
+            thermal,
+            em,
+            mass: data.mass() as f32 * BODY_MASS,
+        }
+    }
+}
+
+/// Glare from hot bodies at `position` that faint thermal and EM signatures are lost in.
+pub fn clutter(position: Vec2, bodies: &[(Vec2, f32)]) -> f32 {
+    bodies
+        .iter()
+        .map(|(body, thermal)| thermal / position.distance_squared(*body).max(1.0))
+        .sum()
+}
+
+/// Give every Orbital body in Sectorspace its signature.
+pub fn body_signature_system(
+    mut commands: Commands,
+    orbitals: Res<Orbitals>,
+    query: Query<(Entity, &OrbitalLink), Without<Signature>>,
+) {
+    for (entity, link) in query.iter() {
+        if let Some(orbital) = orbitals.get(link.0) {
+            commands
+                .entity(entity)
+                .insert(Signature::of_body(&orbital.data));
+        }
+    }
+}
+
+/// Ships run hot with the power they draw, and loud with whatever they broadcast.
+pub fn ship_signature_system(mut query: Query<(&PowerGrid, &mut Signature)>) {
+    for (grid, mut signature) in query.iter_mut() {
+        let (thermal, em) = ship_emissions(grid);
+        if signature.thermal != thermal || signature.em != em {
+            signature.thermal = thermal;
+            signature.em = em;
+        }
+    }
+}
+
+/// Thermal and EM signature of a ship running off `grid`.
+fn ship_emissions(grid: &PowerGrid) -> (f32, f32) {
+    let radiated = grid.dissipation * grid.heat / grid.heat_capacity.max(f32::EPSILON);
+    let em = grid
+        .consumers
+        .iter()
+        .filter(|consumer| consumer.powered)
+        .map(|consumer| match consumer.kind {
+            EquipmentKind::Comms { .. } => consumer.draw * COMMS_EMISSION,
+            EquipmentKind::Sensor {
+                sensor: SensorKind::Active,
+                ..
+            }
+            | EquipmentKind::Jammer { .. } => consumer.draw * ACTIVE_EMISSION,
+            _ => 0.0,
+        })
+        .sum();
+    (grid.draw + radiated, em)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::ship::test_catalog;
+    use crate::generation::{AsteroidData, StationData};
+
+    #[test]
+    fn test_body_signature() {
+        let station = Signature::of_body(&OrbitalData::Station(StationData {
+            designation: String::from("A-1"),
+            name: None,
+            mass: 1.0e-12,
+            radius: 1.0,
+        }));
+        let asteroid = Signature::of_body(&OrbitalData::Asteroid(AsteroidData {
+            designation: String::from("A-2"),
+            name: None,
+            mass: 1.0e-12,
+            radius: 1.0,
+            minerals: 1.0,
+        }));
+        assert!(station.em > 0.0 && station.thermal > 0.0);
+        assert_eq!((asteroid.thermal, asteroid.em), (0.0, 0.0));
+        assert_eq!(station.mass, asteroid.mass);
+
+        // Glare falls off with the square of the distance.
+        let bodies = [(Vec2::ZERO, 100.0)];
+        assert!((clutter(Vec2::new(10.0, 0.0), &bodies) - 1.0).abs() < 1e-6);
+        assert!((clutter(Vec2::new(20.0, 0.0), &bodies) - 0.25).abs() < 1e-6);
+        assert_eq!(clutter(Vec2::ZERO, &bodies), 100.0);
+    }
+
+    #[test]
+    fn test_active_sensors_raise_signature() {
+        let catalog = test_catalog();
+        let (hull, equipment) = catalog.design("Picket").unwrap().resolve(&catalog).unwrap();
+        let radar = equipment
+            .iter()
+            .position(|item| item.id == "radar")
+            .unwrap();
+        let mut grid = PowerGrid::new(hull, equipment);
+
+        grid.set_equipment_active(radar, false);
+        grid.step(1.0, 1.0);
+        let (quiet_thermal, quiet_em) = ship_emissions(&grid);
+        // Only the Transponder is broadcasting.
+        assert!((quiet_em - 0.1 * COMMS_EMISSION).abs() < 1e-4);
+
+        grid.set_equipment_active(radar, true);
+        grid.step(1.0, 1.0);
+        let (thermal, em) = ship_emissions(&grid);
+        assert!((em - quiet_em - 2.0 * ACTIVE_EMISSION).abs() < 1e-3);
+        assert!(thermal > quiet_thermal);
+    }
+}
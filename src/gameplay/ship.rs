@@ -0,0 +1,48 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Starship Hulls, the Equipment fitted into them and how the result performs.
+mod catalog;
+mod design;
+mod equipment;
+mod hull;
+mod player;
+mod power;
+mod shipyard;
+
+#[cfg(test)]
+pub(crate) use self::catalog::test_catalog;
+pub use self::catalog::{ShipCatalog, ShipCatalogIssue, ShipCatalogLoader};
+pub use self::design::{FitError, ShipDesign, ShipStats};
+pub use self::equipment::{
+    ArmorKind, CommsKind, DriveKind, Equipment, EquipmentKind, PowerSource, SensorKind, Software,
+    SpecialtyKind, WeaponKind,
+};
+pub use self::hull::{Hull, HullClass, SlotKind};
+pub use self::player::{
+    apply_player_ship, initialize_player_ship, load_ship_catalog, spawn_player_vessel, PlayerShip,
+    PlayerVessel, ShipCatalogHandle,
+};
+pub use self::power::{
+    cleanup_power_hud, power_demand_system, power_system, setup_power_hud, sunlight,
+    update_power_hud, PowerConsumer, PowerGenerator, PowerGrid, PowerHud, PowerPriority,
+    PowerStore, SOLAR_REFERENCE_DISTANCE,
+};
+pub use self::shipyard::{
+    refit_system, Refit, RefitError, RefitOrder, RefitReceipt, Restriction, Shipyard, RESALE,
+};
@@ -0,0 +1,245 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::design::{FitError, ShipDesign};
+use super::equipment::Equipment;
+use super::hull::Hull;
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use bevy::utils::HashSet;
+use serde::Deserialize;
+use std::fmt;
+
+/// Problem found in a Ship Catalog.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShipCatalogIssue {
+    /// Same hull id listed more than once
+    DuplicateHull(String),
+    /// Same equipment id listed more than once
+    DuplicateEquipment(String),
+    /// Player's starting design isn't listed
+    UnknownStarter(String),
+    /// Stock design that can't be built
+    InvalidDesign(String, FitError),
+}
+
+impl ShipCatalogIssue {
+    /// Fatal issues make the catalog ambiguous or unplayable, the rest are worth a warning.
+    pub fn is_fatal(&self) -> bool {
+        !matches!(self, ShipCatalogIssue::InvalidDesign(..))
+    }
+}
+
+impl fmt::Display for ShipCatalogIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShipCatalogIssue::DuplicateHull(id) => {
+                write!(f, "hull {:?} is listed more than once", id)
+            }
+            ShipCatalogIssue::DuplicateEquipment(id) => {
+                write!(f, "equipment {:?} is listed more than once", id)
+            }
+            ShipCatalogIssue::UnknownStarter(name) => {
+                write!(f, "starting design {:?} is not listed", name)
+            }
+            ShipCatalogIssue::InvalidDesign(name, error) => write!(f, "design {}: {}", name, error),
+        }
+    }
+}
+
+/// Hulls, Equipment and stock Designs, loaded from `*.ships` RON files.
+#[derive(Debug, Clone, Default, Deserialize, TypeUuid)]
+#[uuid = "4c9e2d7a-1f63-4b8e-a5d2-7e0c3b96f1a4"]
+pub struct ShipCatalog {
+    pub hulls: Vec<Hull>,
+    pub equipment: Vec<Equipment>,
+    pub designs: Vec<ShipDesign>,
+    /// Name of the design the Player starts with
+    pub starter: String,
+}
+
+impl ShipCatalog {
+    pub fn from_ron(data: &str) -> Result<(ShipCatalog, Vec<ShipCatalogIssue>), ron::Error> {
+        let catalog: ShipCatalog = ron::from_str(data)?;
+        let issues = catalog.validate();
+        Ok((catalog, issues))
+    }
+    /// Check ids are unique and every stock design can be built.
+    pub fn validate(&self) -> Vec<ShipCatalogIssue> {
+        let mut issues = Vec::new();
+        let mut hulls = HashSet::default();
+        for hull in self.hulls.iter() {
+            if !hulls.insert(hull.id.as_str()) {
+                issues.push(ShipCatalogIssue::DuplicateHull(hull.id.clone()));
+            }
+        }
+        let mut equipment = HashSet::default();
+        for item in self.equipment.iter() {
+            if !equipment.insert(item.id.as_str()) {
+                issues.push(ShipCatalogIssue::DuplicateEquipment(item.id.clone()));
+            }
+        }
+        if self.design(&self.starter).is_none() {
+            issues.push(ShipCatalogIssue::UnknownStarter(self.starter.clone()));
+        }
+        for design in self.designs.iter() {
+            for error in design.validate(self) {
+                issues.push(ShipCatalogIssue::InvalidDesign(design.name.clone(), error));
+            }
+        }
+        issues
+    }
+    pub fn hull(&self, id: &str) -> Option<&Hull> {
+        self.hulls.iter().find(|hull| hull.id == id)
+    }
+    pub fn equipment(&self, id: &str) -> Option<&Equipment> {
+        self.equipment.iter().find(|item| item.id == id)
+    }
+    pub fn design(&self, name: &str) -> Option<&ShipDesign> {
+        self.designs.iter().find(|design| design.name == name)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.hulls.is_empty()
+    }
+}
+
+/// Loads [ShipCatalog] from RON, refusing catalogs with duplicate ids or no starting design.
+#[derive(Default)]
+pub struct ShipCatalogLoader;
+
+impl AssetLoader for ShipCatalogLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let catalog: ShipCatalog = ron::de::from_bytes(bytes)?;
+            let issues = catalog.validate();
+            for issue in issues.iter() {
+                warn!("Ship Catalog {:?}: {}", load_context.path(), issue);
+            }
+            if let Some(issue) = issues.iter().find(|issue| issue.is_fatal()) {
+                anyhow::bail!("Invalid Ship Catalog: {}", issue);
+            }
+            load_context.set_default_asset(LoadedAsset::new(catalog));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ships"]
+    }
+}
+
+/// Catalog shared by the tests of everything built from Hulls and Equipment.
+#[cfg(test)]
+const TEST_CATALOG: &str = r#"(
+    hulls: [
+        (id: "shuttle", name: "Shuttle", class: Shuttle, mass: 40.0, capacity: 20.0,
+         structure: 100.0, cargo_mass: 30.0, cargo_volume: 40.0, fuel_capacity: 10.0,
+         crew: 2, cost: 20000.0, tech: 1, slots: {Reactor: 1, Engine: 1, Hyperdrive: 1, Internal: 2}),
+        (id: "gunship", name: "Gunship", class: Gunship, mass: 100.0, capacity: 70.0,
+         structure: 300.0, cargo_mass: 100.0, cargo_volume: 150.0, fuel_capacity: 20.0,
+         crew: 2, cost: 120000.0, tech: 1,
+         slots: {Reactor: 1, Engine: 1, Hyperdrive: 1, Storage: 1, Shield: 1, Weapon: 2, Armor: 1, Sensor: 1,
+                 Internal: 3}),
+    ],
+    equipment: [
+        (id: "fusion", name: "Fusion Generator", size: 1, mass: 4.0, power: 10.0, heat: 2.0,
+         cost: 5000.0, tech: 1, kind: Generator(source: Fusion, fuel: 0.5)),
+        (id: "ion", name: "Ion Thruster", size: 0, mass: 2.0, power: -2.0, heat: 0.5,
+         cost: 1000.0, tech: 1, kind: Drive(drive: Ion, thrust: 120.0)),
+        (id: "jump", name: "Hyper Drive", size: 2, mass: 6.0, power: -4.0, heat: 1.0,
+         cost: 8000.0, tech: 1, kind: Hyperdrive(rating: 1.0, range: 6, fuel_per_hex: 1.0, rated_mass: 50.0)),
+        (id: "transponder", name: "Transponder", size: 0, mass: 0.1, power: -0.1, heat: 0.0,
+         cost: 100.0, tech: 0, kind: Comms(comms: Transponder, range: 1000.0)),
+        (id: "life", name: "Life Support", size: 0, mass: 1.0, power: -1.0, heat: 0.5,
+         cost: 500.0, tech: 0, kind: LifeSupport(crew: 4)),
+        (id: "battery", name: "Battery", size: 0, mass: 0.5, power: 0.0, heat: 0.0,
+         cost: 800.0, tech: 0, kind: Powerbank(capacity: 10.0, discharge: 1.0)),
+        (id: "shield", name: "Shield", size: 1, mass: 2.0, power: -5.0, heat: 2.0,
+         cost: 25000.0, tech: 2, kind: Shield(strength: 20.0, recharge: 5.0)),
+        (id: "laser", name: "Laser", size: 1, mass: 1.5, power: -6.0, heat: 3.0,
+         cost: 20000.0, tech: 2, kind: Weapon(weapon: Laser, damage: 15.0, range: 30.0, rate: 2.0)),
+        (id: "mass-driver", name: "Mass Driver", size: 3, mass: 15.0, power: -6.0, heat: 6.0,
+         cost: 60000.0, tech: 2, kind: Weapon(weapon: MassDriver, damage: 120.0, range: 50.0, rate: 0.5)),
+        (id: "cruiser-gun", name: "Heavy Turret", size: 7, mass: 80.0, power: -50.0, heat: 20.0,
+         cost: 90000.0, tech: 3, kind: Weapon(weapon: Laser, damage: 200.0, range: 500.0, rate: 0.5)),
+        (id: "ablative", name: "Ablative Plating", size: 1, mass: 5.0, power: 0.0, heat: 0.0,
+         cost: 3000.0, tech: 0, kind: Armor(armor: Ablative, rating: 50.0)),
+        (id: "radar", name: "Active Radar", size: 1, mass: 1.0, power: -2.0, heat: 1.0,
+         cost: 8000.0, tech: 2, kind: Sensor(sensor: Active, range: 50000.0)),
+    ],
+    designs: [
+        (name: "Runabout", hull: "shuttle", equipment: ["fusion", "ion", "jump", "transponder", "life"]),
+        (name: "Courier", hull: "shuttle", equipment: ["fusion", "transponder", "life"]),
+        (name: "Overgunned", hull: "shuttle", equipment: ["ion", "cruiser-gun", "cruiser-gun"]),
+        (name: "Gunship", hull: "gunship", equipment: ["fusion", "battery", "shield", "laser", "transponder", "life"]),
+        (name: "Brawler", hull: "gunship", equipment: ["fusion", "shield", "laser", "ablative"]),
+        (name: "Picket", hull: "gunship", equipment: ["fusion", "radar", "transponder", "life"]),
+    ],
+    starter: "Runabout",
+)"#;
+
+/// Parse the shared test catalog.
+#[cfg(test)]
+pub(crate) fn test_catalog() -> ShipCatalog {
+    ShipCatalog::from_ron(TEST_CATALOG).unwrap().0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::ship::HullClass;
+
+    #[test]
+    fn test_ship_catalog() {
+        let (catalog, issues) = ShipCatalog::from_ron(TEST_CATALOG).unwrap();
+        assert!(issues.iter().all(|issue| !issue.is_fatal()));
+        assert!(!issues.iter().any(
+            |issue| matches!(issue, ShipCatalogIssue::InvalidDesign(name, _) if name == "Runabout")
+        ));
+
+        let runabout = catalog.design("Runabout").unwrap();
+        let stats = runabout.stats(&catalog).unwrap();
+        assert!((stats.mass - 53.1).abs() < 1e-3);
+        assert!((stats.acceleration - 120.0 / 53.1).abs() < 1e-3);
+        assert!(stats.power_balance() > 0.0);
+        // Heavier than the Hyperdrive is rated for, so it can't reach quite as far.
+        assert_eq!(stats.jump_range, 5);
+
+        let errors = catalog.design("Overgunned").unwrap().validate(&catalog);
+        assert!(errors.contains(&FitError::NoTransponder));
+        assert!(errors.contains(&FitError::TooLarge {
+            equipment: String::from("Heavy Turret"),
+            size: 7,
+            class: HullClass::Shuttle,
+        }));
+        assert!(errors
+            .iter()
+            .any(|error| matches!(error, FitError::SlotsExceeded { .. })));
+        assert!(errors
+            .iter()
+            .any(|error| matches!(error, FitError::OverCapacity { .. })));
+        assert!(errors
+            .iter()
+            .any(|error| matches!(error, FitError::PowerDeficit { .. })));
+    }
+}
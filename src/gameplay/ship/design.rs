@@ -0,0 +1,348 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::catalog::ShipCatalog;
+use super::equipment::{CommsKind, Equipment, EquipmentKind};
+use super::hull::{Hull, HullClass, SlotKind};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Hull and the Equipment fitted into it, by catalog id.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShipDesign {
+    pub name: String,
+    pub hull: String,
+    pub equipment: Vec<String>,
+}
+
+/// Performance of a fitted ship.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShipStats {
+    /// Tonnes, hull and equipment
+    pub mass: f32,
+    /// Tonnes of equipment fitted
+    pub equipment_mass: f32,
+    /// Tonnes of equipment the hull can carry
+    pub capacity: f32,
+    /// MW supplied by generators
+    pub power_supply: f32,
+    /// MW drawn by everything else
+    pub power_draw: f32,
+    /// MW of waste heat with everything running
+    pub heat: f32,
+    /// MJ held in Powerbanks and Supercapacitors
+    pub storage: f32,
+    /// MW storage can release at once
+    pub discharge: f32,
+    /// Realspace thrust in kN
+    pub thrust: f32,
+    /// Realspace acceleration in m/s²
+    pub acceleration: f32,
+    /// Hyperspace hexes per day
+    pub hyper_rating: f32,
+    /// Most hexes in a single jump
+    pub jump_range: i32,
+    /// Hyperspace fuel burned per hex
+    pub fuel_per_hex: f32,
+    pub armor: f32,
+    pub shield: f32,
+    pub structure: f32,
+    /// Longest detection range in km
+    pub sensor_range: f32,
+    /// Crew life support can keep alive
+    pub crew: u32,
+    /// Price in credits, hull and equipment
+    pub cost: f32,
+}
+
+impl ShipStats {
+    /// Work out what a Hull fitted with `equipment` can do.
+    pub fn new<'a>(hull: &Hull, equipment: impl IntoIterator<Item = &'a Equipment>) -> ShipStats {
+        let mut stats = ShipStats {
+            mass: hull.mass,
+            capacity: hull.capacity,
+            structure: hull.structure,
+            cost: hull.cost,
+            ..Default::default()
+        };
+        let mut hyperdrive = None;
+        for item in equipment {
+            stats.equipment_mass += item.mass;
+            stats.heat += item.heat;
+            stats.cost += item.cost;
+            if item.power > 0.0 {
+                stats.power_supply += item.power;
+            } else {
+                stats.power_draw -= item.power;
+            }
+            match &item.kind {
+                EquipmentKind::Powerbank {
+                    capacity,
+                    discharge,
+                }
+                | EquipmentKind::Supercapacitor {
+                    capacity,
+                    discharge,
+                } => {
+                    stats.storage += capacity;
+                    stats.discharge += discharge;
+                }
+                EquipmentKind::Drive { thrust, .. } => stats.thrust += thrust,
+                EquipmentKind::Hyperdrive {
+                    rating,
+                    range,
+                    fuel_per_hex,
+                    rated_mass,
+                } => {
+                    // Only the best Hyperdrive fitted is used.
+                    if hyperdrive.map_or(true, |(best, ..)| *rating > best) {
+                        hyperdrive = Some((*rating, *range, *fuel_per_hex, *rated_mass));
+                    }
+                }
+                EquipmentKind::Armor { rating, .. } => stats.armor += rating,
+                EquipmentKind::Shield { strength, .. } => stats.shield += strength,
+                EquipmentKind::Sensor { range, .. } => {
+                    stats.sensor_range = stats.sensor_range.max(*range)
+                }
+                EquipmentKind::LifeSupport { crew } => stats.crew += crew,
+                _ => {}
+            }
+        }
+        stats.mass += stats.equipment_mass;
+        stats.acceleration = stats.thrust / stats.mass.max(f32::EPSILON);
+        if let Some((rating, range, fuel_per_hex, rated_mass)) = hyperdrive {
+            // Ships heavier than the drive is rated for go slower, less far, for more fuel.
+            let load = (stats.mass / rated_mass.max(f32::EPSILON)).max(1.0);
+            stats.hyper_rating = rating / load;
+            stats.jump_range = (range as f32 / load).floor() as i32;
+            stats.fuel_per_hex = fuel_per_hex * load;
+        }
+        stats
+    }
+    /// MW left over with everything running, negative when short.
+    pub fn power_balance(&self) -> f32 {
+        self.power_supply - self.power_draw
+    }
+    /// Stats that differ in `after`, as name, before and after.
+    pub fn changes(&self, after: &ShipStats) -> Vec<(&'static str, f32, f32)> {
+        [
+            ("Mass", self.mass, after.mass),
+            ("Capacity", self.capacity, after.capacity),
+            ("Power Supply", self.power_supply, after.power_supply),
+            ("Power Draw", self.power_draw, after.power_draw),
+            ("Heat", self.heat, after.heat),
+            ("Storage", self.storage, after.storage),
+            ("Thrust", self.thrust, after.thrust),
+            ("Acceleration", self.acceleration, after.acceleration),
+            ("Hyper Rating", self.hyper_rating, after.hyper_rating),
+            (
+                "Jump Range",
+                self.jump_range as f32,
+                after.jump_range as f32,
+            ),
+            ("Fuel per Hex", self.fuel_per_hex, after.fuel_per_hex),
+            ("Armor", self.armor, after.armor),
+            ("Shield", self.shield, after.shield),
+            ("Structure", self.structure, after.structure),
+            ("Sensor Range", self.sensor_range, after.sensor_range),
+            ("Crew", self.crew as f32, after.crew as f32),
+        ]
+        .iter()
+        .copied()
+        .filter(|(_, before, after)| (after - before).abs() > f32::EPSILON)
+        .collect()
+    }
+}
+
+/// Why a design can't be built.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FitError {
+    UnknownHull(String),
+    UnknownEquipment(String),
+    /// Equipment needs a larger Hull Class
+    TooLarge {
+        equipment: String,
+        size: u8,
+        class: HullClass,
+    },
+    SlotsExceeded {
+        slot: SlotKind,
+        used: u8,
+        available: u8,
+    },
+    /// Equipment weighs more than the Hull can carry
+    OverCapacity {
+        mass: f32,
+        capacity: f32,
+    },
+    /// Everything running draws more than the generators supply
+    PowerDeficit {
+        supply: f32,
+        draw: f32,
+    },
+    /// Every ship must carry a Transponder
+    NoTransponder,
+    /// Life Support can't keep the crew alive
+    InsufficientLifeSupport {
+        crew: u32,
+        supported: u32,
+    },
+}
+
+impl fmt::Display for FitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FitError::UnknownHull(id) => write!(f, "unknown hull {:?}", id),
+            FitError::UnknownEquipment(id) => write!(f, "unknown equipment {:?}", id),
+            FitError::TooLarge {
+                equipment,
+                size,
+                class,
+            } => write!(
+                f,
+                "{} needs a class {} hull, not {}",
+                equipment, size, class
+            ),
+            FitError::SlotsExceeded {
+                slot,
+                used,
+                available,
+            } => write!(f, "{} of {} {:?} slots used", used, available, slot),
+            FitError::OverCapacity { mass, capacity } => {
+                write!(
+                    f,
+                    "{:.1}t of equipment over {:.1}t capacity",
+                    mass, capacity
+                )
+            }
+            FitError::PowerDeficit { supply, draw } => {
+                write!(f, "draws {:.1}MW of {:.1}MW supplied", draw, supply)
+            }
+            FitError::NoTransponder => write!(f, "no transponder fitted"),
+            FitError::InsufficientLifeSupport { crew, supported } => {
+                write!(f, "life support for {} of {} crew", supported, crew)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FitError {}
+
+impl FitError {
+    /// Whether the ship can still be built and flown, running its systems or crew short.
+    ///
+    /// Shipyards fit designs with warnings and leave the shortfall to the Player, everything else
+    /// is refused.
+    pub fn is_warning(&self) -> bool {
+        matches!(
+            self,
+            FitError::PowerDeficit { .. } | FitError::InsufficientLifeSupport { .. }
+        )
+    }
+}
+
+impl ShipDesign {
+    /// Look up the Hull and Equipment, skipping anything not in the catalog.
+    pub fn resolve<'a>(&self, catalog: &'a ShipCatalog) -> Option<(&'a Hull, Vec<&'a Equipment>)> {
+        let hull = catalog.hull(&self.hull)?;
+        let equipment = self
+            .equipment
+            .iter()
+            .filter_map(|id| catalog.equipment(id))
+            .collect();
+        Some((hull, equipment))
+    }
+    /// Performance of the design, if its Hull is known.
+    pub fn stats(&self, catalog: &ShipCatalog) -> Option<ShipStats> {
+        let (hull, equipment) = self.resolve(catalog)?;
+        Some(ShipStats::new(hull, equipment))
+    }
+    /// Everything wrong with the design, including [FitError::is_warning] shortfalls it can still
+    /// be built with.
+    pub fn validate(&self, catalog: &ShipCatalog) -> Vec<FitError> {
+        let mut errors: Vec<FitError> = self
+            .equipment
+            .iter()
+            .filter(|id| catalog.equipment(id).is_none())
+            .map(|id| FitError::UnknownEquipment(id.clone()))
+            .collect();
+        let (hull, equipment) = match self.resolve(catalog) {
+            Some(resolved) => resolved,
+            None => {
+                errors.insert(0, FitError::UnknownHull(self.hull.clone()));
+                return errors;
+            }
+        };
+
+        let mut used: BTreeMap<SlotKind, u8> = BTreeMap::new();
+        for item in equipment.iter() {
+            *used.entry(item.kind.slot()).or_insert(0) += 1;
+            if item.size > hull.class.size() {
+                errors.push(FitError::TooLarge {
+                    equipment: item.name.clone(),
+                    size: item.size,
+                    class: hull.class,
+                });
+            }
+        }
+        for (slot, used) in used {
+            let available = hull.slots(slot);
+            if used > available {
+                errors.push(FitError::SlotsExceeded {
+                    slot,
+                    used,
+                    available,
+                });
+            }
+        }
+
+        let stats = ShipStats::new(hull, equipment.iter().copied());
+        if stats.equipment_mass > stats.capacity {
+            errors.push(FitError::OverCapacity {
+                mass: stats.equipment_mass,
+                capacity: stats.capacity,
+            });
+        }
+        if stats.power_draw > stats.power_supply {
+            errors.push(FitError::PowerDeficit {
+                supply: stats.power_supply,
+                draw: stats.power_draw,
+            });
+        }
+        let transponder = equipment.iter().any(|item| {
+            matches!(
+                item.kind,
+                EquipmentKind::Comms {
+                    comms: CommsKind::Transponder,
+                    ..
+                }
+            )
+        });
+        if !transponder {
+            errors.push(FitError::NoTransponder);
+        }
+        if stats.crew < hull.crew {
+            errors.push(FitError::InsufficientLifeSupport {
+                crew: hull.crew,
+                supported: stats.crew,
+            });
+        }
+        errors
+    }
+}
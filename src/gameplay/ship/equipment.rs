@@ -0,0 +1,219 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::hull::SlotKind;
+use serde::{Deserialize, Serialize};
+
+/// What a Power Generator runs on.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum PowerSource {
+    Solar,
+    Biofuel,
+    Fusion,
+    Annihilation,
+    Hawking,
+    ZeroPoint,
+}
+
+/// How a Realspace Drive pushes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum DriveKind {
+    Ion,
+    Hydrojet,
+    Warp,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum ArmorKind {
+    Ablative,
+    Fibrous,
+    Plate,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum WeaponKind {
+    Ballistic,
+    MassDriver,
+    Missile,
+    Nuclear,
+    Laser,
+    ParticleCannon,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum SensorKind {
+    /// Passive full spectrum
+    Electromagnetic,
+    /// Passive gravity fluctuations
+    Gravimetric,
+    /// Active, gains more but gives the ship away
+    Active,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Software {
+    Navigation,
+    Targeting,
+    Scanner,
+    Firewall,
+    Hacking,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum CommsKind {
+    /// Required for every ship.
+    Transponder,
+    ShortRange,
+    LongRange,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum SpecialtyKind {
+    MiningLaser,
+    TractorBeam,
+    Cloak,
+}
+
+/// What a piece of Equipment does.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EquipmentKind {
+    /// Burns `fuel` kg per hour for the power it supplies.
+    Generator {
+        source: PowerSource,
+        fuel: f32,
+    },
+    /// Stores `capacity` MJ, released at up to `discharge` MW.
+    Powerbank {
+        capacity: f32,
+        discharge: f32,
+    },
+    /// Stores less than a Powerbank but releases it far faster.
+    Supercapacitor {
+        capacity: f32,
+        discharge: f32,
+    },
+    /// Realspace thrust in kN
+    Drive {
+        drive: DriveKind,
+        thrust: f32,
+    },
+    /// Hexes per day, most hexes per jump and fuel per hex, for ships up to `rated_mass` tonnes.
+    Hyperdrive {
+        rating: f32,
+        range: i32,
+        fuel_per_hex: f32,
+        rated_mass: f32,
+    },
+    Armor {
+        armor: ArmorKind,
+        rating: f32,
+    },
+    /// Absorbs `strength` damage, recovering `recharge` per second.
+    Shield {
+        strength: f32,
+        recharge: f32,
+    },
+    /// `damage` per shot at up to `range` km, `rate` shots per second.
+    Weapon {
+        weapon: WeaponKind,
+        damage: f32,
+        range: f32,
+        rate: f32,
+    },
+    /// Turret shooting down incoming munitions within `range` km, `rate` shots per second each
+    /// hitting on `accuracy`.
+    PointDefence {
+        range: f32,
+        rate: f32,
+        accuracy: f32,
+    },
+    /// Launches `charges` decoys, each drawing off an incoming munition on `decoy`.
+    Countermeasures {
+        charges: u32,
+        decoy: f32,
+    },
+    /// Blinds munition seekers within `range` km that are weaker than `strength`.
+    Jammer {
+        strength: f32,
+        range: f32,
+    },
+    /// Detection range in km
+    Sensor {
+        sensor: SensorKind,
+        range: f32,
+    },
+    Computer {
+        software: Vec<Software>,
+    },
+    AiCore,
+    /// Keeps `crew` alive
+    LifeSupport {
+        crew: u32,
+    },
+    /// Range in km
+    Comms {
+        comms: CommsKind,
+        range: f32,
+    },
+    Specialty {
+        specialty: SpecialtyKind,
+    },
+}
+
+impl EquipmentKind {
+    /// Mounting the Equipment is fitted into.
+    pub fn slot(&self) -> SlotKind {
+        match self {
+            EquipmentKind::Generator { .. } => SlotKind::Reactor,
+            EquipmentKind::Powerbank { .. } | EquipmentKind::Supercapacitor { .. } => {
+                SlotKind::Storage
+            }
+            EquipmentKind::Drive { .. } => SlotKind::Engine,
+            EquipmentKind::Hyperdrive { .. } => SlotKind::Hyperdrive,
+            EquipmentKind::Armor { .. } => SlotKind::Armor,
+            EquipmentKind::Shield { .. } => SlotKind::Shield,
+            EquipmentKind::Weapon { .. } | EquipmentKind::PointDefence { .. } => SlotKind::Weapon,
+            EquipmentKind::Sensor { .. } | EquipmentKind::Jammer { .. } => SlotKind::Sensor,
+            EquipmentKind::Computer { .. } | EquipmentKind::AiCore => SlotKind::Computer,
+            EquipmentKind::LifeSupport { .. } | EquipmentKind::Comms { .. } => SlotKind::Internal,
+            EquipmentKind::Countermeasures { .. } | EquipmentKind::Specialty { .. } => {
+                SlotKind::Utility
+            }
+        }
+    }
+}
+
+/// Component fitted into a Hull.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Equipment {
+    pub id: String,
+    pub name: String,
+    /// Smallest Hull Class, by number, it fits
+    pub size: u8,
+    /// Tonnes
+    pub mass: f32,
+    /// MW supplied when positive, drawn when negative
+    pub power: f32,
+    /// MW of waste heat while running
+    pub heat: f32,
+    /// Price in credits
+    pub cost: f32,
+    /// Tech level needed to build it
+    pub tech: u8,
+    pub kind: EquipmentKind,
+}
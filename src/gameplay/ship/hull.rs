@@ -0,0 +1,113 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Starship Classification from the Pod up to the Centurian.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum HullClass {
+    Pod,
+    Starfighter,
+    Shuttle,
+    Gunship,
+    Corvette,
+    Frigate,
+    Destroyer,
+    Cruiser,
+    Battleship,
+    Carrier,
+    Dreadnought,
+    Centurian,
+}
+
+impl HullClass {
+    /// Class number, `0` for a Pod to `11` for a Centurian.
+    pub fn size(&self) -> u8 {
+        *self as u8
+    }
+    /// Capital Ships are Corvettes and larger.
+    pub fn is_capital(&self) -> bool {
+        *self >= HullClass::Corvette
+    }
+}
+
+impl fmt::Display for HullClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Class {} {:?}", self.size(), self)
+    }
+}
+
+/// Kind of mounting a piece of Equipment is fitted into.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum SlotKind {
+    /// Power Generators
+    Reactor,
+    /// Powerbanks and Supercapacitors
+    Storage,
+    /// Realspace Drives
+    Engine,
+    /// Hyper Drives
+    Hyperdrive,
+    Armor,
+    Shield,
+    Weapon,
+    Sensor,
+    /// Computers and AI Cores
+    Computer,
+    /// Life Support and Communications
+    Internal,
+    /// Mining Lasers, Tractor Beams, Cloaks and the like
+    Utility,
+}
+
+/// Ship's frame, everything else is fitted into it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hull {
+    pub id: String,
+    pub name: String,
+    pub class: HullClass,
+    /// Tonnes, empty
+    pub mass: f32,
+    /// Tonnes of Equipment the frame can carry
+    pub capacity: f32,
+    /// Damage the frame takes before breaking up
+    pub structure: f32,
+    /// Tonnes of cargo the hold carries
+    pub cargo_mass: f32,
+    /// Cubic metres of cargo the hold carries
+    pub cargo_volume: f32,
+    /// Hyperspace fuel the tanks hold
+    pub fuel_capacity: f32,
+    /// Crew needed to fly her, Pods fly themselves
+    pub crew: u32,
+    /// Price in credits
+    pub cost: f32,
+    /// Tech level needed to build her
+    pub tech: u8,
+    /// Mountings of each kind
+    pub slots: BTreeMap<SlotKind, u8>,
+}
+
+impl Hull {
+    /// Mountings of a kind
+    pub fn slots(&self, slot: SlotKind) -> u8 {
+        self.slots.get(&slot).copied().unwrap_or(0)
+    }
+}
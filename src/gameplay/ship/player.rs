@@ -0,0 +1,169 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::catalog::ShipCatalog;
+use super::design::{ShipDesign, ShipStats};
+use super::power::PowerGrid;
+use crate::cartographer::{Orbitals, Sectors};
+use crate::gameplay::combat::{Combatant, RANGE_SCALE};
+use crate::gameplay::economy::CargoHold;
+use crate::gameplay::hyperspace::{GravimetricSensor, HyperDrive};
+use crate::gameplay::sectorspace::{SectorSpace, ShipMotion, STELLAR_MU};
+use crate::gameplay::sensors::hyperspace_sensor_range;
+use crate::gameplay::{CurrentSector, PlayerAvatar};
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+
+/// Sectorspace units from the Sector's primary the Player arrives at.
+const ARRIVAL_DISTANCE: f32 = 100.0;
+
+/// Ship Catalog hulls and equipment are bought from.
+pub struct ShipCatalogHandle(pub Handle<ShipCatalog>);
+
+/// Tag Denoting the Player's ship in Sectorspace
+pub struct PlayerVessel;
+
+/// Ship the Player flies, and what it can do.
+#[derive(Debug, Clone, Default)]
+pub struct PlayerShip {
+    pub design: ShipDesign,
+    pub stats: Option<ShipStats>,
+}
+
+pub fn load_ship_catalog(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ShipCatalogHandle(asset_server.load("data/starships.ships")));
+}
+
+/// Put the Player in the catalog's starting design once it has loaded.
+pub fn initialize_player_ship(
+    asset_server: Res<AssetServer>,
+    catalogs: Res<Assets<ShipCatalog>>,
+    catalog_handle: Res<ShipCatalogHandle>,
+    mut ship: ResMut<PlayerShip>,
+) {
+    if !ship.design.hull.is_empty() {
+        return;
+    }
+    match catalogs.get(&catalog_handle.0) {
+        Some(catalog) => {
+            if let Some(design) = catalog.design(&catalog.starter) {
+                trace!("Player starts in a {}", design.name);
+                ship.design = design.clone();
+            }
+        }
+        None => {
+            if asset_server.get_load_state(&catalog_handle.0) == LoadState::Failed {
+                error!("Unable to load Ship Catalog, the Player has no ship");
+            }
+        }
+    }
+}
+
+/// Refit the Player's Hyper Drive, Gravimetric Sensors and Cargo Hold whenever their ship changes.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_player_ship(
+    catalogs: Res<Assets<ShipCatalog>>,
+    catalog_handle: Res<ShipCatalogHandle>,
+    mut ship: ResMut<PlayerShip>,
+    mut cargo: ResMut<CargoHold>,
+    mut drive_query: Query<&mut HyperDrive, With<PlayerAvatar>>,
+    mut sensor_query: Query<&mut GravimetricSensor, With<PlayerAvatar>>,
+    mut grid_query: Query<&mut PowerGrid, With<PlayerVessel>>,
+    mut combatant_query: Query<&mut Combatant, With<PlayerVessel>>,
+) {
+    if !ship.is_changed() {
+        return;
+    }
+    let catalog = match catalogs.get(&catalog_handle.0) {
+        Some(catalog) => catalog,
+        None => return,
+    };
+    let (hull, stats) = match (catalog.hull(&ship.design.hull), ship.design.stats(catalog)) {
+        (Some(hull), Some(stats)) => (hull, stats),
+        _ => return,
+    };
+    cargo.mass_capacity = hull.cargo_mass;
+    cargo.volume_capacity = hull.cargo_volume;
+    for mut drive in drive_query.iter_mut() {
+        drive.rating = stats.hyper_rating;
+        drive.range = stats.jump_range;
+        drive.fuel_per_hex = stats.fuel_per_hex;
+        drive.fuel_capacity = hull.fuel_capacity;
+        drive.fuel = drive.fuel.min(hull.fuel_capacity);
+    }
+    // A refit rewires the ship, but the hull stays as hot as it was.
+    if let Some((hull, equipment)) = ship.design.resolve(catalog) {
+        for mut sensor in sensor_query.iter_mut() {
+            sensor.range = hyperspace_sensor_range(&equipment);
+        }
+        for mut grid in grid_query.iter_mut() {
+            let heat = grid.heat;
+            *grid = PowerGrid::new(hull, equipment.iter().copied());
+            grid.heat = heat.min(grid.heat_capacity);
+        }
+        // Refits are only made in dock, where the shipyard patches up any damage too.
+        for mut combatant in combatant_query.iter_mut() {
+            *combatant = Combatant::new(hull, &equipment, &stats, RANGE_SCALE);
+        }
+    }
+    // Only written when it differs, so this doesn't retrigger itself every frame.
+    if ship.stats.as_ref() != Some(&stats) {
+        ship.stats = Some(stats);
+    }
+}
+
+/// Put the Player's ship into Sectorspace, in a circular orbit around the Sector's primary.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_player_vessel(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    current_sector: Res<CurrentSector>,
+    sectors: Res<Sectors>,
+    orbitals: Res<Orbitals>,
+    catalogs: Res<Assets<ShipCatalog>>,
+    catalog_handle: Res<ShipCatalogHandle>,
+    ship: Res<PlayerShip>,
+) {
+    let sector = match sectors.get(current_sector.0) {
+        Some(sector) => sector,
+        None => return,
+    };
+    let mu = orbitals
+        .in_sector(sector.id)
+        .find(|orbital| orbital.parent.is_none())
+        .map_or(0.0, |primary| primary.data.mass() as f32 * STELLAR_MU);
+    let mut entity = commands.spawn_bundle(SpriteBundle {
+        material: materials.add(Color::rgb(0.2, 1.0, 0.4).into()),
+        sprite: Sprite::new(Vec2::new(5.0, 5.0)),
+        transform: Transform::from_xyz(ARRIVAL_DISTANCE, 0.0, 2.0),
+        ..Default::default()
+    });
+    entity
+        .insert(PlayerVessel)
+        .insert(SectorSpace(sector.location))
+        .insert(ShipMotion {
+            velocity: Vec2::new(0.0, (mu / ARRIVAL_DISTANCE).sqrt()),
+            ..Default::default()
+        });
+    let resolved = catalogs
+        .get(&catalog_handle.0)
+        .and_then(|catalog| ship.design.resolve(catalog));
+    if let Some((hull, equipment)) = resolved {
+        entity.insert(PowerGrid::new(hull, equipment));
+    }
+}
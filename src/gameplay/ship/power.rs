@@ -0,0 +1,550 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Generating, storing and drawing power aboard ships, and the waste heat it leaves behind.
+use super::equipment::{Equipment, EquipmentKind, PowerSource};
+use super::hull::{Hull, SlotKind};
+use super::player::PlayerVessel;
+use crate::gameplay::sectorspace::{ShipMotion, StarInfo};
+use crate::utility::WorldClock;
+use bevy::prelude::*;
+
+/// Sectorspace units from a Sun-like star at which Solar Panels give their rated output.
+pub const SOLAR_REFERENCE_DISTANCE: f32 = 150.0;
+/// Most Solar Panels can be overdriven close in to a bright star.
+const MAX_SUNLIGHT: f32 = 4.0;
+/// MJ of heat each tonne of hull soaks up before the ship overheats.
+const HEAT_PER_TONNE: f32 = 1.0;
+/// MW radiated away per unit of hull surface, taken as mass to the power of 2/3.
+const RADIATED_PER_AREA: f32 = 0.5;
+/// Share of heat capacity an overheated ship must cool to before restarting its systems.
+const RESTART_HEAT: f32 = 0.5;
+/// Longest step in seconds a Power Grid is advanced by at once, so stores drain and heat builds
+/// up gradually during Time Warp.
+const MAX_POWER_STEP: f32 = 1.0;
+/// Most steps a Power Grid takes in a frame, any time beyond is dropped.
+const MAX_POWER_SUBSTEPS: usize = 256;
+
+/// Order systems are kept running in when power runs short, lowest shed first.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PowerPriority {
+    Low,
+    Normal,
+    High,
+    /// Kept running even while overheated
+    Critical,
+}
+
+impl PowerPriority {
+    pub fn of(kind: &EquipmentKind) -> PowerPriority {
+        match kind {
+            EquipmentKind::LifeSupport { .. }
+            | EquipmentKind::Comms { .. }
+            | EquipmentKind::Computer { .. }
+            | EquipmentKind::AiCore => PowerPriority::Critical,
+            EquipmentKind::Shield { .. }
+            | EquipmentKind::PointDefence { .. }
+            | EquipmentKind::Drive { .. }
+            | EquipmentKind::Hyperdrive { .. } => PowerPriority::High,
+            EquipmentKind::Weapon { .. }
+            | EquipmentKind::Sensor { .. }
+            | EquipmentKind::Jammer { .. } => PowerPriority::Normal,
+            _ => PowerPriority::Low,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerGenerator {
+    /// Index of the Equipment in the fitted design
+    pub equipment: usize,
+    pub name: String,
+    pub source: PowerSource,
+    /// Rated MW
+    pub output: f32,
+    /// MW of waste heat at full output
+    pub heat: f32,
+}
+
+impl PowerGenerator {
+    /// MW supplied, Solar Panels by how brightly they are lit.
+    pub fn supply(&self, sunlight: f32) -> f32 {
+        match self.source {
+            PowerSource::Solar => self.output * sunlight,
+            _ => self.output,
+        }
+    }
+}
+
+/// Powerbank or Supercapacitor
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerStore {
+    /// Index of the Equipment in the fitted design
+    pub equipment: usize,
+    pub name: String,
+    /// Most MJ held
+    pub capacity: f32,
+    /// MJ held
+    pub charge: f32,
+    /// Most MW taken in or released
+    pub rate: f32,
+    /// Supercapacitors are drained and filled before Powerbanks.
+    pub fast: bool,
+}
+
+/// System drawing power.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerConsumer {
+    /// Index of the Equipment in the fitted design
+    pub equipment: usize,
+    pub name: String,
+    pub kind: EquipmentKind,
+    /// MW drawn while running
+    pub draw: f32,
+    /// MW of waste heat while running
+    pub heat: f32,
+    pub priority: PowerPriority,
+    /// Wants power, Drives only while thrusting and Weapons only while armed
+    pub active: bool,
+    /// Got power last tick
+    pub powered: bool,
+}
+
+/// Generators, stores and systems aboard a ship, stepped every tick.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerGrid {
+    pub generators: Vec<PowerGenerator>,
+    pub stores: Vec<PowerStore>,
+    pub consumers: Vec<PowerConsumer>,
+    /// MJ of waste heat held in the hull
+    pub heat: f32,
+    /// MJ of heat the hull holds before overheating
+    pub heat_capacity: f32,
+    /// MW of heat radiated away
+    pub dissipation: f32,
+    /// MW generated last tick
+    pub supply: f32,
+    /// MW drawn last tick
+    pub draw: f32,
+    /// Systems that wanted power went without last tick
+    pub brownout: bool,
+    /// Everything but Critical systems is shut down until the hull cools
+    pub overheated: bool,
+}
+
+impl PowerGrid {
+    /// Wire up a Hull's Equipment, with stores fully charged and the hull cold.
+    pub fn new<'a>(hull: &Hull, equipment: impl IntoIterator<Item = &'a Equipment>) -> PowerGrid {
+        let mut grid = PowerGrid {
+            generators: Vec::new(),
+            stores: Vec::new(),
+            consumers: Vec::new(),
+            heat: 0.0,
+            heat_capacity: hull.mass * HEAT_PER_TONNE,
+            dissipation: hull.mass.powf(2.0 / 3.0) * RADIATED_PER_AREA,
+            supply: 0.0,
+            draw: 0.0,
+            brownout: false,
+            overheated: false,
+        };
+        for (index, item) in equipment.into_iter().enumerate() {
+            match &item.kind {
+                EquipmentKind::Generator { source, .. } => grid.generators.push(PowerGenerator {
+                    equipment: index,
+                    name: item.name.clone(),
+                    source: *source,
+                    output: item.power.max(0.0),
+                    heat: item.heat,
+                }),
+                EquipmentKind::Powerbank {
+                    capacity,
+                    discharge,
+                }
+                | EquipmentKind::Supercapacitor {
+                    capacity,
+                    discharge,
+                } => grid.stores.push(PowerStore {
+                    equipment: index,
+                    name: item.name.clone(),
+                    capacity: *capacity,
+                    charge: *capacity,
+                    rate: *discharge,
+                    fast: matches!(item.kind, EquipmentKind::Supercapacitor { .. }),
+                }),
+                kind if item.power < 0.0 => grid.consumers.push(PowerConsumer {
+                    equipment: index,
+                    name: item.name.clone(),
+                    kind: kind.clone(),
+                    draw: -item.power,
+                    heat: item.heat,
+                    priority: PowerPriority::of(kind),
+                    active: !matches!(
+                        kind.slot(),
+                        SlotKind::Engine
+                            | SlotKind::Hyperdrive
+                            | SlotKind::Weapon
+                            | SlotKind::Utility
+                    ),
+                    powered: false,
+                }),
+                _ => {}
+            }
+        }
+        grid
+    }
+    /// MJ held across every store.
+    pub fn stored(&self) -> f32 {
+        self.stores.iter().map(|store| store.charge).sum()
+    }
+    /// Most MJ every store can hold.
+    pub fn storage(&self) -> f32 {
+        self.stores.iter().map(|store| store.capacity).sum()
+    }
+    /// Whether anything fitted in a slot got power last tick.
+    pub fn is_powered(&self, slot: SlotKind) -> bool {
+        self.consumers
+            .iter()
+            .any(|consumer| consumer.powered && consumer.kind.slot() == slot)
+    }
+    /// Whether a fitted system got power last tick, those drawing none always run.
+    pub fn is_running(&self, equipment: usize) -> bool {
+        self.consumers
+            .iter()
+            .find(|consumer| consumer.equipment == equipment)
+            .map_or(true, |consumer| consumer.powered)
+    }
+    /// Cut a wrecked piece of Equipment out of the grid.
+    pub fn disable(&mut self, equipment: usize) {
+        self.generators
+            .retain(|generator| generator.equipment != equipment);
+        self.stores.retain(|store| store.equipment != equipment);
+        self.consumers
+            .retain(|consumer| consumer.equipment != equipment);
+    }
+    /// Switch everything fitted in a slot on or off.
+    pub fn set_active(&mut self, slot: SlotKind, active: bool) {
+        for consumer in self.consumers.iter_mut() {
+            if consumer.kind.slot() == slot && consumer.active != active {
+                consumer.active = active;
+            }
+        }
+    }
+    /// Switch a single fitted system on or off.
+    pub fn set_equipment_active(&mut self, equipment: usize, active: bool) {
+        for consumer in self.consumers.iter_mut() {
+            if consumer.equipment == equipment && consumer.active != active {
+                consumer.active = active;
+            }
+        }
+    }
+    /// Advance `seconds` in steps of at most [MAX_POWER_STEP], up to [MAX_POWER_SUBSTEPS] of them.
+    pub fn advance(&mut self, seconds: f32, sunlight: f32) {
+        let substeps = ((seconds / MAX_POWER_STEP).ceil() as usize).clamp(1, MAX_POWER_SUBSTEPS);
+        let step = (seconds / substeps as f32).min(MAX_POWER_STEP);
+        for _ in 0..substeps {
+            self.step(step, sunlight);
+        }
+    }
+    /// Advance `seconds`, with Solar Panels lit at `sunlight` times their rating.
+    pub fn step(&mut self, seconds: f32, sunlight: f32) {
+        if seconds <= 0.0 {
+            return;
+        }
+        if self.overheated && self.heat <= self.heat_capacity * RESTART_HEAT {
+            self.overheated = false;
+        }
+        let supply: f32 = self
+            .generators
+            .iter()
+            .map(|generator| generator.supply(sunlight))
+            .sum();
+        // Stores make up a shortfall at their rate for as long as their charge lasts.
+        let reserve: f32 = self
+            .stores
+            .iter()
+            .map(|store| store.rate.min(store.charge / seconds))
+            .sum();
+        let available = supply + reserve;
+
+        // Highest priority first. Once a system goes without, everything of lower priority does.
+        let mut order: Vec<usize> = (0..self.consumers.len()).collect();
+        order.sort_by_key(|index| std::cmp::Reverse(self.consumers[*index].priority));
+        let mut draw = 0.0;
+        let mut cutoff = None;
+        let mut brownout = false;
+        for index in order {
+            let consumer = &mut self.consumers[index];
+            consumer.powered = false;
+            if !consumer.active || (self.overheated && consumer.priority < PowerPriority::Critical)
+            {
+                continue;
+            }
+            if cutoff.map_or(false, |cutoff| consumer.priority < cutoff)
+                || draw + consumer.draw > available
+            {
+                brownout = true;
+                cutoff.get_or_insert(consumer.priority);
+                continue;
+            }
+            consumer.powered = true;
+            draw += consumer.draw;
+        }
+
+        // Supercapacitors take up the slack first, saving Powerbanks for long hauls.
+        let mut balance = (supply - draw) * seconds;
+        for fast in [true, false] {
+            for store in self.stores.iter_mut().filter(|store| store.fast == fast) {
+                let limit = store.rate * seconds;
+                if balance < 0.0 {
+                    let taken = (-balance).min(store.charge).min(limit);
+                    store.charge -= taken;
+                    balance += taken;
+                } else {
+                    let stored = balance.min(store.capacity - store.charge).min(limit);
+                    store.charge += stored;
+                    balance -= stored;
+                }
+            }
+        }
+
+        // Generators throttle back rather than make power nothing can use.
+        let load = if supply > 0.0 {
+            (1.0 - balance.max(0.0) / (supply * seconds)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let produced: f32 = self
+            .generators
+            .iter()
+            .map(|generator| generator.heat * load)
+            .chain(
+                self.consumers
+                    .iter()
+                    .filter(|consumer| consumer.powered)
+                    .map(|consumer| consumer.heat),
+            )
+            .sum();
+        self.heat =
+            (self.heat + (produced - self.dissipation) * seconds).clamp(0.0, self.heat_capacity);
+        if self.heat >= self.heat_capacity && !self.overheated {
+            trace!("Overheated, shutting down all but critical systems");
+            self.overheated = true;
+        }
+        self.supply = supply;
+        self.draw = draw;
+        self.brownout = brownout;
+    }
+}
+
+/// Sunlight at a position as a multiple of what Solar Panels are rated for.
+pub fn sunlight(position: Vec2, stars: &[(Vec2, f32)]) -> f32 {
+    stars
+        .iter()
+        .map(|(star, luminosity)| {
+            let distance = position.distance(*star).max(1.0);
+            luminosity * (SOLAR_REFERENCE_DISTANCE / distance).powi(2)
+        })
+        .sum::<f32>()
+        .min(MAX_SUNLIGHT)
+}
+
+/// Drives draw power while thrusting, and give no thrust without it.
+pub fn power_demand_system(mut query: Query<(&mut ShipMotion, &mut PowerGrid)>) {
+    for (mut motion, mut grid) in query.iter_mut() {
+        let thrusting = motion.thrust != Vec2::ZERO;
+        grid.set_active(SlotKind::Engine, thrusting);
+        let drives = grid
+            .consumers
+            .iter()
+            .any(|consumer| consumer.kind.slot() == SlotKind::Engine);
+        if thrusting && drives && !grid.is_powered(SlotKind::Engine) {
+            motion.thrust = Vec2::ZERO;
+        }
+    }
+}
+
+/// Step every ship's Power Grid through the world time elapsed this frame.
+pub fn power_system(
+    world_clock: Res<WorldClock>,
+    star_query: Query<(&GlobalTransform, &StarInfo)>,
+    mut grid_query: Query<(&GlobalTransform, &mut PowerGrid)>,
+) {
+    let seconds = world_clock.delta().as_secs_f32();
+    if seconds <= 0.0 {
+        return;
+    }
+    let stars: Vec<(Vec2, f32)> = star_query
+        .iter()
+        .map(|(transform, star)| (transform.translation.truncate(), star.luminosity))
+        .collect();
+    for (transform, mut grid) in grid_query.iter_mut() {
+        grid.advance(seconds, sunlight(transform.translation.truncate(), &stars));
+    }
+}
+
+/// Tag Denoting the Power HUD
+pub struct PowerHud;
+
+pub fn setup_power_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn_bundle(TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    bottom: Val::Px(5.0),
+                    right: Val::Px(5.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            text: Text::with_section(
+                String::new(),
+                TextStyle {
+                    font: asset_server.load("fonts/spacemono.ttf"),
+                    font_size: 14.0,
+                    color: Color::rgb(0.9, 0.9, 0.6),
+                },
+                Default::default(),
+            ),
+            ..Default::default()
+        })
+        .insert(PowerHud);
+}
+
+/// Show the Player's power, stored charge, heat and whatever has been shut down.
+pub fn update_power_hud(
+    grid_query: Query<&PowerGrid, With<PlayerVessel>>,
+    mut hud_query: Query<&mut Text, With<PowerHud>>,
+) {
+    let grid = match grid_query.iter().next() {
+        Some(grid) => grid,
+        None => return,
+    };
+    let mut lines = vec![
+        format!("Power {:.1}/{:.1} MW", grid.draw, grid.supply),
+        format!("Stored {:.0}/{:.0} MJ", grid.stored(), grid.storage()),
+        format!(
+            "Heat {:.0}/{:.0} MJ ({:.1} MW radiated)",
+            grid.heat, grid.heat_capacity, grid.dissipation
+        ),
+    ];
+    if grid.overheated {
+        lines.push(String::from("OVERHEATED"));
+    } else if grid.brownout {
+        lines.push(String::from("BROWNOUT"));
+    }
+    for consumer in grid.consumers.iter() {
+        if consumer.active && !consumer.powered {
+            lines.push(format!("  {} offline", consumer.name));
+        }
+    }
+    for mut text in hud_query.iter_mut() {
+        text.sections[0].value = lines.join("\n");
+    }
+}
+
+pub fn cleanup_power_hud(mut commands: Commands, query: Query<Entity, With<PowerHud>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::ship::test_catalog;
+
+    #[test]
+    fn test_power_grid() {
+        let catalog = test_catalog();
+        let (hull, equipment) = catalog
+            .design("Gunship")
+            .unwrap()
+            .resolve(&catalog)
+            .unwrap();
+        let mut grid = PowerGrid::new(hull, equipment);
+
+        grid.step(1.0, 1.0);
+        assert!(!grid.brownout);
+        assert!(grid.is_powered(SlotKind::Shield));
+        assert!(!grid.is_powered(SlotKind::Weapon));
+
+        // Arming the Laser draws more than the generator and battery together can supply.
+        grid.set_active(SlotKind::Weapon, true);
+        grid.step(1.0, 1.0);
+        assert!(grid.brownout);
+        assert!(grid.is_powered(SlotKind::Shield));
+        assert!(!grid.is_powered(SlotKind::Weapon));
+
+        // Dropping the Shield frees up enough for the Laser.
+        grid.set_active(SlotKind::Shield, false);
+        grid.step(1.0, 1.0);
+        assert!(!grid.brownout);
+        assert!(grid.is_powered(SlotKind::Weapon));
+
+        // An overheated ship keeps only Critical systems running until it has cooled off.
+        grid.heat = grid.heat_capacity;
+        grid.overheated = true;
+        grid.step(1.0, 1.0);
+        assert!(grid.overheated);
+        assert!(!grid.is_powered(SlotKind::Weapon));
+        assert!(grid.is_powered(SlotKind::Internal));
+    }
+
+    #[test]
+    fn test_power_substeps() {
+        let catalog = test_catalog();
+        let (hull, equipment) = catalog
+            .design("Gunship")
+            .unwrap()
+            .resolve(&catalog)
+            .unwrap();
+        let grid = || {
+            let mut grid = PowerGrid::new(hull, equipment.iter().copied());
+            grid.set_active(SlotKind::Weapon, true);
+            grid
+        };
+
+        // A long frame plays out the same as the steps it is made of.
+        let mut stepped = grid();
+        for _ in 0..10 {
+            stepped.step(MAX_POWER_STEP, 1.0);
+        }
+        let mut advanced = grid();
+        advanced.advance(10.0 * MAX_POWER_STEP, 1.0);
+        assert!((stepped.stored() - advanced.stored()).abs() < 1e-3);
+        assert!((stepped.heat - advanced.heat).abs() < 1e-3);
+
+        // Time beyond the substep limit is dropped.
+        let mut capped = grid();
+        capped.advance(MAX_POWER_STEP * MAX_POWER_SUBSTEPS as f32, 1.0);
+        let mut dropped = grid();
+        dropped.advance(1e6, 1.0);
+        assert!((capped.stored() - dropped.stored()).abs() < 1e-3);
+        assert!((capped.heat - dropped.heat).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_sunlight() {
+        let stars = [(Vec2::ZERO, 1.0)];
+        let rated = sunlight(Vec2::new(SOLAR_REFERENCE_DISTANCE, 0.0), &stars);
+        assert!((rated - 1.0).abs() < 1e-5);
+        let far = sunlight(Vec2::new(SOLAR_REFERENCE_DISTANCE * 2.0, 0.0), &stars);
+        assert!((far - 0.25).abs() < 1e-5);
+    }
+}
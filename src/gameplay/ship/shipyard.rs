@@ -0,0 +1,408 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! What Station Shipyards stock, and refitting the Player's ship at them.
+use super::catalog::ShipCatalog;
+use super::design::{FitError, ShipDesign};
+use super::equipment::{Equipment, EquipmentKind, Software, SpecialtyKind, WeaponKind};
+use super::hull::Hull;
+use super::player::{PlayerShip, ShipCatalogHandle};
+use crate::cartographer::{Faction, FactionKind, Factions, Orbitals};
+use crate::gameplay::economy::{Credits, Docked, Economy};
+use crate::generation::OrbitalData;
+use bevy::prelude::*;
+use std::fmt;
+
+/// Share of the list price a Shipyard pays for used Hulls and Equipment.
+pub const RESALE: f32 = 0.6;
+
+/// Who may buy a Hull or piece of Equipment.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Restriction {
+    /// Sold anywhere with the tech to build it
+    Open,
+    /// Capital Hulls and heavy weapons, only sold by Governments and Houses
+    Military,
+    /// Cloaks and intrusion software, only sold by Criminals and Pirates
+    Illicit,
+}
+
+impl Restriction {
+    pub fn of_hull(hull: &Hull) -> Restriction {
+        if hull.class.is_capital() {
+            Restriction::Military
+        } else {
+            Restriction::Open
+        }
+    }
+    pub fn of_equipment(equipment: &Equipment) -> Restriction {
+        match &equipment.kind {
+            EquipmentKind::Weapon {
+                weapon: WeaponKind::Nuclear | WeaponKind::ParticleCannon,
+                ..
+            } => Restriction::Military,
+            EquipmentKind::Jammer { .. } => Restriction::Military,
+            EquipmentKind::Specialty {
+                specialty: SpecialtyKind::Cloak,
+            } => Restriction::Illicit,
+            EquipmentKind::Computer { software } if software.contains(&Software::Hacking) => {
+                Restriction::Illicit
+            }
+            _ => Restriction::Open,
+        }
+    }
+    /// Whether a Shipyard in a Sector run by `authority` will sell it.
+    pub fn permits(&self, authority: Option<FactionKind>) -> bool {
+        match self {
+            Restriction::Open => true,
+            Restriction::Military => matches!(
+                authority,
+                Some(FactionKind::Government | FactionKind::House | FactionKind::Player)
+            ),
+            Restriction::Illicit => {
+                matches!(authority, Some(FactionKind::Criminal | FactionKind::Pirate))
+            }
+        }
+    }
+}
+
+/// Shipyard at a Station, stocking what its tech level and controlling Faction allow.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Shipyard {
+    pub orbital: usize,
+    pub tech: u8,
+    pub authority: Option<FactionKind>,
+}
+
+impl Shipyard {
+    /// Established Factions build better Shipyards, and no two Stations are quite alike.
+    pub fn new(orbital: usize, authority: Option<&Faction>) -> Shipyard {
+        let kind = authority.map(|faction| faction.kind);
+        let base = match kind {
+            None => 0,
+            Some(FactionKind::Pirate | FactionKind::Rebellion) => 1,
+            Some(FactionKind::Criminal | FactionKind::Religious | FactionKind::Guild) => 2,
+            Some(FactionKind::House | FactionKind::Player) => 3,
+            Some(FactionKind::Corporate | FactionKind::Government) => 4,
+        };
+        Shipyard {
+            orbital,
+            tech: base + (orbital % 3) as u8,
+            authority: kind,
+        }
+    }
+    /// Shipyard at the Station the Player is docked at, if they are docked at one.
+    pub fn docked(
+        docked: &Docked,
+        economy: &Economy,
+        orbitals: &Orbitals,
+        factions: &Factions,
+    ) -> Option<Shipyard> {
+        let market = economy.get(docked.0?)?;
+        match orbitals.get(market.orbital).map(|orbital| &orbital.data) {
+            Some(OrbitalData::Station(_)) => Some(Shipyard::new(
+                market.orbital,
+                factions.controller(market.sector),
+            )),
+            _ => None,
+        }
+    }
+    pub fn stocks_hull(&self, hull: &Hull) -> bool {
+        hull.tech <= self.tech && Restriction::of_hull(hull).permits(self.authority)
+    }
+    pub fn stocks_equipment(&self, equipment: &Equipment) -> bool {
+        equipment.tech <= self.tech && Restriction::of_equipment(equipment).permits(self.authority)
+    }
+    /// Hulls on sale, in catalog order.
+    pub fn hulls<'a>(&self, catalog: &'a ShipCatalog) -> Vec<&'a Hull> {
+        catalog
+            .hulls
+            .iter()
+            .filter(|hull| self.stocks_hull(hull))
+            .collect()
+    }
+    /// Equipment on sale, in catalog order.
+    pub fn equipment<'a>(&self, catalog: &'a ShipCatalog) -> Vec<&'a Equipment> {
+        catalog
+            .equipment
+            .iter()
+            .filter(|item| self.stocks_equipment(item))
+            .collect()
+    }
+}
+
+/// Change to the Player's ship made at a Shipyard.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Refit {
+    /// Buy a Hull, trading in the current one and moving over whatever Equipment still fits
+    Hull(String),
+    /// Buy and install Equipment
+    Install(String),
+    /// Remove and sell the fitted Equipment at an index
+    Remove(usize),
+}
+
+/// Why a Refit can't be made.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RefitError {
+    NotDocked,
+    NoCatalog,
+    UnknownItem(String),
+    /// The Shipyard doesn't sell it
+    NotStocked(String),
+    Unaffordable {
+        cost: f32,
+        credits: f32,
+    },
+    /// The result couldn't be built
+    Unfit(FitError),
+}
+
+impl fmt::Display for RefitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RefitError::NotDocked => write!(f, "not docked at a Shipyard"),
+            RefitError::NoCatalog => write!(f, "ship catalog not loaded"),
+            RefitError::UnknownItem(item) => write!(f, "unknown item {:?}", item),
+            RefitError::NotStocked(item) => write!(f, "{} isn't sold here", item),
+            RefitError::Unaffordable { cost, credits } => {
+                write!(f, "costs {:.0} with {:.0} credits", cost, credits)
+            }
+            RefitError::Unfit(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for RefitError {}
+
+impl Refit {
+    /// The design once refitted and what it costs, negative when it pays out.
+    pub fn apply(
+        &self,
+        design: &ShipDesign,
+        catalog: &ShipCatalog,
+    ) -> Result<(ShipDesign, f32), RefitError> {
+        let mut refitted = design.clone();
+        let cost = match self {
+            Refit::Hull(id) => {
+                let hull = catalog
+                    .hull(id)
+                    .ok_or_else(|| RefitError::UnknownItem(id.clone()))?;
+                let trade_in = catalog.hull(&design.hull).map_or(0.0, |old| old.cost) * RESALE;
+                refitted = ShipDesign {
+                    name: hull.name.clone(),
+                    hull: hull.id.clone(),
+                    equipment: Vec::new(),
+                };
+                // Equipment that no longer fits is sold off with the old Hull.
+                let mut sold = 0.0;
+                for id in design.equipment.iter() {
+                    refitted.equipment.push(id.clone());
+                    if refitted
+                        .validate(catalog)
+                        .iter()
+                        .any(|error| !error.is_warning() && *error != FitError::NoTransponder)
+                    {
+                        refitted.equipment.pop();
+                        sold += catalog.equipment(id).map_or(0.0, |item| item.cost) * RESALE;
+                    }
+                }
+                hull.cost - trade_in - sold
+            }
+            Refit::Install(id) => {
+                let item = catalog
+                    .equipment(id)
+                    .ok_or_else(|| RefitError::UnknownItem(id.clone()))?;
+                refitted.equipment.push(item.id.clone());
+                item.cost
+            }
+            Refit::Remove(index) => {
+                if *index >= refitted.equipment.len() {
+                    return Err(RefitError::UnknownItem(format!("slot {}", index)));
+                }
+                let id = refitted.equipment.remove(*index);
+                -catalog.equipment(&id).map_or(0.0, |item| item.cost) * RESALE
+            }
+        };
+        // Running short of power or life support is only a warning, or a ship with a single
+        // Reactor slot could never swap its generator.
+        match refitted
+            .validate(catalog)
+            .into_iter()
+            .find(|error| !error.is_warning())
+        {
+            Some(error) => Err(RefitError::Unfit(error)),
+            None => Ok((refitted, cost)),
+        }
+    }
+}
+
+/// Player's order to refit their ship at the Shipyard on an Orbital.
+#[derive(Debug, Clone)]
+pub struct RefitOrder {
+    pub shipyard: usize,
+    pub refit: Refit,
+}
+
+/// How a RefitOrder went, with what it cost when it went ahead.
+#[derive(Debug, Clone)]
+pub struct RefitReceipt {
+    pub refit: Refit,
+    pub result: Result<f32, RefitError>,
+}
+
+/// Refit the Player's ship at the Shipyard they are docked at.
+#[allow(clippy::too_many_arguments)]
+pub fn refit_system(
+    docked: Res<Docked>,
+    economy: Res<Economy>,
+    orbitals: Res<Orbitals>,
+    factions: Res<Factions>,
+    catalogs: Res<Assets<ShipCatalog>>,
+    catalog_handle: Res<ShipCatalogHandle>,
+    mut orders: EventReader<RefitOrder>,
+    mut ship: ResMut<PlayerShip>,
+    mut credits: ResMut<Credits>,
+    mut receipts: EventWriter<RefitReceipt>,
+) {
+    let shipyard = Shipyard::docked(&docked, &economy, &orbitals, &factions);
+    let catalog = catalogs.get(&catalog_handle.0);
+    for order in orders.iter() {
+        let result = match (shipyard, catalog) {
+            (Some(shipyard), Some(catalog)) if shipyard.orbital == order.shipyard => {
+                refit(&order.refit, &shipyard, catalog, &mut ship, &mut credits)
+            }
+            (_, None) => Err(RefitError::NoCatalog),
+            _ => Err(RefitError::NotDocked),
+        };
+        match &result {
+            Ok(cost) => trace!("Refitted {:?} for {:.0}", order.refit, cost),
+            Err(error) => trace!("Refit {:?} refused: {}", order.refit, error),
+        }
+        receipts.send(RefitReceipt {
+            refit: order.refit.clone(),
+            result,
+        });
+    }
+}
+
+/// Make a Refit if the Shipyard stocks what it needs and the Player can pay for it.
+fn refit(
+    refit: &Refit,
+    shipyard: &Shipyard,
+    catalog: &ShipCatalog,
+    ship: &mut PlayerShip,
+    credits: &mut Credits,
+) -> Result<f32, RefitError> {
+    match refit {
+        Refit::Hull(id) => {
+            let hull = catalog
+                .hull(id)
+                .ok_or_else(|| RefitError::UnknownItem(id.clone()))?;
+            if !shipyard.stocks_hull(hull) {
+                return Err(RefitError::NotStocked(hull.name.clone()));
+            }
+        }
+        Refit::Install(id) => {
+            let item = catalog
+                .equipment(id)
+                .ok_or_else(|| RefitError::UnknownItem(id.clone()))?;
+            if !shipyard.stocks_equipment(item) {
+                return Err(RefitError::NotStocked(item.name.clone()));
+            }
+        }
+        Refit::Remove(_) => {}
+    }
+    let (design, cost) = refit.apply(&ship.design, catalog)?;
+    if cost > credits.0 {
+        return Err(RefitError::Unaffordable {
+            cost,
+            credits: credits.0,
+        });
+    }
+    ship.design = design;
+    credits.0 -= cost;
+    Ok(cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::ship::test_catalog;
+
+    #[test]
+    fn test_refit() {
+        let catalog = test_catalog();
+        let courier = catalog.design("Courier").unwrap();
+
+        let error = Refit::Install(String::from("mass-driver"))
+            .apply(courier, &catalog)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            RefitError::Unfit(FitError::TooLarge { .. })
+        ));
+
+        let (gunship, cost) = Refit::Hull(String::from("gunship"))
+            .apply(courier, &catalog)
+            .unwrap();
+        assert_eq!(gunship.equipment, courier.equipment);
+        assert!((cost - (120000.0 - 20000.0 * RESALE)).abs() < 1e-3);
+        let (gunship, cost) = Refit::Install(String::from("mass-driver"))
+            .apply(&gunship, &catalog)
+            .unwrap();
+        assert_eq!(gunship.equipment.len(), 4);
+        assert!((cost - 60000.0).abs() < 1e-3);
+
+        // Removing the Transponder is refused, removing Life Support or the only generator is
+        // left to the Player as a warning.
+        let error = Refit::Remove(1).apply(courier, &catalog).unwrap_err();
+        assert_eq!(error, RefitError::Unfit(FitError::NoTransponder));
+        let (stripped, cost) = Refit::Remove(2).apply(courier, &catalog).unwrap();
+        assert!((cost + 500.0 * RESALE).abs() < 1e-3);
+        let (unpowered, _) = Refit::Remove(0).apply(courier, &catalog).unwrap();
+        for design in [&stripped, &unpowered] {
+            let errors = design.validate(&catalog);
+            assert_eq!(errors.len(), 1);
+            assert!(errors[0].is_warning());
+        }
+
+        let frontier = Shipyard {
+            orbital: 0,
+            tech: 1,
+            authority: None,
+        };
+        let names: Vec<&str> = frontier
+            .equipment(&catalog)
+            .iter()
+            .map(|item| item.id.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "fusion",
+                "ion",
+                "jump",
+                "transponder",
+                "life",
+                "battery",
+                "ablative"
+            ]
+        );
+    }
+}
@@ -0,0 +1,437 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Shipyard Screen for buying Hulls and fitting Equipment while docked at a Station.
+use super::economy::{Credits, Docked, Economy};
+use super::ship::{
+    PlayerShip, Refit, RefitOrder, RefitReceipt, ShipCatalog, ShipCatalogHandle, Shipyard, RESALE,
+};
+use crate::cartographer::{Factions, Orbitals, Sectors};
+use bevy::prelude::*;
+
+/// Rows of the selected list shown at once.
+const LISTED_ROWS: usize = 12;
+
+/// Tag Denoting the Shipyard Screen
+pub struct ShipyardScreen;
+
+/// Whether the Shipyard Screen is shown in place of the Trading Screen.
+#[derive(Debug, Default)]
+pub struct ShipyardOpen(pub bool);
+
+/// List shown on the Shipyard Screen.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ShipyardTab {
+    Hulls,
+    Equipment,
+    Fitted,
+}
+
+impl Default for ShipyardTab {
+    fn default() -> Self {
+        ShipyardTab::Equipment
+    }
+}
+
+impl ShipyardTab {
+    const ALL: [ShipyardTab; 3] = [
+        ShipyardTab::Hulls,
+        ShipyardTab::Equipment,
+        ShipyardTab::Fitted,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            ShipyardTab::Hulls => "Hulls",
+            ShipyardTab::Equipment => "Equipment",
+            ShipyardTab::Fitted => "Fitted",
+        }
+    }
+    fn cycle(&self, step: usize) -> ShipyardTab {
+        let index = ShipyardTab::ALL
+            .iter()
+            .position(|tab| tab == self)
+            .unwrap_or(0);
+        ShipyardTab::ALL[(index + step) % ShipyardTab::ALL.len()]
+    }
+}
+
+/// Row selected on the Shipyard Screen, and how the last refit went.
+#[derive(Debug, Default)]
+pub struct ShipyardSelection {
+    pub tab: ShipyardTab,
+    pub index: usize,
+    pub status: String,
+}
+
+/// Refit each row of a tab would make, and how the row reads.
+fn listing(
+    tab: ShipyardTab,
+    shipyard: &Shipyard,
+    catalog: &ShipCatalog,
+    ship: &PlayerShip,
+) -> Vec<(Refit, String)> {
+    match tab {
+        ShipyardTab::Hulls => shipyard
+            .hulls(catalog)
+            .into_iter()
+            .map(|hull| {
+                let row = format!(
+                    "{:<14}{:<22}{:>10.0}t{:>9.0}t{:>13.0}",
+                    hull.name,
+                    hull.class.to_string(),
+                    hull.mass,
+                    hull.capacity,
+                    hull.cost
+                );
+                (Refit::Hull(hull.id.clone()), row)
+            })
+            .collect(),
+        ShipyardTab::Equipment => shipyard
+            .equipment(catalog)
+            .into_iter()
+            .map(|item| {
+                let row = format!(
+                    "{:<26}{:<11}{:>2}{:>9.1}t{:>+9.2}MW{:>12.0}",
+                    item.name,
+                    format!("{:?}", item.kind.slot()),
+                    item.size,
+                    item.mass,
+                    item.power,
+                    item.cost
+                );
+                (Refit::Install(item.id.clone()), row)
+            })
+            .collect(),
+        ShipyardTab::Fitted => ship
+            .design
+            .equipment
+            .iter()
+            .enumerate()
+            .filter_map(|(index, id)| catalog.equipment(id).map(|item| (index, item)))
+            .map(|(index, item)| {
+                let row = format!(
+                    "{:<26}{:<11}{:>2}{:>9.1}t{:>+9.2}MW{:>12.0}",
+                    item.name,
+                    format!("{:?}", item.kind.slot()),
+                    item.size,
+                    item.mass,
+                    item.power,
+                    item.cost * RESALE
+                );
+                (Refit::Remove(index), row)
+            })
+            .collect(),
+    }
+}
+
+/// Open and close the Shipyard with `Y`, switch lists with Left and Right and select with Up and
+/// Down. `B` buys the selected Hull or Equipment, `S` removes and sells fitted Equipment.
+#[allow(clippy::too_many_arguments)]
+pub fn process_shipyard_input(
+    keyboard: Res<Input<KeyCode>>,
+    docked: Res<Docked>,
+    economy: Res<Economy>,
+    orbitals: Res<Orbitals>,
+    factions: Res<Factions>,
+    catalogs: Res<Assets<ShipCatalog>>,
+    catalog_handle: Res<ShipCatalogHandle>,
+    ship: Res<PlayerShip>,
+    mut open: ResMut<ShipyardOpen>,
+    mut selection: ResMut<ShipyardSelection>,
+    mut orders: EventWriter<RefitOrder>,
+) {
+    let shipyard = match Shipyard::docked(&docked, &economy, &orbitals, &factions) {
+        Some(shipyard) => shipyard,
+        None => {
+            // Only Stations have Shipyards.
+            if open.0 {
+                open.0 = false;
+            }
+            return;
+        }
+    };
+    if keyboard.just_pressed(KeyCode::Y) {
+        open.0 = !open.0;
+        return;
+    }
+    let catalog = match catalogs.get(&catalog_handle.0) {
+        Some(catalog) if open.0 => catalog,
+        _ => return,
+    };
+    if keyboard.just_pressed(KeyCode::Left) {
+        selection.tab = selection.tab.cycle(ShipyardTab::ALL.len() - 1);
+        selection.index = 0;
+    }
+    if keyboard.just_pressed(KeyCode::Right) {
+        selection.tab = selection.tab.cycle(1);
+        selection.index = 0;
+    }
+    let rows = listing(selection.tab, &shipyard, catalog, &ship);
+    if rows.is_empty() {
+        return;
+    }
+    let count = rows.len();
+    if selection.index >= count {
+        selection.index = count - 1;
+    }
+    if keyboard.just_pressed(KeyCode::Up) {
+        selection.index = (selection.index + count - 1) % count;
+    }
+    if keyboard.just_pressed(KeyCode::Down) {
+        selection.index = (selection.index + 1) % count;
+    }
+    let (refit, _) = &rows[selection.index];
+    let buy = selection.tab != ShipyardTab::Fitted && keyboard.just_pressed(KeyCode::B);
+    let sell = selection.tab == ShipyardTab::Fitted && keyboard.just_pressed(KeyCode::S);
+    if buy || sell {
+        orders.send(RefitOrder {
+            shipyard: shipyard.orbital,
+            refit: refit.clone(),
+        });
+    }
+}
+
+/// Describe how the last refit went.
+pub fn refit_receipt_system(
+    catalogs: Res<Assets<ShipCatalog>>,
+    catalog_handle: Res<ShipCatalogHandle>,
+    mut receipts: EventReader<RefitReceipt>,
+    mut selection: ResMut<ShipyardSelection>,
+) {
+    let catalog = catalogs.get(&catalog_handle.0);
+    for receipt in receipts.iter() {
+        selection.status = match (&receipt.refit, &receipt.result) {
+            (_, Err(error)) => format!("Refused: {}", error),
+            (Refit::Hull(id), Ok(cost)) => format!(
+                "Bought a {} Hull for {:.0} after trade in",
+                catalog
+                    .and_then(|catalog| catalog.hull(id))
+                    .map_or(id.as_str(), |hull| hull.name.as_str()),
+                cost
+            ),
+            (Refit::Install(id), Ok(cost)) => format!(
+                "Installed {} for {:.0}",
+                catalog
+                    .and_then(|catalog| catalog.equipment(id))
+                    .map_or(id.as_str(), |item| item.name.as_str()),
+                cost
+            ),
+            (Refit::Remove(_), Ok(cost)) => format!("Sold fitted Equipment for {:.0}", -cost),
+        };
+    }
+}
+
+/// Show the Shipyard Screen while it's open, and hide it once closed or undocked.
+pub fn toggle_shipyard_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    docked: Res<Docked>,
+    open: Res<ShipyardOpen>,
+    query: Query<Entity, With<ShipyardScreen>>,
+) {
+    if !docked.is_changed() && !open.is_changed() {
+        return;
+    }
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if docked.0.is_none() || !open.0 {
+        return;
+    }
+    commands
+        .spawn_bundle(TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    top: Val::Px(40.0),
+                    left: Val::Px(40.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            text: Text::with_section(
+                String::new(),
+                TextStyle {
+                    font: asset_server.load("fonts/spacemono.ttf"),
+                    font_size: 14.0,
+                    color: Color::rgb(0.8, 0.85, 0.95),
+                },
+                Default::default(),
+            ),
+            ..Default::default()
+        })
+        .insert(ShipyardScreen);
+}
+
+/// Refresh the power, mass and heat budget, stock and the selected refit's changes.
+#[allow(clippy::too_many_arguments)]
+pub fn update_shipyard_screen(
+    docked: Res<Docked>,
+    economy: Res<Economy>,
+    orbitals: Res<Orbitals>,
+    sectors: Res<Sectors>,
+    factions: Res<Factions>,
+    catalogs: Res<Assets<ShipCatalog>>,
+    catalog_handle: Res<ShipCatalogHandle>,
+    ship: Res<PlayerShip>,
+    credits: Res<Credits>,
+    open: Res<ShipyardOpen>,
+    selection: Res<ShipyardSelection>,
+    mut query: Query<&mut Text, With<ShipyardScreen>>,
+) {
+    if !open.0 {
+        return;
+    }
+    let shipyard = match Shipyard::docked(&docked, &economy, &orbitals, &factions) {
+        Some(shipyard) => shipyard,
+        None => return,
+    };
+    let catalog = match catalogs.get(&catalog_handle.0) {
+        Some(catalog) => catalog,
+        None => return,
+    };
+    let station = orbitals
+        .get(shipyard.orbital)
+        .map(|orbital| orbital.data.name().to_owned())
+        .unwrap_or_default();
+    let sector = economy
+        .get(shipyard.orbital)
+        .map(|market| market.sector)
+        .unwrap_or_default();
+    let sector_name = sectors
+        .get(sector)
+        .map(|sector| sector.name.clone())
+        .unwrap_or_default();
+    let authority = factions
+        .controller(sector)
+        .map(|faction| faction.name.as_str())
+        .unwrap_or("Unclaimed");
+
+    let mut lines = vec![
+        format!(
+            "Shipyard - {} ({}) Tech {} ({})",
+            station, sector_name, shipyard.tech, authority
+        ),
+        format!("Credits {:.0}", credits.0),
+    ];
+    let (hull, stats) = match (catalog.hull(&ship.design.hull), ship.design.stats(catalog)) {
+        (Some(hull), Some(stats)) => (hull, stats),
+        _ => return,
+    };
+    lines.push(format!(
+        "{} - {} {}",
+        ship.design.name, hull.name, hull.class
+    ));
+    lines.push(format!(
+        "Power {:.1}/{:.1} MW ({:+.1})  Mass {:.1}/{:.1} t  Heat {:.1} MW  Crew {}/{}",
+        stats.power_draw,
+        stats.power_supply,
+        stats.power_balance(),
+        stats.equipment_mass,
+        stats.capacity,
+        stats.heat,
+        stats.crew,
+        hull.crew
+    ));
+    for error in ship.design.validate(catalog) {
+        let severity = if error.is_warning() {
+            "Warning"
+        } else {
+            "Error"
+        };
+        lines.push(format!("  {}: {}", severity, error));
+    }
+    lines.push(String::new());
+    lines.push(
+        ShipyardTab::ALL
+            .iter()
+            .map(|tab| {
+                if *tab == selection.tab {
+                    format!("[{}]", tab.name())
+                } else {
+                    format!(" {} ", tab.name())
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" "),
+    );
+
+    let rows = listing(selection.tab, &shipyard, catalog, &ship);
+    let first = selection
+        .index
+        .saturating_sub(LISTED_ROWS / 2)
+        .min(rows.len().saturating_sub(LISTED_ROWS));
+    for (index, (_, row)) in rows.iter().enumerate().skip(first).take(LISTED_ROWS) {
+        lines.push(format!(
+            "{}{}",
+            if index == selection.index { "> " } else { "  " },
+            row
+        ));
+    }
+    if rows.is_empty() {
+        lines.push(String::from("  Nothing here"));
+    }
+
+    // What the selected refit would change.
+    lines.push(String::new());
+    if let Some((refit, _)) = rows.get(selection.index) {
+        match refit.apply(&ship.design, catalog) {
+            Ok((design, cost)) => {
+                lines.push(if cost < 0.0 {
+                    format!("Refund {:.0}", -cost)
+                } else {
+                    format!("Cost {:.0}", cost)
+                });
+                if let Some(refitted) = design.stats(catalog) {
+                    for (name, before, after) in stats.changes(&refitted) {
+                        lines.push(format!(
+                            "  {:<14}{:>12.1} -> {:>12.1} ({:+.1})",
+                            name,
+                            before,
+                            after,
+                            after - before
+                        ));
+                    }
+                }
+            }
+            Err(error) => lines.push(format!("Can't refit: {}", error)),
+        }
+    }
+    lines.push(String::new());
+    lines.push(selection.status.clone());
+    lines.push(String::from(
+        "[Left/Right] List [Up/Down] Select [B] Buy [S] Sell Fitted [Y] Close [D] Undock",
+    ));
+
+    for mut text in query.iter_mut() {
+        text.sections[0].value = lines.join("\n");
+    }
+}
+
+/// Close the Shipyard when leaving the Sector.
+pub fn cleanup_shipyard_screen(
+    mut commands: Commands,
+    mut open: ResMut<ShipyardOpen>,
+    query: Query<Entity, With<ShipyardScreen>>,
+) {
+    open.0 = false;
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
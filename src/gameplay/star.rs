@@ -0,0 +1,183 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Star sprites sized and tinted from their Stellar Classification.
+use crate::generation::StarData;
+use crate::utility::preset;
+use bevy::prelude::*;
+
+/// Resolution of the shared glow texture.
+const GLOW_TEXTURE_SIZE: usize = 64;
+/// Font size of spectral class labels.
+const LABEL_FONT_SIZE: f32 = 10.0;
+/// Gap between a star and its label.
+const LABEL_GAP: f32 = 4.0;
+
+/// How a star is drawn, sizes are unitless and scaled by whoever draws it.
+#[derive(Debug, Clone)]
+pub struct StarAppearance {
+    /// Spectral Classification shown in the label
+    pub class: String,
+    /// Colour of the photosphere
+    pub color: Color,
+    /// Size of the star
+    pub size: f32,
+    /// Size of the glow around the star
+    pub glow_size: f32,
+    /// Opacity at the heart of the glow
+    pub glow_alpha: f32,
+}
+
+impl StarAppearance {
+    pub fn new(star: &StarData) -> StarAppearance {
+        let size = log_radius(star.radius);
+        let brightness = luminosity_magnitude(star.luminosity);
+        StarAppearance {
+            class: star.class.clone(),
+            color: star.color,
+            size,
+            glow_size: size * (1.5 + 0.4 * brightness),
+            glow_alpha: (0.15 + 0.04 * brightness).min(0.6),
+        }
+    }
+}
+
+/// Logarithmic size for a star of `radius` Solar Radii, so supergiants hundreds of times the
+/// Sun's size stay on screen without M dwarfs vanishing.
+pub fn log_radius(radius: f64) -> f32 {
+    0.5 + (1.0 + radius.max(0.0)).ln() as f32
+}
+
+/// Orders of magnitude brighter than the dimmest stars, `0.0` for `1e-4` Solar Luminosities.
+fn luminosity_magnitude(luminosity: f64) -> f32 {
+    (luminosity.max(1e-4).log10() + 4.0) as f32
+}
+
+/// Tag Denoting the glow behind a star
+pub struct StarGlow;
+
+/// Tag Denoting a star's spectral class label
+pub struct StarLabel;
+
+/// Keeps a star's glow or label level while the star spins.
+pub struct StarDecoration {
+    /// Offset from the star, measured on screen rather than in the star's spinning frame
+    pub offset: Vec3,
+}
+
+/// Radial gradient shared by every star glow.
+pub struct StarGlowTexture(pub Handle<Texture>);
+
+impl FromWorld for StarGlowTexture {
+    fn from_world(world: &mut World) -> StarGlowTexture {
+        let mut textures = world.get_resource_mut::<Assets<Texture>>().unwrap();
+        StarGlowTexture(textures.add(preset::radial_glow(GLOW_TEXTURE_SIZE)))
+    }
+}
+
+/// Spawn the glow and label of a star as children, `scale` converts the appearance to pixels.
+///
+/// Returns the glow and label entities.
+pub fn spawn_star_decorations(
+    parent: &mut ChildBuilder,
+    appearance: &StarAppearance,
+    scale: f32,
+    visible: bool,
+    glow_texture: &StarGlowTexture,
+    materials: &mut Assets<ColorMaterial>,
+    font: Handle<Font>,
+) -> (Entity, Entity) {
+    let [r, g, b, _] = appearance.color.as_rgba_f32();
+    let glow_size = appearance.glow_size * scale;
+    let glow_offset = Vec3::new(0.0, 0.0, -0.5);
+    let label_offset = Vec3::new(0.0, -(appearance.size * scale / 2.0 + LABEL_GAP), 0.5);
+    let glow = parent
+        .spawn_bundle(SpriteBundle {
+            material: materials.add(ColorMaterial {
+                color: Color::rgba(r, g, b, appearance.glow_alpha),
+                texture: Some(glow_texture.0.clone()),
+            }),
+            sprite: Sprite::new(Vec2::new(glow_size, glow_size)),
+            transform: Transform::from_translation(glow_offset),
+            visible: Visible {
+                is_visible: visible,
+                is_transparent: true,
+            },
+            ..Default::default()
+        })
+        .insert(StarGlow)
+        .insert(StarDecoration {
+            offset: glow_offset,
+        })
+        .id();
+    let label = parent
+        .spawn_bundle(Text2dBundle {
+            text: Text::with_section(
+                appearance.class.clone(),
+                TextStyle {
+                    font,
+                    font_size: LABEL_FONT_SIZE,
+                    color: appearance.color,
+                },
+                TextAlignment {
+                    vertical: VerticalAlign::Top,
+                    horizontal: HorizontalAlign::Center,
+                },
+            ),
+            transform: Transform::from_translation(label_offset),
+            visible: Visible {
+                is_visible: visible,
+                is_transparent: true,
+            },
+            ..Default::default()
+        })
+        .insert(StarLabel)
+        .insert(StarDecoration {
+            offset: label_offset,
+        })
+        .id();
+    (glow, label)
+}
+
+/// Counter-rotate star decorations against their star's spin so labels stay upright and below.
+pub fn level_star_decorations(
+    star_query: Query<&Transform, Without<StarDecoration>>,
+    mut decoration_query: Query<(&Parent, &StarDecoration, &mut Transform)>,
+) {
+    for (parent, decoration, mut transform) in decoration_query.iter_mut() {
+        if let Ok(star) = star_query.get(parent.0) {
+            let level = star.rotation.inverse();
+            transform.rotation = level;
+            transform.translation = level.mul_vec3(decoration.offset);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::log_radius;
+
+    #[test]
+    fn test_log_radius() {
+        let dwarf = log_radius(0.1);
+        let sun = log_radius(1.0);
+        let giant = log_radius(1000.0);
+        assert!(dwarf < sun && sun < giant);
+        assert!(giant / dwarf < 20.0);
+    }
+}
@@ -0,0 +1,285 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Background strategic simulation, Factions push their borders while the Player plays.
+use crate::cartographer::{
+    DiplomaticEvent, DiplomaticEventKind, FactionId, FactionKind, Factions, Orbitals, Sectors,
+    Stance,
+};
+use crate::generation::{OrbitalData, UniverseSeed};
+use crate::utility::{WorldClock, SECONDS_PER_DAY};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro128PlusPlus;
+
+/// Days between strategic turns.
+pub const TURN_DAYS: f64 = 7.0;
+/// Most turns played in a single frame when Time Warp outpaces the simulation.
+const MAX_TURNS_PER_FRAME: usize = 4;
+/// Hexes a Faction can reach across to claim a Sector.
+const BORDER_RANGE: i32 = 2;
+/// Chance per turn a Faction claims an unclaimed Sector on its border.
+const EXPANSION_CHANCE: f64 = 0.3;
+/// Scales the odds of taking a Sector from a hostile Faction.
+const AGGRESSION: f32 = 0.5;
+
+/// Progress of the strategic simulation
+#[derive(Debug, Clone)]
+pub struct StrategicTurn {
+    /// Turns played so far
+    pub number: u64,
+    /// World time in seconds the next turn is played at
+    pub next: f64,
+}
+
+impl Default for StrategicTurn {
+    fn default() -> StrategicTurn {
+        StrategicTurn {
+            number: 0,
+            next: TURN_DAYS * SECONDS_PER_DAY,
+        }
+    }
+}
+
+/// A Sector changed hands.
+#[derive(Debug, Copy, Clone)]
+pub struct TerritoryChange {
+    pub sector: usize,
+    pub from: Option<FactionId>,
+    pub to: Option<FactionId>,
+}
+
+/// What a Sector is worth to whoever holds it, its people and stations drive its economy.
+pub fn sector_value(orbitals: &Orbitals, sector: usize) -> f32 {
+    1.0 + orbitals
+        .in_sector(sector)
+        .filter(|orbital| !orbital.destroyed)
+        .map(|orbital| match &orbital.data {
+            OrbitalData::Planet(data) => data.population * 4.0,
+            OrbitalData::Station(_) => 2.0,
+            _ => 0.0,
+        })
+        .sum::<f32>()
+}
+
+/// Play strategic turns as World Time passes them.
+#[allow(clippy::too_many_arguments)]
+pub fn strategic_simulation_system(
+    world_clock: Res<WorldClock>,
+    universe_seed: Res<UniverseSeed>,
+    mut turn: ResMut<StrategicTurn>,
+    mut factions: ResMut<Factions>,
+    sectors: Res<Sectors>,
+    orbitals: Res<Orbitals>,
+    mut changes: EventWriter<TerritoryChange>,
+    mut diplomacy: EventWriter<DiplomaticEvent>,
+) {
+    let seconds = world_clock.seconds_since_epoch();
+    let mut played = 0;
+    while seconds >= turn.next && played < MAX_TURNS_PER_FRAME {
+        let seed = universe_seed.0 ^ turn.number.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let mut rng = Xoshiro128PlusPlus::seed_from_u64(seed);
+        for change in play_turn(&mut rng, &mut factions, &sectors, &orbitals) {
+            if let Some(from) = change.from {
+                if let Some(to) = change.to {
+                    diplomacy.send(DiplomaticEvent {
+                        kind: DiplomaticEventKind::Incident,
+                        a: to,
+                        b: from,
+                    });
+                }
+            }
+            changes.send(change);
+        }
+        turn.number += 1;
+        turn.next += TURN_DAYS * SECONDS_PER_DAY;
+        played += 1;
+    }
+}
+
+/// Every Faction holding territory makes one push at its border.
+fn play_turn<R: Rng>(
+    rng: &mut R,
+    factions: &mut Factions,
+    sectors: &Sectors,
+    orbitals: &Orbitals,
+) -> Vec<TerritoryChange> {
+    let mut owners: HashMap<usize, FactionId> = HashMap::default();
+    let mut strength: HashMap<FactionId, f32> = HashMap::default();
+    for faction in factions.iter() {
+        for sector in faction.territory.iter() {
+            owners.insert(*sector, faction.id);
+        }
+        let value = faction
+            .territory
+            .iter()
+            .map(|sector| sector_value(orbitals, *sector))
+            .sum();
+        strength.insert(faction.id, value);
+    }
+
+    let mut claims = Vec::new();
+    for faction in factions.iter() {
+        if faction.territory.is_empty() || faction.kind == FactionKind::Player {
+            continue;
+        }
+        let mut border: Vec<usize> = faction
+            .territory
+            .iter()
+            .filter_map(|sector| sectors.get(*sector))
+            .flat_map(|sector| sectors.within(sector.location, BORDER_RANGE))
+            .map(|sector| sector.id)
+            .filter(|sector| owners.get(sector) != Some(&faction.id))
+            .collect();
+        border.sort_unstable();
+        border.dedup();
+        let target = match border.choose(rng) {
+            Some(target) => *target,
+            None => continue,
+        };
+        match owners.get(&target) {
+            None => {
+                if rng.gen_bool(EXPANSION_CHANCE) {
+                    claims.push(TerritoryChange {
+                        sector: target,
+                        from: None,
+                        to: Some(faction.id),
+                    });
+                }
+            }
+            Some(defender) => {
+                if factions.stance(faction.id, *defender) != Stance::Hostile {
+                    continue;
+                }
+                let attack = strength[&faction.id];
+                let defence = strength.get(defender).copied().unwrap_or(0.0);
+                let odds = attack / (attack + defence).max(f32::EPSILON) * AGGRESSION;
+                if rng.gen::<f32>() < odds {
+                    claims.push(TerritoryChange {
+                        sector: target,
+                        from: Some(*defender),
+                        to: Some(faction.id),
+                    });
+                }
+            }
+        }
+    }
+
+    // Two Factions may have gone for the same Sector, only the first to arrive takes it.
+    let mut changes = Vec::new();
+    for claim in claims {
+        let holder = factions.controller(claim.sector).map(|faction| faction.id);
+        if holder == claim.from {
+            factions.transfer_sector(claim.sector, claim.to);
+            changes.push(claim);
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartographer::{test_sector_row, PoliticalAxis};
+
+    /// Two Governments, each holding the given Sectors, with their politics setting their stance.
+    fn rivals(
+        west: &[usize],
+        east: &[usize],
+        east_politics: PoliticalAxis,
+    ) -> (Factions, FactionId, FactionId) {
+        let mut factions = Factions::default();
+        let west_politics = PoliticalAxis::new(-1.0, -1.0, -1.0, -1.0);
+        let mut create = |name: &str, politics, territory: &[usize]| {
+            let id = factions
+                .create_faction(name, FactionKind::Government, politics)
+                .unwrap();
+            let faction = factions.get_mut_by_id(id).unwrap();
+            faction.home = territory.first().copied();
+            faction.territory = territory.iter().copied().collect();
+            id
+        };
+        let west = create("West", west_politics, west);
+        let east = create("East", east_politics, east);
+        factions.initialize_relations();
+        (factions, west, east)
+    }
+
+    /// Play `turns` strategic turns from `seed`, returning every Sector that changed hands.
+    fn play(
+        seed: u64,
+        turns: usize,
+        factions: &mut Factions,
+    ) -> Vec<(usize, Option<FactionId>, Option<FactionId>)> {
+        let (sectors, orbitals) = (test_sector_row(6), Orbitals::default());
+        let mut rng = Xoshiro128PlusPlus::seed_from_u64(seed);
+        (0..turns)
+            .flat_map(|_| play_turn(&mut rng, factions, &sectors, &orbitals))
+            .map(|change| (change.sector, change.from, change.to))
+            .collect()
+    }
+
+    #[test]
+    fn test_expansion_into_unclaimed_sectors() {
+        let allies = PoliticalAxis::new(-1.0, -1.0, -1.0, -1.0);
+        let (mut factions, west, east) = rivals(&[0], &[5], allies);
+        let changes = play(1, 50, &mut factions);
+        assert!(!changes.is_empty());
+        for (sector, from, to) in changes {
+            assert_eq!(from, None);
+            assert!(to == Some(west) || to == Some(east));
+            assert_eq!(factions.controller(sector).map(|faction| faction.id), to);
+        }
+    }
+
+    #[test]
+    fn test_allied_borders_hold() {
+        let allies = PoliticalAxis::new(-1.0, -1.0, -1.0, -1.0);
+        let (mut factions, _, _) = rivals(&[0, 1, 2], &[3, 4, 5], allies);
+        assert!(play(1, 50, &mut factions).is_empty());
+    }
+
+    #[test]
+    fn test_hostile_conquest() {
+        let enemies = PoliticalAxis::new(1.0, 1.0, 1.0, 1.0);
+        let (mut factions, west, east) = rivals(&[0, 1, 2], &[3, 4, 5], enemies);
+        assert_eq!(factions.stance(west, east), Stance::Hostile);
+        let changes = play(1, 50, &mut factions);
+        assert!(!changes.is_empty());
+        for (_, from, to) in changes.iter() {
+            assert!(
+                *from == Some(west) && *to == Some(east)
+                    || *from == Some(east) && *to == Some(west)
+            );
+        }
+        // Sectors change hands, but none are lost along the way.
+        for sector in 0..6 {
+            assert!(factions.controller(sector).is_some());
+        }
+    }
+
+    #[test]
+    fn test_turns_are_reproducible() {
+        let enemies = PoliticalAxis::new(1.0, 1.0, 1.0, 1.0);
+        let (mut a, _, _) = rivals(&[0, 1], &[4, 5], enemies);
+        let (mut b, _, _) = rivals(&[0, 1], &[4, 5], enemies);
+        assert_eq!(play(9, 20, &mut a), play(9, 20, &mut b));
+    }
+}
@@ -17,7 +17,13 @@
 //
 
 mod input;
+mod lighting;
 mod setup;
 
-pub use self::input::{process_gamepad_input, process_keyboard_input, process_mouse_input};
-pub use self::setup::{cleanup_tilespace, initialize_tilespace, setup_tilespace, update_tilespace};
+pub use self::input::{
+    process_gamepad_input, process_keyboard_input, process_liftoff_input, process_mouse_input,
+};
+pub use self::lighting::{update_tilespace_lighting, Daylight, SurfaceLocation, TilespaceLit};
+pub use self::setup::{
+    cleanup_tilespace, initialize_tilespace, setup_tilespace, update_tilespace, Tilespace,
+};
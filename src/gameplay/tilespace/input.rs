@@ -16,10 +16,12 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
 
+use crate::gameplay::GameplayState;
 use bevy::app::EventReader;
 use bevy::input::gamepad::{GamepadEvent, GamepadEventType};
 use bevy::input::keyboard::KeyboardInput;
 use bevy::input::mouse::{MouseButtonInput, MouseMotion, MouseWheel};
+use bevy::prelude::*;
 use bevy::window::CursorMoved;
 
 pub fn process_keyboard_input(mut keyboard_input_events: EventReader<KeyboardInput>) {
@@ -66,3 +68,16 @@ pub fn process_gamepad_input(mut gamepad_event: EventReader<GamepadEvent>) {
         }
     }
 }
+
+/// Lift off back into Sectorspace with `L`.
+pub fn process_liftoff_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut gameplay_state: ResMut<State<GameplayState>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::L) {
+        return;
+    }
+    if let Err(error) = gameplay_state.set(GameplayState::Sectorspace) {
+        warn!("Unable to lift off: {:?}", error);
+    }
+}
@@ -0,0 +1,110 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use crate::cartographer::Orbitals;
+use crate::gameplay::sectorspace::OrbitalParameters;
+use crate::utility::WorldClock;
+use bevy::prelude::*;
+
+/// Brightness of the night side, tiles are never fully black.
+const NIGHT_BRIGHTNESS: f32 = 0.15;
+/// Solar elevation in degrees where dawn begins (Civil Twilight)
+const DAWN_ELEVATION: f32 = -6.0;
+/// Solar elevation in degrees where full daylight is reached
+const DAY_ELEVATION: f32 = 10.0;
+
+/// Where on a surface the player currently is.
+#[derive(Debug, Copy, Clone)]
+pub struct SurfaceLocation {
+    /// Orbital ID of the body
+    pub orbital: usize,
+    /// Latitude in degrees
+    pub latitude: f32,
+    /// Longitude in degrees
+    pub longitude: f32,
+}
+
+/// Current daylight level between `0.0` (Night) and `1.0` (Day)
+#[derive(Debug, Copy, Clone)]
+pub struct Daylight {
+    /// Local Time of Day as a fraction of a Solar Day, `0.5` being noon.
+    pub time_of_day: f32,
+    /// Brightness applied to lit tiles.
+    pub brightness: f32,
+}
+
+impl Default for Daylight {
+    fn default() -> Daylight {
+        Daylight {
+            time_of_day: 0.5,
+            brightness: 1.0,
+        }
+    }
+}
+
+/// Tilespace Sprite tinted by Daylight, with its colour in full daylight.
+pub struct TilespaceLit(pub Color);
+
+/// Follow the local time of day at the player's surface location.
+pub fn update_tilespace_lighting(
+    world_clock: Res<WorldClock>,
+    surface: Option<Res<SurfaceLocation>>,
+    orbitals: Res<Orbitals>,
+    mut daylight: ResMut<Daylight>,
+    mut clear_color: ResMut<ClearColor>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    lit_query: Query<(&Handle<ColorMaterial>, &TilespaceLit)>,
+) {
+    let surface = match surface {
+        Some(surface) => surface,
+        None => return,
+    };
+    let orbital = match orbitals.get(surface.orbital) {
+        Some(orbital) => orbital,
+        None => return,
+    };
+    let parameters = OrbitalParameters::from(orbital);
+    // Orbits out to the root star, whose light it is.
+    let mut parents = Vec::new();
+    let mut parent = orbital.parent.and_then(|parent| orbitals.get(parent));
+    while let Some(orbital) = parent.filter(|orbital| orbital.parent.is_some()) {
+        parents.push(OrbitalParameters::from(orbital));
+        parent = orbital.parent.and_then(|parent| orbitals.get(parent));
+    }
+    let seconds = world_clock.seconds_since_epoch();
+    let elevation = parameters
+        .solar_elevation(seconds, surface.latitude, surface.longitude, &parents)
+        .to_degrees();
+    let dawn = ((elevation - DAWN_ELEVATION) / (DAY_ELEVATION - DAWN_ELEVATION)).clamp(0.0, 1.0);
+    daylight.time_of_day = parameters.time_of_day(seconds, surface.longitude, &parents);
+    // Smoothstep through twilight
+    let dawn = dawn * dawn * (3.0 - 2.0 * dawn);
+    daylight.brightness = NIGHT_BRIGHTNESS + (1.0 - NIGHT_BRIGHTNESS) * dawn;
+
+    let brightness = daylight.brightness;
+    clear_color.0 = Color::rgb(0.04 * brightness, 0.04 * brightness, 0.08 * brightness);
+    for (handle, lit) in lit_query.iter() {
+        if let Some(material) = materials.get_mut(handle) {
+            material.color = Color::rgb(
+                lit.0.r() * brightness,
+                lit.0.g() * brightness,
+                lit.0.b() * brightness,
+            );
+        }
+    }
+}
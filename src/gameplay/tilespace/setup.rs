@@ -16,14 +16,99 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
 
+use super::{SurfaceLocation, TilespaceLit};
+use crate::cartographer::Orbitals;
+use crate::gameplay::PlayerAvatar;
 use bevy::prelude::*;
 use bevy::utils::HashMap;
 use bevy_tilemap::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro128PlusPlus;
+
+/// Tiles laid out across the surface around the Player.
+const SURFACE_WIDTH: i32 = 25;
+const SURFACE_HEIGHT: i32 = 19;
+/// Pixels per surface tile.
+const TILE_SIZE: f32 = 32.0;
+/// Shades of the body's colour the surface is patched together from.
+const SURFACE_SHADES: [f32; 4] = [0.7, 0.8, 0.9, 1.0];
+
+/// Tag Denoting an Entity spawned in Tilespace
+pub struct Tilespace;
 
 pub fn initialize_tilespace(mut commands: Commands) {}
 
-pub fn setup_tilespace(mut commands: Commands) {}
+/// Lay out the surface the Player landed on, patched in shades of the body's colour.
+pub fn setup_tilespace(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    surface: Option<Res<SurfaceLocation>>,
+    orbitals: Res<Orbitals>,
+    avatar_query: Query<&GlobalTransform, With<PlayerAvatar>>,
+) {
+    let (surface, orbital) = match surface.and_then(|surface| {
+        let orbital = orbitals.get(surface.orbital)?;
+        Some((surface, orbital))
+    }) {
+        Some(landing) => landing,
+        None => {
+            warn!("Entered Tilespace without landing anywhere");
+            return;
+        }
+    };
+    trace!(
+        "Setup Tilespace on {} at {:.1}, {:.1}",
+        orbital.data.designation(),
+        surface.latitude,
+        surface.longitude
+    );
+    // The camera rides along with the Player's Avatar.
+    let center = avatar_query
+        .iter()
+        .next()
+        .map_or(Vec2::ZERO, |avatar| avatar.translation.truncate());
+    let color = orbital.data.color();
+    let shades: Vec<(Handle<ColorMaterial>, Color)> = SURFACE_SHADES
+        .iter()
+        .map(|shade| {
+            let color = Color::rgb(color.r() * shade, color.g() * shade, color.b() * shade);
+            (materials.add(color.into()), color)
+        })
+        .collect();
+    // The same spot on the same body always looks the same.
+    let seed = (surface.orbital as u64) << 32
+        ^ (surface.latitude.to_bits() as u64) << 16
+        ^ surface.longitude.to_bits() as u64;
+    let mut rng = Xoshiro128PlusPlus::seed_from_u64(seed);
+    for y in -SURFACE_HEIGHT / 2..=SURFACE_HEIGHT / 2 {
+        for x in -SURFACE_WIDTH / 2..=SURFACE_WIDTH / 2 {
+            let (material, color) = &shades[rng.gen_range(0..shades.len())];
+            let position = center + Vec2::new(x as f32, y as f32) * TILE_SIZE;
+            commands
+                .spawn_bundle(SpriteBundle {
+                    material: material.clone(),
+                    sprite: Sprite::new(Vec2::new(TILE_SIZE, TILE_SIZE)),
+                    transform: Transform::from_translation(position.extend(1.0)),
+                    ..Default::default()
+                })
+                .insert(TilespaceLit(*color))
+                .insert(Tilespace);
+        }
+    }
+}
 
 pub fn update_tilespace(mut commands: Commands) {}
 
-pub fn cleanup_tilespace(mut commands: Commands) {}
+/// Despawn the surface and restore the sky on leaving Tilespace.
+pub fn cleanup_tilespace(
+    mut commands: Commands,
+    mut clear_color: ResMut<ClearColor>,
+    query: Query<Entity, With<Tilespace>>,
+) {
+    trace!("Cleanup Tilespace");
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<SurfaceLocation>();
+    clear_color.0 = Color::rgb(0.04, 0.04, 0.04);
+}
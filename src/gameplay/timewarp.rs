@@ -0,0 +1,149 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Player controlled Time Warp with safeguards that drop back to real time.
+use super::ship::PlayerVessel;
+use crate::utility::{WorldClock, WARP_LEVELS};
+use bevy::prelude::*;
+
+/// Request to drop out of Time Warp, sent when something needs the player's attention.
+#[derive(Debug, Clone)]
+pub struct WarpInterrupt {
+    /// Why Time Warp was interrupted
+    pub reason: String,
+}
+
+impl WarpInterrupt {
+    pub fn new(reason: impl Into<String>) -> WarpInterrupt {
+        WarpInterrupt {
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Body which drops Time Warp when the player comes within `radius` of it.
+pub struct WarpHazard {
+    pub radius: f32,
+}
+
+/// Time Warp HUD Tag
+pub struct TimeWarpHud;
+
+/// Step Time Warp up and down with `.` and `,`, `/` drops straight back to real time.
+pub fn process_time_warp_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut world_clock: ResMut<WorldClock>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Period) {
+        world_clock.increase_warp();
+        trace!("Time Warp: {}x", world_clock.multiplier());
+    }
+    if keyboard_input.just_pressed(KeyCode::Comma) {
+        world_clock.decrease_warp();
+        trace!("Time Warp: {}x", world_clock.multiplier());
+    }
+    if keyboard_input.just_pressed(KeyCode::Slash) {
+        world_clock.drop_warp();
+        trace!("Time Warp: {}x", world_clock.multiplier());
+    }
+}
+
+/// Drop out of Time Warp whenever anything raises a [WarpInterrupt].
+pub fn time_warp_safeguard_system(
+    mut world_clock: ResMut<WorldClock>,
+    mut interrupts: EventReader<WarpInterrupt>,
+) {
+    if let Some(interrupt) = interrupts.iter().last() {
+        if world_clock.warp_level() > 0 {
+            info!("Dropping out of Time Warp: {}", interrupt.reason);
+            world_clock.drop_warp();
+        }
+    }
+}
+
+/// Raise a [WarpInterrupt] when the Player's ship nears a hazard in Sectorspace.
+pub fn warp_hazard_system(
+    world_clock: Res<WorldClock>,
+    mut interrupts: EventWriter<WarpInterrupt>,
+    vessel_query: Query<&GlobalTransform, With<PlayerVessel>>,
+    hazard_query: Query<(&GlobalTransform, &WarpHazard), Without<PlayerVessel>>,
+) {
+    if world_clock.warp_level() == 0 {
+        return;
+    }
+    for vessel in vessel_query.iter() {
+        let hazardous = hazard_query.iter().any(|(transform, hazard)| {
+            vessel
+                .translation
+                .truncate()
+                .distance(transform.translation.truncate())
+                < hazard.radius
+        });
+        if hazardous {
+            interrupts.send(WarpInterrupt::new("Proximity Alert"));
+        }
+    }
+}
+
+pub fn setup_time_warp_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn_bundle(TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    top: Val::Px(5.0),
+                    right: Val::Px(5.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            text: Text::with_section(
+                time_warp_label(WARP_LEVELS[0]),
+                TextStyle {
+                    font: asset_server.load("fonts/spacemono.ttf"),
+                    font_size: 20.0,
+                    color: Color::rgb(0.9, 0.9, 0.9),
+                },
+                Default::default(),
+            ),
+            ..Default::default()
+        })
+        .insert(TimeWarpHud);
+}
+
+pub fn update_time_warp_hud(
+    world_clock: Res<WorldClock>,
+    mut query: Query<&mut Text, With<TimeWarpHud>>,
+) {
+    if !world_clock.is_changed() {
+        return;
+    }
+    for mut text in query.iter_mut() {
+        text.sections[0].value = time_warp_label(world_clock.multiplier());
+    }
+}
+
+pub fn cleanup_time_warp_hud(mut commands: Commands, query: Query<Entity, With<TimeWarpHud>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn time_warp_label(multiplier: f32) -> String {
+    format!("Warp {}x", multiplier)
+}
@@ -0,0 +1,416 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Docking with Markets and the Trading Screen shown while docked.
+use super::combat::Hostile;
+use super::economy::{
+    is_contraband, trade_prices, CargoHold, Commodity, Credits, Docked, Economy, Market,
+    PriceLedger, TradeOrder, TradeReceipt, HISTORY_DAYS,
+};
+use super::quest::{QuestBoard, LISTED_QUESTS};
+use super::sectorspace::{Destroyed, Dockable, OrbitalLink};
+use super::ship::PlayerVessel;
+use super::shipyard::ShipyardOpen;
+use crate::cartographer::{Factions, Orbitals, PoliticalAxis, Sectors};
+use crate::utility::{WorldClock, SECONDS_PER_DAY};
+use bevy::prelude::*;
+use std::cmp::Ordering;
+
+/// Units traded with Shift held.
+const BULK_UNITS: i32 = 10;
+/// Other Markets listed for the selected Commodity.
+const KNOWN_MARKETS: usize = 5;
+/// Distance from a Station or Spaceport the ship can dock from.
+const DOCKING_RANGE: f32 = 25.0;
+/// Distance within which Hostile ships prevent docking.
+const HOSTILE_RANGE: f32 = 500.0;
+
+/// Tag Denoting the Trading Screen
+pub struct TradingScreen;
+
+/// Commodity selected on the Trading Screen, and how the last trade went.
+#[derive(Debug, Default)]
+pub struct TradingSelection {
+    pub index: usize,
+    pub status: String,
+}
+
+impl TradingSelection {
+    pub fn commodity(&self) -> Commodity {
+        Commodity::ALL[self.index % Commodity::ALL.len()]
+    }
+}
+
+/// Dock and undock with `D` within reach of a Station or Spaceport, `Tab` moves on to the next
+/// Market in reach. Neither works with Hostile ships nearby.
+pub fn process_docking_input(
+    keyboard: Res<Input<KeyCode>>,
+    economy: Res<Economy>,
+    mut docked: ResMut<Docked>,
+    vessel_query: Query<&GlobalTransform, With<PlayerVessel>>,
+    dockable_query: Query<(&GlobalTransform, &OrbitalLink), (With<Dockable>, Without<Destroyed>)>,
+    hostile_query: Query<&GlobalTransform, With<Hostile>>,
+) {
+    let dock = keyboard.just_pressed(KeyCode::D);
+    let next = keyboard.just_pressed(KeyCode::Tab);
+    if !dock && !next {
+        return;
+    }
+    let vessel = match vessel_query.iter().next() {
+        Some(vessel) => vessel.translation.truncate(),
+        None => return,
+    };
+    let hostiles: Vec<Vec2> = hostile_query
+        .iter()
+        .map(|transform| transform.translation.truncate())
+        .collect();
+    if hostiles_near(vessel, &hostiles) {
+        info!("Hostile ships nearby, unable to dock or undock");
+        return;
+    }
+    if dock && docked.0.is_some() {
+        trace!("Undocked");
+        docked.0 = None;
+        return;
+    }
+    let markets = docking_targets(
+        vessel,
+        dockable_query
+            .iter()
+            .filter(|(_, link)| economy.get(link.0).is_some())
+            .map(|(transform, link)| (link.0, transform.translation.truncate())),
+    );
+    let target = match docked.0 {
+        Some(current) if next => markets
+            .iter()
+            .position(|orbital| *orbital == current)
+            .map(|index| markets[(index + 1) % markets.len()]),
+        None if dock => markets.first().copied(),
+        _ => None,
+    };
+    match target {
+        Some(target) => {
+            trace!("Docked at Orbital {}", target);
+            docked.0 = Some(target);
+        }
+        None if dock => info!("No Station or Spaceport within docking range"),
+        None => {}
+    }
+}
+
+/// Whether any Hostile ship is close enough to prevent docking.
+fn hostiles_near(vessel: Vec2, hostiles: &[Vec2]) -> bool {
+    hostiles
+        .iter()
+        .any(|hostile| (*hostile - vessel).length() <= HOSTILE_RANGE)
+}
+
+/// Orbitals within docking range of the vessel, nearest first.
+fn docking_targets(vessel: Vec2, dockables: impl Iterator<Item = (usize, Vec2)>) -> Vec<usize> {
+    let mut targets: Vec<(f32, usize)> = dockables
+        .map(|(orbital, position)| ((position - vessel).length(), orbital))
+        .filter(|(distance, _)| *distance <= DOCKING_RANGE)
+        .collect();
+    targets.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    targets.into_iter().map(|(_, orbital)| orbital).collect()
+}
+
+/// Leave whatever Market the Player was docked at when leaving the Sector.
+pub fn undock_system(
+    mut commands: Commands,
+    mut docked: ResMut<Docked>,
+    query: Query<Entity, With<TradingScreen>>,
+) {
+    docked.0 = None;
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Select with Up and Down, `B` buys and `S` sells, holding Shift trades in bulk.
+pub fn process_trading_input(
+    keyboard: Res<Input<KeyCode>>,
+    docked: Res<Docked>,
+    shipyard: Res<ShipyardOpen>,
+    mut selection: ResMut<TradingSelection>,
+    mut orders: EventWriter<TradeOrder>,
+) {
+    let market = match docked.0 {
+        Some(market) if !shipyard.0 => market,
+        _ => return,
+    };
+    let count = Commodity::ALL.len();
+    if keyboard.just_pressed(KeyCode::Up) {
+        selection.index = (selection.index + count - 1) % count;
+    }
+    if keyboard.just_pressed(KeyCode::Down) {
+        selection.index = (selection.index + 1) % count;
+    }
+    let units = if keyboard.pressed(KeyCode::LShift) || keyboard.pressed(KeyCode::RShift) {
+        BULK_UNITS
+    } else {
+        1
+    };
+    let commodity = selection.commodity();
+    if keyboard.just_pressed(KeyCode::B) {
+        orders.send(TradeOrder {
+            market,
+            commodity,
+            units,
+        });
+    }
+    if keyboard.just_pressed(KeyCode::S) {
+        orders.send(TradeOrder {
+            market,
+            commodity,
+            units: -units,
+        });
+    }
+}
+
+/// Describe how the last trade went.
+pub fn trade_receipt_system(
+    mut receipts: EventReader<TradeReceipt>,
+    mut selection: ResMut<TradingSelection>,
+) {
+    for receipt in receipts.iter() {
+        let action = if receipt.units > 0 { "Bought" } else { "Sold" };
+        selection.status = if receipt.seized {
+            format!(
+                "Inspected! {} {} confiscated and fined",
+                receipt.units.abs(),
+                receipt.commodity.name()
+            )
+        } else {
+            format!(
+                "{} {} {} at {:.1}",
+                action,
+                receipt.units.abs(),
+                receipt.commodity.name(),
+                receipt.price
+            )
+        };
+    }
+}
+
+/// Show the Trading Screen while docked, and hide it once undocked or in the Shipyard.
+pub fn toggle_trading_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    docked: Res<Docked>,
+    shipyard: Res<ShipyardOpen>,
+    query: Query<Entity, With<TradingScreen>>,
+) {
+    if !docked.is_changed() && !shipyard.is_changed() {
+        return;
+    }
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if docked.0.is_none() || shipyard.0 {
+        return;
+    }
+    commands
+        .spawn_bundle(TextBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    top: Val::Px(40.0),
+                    left: Val::Px(40.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            text: Text::with_section(
+                String::new(),
+                TextStyle {
+                    font: asset_server.load("fonts/spacemono.ttf"),
+                    font_size: 14.0,
+                    color: Color::rgb(0.8, 0.9, 0.8),
+                },
+                Default::default(),
+            ),
+            ..Default::default()
+        })
+        .insert(TradingScreen);
+}
+
+/// Refresh prices, the hold and known Markets on the Trading Screen.
+#[allow(clippy::too_many_arguments)]
+pub fn update_trading_screen(
+    world_clock: Res<WorldClock>,
+    docked: Res<Docked>,
+    economy: Res<Economy>,
+    factions: Res<Factions>,
+    sectors: Res<Sectors>,
+    orbitals: Res<Orbitals>,
+    ledger: Res<PriceLedger>,
+    credits: Res<Credits>,
+    cargo: Res<CargoHold>,
+    board: Res<QuestBoard>,
+    selection: Res<TradingSelection>,
+    mut query: Query<&mut Text, With<TradingScreen>>,
+) {
+    let market = match docked.0.and_then(|orbital| economy.get(orbital)) {
+        Some(market) => market,
+        None => return,
+    };
+    let name = |orbital: usize| {
+        orbitals
+            .get(orbital)
+            .map(|orbital| orbital.data.name().to_owned())
+            .unwrap_or_default()
+    };
+    let sector_name = |sector: usize| {
+        sectors
+            .get(sector)
+            .map(|sector| sector.name.clone())
+            .unwrap_or_default()
+    };
+    let authority = factions.controller(market.sector);
+    let politics = authority.map(|faction| &faction.politics);
+
+    let mut lines = vec![
+        format!(
+            "{} - {} ({})",
+            name(market.orbital),
+            sector_name(market.sector),
+            authority
+                .map(|faction| faction.name.as_str())
+                .unwrap_or("Unclaimed")
+        ),
+        format!(
+            "Credits {:.0}   Hold {:.1}/{:.0} t  {:.1}/{:.0} m3",
+            credits.0,
+            cargo.mass(),
+            cargo.mass_capacity,
+            cargo.volume(),
+            cargo.volume_capacity
+        ),
+        String::new(),
+        format!(
+            "  {:<12}{:>9}{:>9}{:>8}{:>6}",
+            "Commodity", "Buy", "Sell", "Stock", "Held"
+        ),
+    ];
+    for (index, commodity) in Commodity::ALL.iter().enumerate() {
+        let (buy, sell) = trade_prices(market, *commodity, politics);
+        let banned = politics.map_or(false, |politics| is_contraband(*commodity, politics));
+        lines.push(format!(
+            "{}{}{:<11}{:>9.1}{:>9.1}{:>8.0}{:>6}",
+            if index == selection.index { ">" } else { " " },
+            if banned { "*" } else { " " },
+            commodity.name(),
+            buy,
+            sell,
+            market.stock(*commodity),
+            cargo.count(*commodity)
+        ));
+    }
+    lines.push(String::new());
+    let commodity = selection.commodity();
+    lines.push(price_trend(market, commodity, politics));
+    lines.push(format!("Known {} Markets:", commodity.name()));
+    let seconds = world_clock.seconds_since_epoch();
+    for (orbital, known, price) in ledger
+        .best_sales(commodity)
+        .into_iter()
+        .filter(|(orbital, _, _)| *orbital != market.orbital)
+        .take(KNOWN_MARKETS)
+    {
+        lines.push(format!(
+            "  {:<24}{:>9.1}{:>6.0}d ago",
+            format!("{} ({})", name(orbital), sector_name(known.sector)),
+            price,
+            (seconds - known.seconds) / SECONDS_PER_DAY
+        ));
+    }
+    lines.push(String::new());
+    lines.push(String::from("Jobs:"));
+    for (index, quest) in board.offered.iter().take(LISTED_QUESTS).enumerate() {
+        lines.push(format!(
+            "  [{}] {}",
+            index + 1,
+            quest.describe(&factions, &sectors)
+        ));
+    }
+    for quest in board.accepted.iter() {
+        lines.push(format!("  Taken: {}", quest.describe(&factions, &sectors)));
+    }
+    lines.push(String::new());
+    lines.push(selection.status.clone());
+    lines.push(String::from(
+        "[Up/Down] Select [B] Buy [S] Sell [Shift] x10 [1-3] Take Job [Tab] Next Market [Y] Shipyard [D] Undock  * Contraband",
+    ));
+
+    for mut text in query.iter_mut() {
+        text.sections[0].value = lines.join("\n");
+    }
+}
+
+/// Low, high and change over the days a Market remembers.
+fn price_trend(market: &Market, commodity: Commodity, politics: Option<&PoliticalAxis>) -> String {
+    let history: Vec<f32> = market.price_history(commodity).collect();
+    let (first, low, high) = match history.first() {
+        Some(first) => (
+            *first,
+            history.iter().copied().fold(f32::MAX, f32::min),
+            history.iter().copied().fold(f32::MIN, f32::max),
+        ),
+        None => return format!("{}: no price history yet", commodity.name()),
+    };
+    let now = market.price(commodity);
+    let (buy, _) = trade_prices(market, commodity, politics);
+    format!(
+        "{} over {} days: low {:.1} high {:.1}, {:+.0}% (buying at {:.1})",
+        commodity.name(),
+        history.len().min(HISTORY_DAYS),
+        low,
+        high,
+        (now / first - 1.0) * 100.0,
+        buy
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_docking_targets() {
+        let vessel = Vec2::new(100.0, 0.0);
+        let dockables = vec![
+            (1, Vec2::new(120.0, 0.0)),
+            (2, Vec2::new(100.0, 10.0)),
+            (3, Vec2::new(300.0, 0.0)),
+        ];
+        assert_eq!(docking_targets(vessel, dockables.into_iter()), vec![2, 1]);
+        assert!(docking_targets(Vec2::ZERO, std::iter::empty()).is_empty());
+    }
+
+    #[test]
+    fn test_hostiles_near() {
+        let vessel = Vec2::new(100.0, 0.0);
+        assert!(!hostiles_near(vessel, &[]));
+        assert!(!hostiles_near(vessel, &[Vec2::new(100.0, 1000.0)]));
+        assert!(hostiles_near(
+            vessel,
+            &[Vec2::new(100.0, 1000.0), Vec2::new(300.0, 0.0)]
+        ));
+    }
+}
@@ -20,36 +20,78 @@
 mod apartment;
 mod context;
 mod faction;
+mod feature;
 mod sector;
+mod stellar;
 
-pub use self::context::{GenerationConfiguration, GenerationContext, GenerationState};
-pub use self::faction::{generate_government_faction, ProtoFaction};
-pub use self::sector::{ProtoCluster, ProtoOrbital, ProtoSector};
+pub use self::context::{
+    GenerationConfiguration, GenerationContext, GenerationState, UniverseSeed,
+};
+pub use self::faction::{
+    generate_faction, generate_faction_name, generate_government_faction, ProtoFaction,
+};
+pub use self::feature::LagrangePoint;
+pub use self::sector::{
+    AsteroidData, BeltData, BlackHoleData, MoonData, OrbitalData, PlanetData, ProtoCluster,
+    ProtoOrbital, ProtoRotation, ProtoSector, StarData, StationData,
+};
+pub use self::stellar::{
+    CatalogIssue, LuminosityClass, SpectralClass, SpectralParseError, SpectralType, StellarCatalog,
+    StellarCatalogLoader, StellarClass,
+};
 use super::GameState;
+use bevy::asset::LoadState;
 use bevy::ecs::schedule::StateError;
 use bevy::prelude::*;
 
+/// Stellar Catalog stars are generated from.
+pub struct StellarCatalogHandle(pub Handle<StellarCatalog>);
+
 pub fn bootstrap_worldgeneration(app: &mut AppBuilder) {
-    app.add_system_set(
-        SystemSet::on_enter(GameState::WorldGeneration).with_system(setup_worldgen.system()),
-    )
-    .add_system_set(
-        SystemSet::on_update(GameState::WorldGeneration).with_system(update_worldgen.system()),
-    )
-    .add_system_set(
-        SystemSet::on_exit(GameState::WorldGeneration).with_system(cleanup_worldgen.system()),
-    );
+    app.add_asset::<StellarCatalog>()
+        .init_asset_loader::<StellarCatalogLoader>()
+        .add_system_set(
+            SystemSet::on_enter(GameState::WorldGeneration).with_system(setup_worldgen.system()),
+        )
+        .add_system_set(
+            SystemSet::on_update(GameState::WorldGeneration).with_system(update_worldgen.system()),
+        )
+        .add_system_set(
+            SystemSet::on_exit(GameState::WorldGeneration).with_system(cleanup_worldgen.system()),
+        );
 }
 
-pub fn setup_worldgen(mut commands: Commands) {
+pub fn setup_worldgen(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.insert_resource(GenerationContext::default());
+    commands.insert_resource(StellarCatalogHandle(
+        asset_server.load("data/stellar.catalog"),
+    ));
 }
 
 pub fn update_worldgen(
     mut commands: Commands,
     mut state: ResMut<State<GameState>>,
     mut context: ResMut<GenerationContext>,
+    asset_server: Res<AssetServer>,
+    catalogs: Res<Assets<StellarCatalog>>,
+    catalog_handle: Res<StellarCatalogHandle>,
 ) {
+    if context.state == GenerationState::Configuration && context.catalog.is_empty() {
+        // Stars can't be generated until the catalog has loaded.
+        match catalogs.get(&catalog_handle.0) {
+            Some(catalog) => context.catalog = catalog.clone(),
+            None => {
+                if asset_server.get_load_state(&catalog_handle.0) == LoadState::Failed {
+                    // A Universe without stars isn't worth playing, go back to the menus.
+                    error!("Unable to load Stellar Catalog, abandoning World Generation");
+                    if let Err(error) = state.set(GameState::MainMenu) {
+                        warn!("Unable to return to Main Menu: {:?}", error);
+                    }
+                }
+                return;
+            }
+        }
+    }
     if context.state == GenerationState::Complete {
         state
             .set(GameState::GameplayMode)
@@ -61,5 +103,6 @@ pub fn update_worldgen(
 
 pub fn cleanup_worldgen(mut commands: Commands) {
     trace!("Cleanup WorldGen");
-    commands.remove_resource::<GenerationContext>()
+    commands.remove_resource::<GenerationContext>();
+    commands.remove_resource::<StellarCatalogHandle>()
 }
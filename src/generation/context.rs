@@ -14,7 +14,9 @@
 // limitations under the License.
 //
 
-use super::{ProtoCluster, ProtoFaction, ProtoSector};
+use super::{ProtoCluster, ProtoFaction, ProtoSector, StellarCatalog};
+use crate::cartographer::{Factions, Orbitals, Sectors};
+use crate::gameplay::CurrentSector;
 use crate::generation::ProtoOrbital;
 use bevy::prelude::Commands;
 use hexgrid::{Coordinate, Direction, Spin, Spiral};
@@ -31,6 +33,7 @@ pub enum GenerationState {
     Initialization,
     ClusterGeneration,
     SectorGeneration(Spiral<i32>),
+    FactionGeneration,
     Finalization,
     Complete,
 }
@@ -61,6 +64,10 @@ impl Default for GenerationConfiguration {
     }
 }
 
+/// Seed the Universe was generated from, kept to make gameplay rolls reproducible.
+#[derive(Debug, Copy, Clone)]
+pub struct UniverseSeed(pub u64);
+
 #[derive(Debug)]
 pub struct GenerationContext {
     pub state: GenerationState,
@@ -70,6 +77,7 @@ pub struct GenerationContext {
     pub sectors: Vec<ProtoSector>,
     pub orbitals: Vec<ProtoOrbital>,
     pub factions: Vec<ProtoFaction>,
+    pub catalog: StellarCatalog,
 }
 
 impl Default for GenerationContext {
@@ -84,6 +92,7 @@ impl Default for GenerationContext {
             sectors: Vec::default(),
             orbitals: Vec::default(),
             factions: Vec::default(),
+            catalog: StellarCatalog::default(),
         }
     }
 }
@@ -127,11 +136,23 @@ impl GenerationContext {
                     );
                     self.step_generate_sector(commands, location);
                 } else {
-                    trace!("Generation set to Finalization State");
-                    self.state = GenerationState::Finalization;
+                    trace!("Generation set to Faction Generation State");
+                    self.state = GenerationState::FactionGeneration;
                 }
             }
+            GenerationState::FactionGeneration => {
+                self.step_generate_factions();
+                trace!("Generation set to Finalization State");
+                self.state = GenerationState::Finalization;
+            }
             GenerationState::Finalization => {
+                if let Some(sector) = self.sectors.first() {
+                    commands.insert_resource(CurrentSector(sector.id));
+                }
+                commands.insert_resource(UniverseSeed(self.config.universe_seed));
+                commands.insert_resource(Sectors::from(self.sectors.clone()));
+                commands.insert_resource(Orbitals::from(self.orbitals.clone()));
+                commands.insert_resource(Factions::from(self.factions.as_slice()));
                 trace!("Generation set to Complete State");
                 self.state = GenerationState::Complete;
             }
@@ -14,18 +14,260 @@
 // limitations under the License.
 //
 
-use super::GenerationConfiguration;
-use super::GenerationContext;
+use super::{GenerationContext, ProtoSector};
+use crate::cartographer::{FactionKind, Factions, PoliticalAxis};
+use bevy::prelude::Color;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashMap, HashSet, VecDeque};
+use tracing::trace;
 
-#[derive(Debug)]
+/// Share of all Sectors claimed by Governments between them.
+const GOVERNED_SHARE: f32 = 0.6;
+/// Hue step between consecutive Factions, the golden angle keeps neighbours distinct.
+const GOLDEN_ANGLE: f32 = 137.508;
+/// Tries at finding an unused name before settling for a duplicate.
+const NAME_ATTEMPTS: usize = 8;
+
+#[derive(Debug, Clone)]
 pub struct ProtoFaction {
-    name: String,
+    pub name: String,
+    pub kind: FactionKind,
+    pub politics: PoliticalAxis,
+    pub color: Color,
+    /// Sector the Faction is headquartered in
+    pub home: Option<usize>,
+    /// Sectors the Faction controls
+    pub territory: HashSet<usize>,
 }
 
-/// Generate Faction
-pub fn generate_government_faction(mut context: &GenerationContext) {}
+impl GenerationContext {
+    /// Seed Factions of every kind into the generated Sectors, growing territory out from their
+    /// home Sectors.
+    pub fn step_generate_factions(&mut self) {
+        let sector_count = self.sectors.len();
+        if sector_count == 0 {
+            return;
+        }
+        let governments = (self.clusters.len() / 3).clamp(1, 12);
+        let roster = [
+            (FactionKind::Government, governments),
+            (FactionKind::House, self.random.gen_range(1..=3)),
+            (FactionKind::Religious, self.random.gen_range(1..=3)),
+            (FactionKind::Pirate, self.random.gen_range(2..=4)),
+            (FactionKind::Corporate, self.random.gen_range(3..=6)),
+            (FactionKind::Guild, self.random.gen_range(1..=2)),
+            (FactionKind::Criminal, self.random.gen_range(1..=3)),
+            (FactionKind::Rebellion, 1),
+        ];
+        let mut homes: HashSet<usize> = HashSet::new();
+        for (kind, count) in roster.iter() {
+            for _ in 0..*count {
+                let mut faction = generate_faction(*kind, self.factions.len(), &mut self.random);
+                // Names are how Factions are looked up, re-roll any that are already taken.
+                for _ in 0..NAME_ATTEMPTS {
+                    if !self.factions.iter().any(|other| other.name == faction.name) {
+                        break;
+                    }
+                    faction.name = generate_faction_name(*kind, &mut self.random);
+                }
+                faction.home = self.choose_home(&homes);
+                if let Some(home) = faction.home {
+                    homes.insert(home);
+                }
+                trace!("Generated {:?} Faction {}", kind, faction.name);
+                self.factions.push(faction);
+            }
+        }
+        let governed = (sector_count as f32 * GOVERNED_SHARE / governments as f32) as usize;
+        let budgets: Vec<usize> = self
+            .factions
+            .iter()
+            .map(|faction| match faction.kind {
+                FactionKind::Government => self.random.gen_range(governed / 2..=governed.max(1)),
+                FactionKind::House => self.random.gen_range(5..=15),
+                FactionKind::Religious => self.random.gen_range(3..=8),
+                FactionKind::Pirate => self.random.gen_range(2..=6),
+                _ => 0,
+            })
+            .collect();
+        self.spread_territory(budgets);
+    }
+    /// Random Sector not already home to another Faction.
+    fn choose_home(&mut self, taken: &HashSet<usize>) -> Option<usize> {
+        for _ in 0..100 {
+            let sector = self.sectors.choose(&mut self.random)?;
+            if !taken.contains(&sector.id) {
+                return Some(sector.id);
+            }
+        }
+        None
+    }
+    /// Grow each Faction's territory one Sector at a time in turn, spreading through neighbouring
+    /// Sectors of a cluster and settling the nearest cluster with room once it's hemmed in.
+    fn spread_territory(&mut self, mut budgets: Vec<usize>) {
+        let locations: HashMap<_, usize> = self
+            .sectors
+            .iter()
+            .enumerate()
+            .map(|(index, sector)| (sector.location, index))
+            .collect();
+        let mut owner: HashMap<usize, usize> = HashMap::new();
+        let mut frontiers: Vec<VecDeque<usize>> = vec![VecDeque::new(); self.factions.len()];
+        for (index, faction) in self.factions.iter().enumerate() {
+            if let (Some(home), true) = (faction.home, budgets[index] > 0) {
+                frontiers[index].push_back(home);
+            }
+        }
+        let mut growing = true;
+        while growing {
+            growing = false;
+            for index in 0..self.factions.len() {
+                if budgets[index] == 0 {
+                    continue;
+                }
+                let claimed = loop {
+                    let sector = match frontiers[index].pop_front() {
+                        Some(sector) => sector,
+                        None => match self.nearest_free_sector(index, &owner) {
+                            Some(sector) => sector,
+                            None => break None,
+                        },
+                    };
+                    if !owner.contains_key(&sector) {
+                        break Some(sector);
+                    }
+                };
+                let sector = match claimed {
+                    Some(sector) => sector,
+                    None => {
+                        budgets[index] = 0;
+                        continue;
+                    }
+                };
+                owner.insert(sector, index);
+                self.factions[index].territory.insert(sector);
+                budgets[index] -= 1;
+                growing = true;
+                for neighbour in hex_neighbours(self.sectors[sector].location) {
+                    if let Some(next) = locations.get(&neighbour) {
+                        if !owner.contains_key(next) {
+                            frontiers[index].push_back(*next);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    /// Unclaimed Sector in the nearest cluster to a Faction's home that still has room.
+    fn nearest_free_sector(&self, faction: usize, owner: &HashMap<usize, usize>) -> Option<usize> {
+        let home = &self.sectors[self.factions[faction].home?];
+        self.sectors
+            .iter()
+            .filter(|sector| !owner.contains_key(&sector.id))
+            .min_by_key(|sector| {
+                let cluster = &self.clusters[sector.cluster];
+                (
+                    cluster.center.distance(home.location),
+                    sector.location.distance(home.location),
+                )
+            })
+            .map(|sector: &ProtoSector| sector.id)
+    }
+}
 
-const ADJECTIVES: [&str; 2] = ["Holy", "Inscrutable"];
+/// Generate a Faction of `kind`, `index` spreads colours out between Factions.
+pub fn generate_faction<R: Rng>(kind: FactionKind, index: usize, rng: &mut R) -> ProtoFaction {
+    ProtoFaction {
+        name: generate_faction_name(kind, rng),
+        kind,
+        politics: generate_politics(kind, rng),
+        color: Color::hsl(
+            (index as f32 * GOLDEN_ANGLE) % 360.0,
+            rng.gen_range(0.5..0.9),
+            rng.gen_range(0.4..0.6),
+        ),
+        home: None,
+        territory: HashSet::new(),
+    }
+}
+
+/// Generate Government Faction
+pub fn generate_government_faction<R: Rng>(index: usize, rng: &mut R) -> ProtoFaction {
+    generate_faction(FactionKind::Government, index, rng)
+}
+
+/// Name such as "Holy Veranthi Confederation".
+pub fn generate_faction_name<R: Rng>(kind: FactionKind, rng: &mut R) -> String {
+    let root = nominae::Totro::generate(2, 8, rng);
+    let kind_name = faction_types(kind)
+        .choose(rng)
+        .copied()
+        .unwrap_or("Faction");
+    if rng.gen_bool(0.5) {
+        let adjective = ADJECTIVES.choose(rng).copied().unwrap_or("Free");
+        format!("{} {} {}", adjective, root, kind_name)
+    } else {
+        format!("{} {}", root, kind_name)
+    }
+}
+
+/// Politics leaning the way a kind of Faction tends to.
+fn generate_politics<R: Rng>(kind: FactionKind, rng: &mut R) -> PoliticalAxis {
+    // Economic, Civil, Social, Diplomatic
+    let (economic, civil, social, diplomatic) = match kind {
+        FactionKind::Corporate => (0.6, 0.2, 0.0, -0.4),
+        FactionKind::Criminal => (0.5, -0.3, 0.0, 0.0),
+        FactionKind::Government => (0.0, 0.3, 0.0, 0.2),
+        FactionKind::House => (0.2, 0.6, -0.5, 0.3),
+        FactionKind::Guild => (-0.2, 0.0, 0.2, -0.3),
+        FactionKind::Player => (0.0, 0.0, 0.0, 0.0),
+        FactionKind::Rebellion => (-0.3, -0.6, 0.3, 0.0),
+        FactionKind::Pirate => (0.3, -0.8, 0.0, 0.4),
+        FactionKind::Religious => (-0.2, 0.4, -0.7, 0.2),
+    };
+    let mut lean = |bias: f32| bias + rng.gen_range(-0.4..0.4);
+    PoliticalAxis::new(lean(economic), lean(civil), lean(social), lean(diplomatic))
+}
+
+/// The six Sectors around `location`.
+fn hex_neighbours(
+    location: hexgrid::Coordinate<i32>,
+) -> impl Iterator<Item = hexgrid::Coordinate<i32>> {
+    const OFFSETS: [(i32, i32); 6] = [(1, 0), (-1, 0), (0, 1), (0, -1), (1, -1), (-1, 1)];
+    OFFSETS
+        .iter()
+        .map(move |(dx, dy)| hexgrid::Coordinate::from_cubic(location.x + dx, location.y + dy))
+}
+
+fn faction_types(kind: FactionKind) -> &'static [&'static str] {
+    match kind {
+        FactionKind::Corporate => &CORPORATE_TYPES,
+        FactionKind::Criminal => &CRIMINAL_TYPES,
+        FactionKind::Government => &TYPES,
+        FactionKind::House => &HOUSE_TYPES,
+        FactionKind::Guild => &GUILD_TYPES,
+        FactionKind::Player => &["Company"],
+        FactionKind::Rebellion => &REBELLION_TYPES,
+        FactionKind::Pirate => &PIRATE_TYPES,
+        FactionKind::Religious => &RELIGIOUS_TYPES,
+    }
+}
+
+const ADJECTIVES: [&str; 12] = [
+    "Holy",
+    "Inscrutable",
+    "United",
+    "Free",
+    "Grand",
+    "Eternal",
+    "Sovereign",
+    "Radiant",
+    "Iron",
+    "Crimson",
+    "Silent",
+    "Ascendant",
+];
 
 const TYPES: [&str; 7] = [
     "Cabal",
@@ -36,3 +278,17 @@ const TYPES: [&str; 7] = [
     "Empire",
     "Kingdom",
 ];
+
+const CORPORATE_TYPES: [&str; 5] = [
+    "Corporation",
+    "Industries",
+    "Holdings",
+    "Combine",
+    "Conglomerate",
+];
+const CRIMINAL_TYPES: [&str; 4] = ["Syndicate", "Cartel", "Family", "Network"];
+const HOUSE_TYPES: [&str; 3] = ["House", "Dynasty", "Lineage"];
+const GUILD_TYPES: [&str; 4] = ["Guild", "Union", "League", "Brotherhood"];
+const REBELLION_TYPES: [&str; 4] = ["Front", "Resistance", "Liberation Army", "Movement"];
+const PIRATE_TYPES: [&str; 4] = ["Raiders", "Reavers", "Corsairs", "Marauders"];
+const RELIGIOUS_TYPES: [&str; 4] = ["Church", "Order", "Temple", "Faith"];
@@ -0,0 +1,319 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Orbital Features such as Stations, Asteroid Belts, Planetary Rings and Trojan Clusters.
+use super::sector::{AsteroidData, BeltData, OrbitalData, ProtoRotation, StationData};
+use super::GenerationContext;
+use rand::Rng;
+use std::f32::consts::TAU;
+
+/// Stable Lagrange Points of a two body system.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LagrangePoint {
+    /// Leads the secondary body by 60 degrees
+    L4,
+    /// Trails the secondary body by 60 degrees
+    L5,
+}
+
+impl LagrangePoint {
+    /// Phase offset in radians from the secondary body.
+    pub fn phase(self) -> f32 {
+        match self {
+            LagrangePoint::L4 => TAU / 6.0,
+            LagrangePoint::L5 => -TAU / 6.0,
+        }
+    }
+    /// Short label used in designations.
+    pub fn label(self) -> &'static str {
+        match self {
+            LagrangePoint::L4 => "L4",
+            LagrangePoint::L5 => "L5",
+        }
+    }
+}
+
+impl GenerationContext {
+    /// Generate Stations, Rings and Trojan Clusters around a freshly generated Planet.
+    pub(super) fn generate_planet_features<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        sector: usize,
+        planet: usize,
+    ) {
+        let (designation, radius, ring, populated) = match &self.orbitals[planet].data {
+            OrbitalData::Planet(data) => (
+                data.designation.clone(),
+                data.radius as f32,
+                data.ring,
+                data.population > 0.0,
+            ),
+            _ => return,
+        };
+        if ring {
+            self.generate_belt(
+                rng,
+                sector,
+                planet,
+                format!("{}-R", designation),
+                radius * 1.6,
+                radius * 0.6,
+            );
+        }
+        if populated && rng.gen_range(0..2) == 0 {
+            // Orbital Station around a populated world, just outside any ring.
+            self.push_orbital(
+                sector,
+                Some(planet),
+                radius * 2.0,
+                rng.gen_range(0.1..1.0),
+                ProtoRotation::default(),
+                OrbitalData::Station(StationData {
+                    designation: format!("{}-S", designation),
+                    name: None,
+                    mass: 1.0e-6,
+                    radius: 0.5,
+                }),
+            );
+        }
+        for point in [LagrangePoint::L4, LagrangePoint::L5] {
+            match rng.gen_range(0..10) {
+                0..=1 => self.generate_trojans(rng, sector, planet, point),
+                2 if populated => {
+                    self.generate_lagrange_station(sector, planet, point);
+                }
+                _ => {}
+            }
+        }
+    }
+    /// Generate an annular field of debris orbiting `parent`.
+    pub(super) fn generate_belt<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        sector: usize,
+        parent: usize,
+        designation: String,
+        radius: f32,
+        width: f32,
+    ) -> usize {
+        let period = self.orbitals[parent].period.max(1.0) * rng.gen_range(0.5..2.0);
+        self.push_orbital(
+            sector,
+            Some(parent),
+            radius,
+            period,
+            ProtoRotation::default(),
+            OrbitalData::Belt(BeltData {
+                designation,
+                name: None,
+                mass: rng.gen_range(0.0001..0.01),
+                width: width as f64,
+                density: rng.gen_range(0.001..0.01),
+                minerals: rng.gen_range(1000.0..100000.0),
+            }),
+        )
+    }
+    /// Park a Station at one of a Planet's stable Lagrange points.
+    pub(super) fn generate_lagrange_station(
+        &mut self,
+        sector: usize,
+        planet: usize,
+        point: LagrangePoint,
+    ) -> Option<usize> {
+        let (primary, radius, period, phase, designation) = self.lagrange_orbit(planet)?;
+        let id = self.push_orbital(
+            sector,
+            Some(primary),
+            radius,
+            period,
+            ProtoRotation::default(),
+            OrbitalData::Station(StationData {
+                designation: format!("{}-{}", designation, point.label()),
+                name: None,
+                mass: 1.0e-6,
+                radius: 0.5,
+            }),
+        );
+        self.orbitals[id].phase = phase + point.phase();
+        Some(id)
+    }
+    /// Scatter a cluster of Trojan Asteroids around one of a Planet's stable Lagrange points.
+    pub(super) fn generate_trojans<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        sector: usize,
+        planet: usize,
+        point: LagrangePoint,
+    ) {
+        let (primary, radius, period, phase, designation) = match self.lagrange_orbit(planet) {
+            Some(orbit) => orbit,
+            None => return,
+        };
+        for trojan in 0..rng.gen_range(3..8) {
+            let id = self.push_orbital(
+                sector,
+                Some(primary),
+                radius + rng.gen_range(-radius * 0.02..radius * 0.02),
+                period,
+                ProtoRotation {
+                    period: rng.gen_range(0.1..2.0),
+                    axial_tilt: rng.gen_range(0.0..180.0),
+                    tidally_locked: false,
+                },
+                OrbitalData::Asteroid(AsteroidData {
+                    designation: format!("{}-{}-{}", designation, point.label(), trojan + 1),
+                    name: None,
+                    mass: rng.gen_range(1.0e-9..1.0e-6),
+                    radius: rng.gen_range(0.1..0.5),
+                    minerals: rng.gen_range(100.0..5000.0),
+                }),
+            );
+            self.orbitals[id].phase = phase + point.phase() + rng.gen_range(-0.1..0.1);
+        }
+    }
+    /// Primary, Radius, Period, Phase and Designation shared by everything at a Planet's Lagrange
+    /// points.
+    fn lagrange_orbit(&self, planet: usize) -> Option<(usize, f32, f32, f32, String)> {
+        let planet = &self.orbitals[planet];
+        Some((
+            planet.parent?,
+            planet.radius,
+            planet.period,
+            planet.phase,
+            planet.data.designation().to_owned(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::{PlanetData, StarData};
+    use bevy::prelude::Color;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro128PlusPlus;
+
+    /// Context holding a Star with a single Planet orbiting it.
+    fn planetary_system(ring: bool, population: f32) -> (GenerationContext, usize, usize) {
+        let mut context = GenerationContext::default();
+        let star = context.push_orbital(
+            0,
+            None,
+            0.0,
+            0.0,
+            ProtoRotation::default(),
+            OrbitalData::Star(StarData {
+                designation: String::from("T-1"),
+                name: None,
+                class: String::from("G2V"),
+                mass: 1.0,
+                luminosity: 1.0,
+                radius: 1.0,
+                temp: 5778.0,
+                color: Color::WHITE,
+            }),
+        );
+        let planet = context.push_orbital(
+            0,
+            Some(star),
+            300.0,
+            365.0,
+            ProtoRotation::default(),
+            OrbitalData::Planet(PlanetData {
+                designation: String::from("T-1-1"),
+                name: None,
+                mass: 1.0,
+                radius: 10.0,
+                temp: 288.0,
+                color: Color::BLUE,
+                foliage: 0.5,
+                minerals: 0.5,
+                water: 0.5,
+                gases: 0.5,
+                population,
+                ring,
+            }),
+        );
+        context.orbitals[planet].phase = 1.0;
+        (context, star, planet)
+    }
+
+    #[test]
+    fn test_lagrange_stations_share_the_planets_orbit() {
+        let (mut context, star, planet) = planetary_system(false, 1.0);
+        for point in [LagrangePoint::L4, LagrangePoint::L5] {
+            let station = context.generate_lagrange_station(0, planet, point).unwrap();
+            let station = &context.orbitals[station];
+            assert_eq!(station.parent, Some(star));
+            assert_eq!(station.radius, 300.0);
+            assert_eq!(station.period, 365.0);
+            assert!((station.phase - (1.0 + point.phase())).abs() < 1e-6);
+            assert!(station.data.designation().ends_with(point.label()));
+        }
+        // Lagrange points only exist around a primary.
+        assert_eq!(
+            context.generate_lagrange_station(0, star, LagrangePoint::L4),
+            None
+        );
+    }
+
+    #[test]
+    fn test_trojans_cluster_around_lagrange_point() {
+        let (mut context, star, planet) = planetary_system(false, 0.0);
+        let mut rng = Xoshiro128PlusPlus::seed_from_u64(3);
+        context.generate_trojans(&mut rng, 0, planet, LagrangePoint::L5);
+        let trojans: Vec<_> = context.orbitals.iter().skip(planet + 1).collect();
+        assert!((3..8).contains(&trojans.len()));
+        for trojan in trojans {
+            assert!(matches!(trojan.data, OrbitalData::Asteroid(_)));
+            assert_eq!(trojan.parent, Some(star));
+            assert!((trojan.radius - 300.0).abs() <= 300.0 * 0.02);
+            assert!((trojan.phase - (1.0 + LagrangePoint::L5.phase())).abs() <= 0.1);
+        }
+    }
+
+    #[test]
+    fn test_ring_lies_between_planet_and_station() {
+        for seed in 0..16 {
+            let (mut context, _, planet) = planetary_system(true, 1.0);
+            let mut rng = Xoshiro128PlusPlus::seed_from_u64(seed);
+            context.generate_planet_features(&mut rng, 0, planet);
+            let (ring, width) = context
+                .orbitals
+                .iter()
+                .find_map(|orbital| match &orbital.data {
+                    OrbitalData::Belt(data) if orbital.parent == Some(planet) => {
+                        Some((orbital.radius, data.width as f32))
+                    }
+                    _ => None,
+                })
+                .expect("Ringed Planet generated without a Ring");
+            assert!((ring - 16.0).abs() < 1e-4);
+            assert!((width - 6.0).abs() < 1e-4);
+            // Clear of the planet's surface and inside any orbiting station.
+            assert!(ring - width / 2.0 > 10.0);
+            for orbital in context.orbitals.iter() {
+                if let (OrbitalData::Station(_), Some(parent)) = (&orbital.data, orbital.parent) {
+                    if parent == planet {
+                        assert!(orbital.radius > ring + width / 2.0);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -53,22 +53,42 @@ pub struct ProtoSector {
     pub location: Coordinate<i32>,
     /// Sector Position
     pub position: Position<f32>,
+    /// Anchor Orbital ID
+    pub anchor: usize,
 }
 
 #[derive(Clone, Debug)]
 pub struct ProtoOrbital {
     /// Orbital ID
-    id: usize,
+    pub id: usize,
+    /// Sector ID
+    pub sector: usize,
     /// Orbital Parent ID
-    parent: Option<usize>,
+    pub parent: Option<usize>,
     /// Orbital Radius from parent
-    radius: f32,
+    pub radius: f32,
     /// Orbital Period in days
-    period: f32,
+    pub period: f32,
+    /// Orbital Phase offset in radians, used to place bodies at Lagrange Points
+    pub phase: f32,
+    /// Rotation about its own axis
+    pub rotation: ProtoRotation,
+    /// Has this Orbital been destroyed
+    pub destroyed: bool,
     /// Entity
-    entity: Option<Entity>,
+    pub entity: Option<Entity>,
     /// Orbital Data
-    data: OrbitalData,
+    pub data: OrbitalData,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ProtoRotation {
+    /// Sidereal Rotation Period in days
+    pub period: f32,
+    /// Axial Tilt in degrees relative to the orbital plane
+    pub axial_tilt: f32,
+    /// Does this body always show the same face to its parent
+    pub tidally_locked: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -79,59 +99,148 @@ pub enum OrbitalData {
     Station(StationData),
     Moon(MoonData),
     Asteroid(AsteroidData),
+    Belt(BeltData),
+}
+
+impl OrbitalData {
+    /// Designation of the Orbital Body
+    pub fn designation(&self) -> &str {
+        match self {
+            OrbitalData::BlackHole(data) => &data.designation,
+            OrbitalData::Star(data) => &data.designation,
+            OrbitalData::Planet(data) => &data.designation,
+            OrbitalData::Station(data) => &data.designation,
+            OrbitalData::Moon(data) => &data.designation,
+            OrbitalData::Asteroid(data) => &data.designation,
+            OrbitalData::Belt(data) => &data.designation,
+        }
+    }
+    /// Display Name of the Orbital Body, falling back to its designation.
+    pub fn name(&self) -> &str {
+        let name = match self {
+            OrbitalData::BlackHole(data) => &data.name,
+            OrbitalData::Star(data) => &data.name,
+            OrbitalData::Planet(data) => &data.name,
+            OrbitalData::Station(data) => &data.name,
+            OrbitalData::Moon(data) => &data.name,
+            OrbitalData::Asteroid(data) => &data.name,
+            OrbitalData::Belt(data) => &data.name,
+        };
+        name.as_deref().unwrap_or_else(|| self.designation())
+    }
+    /// Mass of the Orbital Body
+    pub fn mass(&self) -> f64 {
+        match self {
+            OrbitalData::BlackHole(data) => data.mass,
+            OrbitalData::Star(data) => data.mass,
+            OrbitalData::Planet(data) => data.mass,
+            OrbitalData::Station(data) => data.mass,
+            OrbitalData::Moon(data) => data.mass,
+            OrbitalData::Asteroid(data) => data.mass,
+            OrbitalData::Belt(data) => data.mass,
+        }
+    }
+    /// Radius of the Orbital Body
+    pub fn radius(&self) -> f64 {
+        match self {
+            OrbitalData::BlackHole(data) => data.radius,
+            OrbitalData::Star(data) => data.radius,
+            OrbitalData::Planet(data) => data.radius,
+            OrbitalData::Station(data) => data.radius,
+            OrbitalData::Moon(data) => data.radius,
+            OrbitalData::Asteroid(data) => data.radius,
+            OrbitalData::Belt(data) => data.width,
+        }
+    }
+    /// Display Color of the Orbital Body
+    pub fn color(&self) -> Color {
+        match self {
+            OrbitalData::BlackHole(_) => Color::rgb(0.1, 0.0, 0.2),
+            OrbitalData::Star(data) => data.color,
+            OrbitalData::Planet(data) => data.color,
+            OrbitalData::Station(_) => Color::rgb(0.7, 0.7, 0.7),
+            OrbitalData::Moon(_) => Color::rgb(0.5, 0.5, 0.5),
+            OrbitalData::Asteroid(_) => Color::rgb(0.4, 0.35, 0.3),
+            OrbitalData::Belt(_) => Color::rgb(0.4, 0.35, 0.3),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct BlackHoleData {
-    designation: String,
-    name: Option<String>,
-    mass: f64,
-    radius: f64,
+    pub designation: String,
+    pub name: Option<String>,
+    pub mass: f64,
+    pub radius: f64,
 }
 
 #[derive(Clone, Debug)]
 pub struct StarData {
-    designation: String,
-    name: Option<String>,
-    mass: f64,
-    luminosity: f64,
-    radius: f64,
-    temp: f64,
-    color: Color,
+    pub designation: String,
+    pub name: Option<String>,
+    /// Spectral Classification such as `G2V`
+    pub class: String,
+    pub mass: f64,
+    pub luminosity: f64,
+    pub radius: f64,
+    pub temp: f64,
+    pub color: Color,
 }
 
 #[derive(Clone, Debug)]
 pub struct PlanetData {
-    designation: String,
-    name: Option<String>,
-    mass: f64,
-    radius: f64,
-    temp: f64,
-    color: Color,
+    pub designation: String,
+    pub name: Option<String>,
+    pub mass: f64,
+    pub radius: f64,
+    pub temp: f64,
+    pub color: Color,
+    pub foliage: f32,
+    pub minerals: f32,
+    pub water: f32,
+    pub gases: f32,
+    pub population: f32,
+    pub ring: bool,
 }
 
 #[derive(Clone, Debug)]
 pub struct StationData {
-    designation: String,
-    name: Option<String>,
-    mass: f64,
-    radius: f64,
+    pub designation: String,
+    pub name: Option<String>,
+    pub mass: f64,
+    pub radius: f64,
 }
 
 #[derive(Clone, Debug)]
 pub struct MoonData {
-    designation: String,
-    name: Option<String>,
-    mass: f64,
-    radius: f64,
+    pub designation: String,
+    pub name: Option<String>,
+    pub mass: f64,
+    pub radius: f64,
 }
 
 #[derive(Clone, Debug)]
 pub struct AsteroidData {
-    designation: String,
-    name: Option<String>,
-    mass: f64,
-    radius: f64,
+    pub designation: String,
+    pub name: Option<String>,
+    pub mass: f64,
+    pub radius: f64,
+    /// Minerals remaining to be mined
+    pub minerals: f32,
+}
+
+/// Annular field of debris, either an Asteroid Belt around a Star or a Planetary Ring.
+#[derive(Clone, Debug)]
+pub struct BeltData {
+    pub designation: String,
+    pub name: Option<String>,
+    pub mass: f64,
+    /// Width of the annulus centered on the orbital radius
+    pub width: f64,
+    /// Bodies per unit of area, used when scattering the field
+    pub density: f32,
+    /// Minerals remaining to be mined
+    pub minerals: f32,
 }
 
 impl GenerationContext {
@@ -174,6 +283,16 @@ impl GenerationContext {
     pub fn step_generate_sector(&mut self, mut commands: Commands, location: Coordinate<i32>) {
         trace!("Generation of Sector {:?}", &location);
 
+        // Sectors only exist inside of clusters, everything else is empty space.
+        let cluster = match self
+            .clusters
+            .iter()
+            .find(|cluster| cluster.center.distance(location) <= cluster.radius)
+        {
+            Some(cluster) => cluster.id,
+            None => return,
+        };
+
         let sector_seed =
             self.config.universe_seed ^ ((location.x as u64) << 32 | (location.y as u64) << 0);
         let sector_distance = location.distance(Coordinate::default());
@@ -189,23 +308,207 @@ impl GenerationContext {
             format!("O-0") // Omega Sector - Center of galaxy
         };
         let mut rng = rand_xoshiro::Xoshiro128PlusPlus::seed_from_u64(sector_seed);
+        let sector_id = self.sectors.len();
         // Generate Sector Anchor
-        let anchor = match rng.gen_range(0..100) {
-            0..=90 => {
-                let stellar_class = &STELLAR_TYPE[rng.gen_range(0..STELLAR_TYPE.len())];
-                let star_data = StarData {
+        let anchor_data = match rng.gen_range(0..100) {
+            0..=89 => {
+                let stellar_class = match self.catalog.choose(&mut rng) {
+                    Some(stellar_class) => stellar_class,
+                    None => return, // Nothing to fill the sector with
+                };
+                OrbitalData::Star(StarData {
                     designation: format!("{}-0", sector_designation),
                     name: None,
-                    mass: 0.0,
-                    luminosity: 0.0,
-                    radius: 0.0,
-                    temp: 0.0,
-                    color: Default::default(),
-                };
+                    class: stellar_class.class.to_string(),
+                    mass: stellar_class.mass as f64,
+                    luminosity: stellar_class.luminosity as f64,
+                    radius: stellar_class.radius as f64,
+                    temp: stellar_class.temp as f64,
+                    color: stellar_class.color,
+                })
             }
-            90..=100 => {}
+            90..=94 => OrbitalData::BlackHole(BlackHoleData {
+                designation: format!("{}-0", sector_designation),
+                name: None,
+                mass: rng.gen_range(5.0..50.0),
+                radius: rng.gen_range(0.5..2.0),
+            }),
+            95..=99 => return, // Rogue sector with no anchor
             _ => panic!("Rolled outside expected range"),
         };
+        let anchor_radius = anchor_data.radius() as f32;
+        let anchor_mass = anchor_data.mass();
+        let anchor_rotation = ProtoRotation {
+            period: rng.gen_range(1.0..50.0),
+            axial_tilt: rng.gen_range(0.0..10.0),
+            tidally_locked: false,
+        };
+        let anchor = self.push_orbital(sector_id, None, 0.0, 0.0, anchor_rotation, anchor_data);
+
+        let planet_count = rng.gen_range(0..15);
+        let mut distance_from_primary = anchor_radius * 2.0;
+        let mut belt_count = 0;
+        for planet_orbit in 0..planet_count {
+            distance_from_primary += rng.gen_range(20.0..200.0);
+            if rng.gen_range(0..10) == 0 {
+                // Asteroid Belt takes the place of a Planet
+                let width = rng.gen_range(10.0..40.0);
+                self.generate_belt(
+                    &mut rng,
+                    sector_id,
+                    anchor,
+                    format!("{}-B{}", sector_designation, belt_count + 1),
+                    distance_from_primary + width / 2.0,
+                    width,
+                );
+                distance_from_primary += width + rng.gen_range(20.0..200.0);
+                belt_count += 1;
+            }
+            // TODO: Make temperature a function of distance from star
+            let planet_temperature = rng.gen_range(-200.0..300.0);
+            // TODO: Make this based on Temperature
+            let planet_foliage = rng.gen_range(0.0..1.0);
+            let planet_minerals = rng.gen_range(0.0..1.0);
+            let planet_water = rng.gen_range(0.0..1.0);
+            let planet_gases = rng.gen_range(0.0..1.0);
+            let feature_sum =
+                1.0 / (planet_foliage + planet_minerals + planet_water + planet_gases);
+            let planet_data = PlanetData {
+                designation: format!("{}-{}", sector_designation, planet_orbit + 1),
+                name: None,
+                mass: rng.gen_range(0.01..300.0),
+                radius: rng.gen_range(2.0..10.0),
+                temp: planet_temperature,
+                color: Color::rgb(planet_minerals, planet_foliage, planet_water),
+                foliage: planet_foliage * feature_sum,
+                minerals: planet_minerals * feature_sum,
+                water: planet_water * feature_sum,
+                gases: planet_gases * feature_sum,
+                // TODO: Make population based on habitat properties
+                population: f32::max(0.0, rng.gen_range(-5000000.0..20000000.0)),
+                ring: rng.gen_range(0.0..1.0) < 0.10,
+            };
+            let planet_radius = planet_data.radius as f32;
+            let planet_mass = planet_data.mass;
+            let planet_period = (planet_orbit + 1) as f32 * rng.gen_range(1.0..60.0);
+            let planet_rotation = generate_rotation(
+                &mut rng,
+                planet_period,
+                tidally_locked(anchor_mass, distance_from_primary, PLANET_TIDAL_REACH),
+            );
+            let planet = self.push_orbital(
+                sector_id,
+                Some(anchor),
+                distance_from_primary,
+                planet_period,
+                planet_rotation,
+                OrbitalData::Planet(planet_data),
+            );
+
+            let moon_count = i32::max(0, rng.gen_range(-5..5)) as usize;
+            let mut distance_from_planet = planet_radius * 2.0;
+            for moon_orbit in 0..moon_count {
+                distance_from_planet += rng.gen_range(1.0..5.0);
+                // TODO: Make Moon Generation better
+                let moon_data = MoonData {
+                    designation: format!(
+                        "{}-{}{}",
+                        sector_designation,
+                        planet_orbit + 1,
+                        (b'a' + moon_orbit as u8) as char
+                    ),
+                    name: None,
+                    mass: rng.gen_range(0.001..0.1),
+                    radius: rng.gen_range(1.0..5.0),
+                };
+                // TODO: Make this based on location
+                let moon_period = (moon_orbit + 1) as f32 * rng.gen_range(1.0..60.0);
+                let moon_rotation = generate_rotation(
+                    &mut rng,
+                    moon_period,
+                    tidally_locked(planet_mass, distance_from_planet, MOON_TIDAL_REACH),
+                );
+                self.push_orbital(
+                    sector_id,
+                    Some(planet),
+                    distance_from_planet,
+                    moon_period,
+                    moon_rotation,
+                    OrbitalData::Moon(moon_data),
+                );
+            }
+            self.generate_planet_features(&mut rng, sector_id, planet);
+        }
+
+        self.sectors.push(ProtoSector {
+            id: sector_id,
+            cluster,
+            name: format!(
+                "{} {}",
+                nominae::Totro::generate(3, 8, &mut rng),
+                sector_distance
+            ),
+            designation: sector_designation,
+            location,
+            position: location.to_pixel(Spacing::PointyTop(1.0)),
+            anchor,
+        });
+    }
+    /// Record a new Orbital, returning its ID.
+    pub(super) fn push_orbital(
+        &mut self,
+        sector: usize,
+        parent: Option<usize>,
+        radius: f32,
+        period: f32,
+        rotation: ProtoRotation,
+        data: OrbitalData,
+    ) -> usize {
+        let id = self.orbitals.len();
+        self.orbitals.push(ProtoOrbital {
+            id,
+            sector,
+            parent,
+            radius,
+            period,
+            phase: 0.0,
+            rotation,
+            destroyed: false,
+            entity: None,
+            data,
+        });
+        id
+    }
+}
+
+/// Tidal reach of a Star over its Planets, per cube root of stellar mass.
+const PLANET_TIDAL_REACH: f64 = 40.0;
+/// Tidal reach of a Planet over its Moons, per cube root of planetary mass.
+const MOON_TIDAL_REACH: f64 = 4.0;
+
+/// Rough Tidal Locking test, tidal force falls off with the cube of distance so bodies closer
+/// than `reach * cbrt(parent_mass)` are considered locked.
+#[inline]
+fn tidally_locked(parent_mass: f64, distance: f32, reach: f64) -> bool {
+    (distance as f64) < reach * parent_mass.cbrt()
+}
+
+/// Generate rotation of a body, locked bodies rotate once per orbit.
+fn generate_rotation<R: Rng>(rng: &mut R, orbital_period: f32, locked: bool) -> ProtoRotation {
+    ProtoRotation {
+        period: if locked {
+            orbital_period
+        } else {
+            rng.gen_range(0.1..5.0)
+        },
+        axial_tilt: if locked {
+            0.0
+        } else if rng.gen_range(0..20) == 0 {
+            rng.gen_range(90.0..180.0) // Retrograde Rotation
+        } else {
+            rng.gen_range(0.0..45.0)
+        },
+        tidally_locked: locked,
     }
 }
 
@@ -415,1117 +718,3 @@ fn generate_companion_stellar_mass(rng: &mut XorShiftRng, primary_mass: f32) ->
     }
 }
 */
-
-#[derive(Debug)]
-struct StellarClass {
-    name: &'static str,
-    mass: f32,
-    luminosity: f32,
-    radius: f32,
-    temp: f32,
-    color: Color,
-}
-
-#[rustfmt::skip]
-const STELLAR_TYPE: [StellarClass; 1100] = [
-    StellarClass { name: "O0Ia0", mass: 160f32, luminosity: 34100000f32, radius: 80.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "O0Ia", mass: 150f32, luminosity: 2590000f32, radius: 22.1f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "O0Ib", mass: 140f32, luminosity: 2150000f32, radius: 20.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "O0II", mass: 130f32, luminosity: 2150000f32, radius: 20.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "O0III", mass: 120f32, luminosity: 2150000f32, radius: 20.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "O0IV", mass: 110f32, luminosity: 1360000f32, radius: 16f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "O0V", mass: 100f32, luminosity: 1240000f32, radius: 15.3f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "O0VI", mass: 60f32, luminosity: 940000f32, radius: 13.3f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "O1Ia0", mass: 159.7f32, luminosity: 27100000f32, radius: 78.8f32, temp: 47600f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "O1Ia", mass: 149.3f32, luminosity: 2250000f32, radius: 22.7f32, temp: 47600f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "O1Ib", mass: 139f32, luminosity: 1870000f32, radius: 20.7f32, temp: 47600f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "O1II", mass: 128.6f32, luminosity: 1730000f32, radius: 19.8f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "O1III", mass: 118.2f32, luminosity: 1580000f32, radius: 18.9f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "O1IV", mass: 107.9f32, luminosity: 1090000f32, radius: 15.7f32, temp: 47800f32, color: Color::rgb(0.568627450980392f32, 0.654901960784314f32, 1f32) },
-    StellarClass { name: "O1V", mass: 97.5f32, luminosity: 994000f32, radius: 15f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "O1VI", mass: 37f32, luminosity: 754000f32, radius: 13.1f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "O2Ia0", mass: 159.4f32, luminosity: 21400000f32, radius: 77.7f32, temp: 45200f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "O2Ia", mass: 148.6f32, luminosity: 2140000f32, radius: 24.6f32, temp: 45200f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "O2Ib", mass: 137.9f32, luminosity: 1620000f32, radius: 21.4f32, temp: 45200f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "O2II", mass: 127.2f32, luminosity: 1520000f32, radius: 20.3f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "O2III", mass: 116.5f32, luminosity: 1260000f32, radius: 18.5f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.662745098039216f32, 1f32) },
-    StellarClass { name: "O2IV", mass: 105.7f32, luminosity: 872000f32, radius: 15.4f32, temp: 45600f32, color: Color::rgb(0.572549019607843f32, 0.662745098039216f32, 1f32) },
-    StellarClass { name: "O2V", mass: 95f32, luminosity: 795000f32, radius: 14.7f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "O2VI", mass: 30f32, luminosity: 603000f32, radius: 12.8f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "O3Ia0", mass: 159f32, luminosity: 16800000f32, radius: 76.9f32, temp: 42800f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "O3Ia", mass: 148f32, luminosity: 1850000f32, radius: 25.5f32, temp: 42800f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "O3Ib", mass: 136.9f32, luminosity: 1400000f32, radius: 22.2f32, temp: 42800f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "O3II", mass: 125.8f32, luminosity: 1210000f32, radius: 20f32, temp: 43400f32, color: Color::rgb(0.584313725490196f32, 0.67843137254902f32, 1f32) },
-    StellarClass { name: "O3III", mass: 114.7f32, luminosity: 917000f32, radius: 17.5f32, temp: 43400f32, color: Color::rgb(0.588235294117647f32, 0.670588235294118f32, 1f32) },
-    StellarClass { name: "O3IV", mass: 103.6f32, luminosity: 696000f32, radius: 15.2f32, temp: 43400f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "O3V", mass: 92.5f32, luminosity: 634000f32, radius: 14.5f32, temp: 43400f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "O3VI", mass: 23f32, luminosity: 481000f32, radius: 12.6f32, temp: 43400f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "O4Ia0", mass: 158.7f32, luminosity: 13200000f32, radius: 76.4f32, temp: 40400f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "O4Ia", mass: 147.3f32, luminosity: 1740000f32, radius: 27.7f32, temp: 40400f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "O4Ib", mass: 135.8f32, luminosity: 1200000f32, radius: 23.1f32, temp: 40400f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "O4II", mass: 124.4f32, luminosity: 960000f32, radius: 19.8f32, temp: 41200f32, color: Color::rgb(0.592156862745098f32, 0.686274509803922f32, 1f32) },
-    StellarClass { name: "O4III", mass: 112.9f32, luminosity: 728000f32, radius: 17.3f32, temp: 41200f32, color: Color::rgb(0.596078431372549f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "O4IV", mass: 101.5f32, luminosity: 552000f32, radius: 15f32, temp: 41200f32, color: Color::rgb(0.584313725490196f32, 0.670588235294118f32, 1f32) },
-    StellarClass { name: "O4V", mass: 90f32, luminosity: 504000f32, radius: 14.4f32, temp: 41200f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "O4VI", mass: 20f32, luminosity: 382000f32, radius: 12.5f32, temp: 41200f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "O5Ia0", mass: 158.4f32, luminosity: 10300000f32, radius: 76.2f32, temp: 38000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "O5Ia", mass: 142f32, luminosity: 1480000f32, radius: 29f32, temp: 38000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "O5Ib", mass: 125.6f32, luminosity: 1030000f32, radius: 24.1f32, temp: 38000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "O5II", mass: 109.2f32, luminosity: 759000f32, radius: 19.7f32, temp: 39000f32, color: Color::rgb(0.6f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "O5III", mass: 92.8f32, luminosity: 525000f32, radius: 16.4f32, temp: 39000f32, color: Color::rgb(0.603921568627451f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "O5IV", mass: 76.4f32, luminosity: 437000f32, radius: 14.9f32, temp: 39000f32, color: Color::rgb(0.588235294117647f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "O5V", mass: 60f32, luminosity: 398000f32, radius: 14.2f32, temp: 39000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "O5VI", mass: 17.5f32, luminosity: 302000f32, radius: 12.4f32, temp: 39000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "O6Ia0", mass: 136.7f32, luminosity: 7810000f32, radius: 76.6f32, temp: 35400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "O6Ia", mass: 120.1f32, luminosity: 1360000f32, radius: 31.9f32, temp: 35400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "O6Ib", mass: 103.5f32, luminosity: 781000f32, radius: 24.2f32, temp: 35400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "O6II", mass: 86.9f32, luminosity: 654000f32, radius: 20.5f32, temp: 36800f32, color: Color::rgb(0.607843137254902f32, 0.701960784313725f32, 1f32) },
-    StellarClass { name: "O6III", mass: 70.2f32, luminosity: 376000f32, radius: 15.6f32, temp: 36800f32, color: Color::rgb(0.611764705882353f32, 0.686274509803922f32, 1f32) },
-    StellarClass { name: "O6IV", mass: 53.6f32, luminosity: 313000f32, radius: 14.2f32, temp: 36800f32, color: Color::rgb(0.592156862745098f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "O6V", mass: 37f32, luminosity: 260000f32, radius: 12.9f32, temp: 36800f32, color: Color::rgb(0.635294117647059f32, 0.72156862745098f32, 1f32) },
-    StellarClass { name: "O6VI", mass: 14.2f32, luminosity: 180000f32, radius: 10.8f32, temp: 36800f32, color: Color::rgb(0.635294117647059f32, 0.72156862745098f32, 1f32) },
-    StellarClass { name: "O7Ia0", mass: 115.1f32, luminosity: 5880000f32, radius: 77.4f32, temp: 32800f32, color: Color::rgb(0.627450980392157f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "O7Ia", mass: 100.9f32, luminosity: 1120000f32, radius: 33.8f32, temp: 32800f32, color: Color::rgb(0.627450980392157f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "O7Ib", mass: 86.7f32, luminosity: 588000f32, radius: 24.5f32, temp: 32800f32, color: Color::rgb(0.627450980392157f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "O7II", mass: 72.5f32, luminosity: 510000f32, radius: 20.5f32, temp: 34600f32, color: Color::rgb(0.611764705882353f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "O7III", mass: 58.4f32, luminosity: 294000f32, radius: 15.5f32, temp: 34600f32, color: Color::rgb(0.619607843137255f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "O7IV", mass: 44.2f32, luminosity: 223000f32, radius: 13.5f32, temp: 34600f32, color: Color::rgb(0.596078431372549f32, 0.686274509803922f32, 1f32) },
-    StellarClass { name: "O7V", mass: 30f32, luminosity: 154000f32, radius: 11.3f32, temp: 34600f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "O7VI", mass: 10.9f32, luminosity: 107000f32, radius: 9.36f32, temp: 34600f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "O8Ia0", mass: 93.4f32, luminosity: 4370000f32, radius: 78.7f32, temp: 30200f32, color: Color::rgb(0.635294117647059f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "O8Ia", mass: 81.7f32, luminosity: 913000f32, radius: 36f32, temp: 30200f32, color: Color::rgb(0.635294117647059f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "O8Ib", mass: 69.9f32, luminosity: 437000f32, radius: 24.9f32, temp: 30200f32, color: Color::rgb(0.635294117647059f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "O8II", mass: 58.2f32, luminosity: 360000f32, radius: 19.6f32, temp: 32400f32, color: Color::rgb(0.619607843137255f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "O8III", mass: 46.5f32, luminosity: 207000f32, radius: 14.9f32, temp: 32400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "O8IV", mass: 34.7f32, luminosity: 157000f32, radius: 13f32, temp: 32400f32, color: Color::rgb(0.6f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "O8V", mass: 23f32, luminosity: 99100f32, radius: 10.3f32, temp: 32400f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "O8VI", mass: 7.6f32, luminosity: 57000f32, radius: 7.81f32, temp: 32400f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "O9Ia0", mass: 71.8f32, luminosity: 3190000f32, radius: 80.5f32, temp: 27600f32, color: Color::rgb(0.643137254901961f32, 0.725490196078431f32, 1f32) },
-    StellarClass { name: "O9Ia", mass: 63.1f32, luminosity: 731000f32, radius: 38.5f32, temp: 27600f32, color: Color::rgb(0.643137254901961f32, 0.725490196078431f32, 1f32) },
-    StellarClass { name: "O9Ib", mass: 54.5f32, luminosity: 319000f32, radius: 25.5f32, temp: 27600f32, color: Color::rgb(0.643137254901961f32, 0.725490196078431f32, 1f32) },
-    StellarClass { name: "O9II", mass: 45.9f32, luminosity: 276000f32, radius: 19.8f32, temp: 30200f32, color: Color::rgb(0.627450980392157f32, 0.729411764705882f32, 1f32) },
-    StellarClass { name: "O9III", mass: 37.3f32, luminosity: 159000f32, radius: 15f32, temp: 30200f32, color: Color::rgb(0.619607843137255f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "O9IV", mass: 28.6f32, luminosity: 110000f32, radius: 12.5f32, temp: 30200f32, color: Color::rgb(0.607843137254902f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "O9V", mass: 20f32, luminosity: 57600f32, radius: 9.04f32, temp: 30200f32, color: Color::rgb(0.603921568627451f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "O9VI", mass: 6.7f32, luminosity: 33100f32, radius: 6.85f32, temp: 30200f32, color: Color::rgb(0.603921568627451f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "B0Ia0", mass: 50.1f32, luminosity: 2280000f32, radius: 83f32, temp: 25000f32, color: Color::rgb(0.631372549019608f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "B0Ia", mass: 44.7f32, luminosity: 573000f32, radius: 41.6f32, temp: 25000f32, color: Color::rgb(0.631372549019608f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "B0Ib", mass: 39.2f32, luminosity: 228000f32, radius: 26.2f32, temp: 25000f32, color: Color::rgb(0.631372549019608f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "B0II", mass: 33.8f32, luminosity: 190000f32, radius: 19.1f32, temp: 28000f32, color: Color::rgb(0.635294117647059f32, 0.737254901960784f32, 1f32) },
-    StellarClass { name: "B0III", mass: 28.4f32, luminosity: 109000f32, radius: 14.5f32, temp: 28000f32, color: Color::rgb(0.619607843137255f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "B0IV", mass: 22.9f32, luminosity: 75700f32, radius: 12.1f32, temp: 28000f32, color: Color::rgb(0.611764705882353f32, 0.701960784313725f32, 1f32) },
-    StellarClass { name: "B0V", mass: 17.5f32, luminosity: 36200f32, radius: 8.34f32, temp: 28000f32, color: Color::rgb(0.611764705882353f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "B0VI", mass: 5.9f32, luminosity: 19000f32, radius: 6.04f32, temp: 28000f32, color: Color::rgb(0.611764705882353f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "B1Ia0", mass: 45.1f32, luminosity: 2020000f32, radius: 86.2f32, temp: 23790f32, color: Color::rgb(0.658823529411765f32, 0.756862745098039f32, 1f32) },
-    StellarClass { name: "B1Ia", mass: 40f32, luminosity: 507000f32, radius: 43.2f32, temp: 23790f32, color: Color::rgb(0.658823529411765f32, 0.756862745098039f32, 1f32) },
-    StellarClass { name: "B1Ib", mass: 34.8f32, luminosity: 184000f32, radius: 26f32, temp: 23790f32, color: Color::rgb(0.658823529411765f32, 0.756862745098039f32, 1f32) },
-    StellarClass { name: "B1II", mass: 29.7f32, luminosity: 134000f32, radius: 18.3f32, temp: 26190f32, color: Color::rgb(0.63921568627451f32, 0.745098039215686f32, 1f32) },
-    StellarClass { name: "B1III", mass: 24.5f32, luminosity: 53400f32, radius: 11.6f32, temp: 26190f32, color: Color::rgb(0.619607843137255f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "B1IV", mass: 19.4f32, luminosity: 37000f32, radius: 9.63f32, temp: 26190f32, color: Color::rgb(0.615686274509804f32, 0.705882352941177f32, 1f32) },
-    StellarClass { name: "B1V", mass: 14.2f32, luminosity: 19400f32, radius: 6.97f32, temp: 26190f32, color: Color::rgb(0.627450980392157f32, 0.713725490196078f32, 1f32) },
-    StellarClass { name: "B1VI", mass: 5.2f32, luminosity: 10200f32, radius: 5.05f32, temp: 26190f32, color: Color::rgb(0.627450980392157f32, 0.713725490196078f32, 1f32) },
-    StellarClass { name: "B2Ia0", mass: 40.1f32, luminosity: 1620000f32, radius: 85.7f32, temp: 22580f32, color: Color::rgb(0.694117647058824f32, 0.768627450980392f32, 1f32) },
-    StellarClass { name: "B2Ia", mass: 35.2f32, luminosity: 446000f32, radius: 45f32, temp: 22580f32, color: Color::rgb(0.694117647058824f32, 0.768627450980392f32, 1f32) },
-    StellarClass { name: "B2Ib", mass: 30.4f32, luminosity: 162000f32, radius: 27.1f32, temp: 22580f32, color: Color::rgb(0.694117647058824f32, 0.768627450980392f32, 1f32) },
-    StellarClass { name: "B2II", mass: 25.5f32, luminosity: 93600f32, radius: 17.7f32, temp: 24380f32, color: Color::rgb(0.647058823529412f32, 0.752941176470588f32, 1f32) },
-    StellarClass { name: "B2III", mass: 20.6f32, luminosity: 28300f32, radius: 9.71f32, temp: 24380f32, color: Color::rgb(0.623529411764706f32, 0.705882352941177f32, 1f32) },
-    StellarClass { name: "B2IV", mass: 15.8f32, luminosity: 19600f32, radius: 8.08f32, temp: 24380f32, color: Color::rgb(0.623529411764706f32, 0.701960784313725f32, 1f32) },
-    StellarClass { name: "B2V", mass: 10.9f32, luminosity: 9360f32, radius: 5.59f32, temp: 24380f32, color: Color::rgb(0.627450980392157f32, 0.705882352941177f32, 1f32) },
-    StellarClass { name: "B2VI", mass: 4.5f32, luminosity: 5390f32, radius: 4.24f32, temp: 24380f32, color: Color::rgb(0.627450980392157f32, 0.705882352941177f32, 1f32) },
-    StellarClass { name: "B3Ia0", mass: 35.1f32, luminosity: 1420000f32, radius: 89.5f32, temp: 21370f32, color: Color::rgb(0.686274509803922f32, 0.76078431372549f32, 1f32) },
-    StellarClass { name: "B3Ia", mass: 30.5f32, luminosity: 428000f32, radius: 49.2f32, temp: 21370f32, color: Color::rgb(0.686274509803922f32, 0.76078431372549f32, 1f32) },
-    StellarClass { name: "B3Ib", mass: 25.9f32, luminosity: 129000f32, radius: 27f32, temp: 21370f32, color: Color::rgb(0.686274509803922f32, 0.76078431372549f32, 1f32) },
-    StellarClass { name: "B3II", mass: 21.4f32, luminosity: 64500f32, radius: 17.1f32, temp: 22570f32, color: Color::rgb(0.658823529411765f32, 0.756862745098039f32, 1f32) },
-    StellarClass { name: "B3III", mass: 16.8f32, luminosity: 13500f32, radius: 7.82f32, temp: 22570f32, color: Color::rgb(0.63921568627451f32, 0.733333333333333f32, 1f32) },
-    StellarClass { name: "B3IV", mass: 12.2f32, luminosity: 9320f32, radius: 6.51f32, temp: 22570f32, color: Color::rgb(0.650980392156863f32, 0.737254901960784f32, 1f32) },
-    StellarClass { name: "B3V", mass: 7.6f32, luminosity: 4890f32, radius: 4.71f32, temp: 22570f32, color: Color::rgb(0.647058823529412f32, 0.725490196078431f32, 1f32) },
-    StellarClass { name: "B3VI", mass: 3.8f32, luminosity: 2570f32, radius: 3.41f32, temp: 22570f32, color: Color::rgb(0.647058823529412f32, 0.725490196078431f32, 1f32) },
-    StellarClass { name: "B4Ia0", mass: 30.1f32, luminosity: 1230000f32, radius: 93.7f32, temp: 20160f32, color: Color::rgb(0.733333333333333f32, 0.796078431372549f32, 1f32) },
-    StellarClass { name: "B4Ia", mass: 26.2f32, luminosity: 338000f32, radius: 49.2f32, temp: 20160f32, color: Color::rgb(0.733333333333333f32, 0.796078431372549f32, 1f32) },
-    StellarClass { name: "B4Ib", mass: 22.3f32, luminosity: 112000f32, radius: 28.3f32, temp: 20160f32, color: Color::rgb(0.733333333333333f32, 0.796078431372549f32, 1f32) },
-    StellarClass { name: "B4II", mass: 18.4f32, luminosity: 43700f32, radius: 16.7f32, temp: 20760f32, color: Color::rgb(0.674509803921569f32, 0.76078431372549f32, 1f32) },
-    StellarClass { name: "B4III", mass: 14.5f32, luminosity: 6930f32, radius: 6.63f32, temp: 20760f32, color: Color::rgb(0.650980392156863f32, 0.737254901960784f32, 1f32) },
-    StellarClass { name: "B4IV", mass: 10.6f32, luminosity: 4790f32, radius: 5.52f32, temp: 20760f32, color: Color::rgb(0.662745098039216f32, 0.745098039215686f32, 1f32) },
-    StellarClass { name: "B4V", mass: 6.7f32, luminosity: 2290f32, radius: 3.82f32, temp: 20760f32, color: Color::rgb(0.643137254901961f32, 0.72156862745098f32, 1f32) },
-    StellarClass { name: "B4VI", mass: 3.4f32, luminosity: 1320f32, radius: 2.89f32, temp: 20760f32, color: Color::rgb(0.643137254901961f32, 0.72156862745098f32, 1f32) },
-    StellarClass { name: "B5Ia0", mass: 25.1f32, luminosity: 965000f32, radius: 93.9f32, temp: 18950f32, color: Color::rgb(0.701960784313725f32, 0.792156862745098f32, 1f32) },
-    StellarClass { name: "B5Ia", mass: 21.9f32, luminosity: 291000f32, radius: 51.6f32, temp: 18950f32, color: Color::rgb(0.701960784313725f32, 0.792156862745098f32, 1f32) },
-    StellarClass { name: "B5Ib", mass: 18.7f32, luminosity: 88000f32, radius: 28.4f32, temp: 18950f32, color: Color::rgb(0.701960784313725f32, 0.792156862745098f32, 1f32) },
-    StellarClass { name: "B5II", mass: 15.5f32, luminosity: 29100f32, radius: 16.3f32, temp: 18950f32, color: Color::rgb(0.686274509803922f32, 0.764705882352941f32, 1f32) },
-    StellarClass { name: "B5III", mass: 12.3f32, luminosity: 3190f32, radius: 5.4f32, temp: 18950f32, color: Color::rgb(0.658823529411765f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "B5IV", mass: 9.1f32, luminosity: 2210f32, radius: 4.5f32, temp: 18950f32, color: Color::rgb(0.674509803921569f32, 0.752941176470588f32, 1f32) },
-    StellarClass { name: "B5V", mass: 5.9f32, luminosity: 1160f32, radius: 3.26f32, temp: 18950f32, color: Color::rgb(0.666666666666667f32, 0.749019607843137f32, 1f32) },
-    StellarClass { name: "B5VI", mass: 2.9f32, luminosity: 667f32, radius: 2.47f32, temp: 18950f32, color: Color::rgb(0.666666666666667f32, 0.749019607843137f32, 1f32) },
-    StellarClass { name: "B6Ia0", mass: 23.2f32, luminosity: 758000f32, radius: 102f32, temp: 17140f32, color: Color::rgb(0.749019607843137f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "B6Ia", mass: 20.2f32, luminosity: 229000f32, radius: 55.9f32, temp: 17140f32, color: Color::rgb(0.749019607843137f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "B6Ib", mass: 17.2f32, luminosity: 63100f32, radius: 29.4f32, temp: 17140f32, color: Color::rgb(0.749019607843137f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "B6II", mass: 14.2f32, luminosity: 17400f32, radius: 15.4f32, temp: 17140f32, color: Color::rgb(0.694117647058824f32, 0.768627450980392f32, 1f32) },
-    StellarClass { name: "B6III", mass: 11.2f32, luminosity: 1740f32, radius: 4.87f32, temp: 17140f32, color: Color::rgb(0.666666666666667f32, 0.745098039215686f32, 1f32) },
-    StellarClass { name: "B6IV", mass: 8.2f32, luminosity: 1200f32, radius: 4.05f32, temp: 17140f32, color: Color::rgb(0.686274509803922f32, 0.76078431372549f32, 1f32) },
-    StellarClass { name: "B6V", mass: 5.2f32, luminosity: 692f32, radius: 3.07f32, temp: 17140f32, color: Color::rgb(0.674509803921569f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "B6VI", mass: 2.7f32, luminosity: 363f32, radius: 2.23f32, temp: 17140f32, color: Color::rgb(0.674509803921569f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "B7Ia0", mass: 21.4f32, luminosity: 533000f32, radius: 107f32, temp: 15330f32, color: Color::rgb(0.764705882352941f32, 0.819607843137255f32, 1f32) },
-    StellarClass { name: "B7Ia", mass: 18.6f32, luminosity: 193000f32, radius: 64.3f32, temp: 15330f32, color: Color::rgb(0.764705882352941f32, 0.819607843137255f32, 1f32) },
-    StellarClass { name: "B7Ib", mass: 15.8f32, luminosity: 44300f32, radius: 30.8f32, temp: 15330f32, color: Color::rgb(0.764705882352941f32, 0.819607843137255f32, 1f32) },
-    StellarClass { name: "B7II", mass: 12.9f32, luminosity: 11100f32, radius: 15.4f32, temp: 15330f32, color: Color::rgb(0.701960784313725f32, 0.776470588235294f32, 1f32) },
-    StellarClass { name: "B7III", mass: 10.1f32, luminosity: 1010f32, radius: 4.66f32, temp: 15330f32, color: Color::rgb(0.670588235294118f32, 0.749019607843137f32, 1f32) },
-    StellarClass { name: "B7IV", mass: 7.3f32, luminosity: 640f32, radius: 3.7f32, temp: 15330f32, color: Color::rgb(0.666666666666667f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "B7V", mass: 4.5f32, luminosity: 404f32, radius: 2.94f32, temp: 15330f32, color: Color::rgb(0.67843137254902f32, 0.749019607843137f32, 1f32) },
-    StellarClass { name: "B7VI", mass: 2.5f32, luminosity: 193f32, radius: 2.03f32, temp: 15330f32, color: Color::rgb(0.67843137254902f32, 0.749019607843137f32, 1f32) },
-    StellarClass { name: "B8Ia0", mass: 19.5f32, luminosity: 367000f32, radius: 114f32, temp: 13520f32, color: Color::rgb(0.713725490196078f32, 0.807843137254902f32, 1f32) },
-    StellarClass { name: "B8Ia", mass: 16.9f32, luminosity: 160000f32, radius: 75.2f32, temp: 13520f32, color: Color::rgb(0.713725490196078f32, 0.807843137254902f32, 1f32) },
-    StellarClass { name: "B8Ib", mass: 14.3f32, luminosity: 33400f32, radius: 34.4f32, temp: 13520f32, color: Color::rgb(0.713725490196078f32, 0.807843137254902f32, 1f32) },
-    StellarClass { name: "B8II", mass: 11.7f32, luminosity: 6990f32, radius: 15.7f32, temp: 13520f32, color: Color::rgb(0.709803921568627f32, 0.780392156862745f32, 1f32) },
-    StellarClass { name: "B8III", mass: 9f32, luminosity: 530f32, radius: 4.33f32, temp: 13520f32, color: Color::rgb(0.686274509803922f32, 0.756862745098039f32, 1f32) },
-    StellarClass { name: "B8IV", mass: 6.4f32, luminosity: 334f32, radius: 3.44f32, temp: 13520f32, color: Color::rgb(0.686274509803922f32, 0.756862745098039f32, 1f32) },
-    StellarClass { name: "B8V", mass: 3.8f32, luminosity: 211f32, radius: 2.73f32, temp: 13520f32, color: Color::rgb(0.694117647058824f32, 0.764705882352941f32, 1f32) },
-    StellarClass { name: "B8VI", mass: 2.4f32, luminosity: 101f32, radius: 1.89f32, temp: 13520f32, color: Color::rgb(0.694117647058824f32, 0.764705882352941f32, 1f32) },
-    StellarClass { name: "B9Ia0", mass: 17.7f32, luminosity: 273000f32, radius: 131f32, temp: 11710f32, color: Color::rgb(0.8f32, 0.847058823529412f32, 1f32) },
-    StellarClass { name: "B9Ia", mass: 15.3f32, luminosity: 131000f32, radius: 90.5f32, temp: 11710f32, color: Color::rgb(0.8f32, 0.847058823529412f32, 1f32) },
-    StellarClass { name: "B9Ib", mass: 12.9f32, luminosity: 22700f32, radius: 37.7f32, temp: 11710f32, color: Color::rgb(0.8f32, 0.847058823529412f32, 1f32) },
-    StellarClass { name: "B9II", mass: 10.5f32, luminosity: 4320f32, radius: 16.5f32, temp: 11710f32, color: Color::rgb(0.713725490196078f32, 0.788235294117647f32, 1f32) },
-    StellarClass { name: "B9III", mass: 8.1f32, luminosity: 299f32, radius: 4.33f32, temp: 11710f32, color: Color::rgb(0.698039215686274f32, 0.764705882352941f32, 1f32) },
-    StellarClass { name: "B9IV", mass: 5.7f32, luminosity: 172f32, radius: 3.29f32, temp: 11710f32, color: Color::rgb(0.705882352941177f32, 0.772549019607843f32, 1f32) },
-    StellarClass { name: "B9V", mass: 3.4f32, luminosity: 119f32, radius: 2.73f32, temp: 11710f32, color: Color::rgb(0.709803921568627f32, 0.776470588235294f32, 1f32) },
-    StellarClass { name: "B9VI", mass: 2.1f32, luminosity: 52f32, radius: 1.81f32, temp: 11710f32, color: Color::rgb(0.709803921568627f32, 0.776470588235294f32, 1f32) },
-    StellarClass { name: "A0Ia0", mass: 15.8f32, luminosity: 186000f32, radius: 151f32, temp: 9900f32, color: Color::rgb(0.733333333333333f32, 0.807843137254902f32, 1f32) },
-    StellarClass { name: "A0Ia", mass: 13.7f32, luminosity: 107000f32, radius: 114f32, temp: 9900f32, color: Color::rgb(0.733333333333333f32, 0.807843137254902f32, 1f32) },
-    StellarClass { name: "A0Ib", mass: 11.5f32, luminosity: 15400f32, radius: 43.5f32, temp: 9900f32, color: Color::rgb(0.733333333333333f32, 0.807843137254902f32, 1f32) },
-    StellarClass { name: "A0II", mass: 9.4f32, luminosity: 2680f32, radius: 18.1f32, temp: 9900f32, color: Color::rgb(0.72156862745098f32, 0.792156862745098f32, 1f32) },
-    StellarClass { name: "A0III", mass: 7.2f32, luminosity: 154f32, radius: 4.35f32, temp: 9900f32, color: Color::rgb(0.737254901960784f32, 0.803921568627451f32, 1f32) },
-    StellarClass { name: "A0IV", mass: 5.1f32, luminosity: 88.8f32, radius: 3.3f32, temp: 9900f32, color: Color::rgb(0.701960784313725f32, 0.772549019607843f32, 1f32) },
-    StellarClass { name: "A0V", mass: 2.9f32, luminosity: 67.4f32, radius: 2.88f32, temp: 9900f32, color: Color::rgb(0.725490196078431f32, 0.788235294117647f32, 1f32) },
-    StellarClass { name: "A0VI", mass: 1.9f32, luminosity: 26.8f32, radius: 1.81f32, temp: 9900f32, color: Color::rgb(0.725490196078431f32, 0.788235294117647f32, 1f32) },
-    StellarClass { name: "A1Ia0", mass: 15.2f32, luminosity: 198000f32, radius: 162f32, temp: 9707f32, color: Color::rgb(0.83921568627451f32, 0.874509803921569f32, 1f32) },
-    StellarClass { name: "A1Ia", mass: 13.1f32, luminosity: 114000f32, radius: 123f32, temp: 9707f32, color: Color::rgb(0.83921568627451f32, 0.874509803921569f32, 1f32) },
-    StellarClass { name: "A1Ib", mass: 11f32, luminosity: 15000f32, radius: 44.6f32, temp: 9707f32, color: Color::rgb(0.83921568627451f32, 0.874509803921569f32, 1f32) },
-    StellarClass { name: "A1II", mass: 8.9f32, luminosity: 2350f32, radius: 17.9f32, temp: 9650f32, color: Color::rgb(0.729411764705882f32, 0.8f32, 1f32) },
-    StellarClass { name: "A1III", mass: 6.9f32, luminosity: 124f32, radius: 4.1f32, temp: 9650f32, color: Color::rgb(0.737254901960784f32, 0.8f32, 1f32) },
-    StellarClass { name: "A1IV", mass: 4.8f32, luminosity: 71.1f32, radius: 3.11f32, temp: 9650f32, color: Color::rgb(0.717647058823529f32, 0.784313725490196f32, 1f32) },
-    StellarClass { name: "A1V", mass: 2.7f32, luminosity: 49.2f32, radius: 2.59f32, temp: 9650f32, color: Color::rgb(0.709803921568627f32, 0.780392156862745f32, 1f32) },
-    StellarClass { name: "A1VI", mass: 1.8f32, luminosity: 19.6f32, radius: 1.63f32, temp: 9650f32, color: Color::rgb(0.709803921568627f32, 0.780392156862745f32, 1f32) },
-    StellarClass { name: "A2Ia0", mass: 14.5f32, luminosity: 210000f32, radius: 174f32, temp: 9513f32, color: Color::rgb(0.780392156862745f32, 0.83921568627451f32, 1f32) },
-    StellarClass { name: "A2Ia", mass: 12.5f32, luminosity: 121000f32, radius: 132f32, temp: 9513f32, color: Color::rgb(0.780392156862745f32, 0.83921568627451f32, 1f32) },
-    StellarClass { name: "A2Ib", mass: 10.5f32, luminosity: 13300f32, radius: 43.7f32, temp: 9513f32, color: Color::rgb(0.780392156862745f32, 0.83921568627451f32, 1f32) },
-    StellarClass { name: "A2II", mass: 8.5f32, luminosity: 2070f32, radius: 17.7f32, temp: 9400f32, color: Color::rgb(0.737254901960784f32, 0.803921568627451f32, 1f32) },
-    StellarClass { name: "A2III", mass: 6.5f32, luminosity: 99f32, radius: 3.87f32, temp: 9400f32, color: Color::rgb(0.741176470588235f32, 0.8f32, 1f32) },
-    StellarClass { name: "A2IV", mass: 4.5f32, luminosity: 57f32, radius: 2.93f32, temp: 9400f32, color: Color::rgb(0.729411764705882f32, 0.792156862745098f32, 1f32) },
-    StellarClass { name: "A2V", mass: 2.5f32, luminosity: 39.4f32, radius: 2.44f32, temp: 9400f32, color: Color::rgb(0.733333333333333f32, 0.796078431372549f32, 1f32) },
-    StellarClass { name: "A2VI", mass: 1.8f32, luminosity: 15.7f32, radius: 1.54f32, temp: 9400f32, color: Color::rgb(0.733333333333333f32, 0.796078431372549f32, 1f32) },
-    StellarClass { name: "A3Ia0", mass: 13.9f32, luminosity: 224000f32, radius: 187f32, temp: 9320f32, color: Color::rgb(0.811764705882353f32, 0.858823529411765f32, 1f32) },
-    StellarClass { name: "A3Ia", mass: 12f32, luminosity: 129000f32, radius: 142f32, temp: 9320f32, color: Color::rgb(0.811764705882353f32, 0.858823529411765f32, 1f32) },
-    StellarClass { name: "A3Ib", mass: 10f32, luminosity: 12900f32, radius: 44.9f32, temp: 9320f32, color: Color::rgb(0.811764705882353f32, 0.858823529411765f32, 1f32) },
-    StellarClass { name: "A3II", mass: 8.1f32, luminosity: 1660f32, radius: 16.7f32, temp: 9150f32, color: Color::rgb(0.745098039215686f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "A3III", mass: 6.2f32, luminosity: 87.1f32, radius: 3.83f32, temp: 9150f32, color: Color::rgb(0.741176470588235f32, 0.796078431372549f32, 1f32) },
-    StellarClass { name: "A3IV", mass: 4.3f32, luminosity: 41.7f32, radius: 2.65f32, temp: 9150f32, color: Color::rgb(0.745098039215686f32, 0.803921568627451f32, 1f32) },
-    StellarClass { name: "A3V", mass: 2.4f32, luminosity: 28.9f32, radius: 2.2f32, temp: 9150f32, color: Color::rgb(0.749019607843137f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "A3VI", mass: 1.8f32, luminosity: 11.5f32, radius: 1.39f32, temp: 9150f32, color: Color::rgb(0.749019607843137f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "A4Ia0", mass: 13.2f32, luminosity: 239000f32, radius: 202f32, temp: 9127f32, color: Color::rgb(0.843137254901961f32, 0.87843137254902f32, 1f32) },
-    StellarClass { name: "A4Ia", mass: 11.4f32, luminosity: 138000f32, radius: 153f32, temp: 9127f32, color: Color::rgb(0.843137254901961f32, 0.87843137254902f32, 1f32) },
-    StellarClass { name: "A4Ib", mass: 9.5f32, luminosity: 11400f32, radius: 44.1f32, temp: 9127f32, color: Color::rgb(0.843137254901961f32, 0.87843137254902f32, 1f32) },
-    StellarClass { name: "A4II", mass: 7.7f32, luminosity: 1460f32, radius: 16.6f32, temp: 8900f32, color: Color::rgb(0.752941176470588f32, 0.815686274509804f32, 1f32) },
-    StellarClass { name: "A4III", mass: 5.8f32, luminosity: 70f32, radius: 3.63f32, temp: 8900f32, color: Color::rgb(0.768627450980392f32, 0.819607843137255f32, 1f32) },
-    StellarClass { name: "A4IV", mass: 4f32, luminosity: 33.5f32, radius: 2.51f32, temp: 8900f32, color: Color::rgb(0.764705882352941f32, 0.823529411764706f32, 1f32) },
-    StellarClass { name: "A4V", mass: 2.1f32, luminosity: 23.2f32, radius: 2.09f32, temp: 8900f32, color: Color::rgb(0.772549019607843f32, 0.827450980392157f32, 1f32) },
-    StellarClass { name: "A4VI", mass: 1.7f32, luminosity: 9.23f32, radius: 1.32f32, temp: 8900f32, color: Color::rgb(0.772549019607843f32, 0.827450980392157f32, 1f32) },
-    StellarClass { name: "A5Ia0", mass: 12.6f32, luminosity: 255000f32, radius: 217f32, temp: 8933f32, color: Color::rgb(0.874509803921569f32, 0.898039215686275f32, 1f32) },
-    StellarClass { name: "A5Ia", mass: 10.8f32, luminosity: 161000f32, radius: 173f32, temp: 8933f32, color: Color::rgb(0.874509803921569f32, 0.898039215686275f32, 1f32) },
-    StellarClass { name: "A5Ib", mass: 9f32, luminosity: 11100f32, radius: 45.4f32, temp: 8933f32, color: Color::rgb(0.874509803921569f32, 0.898039215686275f32, 1f32) },
-    StellarClass { name: "A5II", mass: 7.2f32, luminosity: 1290f32, radius: 16.5f32, temp: 8650f32, color: Color::rgb(0.76078431372549f32, 0.823529411764706f32, 1f32) },
-    StellarClass { name: "A5III", mass: 5.4f32, luminosity: 56.4f32, radius: 3.45f32, temp: 8650f32, color: Color::rgb(0.792156862745098f32, 0.843137254901961f32, 1f32) },
-    StellarClass { name: "A5IV", mass: 3.7f32, luminosity: 27f32, radius: 2.38f32, temp: 8650f32, color: Color::rgb(0.831372549019608f32, 0.862745098039216f32, 1f32) },
-    StellarClass { name: "A5V", mass: 1.9f32, luminosity: 17f32, radius: 1.89f32, temp: 8650f32, color: Color::rgb(0.792156862745098f32, 0.843137254901961f32, 1f32) },
-    StellarClass { name: "A5VI", mass: 1.6f32, luminosity: 6.78f32, radius: 1.2f32, temp: 8650f32, color: Color::rgb(0.792156862745098f32, 0.843137254901961f32, 1f32) },
-    StellarClass { name: "A6Ia0", mass: 12.6f32, luminosity: 249000f32, radius: 224f32, temp: 8740f32, color: Color::rgb(0.858823529411765f32, 0.886274509803922f32, 1f32) },
-    StellarClass { name: "A6Ia", mass: 10.8f32, luminosity: 189000f32, radius: 195f32, temp: 8740f32, color: Color::rgb(0.858823529411765f32, 0.886274509803922f32, 1f32) },
-    StellarClass { name: "A6Ib", mass: 9f32, luminosity: 10900f32, radius: 46.9f32, temp: 8740f32, color: Color::rgb(0.858823529411765f32, 0.886274509803922f32, 1f32) },
-    StellarClass { name: "A6II", mass: 7.2f32, luminosity: 1140f32, radius: 16.5f32, temp: 8400f32, color: Color::rgb(0.768627450980392f32, 0.827450980392157f32, 1f32) },
-    StellarClass { name: "A6III", mass: 5.4f32, luminosity: 45.5f32, radius: 3.28f32, temp: 8400f32, color: Color::rgb(0.819607843137255f32, 0.858823529411765f32, 1f32) },
-    StellarClass { name: "A6IV", mass: 3.6f32, luminosity: 21.8f32, radius: 2.27f32, temp: 8400f32, color: Color::rgb(0.792156862745098f32, 0.83921568627451f32, 1f32) },
-    StellarClass { name: "A6V", mass: 1.8f32, luminosity: 15.1f32, radius: 1.89f32, temp: 8400f32, color: Color::rgb(0.780392156862745f32, 0.831372549019608f32, 1f32) },
-    StellarClass { name: "A6VI", mass: 1.6f32, luminosity: 5.47f32, radius: 1.14f32, temp: 8400f32, color: Color::rgb(0.780392156862745f32, 0.831372549019608f32, 1f32) },
-    StellarClass { name: "A7Ia0", mass: 12.6f32, luminosity: 267000f32, radius: 243f32, temp: 8547f32, color: Color::rgb(0.843137254901961f32, 0.874509803921569f32, 1f32) },
-    StellarClass { name: "A7Ia", mass: 10.8f32, luminosity: 222000f32, radius: 221f32, temp: 8547f32, color: Color::rgb(0.843137254901961f32, 0.874509803921569f32, 1f32) },
-    StellarClass { name: "A7Ib", mass: 9f32, luminosity: 10600f32, radius: 48.4f32, temp: 8547f32, color: Color::rgb(0.843137254901961f32, 0.874509803921569f32, 1f32) },
-    StellarClass { name: "A7II", mass: 7.2f32, luminosity: 1110f32, radius: 17.2f32, temp: 8150f32, color: Color::rgb(0.772549019607843f32, 0.835294117647059f32, 1f32) },
-    StellarClass { name: "A7III", mass: 5.4f32, luminosity: 36.8f32, radius: 3.14f32, temp: 8150f32, color: Color::rgb(0.823529411764706f32, 0.858823529411765f32, 1f32) },
-    StellarClass { name: "A7IV", mass: 3.6f32, luminosity: 19.3f32, radius: 2.27f32, temp: 8150f32, color: Color::rgb(0.752941176470588f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "A7V", mass: 1.8f32, luminosity: 12.2f32, radius: 1.81f32, temp: 8150f32, color: Color::rgb(0.784313725490196f32, 0.835294117647059f32, 1f32) },
-    StellarClass { name: "A7VI", mass: 1.5f32, luminosity: 4.43f32, radius: 1.09f32, temp: 8150f32, color: Color::rgb(0.784313725490196f32, 0.835294117647059f32, 1f32) },
-    StellarClass { name: "A8Ia0", mass: 12.6f32, luminosity: 260000f32, radius: 251f32, temp: 8353f32, color: Color::rgb(0.823529411764706f32, 0.866666666666667f32, 1f32) },
-    StellarClass { name: "A8Ia", mass: 10.8f32, luminosity: 238000f32, radius: 240f32, temp: 8353f32, color: Color::rgb(0.823529411764706f32, 0.866666666666667f32, 1f32) },
-    StellarClass { name: "A8Ib", mass: 9f32, luminosity: 10400f32, radius: 50.1f32, temp: 8353f32, color: Color::rgb(0.823529411764706f32, 0.866666666666667f32, 1f32) },
-    StellarClass { name: "A8II", mass: 7.2f32, luminosity: 990f32, radius: 17.3f32, temp: 7900f32, color: Color::rgb(0.780392156862745f32, 0.83921568627451f32, 1f32) },
-    StellarClass { name: "A8III", mass: 5.4f32, luminosity: 32.8f32, radius: 3.15f32, temp: 7900f32, color: Color::rgb(0.819607843137255f32, 0.858823529411765f32, 1f32) },
-    StellarClass { name: "A8IV", mass: 3.6f32, luminosity: 15.7f32, radius: 2.18f32, temp: 7900f32, color: Color::rgb(0.815686274509804f32, 0.850980392156863f32, 1f32) },
-    StellarClass { name: "A8V", mass: 1.8f32, luminosity: 10.9f32, radius: 1.81f32, temp: 7900f32, color: Color::rgb(0.835294117647059f32, 0.870588235294118f32, 1f32) },
-    StellarClass { name: "A8VI", mass: 1.5f32, luminosity: 3.59f32, radius: 1.04f32, temp: 7900f32, color: Color::rgb(0.835294117647059f32, 0.870588235294118f32, 1f32) },
-    StellarClass { name: "A9Ia0", mass: 12.6f32, luminosity: 280000f32, radius: 273f32, temp: 8160f32, color: Color::rgb(0.807843137254902f32, 0.854901960784314f32, 1f32) },
-    StellarClass { name: "A9Ia", mass: 10.8f32, luminosity: 233000f32, radius: 249f32, temp: 8160f32, color: Color::rgb(0.807843137254902f32, 0.854901960784314f32, 1f32) },
-    StellarClass { name: "A9Ib", mass: 9f32, luminosity: 10200f32, radius: 52f32, temp: 8160f32, color: Color::rgb(0.807843137254902f32, 0.854901960784314f32, 1f32) },
-    StellarClass { name: "A9II", mass: 7.1f32, luminosity: 970f32, radius: 18.3f32, temp: 7650f32, color: Color::rgb(0.788235294117647f32, 0.847058823529412f32, 1f32) },
-    StellarClass { name: "A9III", mass: 5.3f32, luminosity: 26.7f32, radius: 3.03f32, temp: 7650f32, color: Color::rgb(0.819607843137255f32, 0.858823529411765f32, 1f32) },
-    StellarClass { name: "A9IV", mass: 3.5f32, luminosity: 14f32, radius: 2.2f32, temp: 7650f32, color: Color::rgb(0.87843137254902f32, 0.890196078431372f32, 1f32) },
-    StellarClass { name: "A9V", mass: 1.7f32, luminosity: 8.85f32, radius: 1.75f32, temp: 7650f32, color: Color::rgb(0.858823529411765f32, 0.87843137254902f32, 1f32) },
-    StellarClass { name: "A9VI", mass: 1.4f32, luminosity: 2.93f32, radius: 1f32, temp: 7650f32, color: Color::rgb(0.858823529411765f32, 0.87843137254902f32, 1f32) },
-    StellarClass { name: "F0Ia0", mass: 12.6f32, luminosity: 274000f32, radius: 283f32, temp: 7967f32, color: Color::rgb(0.792156862745098f32, 0.843137254901961f32, 1f32) },
-    StellarClass { name: "F0Ia", mass: 10.8f32, luminosity: 228000f32, radius: 258f32, temp: 7967f32, color: Color::rgb(0.792156862745098f32, 0.843137254901961f32, 1f32) },
-    StellarClass { name: "F0Ib", mass: 8.9f32, luminosity: 9960f32, radius: 54f32, temp: 7967f32, color: Color::rgb(0.792156862745098f32, 0.843137254901961f32, 1f32) },
-    StellarClass { name: "F0II", mass: 7.1f32, luminosity: 870f32, radius: 18.5f32, temp: 7400f32, color: Color::rgb(0.796078431372549f32, 0.850980392156863f32, 1f32) },
-    StellarClass { name: "F0III", mass: 5.3f32, luminosity: 21.9f32, radius: 2.93f32, temp: 7400f32, color: Color::rgb(0.835294117647059f32, 0.870588235294118f32, 1f32) },
-    StellarClass { name: "F0IV", mass: 3.4f32, luminosity: 11.5f32, radius: 2.12f32, temp: 7400f32, color: Color::rgb(0.854901960784314f32, 0.87843137254902f32, 1f32) },
-    StellarClass { name: "F0V", mass: 1.6f32, luminosity: 7.94f32, radius: 1.77f32, temp: 7400f32, color: Color::rgb(0.87843137254902f32, 0.898039215686275f32, 1f32) },
-    StellarClass { name: "F0VI", mass: 1.4f32, luminosity: 2.4f32, radius: 0.971f32, temp: 7400f32, color: Color::rgb(0.87843137254902f32, 0.898039215686275f32, 1f32) },
-    StellarClass { name: "F1Ia0", mass: 12.1f32, luminosity: 296000f32, radius: 309f32, temp: 7773f32, color: Color::rgb(0.874509803921569f32, 0.898039215686275f32, 1f32) },
-    StellarClass { name: "F1Ia", mass: 10.3f32, luminosity: 224000f32, radius: 269f32, temp: 7773f32, color: Color::rgb(0.874509803921569f32, 0.898039215686275f32, 1f32) },
-    StellarClass { name: "F1Ib", mass: 8.6f32, luminosity: 9790f32, radius: 56.2f32, temp: 7773f32, color: Color::rgb(0.874509803921569f32, 0.898039215686275f32, 1f32) },
-    StellarClass { name: "F1II", mass: 6.8f32, luminosity: 865f32, radius: 19.2f32, temp: 7260f32, color: Color::rgb(0.847058823529412f32, 0.882352941176471f32, 1f32) },
-    StellarClass { name: "F1III", mass: 5.1f32, luminosity: 21.7f32, radius: 3.04f32, temp: 7260f32, color: Color::rgb(0.890196078431372f32, 0.909803921568627f32, 1f32) },
-    StellarClass { name: "F1IV", mass: 3.3f32, luminosity: 12.5f32, radius: 2.3f32, temp: 7260f32, color: Color::rgb(0.874509803921569f32, 0.890196078431372f32, 1f32) },
-    StellarClass { name: "F1V", mass: 1.6f32, luminosity: 6.56f32, radius: 1.67f32, temp: 7260f32, color: Color::rgb(0.901960784313726f32, 0.917647058823529f32, 1f32) },
-    StellarClass { name: "F1VI", mass: 1.3f32, luminosity: 1.98f32, radius: 0.917f32, temp: 7260f32, color: Color::rgb(0.901960784313726f32, 0.917647058823529f32, 1f32) },
-    StellarClass { name: "F2Ia0", mass: 11.6f32, luminosity: 291000f32, radius: 323f32, temp: 7580f32, color: Color::rgb(0.956862745098039f32, 0.952941176470588f32, 1f32) },
-    StellarClass { name: "F2Ia", mass: 9.9f32, luminosity: 202000f32, radius: 268f32, temp: 7580f32, color: Color::rgb(0.956862745098039f32, 0.952941176470588f32, 1f32) },
-    StellarClass { name: "F2Ib", mass: 8.2f32, luminosity: 8800f32, radius: 56.1f32, temp: 7580f32, color: Color::rgb(0.956862745098039f32, 0.952941176470588f32, 1f32) },
-    StellarClass { name: "F2II", mass: 6.5f32, luminosity: 860f32, radius: 19.9f32, temp: 7120f32, color: Color::rgb(0.898039215686275f32, 0.913725490196078f32, 1f32) },
-    StellarClass { name: "F2III", mass: 4.9f32, luminosity: 19.7f32, radius: 3.01f32, temp: 7120f32, color: Color::rgb(0.945098039215686f32, 0.945098039215686f32, 1f32) },
-    StellarClass { name: "F2IV", mass: 3.2f32, luminosity: 14.9f32, radius: 2.62f32, temp: 7120f32, color: Color::rgb(0.890196078431372f32, 0.901960784313726f32, 1f32) },
-    StellarClass { name: "F2V", mass: 1.5f32, luminosity: 5.95f32, radius: 1.65f32, temp: 7120f32, color: Color::rgb(0.925490196078431f32, 0.937254901960784f32, 1f32) },
-    StellarClass { name: "F2VI", mass: 1.3f32, luminosity: 1.64f32, radius: 0.867f32, temp: 7120f32, color: Color::rgb(0.925490196078431f32, 0.937254901960784f32, 1f32) },
-    StellarClass { name: "F3Ia0", mass: 11f32, luminosity: 316000f32, radius: 354f32, temp: 7387f32, color: Color::rgb(0.925490196078431f32, 0.929411764705882f32, 1f32) },
-    StellarClass { name: "F3Ia", mass: 9.4f32, luminosity: 199000f32, radius: 281f32, temp: 7387f32, color: Color::rgb(0.925490196078431f32, 0.929411764705882f32, 1f32) },
-    StellarClass { name: "F3Ib", mass: 7.9f32, luminosity: 8700f32, radius: 58.7f32, temp: 7387f32, color: Color::rgb(0.925490196078431f32, 0.929411764705882f32, 1f32) },
-    StellarClass { name: "F3II", mass: 6.3f32, luminosity: 782f32, radius: 19.7f32, temp: 6980f32, color: Color::rgb(0.92156862745098f32, 0.929411764705882f32, 1f32) },
-    StellarClass { name: "F3III", mass: 4.7f32, luminosity: 19.6f32, radius: 3.12f32, temp: 6980f32, color: Color::rgb(0.945098039215686f32, 0.945098039215686f32, 1f32) },
-    StellarClass { name: "F3IV", mass: 3.1f32, luminosity: 16.3f32, radius: 2.85f32, temp: 6980f32, color: Color::rgb(0.890196078431372f32, 0.901960784313726f32, 1f32) },
-    StellarClass { name: "F3V", mass: 1.5f32, luminosity: 4.94f32, radius: 1.57f32, temp: 6980f32, color: Color::rgb(0.901960784313726f32, 0.913725490196078f32, 1f32) },
-    StellarClass { name: "F3VI", mass: 1.2f32, luminosity: 1.49f32, radius: 0.86f32, temp: 6980f32, color: Color::rgb(0.901960784313726f32, 0.913725490196078f32, 1f32) },
-    StellarClass { name: "F4Ia0", mass: 10.5f32, luminosity: 343000f32, radius: 389f32, temp: 7193f32, color: Color::rgb(0.890196078431372f32, 0.905882352941176f32, 1f32) },
-    StellarClass { name: "F4Ia", mass: 9f32, luminosity: 180000f32, radius: 282f32, temp: 7193f32, color: Color::rgb(0.890196078431372f32, 0.905882352941176f32, 1f32) },
-    StellarClass { name: "F4Ib", mass: 7.5f32, luminosity: 7860f32, radius: 58.9f32, temp: 7193f32, color: Color::rgb(0.890196078431372f32, 0.905882352941176f32, 1f32) },
-    StellarClass { name: "F4II", mass: 6f32, luminosity: 781f32, radius: 20.5f32, temp: 6840f32, color: Color::rgb(0.945098039215686f32, 0.945098039215686f32, 1f32) },
-    StellarClass { name: "F4III", mass: 4.5f32, luminosity: 19.6f32, radius: 3.25f32, temp: 6840f32, color: Color::rgb(0.945098039215686f32, 0.941176470588235f32, 1f32) },
-    StellarClass { name: "F4IV", mass: 3f32, luminosity: 19.6f32, radius: 3.25f32, temp: 6840f32, color: Color::rgb(0.917647058823529f32, 0.92156862745098f32, 1f32) },
-    StellarClass { name: "F4V", mass: 1.4f32, luminosity: 4.5f32, radius: 1.56f32, temp: 6840f32, color: Color::rgb(0.87843137254902f32, 0.886274509803922f32, 1f32) },
-    StellarClass { name: "F4VI", mass: 1.1f32, luminosity: 1.24f32, radius: 0.817f32, temp: 6840f32, color: Color::rgb(0.87843137254902f32, 0.886274509803922f32, 1f32) },
-    StellarClass { name: "F5Ia0", mass: 10f32, luminosity: 374000f32, radius: 429f32, temp: 7000f32, color: Color::rgb(0.858823529411765f32, 0.882352941176471f32, 1f32) },
-    StellarClass { name: "F5Ia", mass: 8.6f32, luminosity: 163000f32, radius: 283f32, temp: 7000f32, color: Color::rgb(0.858823529411765f32, 0.882352941176471f32, 1f32) },
-    StellarClass { name: "F5Ib", mass: 7.1f32, luminosity: 7820f32, radius: 62f32, temp: 7000f32, color: Color::rgb(0.858823529411765f32, 0.882352941176471f32, 1f32) },
-    StellarClass { name: "F5II", mass: 5.7f32, luminosity: 783f32, radius: 21.4f32, temp: 6700f32, color: Color::rgb(0.968627450980392f32, 0.949019607843137f32, 1f32) },
-    StellarClass { name: "F5III", mass: 4.3f32, luminosity: 21.6f32, radius: 3.55f32, temp: 6700f32, color: Color::rgb(0.949019607843137f32, 0.941176470588235f32, 1f32) },
-    StellarClass { name: "F5IV", mass: 2.8f32, luminosity: 21.6f32, radius: 3.55f32, temp: 6700f32, color: Color::rgb(0.945098039215686f32, 0.937254901960784f32, 1f32) },
-    StellarClass { name: "F5V", mass: 1.4f32, luminosity: 3.75f32, radius: 1.48f32, temp: 6700f32, color: Color::rgb(0.972549019607843f32, 0.968627450980392f32, 1f32) },
-    StellarClass { name: "F5VI", mass: 1.1f32, luminosity: 1.03f32, radius: 0.777f32, temp: 6700f32, color: Color::rgb(0.972549019607843f32, 0.968627450980392f32, 1f32) },
-    StellarClass { name: "F6Ia0", mass: 10f32, luminosity: 374000f32, radius: 460f32, temp: 6760f32, color: Color::rgb(1f32, 0.917647058823529f32, 0.988235294117647f32) },
-    StellarClass { name: "F6Ia", mass: 8.6f32, luminosity: 149000f32, radius: 290f32, temp: 6760f32, color: Color::rgb(1f32, 0.917647058823529f32, 0.988235294117647f32) },
-    StellarClass { name: "F6Ib", mass: 7.1f32, luminosity: 7820f32, radius: 66.4f32, temp: 6760f32, color: Color::rgb(1f32, 0.917647058823529f32, 0.988235294117647f32) },
-    StellarClass { name: "F6II", mass: 5.7f32, luminosity: 786f32, radius: 22.4f32, temp: 6560f32, color: Color::rgb(0.992156862745098f32, 0.976470588235294f32, 1f32) },
-    StellarClass { name: "F6III", mass: 4.2f32, luminosity: 23.7f32, radius: 3.89f32, temp: 6560f32, color: Color::rgb(0.945098039215686f32, 0.941176470588235f32, 1f32) },
-    StellarClass { name: "F6IV", mass: 2.8f32, luminosity: 16.4f32, radius: 3.23f32, temp: 6560f32, color: Color::rgb(0.945098039215686f32, 0.937254901960784f32, 1f32) },
-    StellarClass { name: "F6V", mass: 1.3f32, luminosity: 3.13f32, radius: 1.41f32, temp: 6560f32, color: Color::rgb(0.956862745098039f32, 0.945098039215686f32, 1f32) },
-    StellarClass { name: "F6VI", mass: 1f32, luminosity: 0.862f32, radius: 0.741f32, temp: 6560f32, color: Color::rgb(0.956862745098039f32, 0.945098039215686f32, 1f32) },
-    StellarClass { name: "F7Ia0", mass: 10f32, luminosity: 413000f32, radius: 519f32, temp: 6520f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.980392156862745f32) },
-    StellarClass { name: "F7Ia", mass: 8.5f32, luminosity: 137000f32, radius: 299f32, temp: 6520f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.980392156862745f32) },
-    StellarClass { name: "F7Ib", mass: 7.1f32, luminosity: 7180f32, radius: 68.4f32, temp: 6520f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.980392156862745f32) },
-    StellarClass { name: "F7II", mass: 5.6f32, luminosity: 791f32, radius: 23.4f32, temp: 6420f32, color: Color::rgb(1f32, 0.980392156862745f32, 0.984313725490196f32) },
-    StellarClass { name: "F7III", mass: 4.2f32, luminosity: 26.2f32, radius: 4.26f32, temp: 6420f32, color: Color::rgb(0.945098039215686f32, 0.941176470588235f32, 1f32) },
-    StellarClass { name: "F7IV", mass: 2.7f32, luminosity: 12.5f32, radius: 2.95f32, temp: 6420f32, color: Color::rgb(0.941176470588235f32, 0.937254901960784f32, 1f32) },
-    StellarClass { name: "F7V", mass: 1.3f32, luminosity: 2.62f32, radius: 1.35f32, temp: 6420f32, color: Color::rgb(0.964705882352941f32, 0.952941176470588f32, 1f32) },
-    StellarClass { name: "F7VI", mass: 1f32, luminosity: 0.791f32, radius: 0.741f32, temp: 6420f32, color: Color::rgb(0.964705882352941f32, 0.952941176470588f32, 1f32) },
-    StellarClass { name: "F8Ia0", mass: 10f32, luminosity: 419000f32, radius: 564f32, temp: 6280f32, color: Color::rgb(1f32, 0.988235294117647f32, 0.968627450980392f32) },
-    StellarClass { name: "F8Ia", mass: 8.5f32, luminosity: 127000f32, radius: 310f32, temp: 6280f32, color: Color::rgb(1f32, 0.988235294117647f32, 0.968627450980392f32) },
-    StellarClass { name: "F8Ib", mass: 7.1f32, luminosity: 7290f32, radius: 74.3f32, temp: 6280f32, color: Color::rgb(1f32, 0.988235294117647f32, 0.968627450980392f32) },
-    StellarClass { name: "F8II", mass: 5.6f32, luminosity: 729f32, radius: 23.5f32, temp: 6280f32, color: Color::rgb(1f32, 0.972549019607843f32, 0.96078431372549f32) },
-    StellarClass { name: "F8III", mass: 4.1f32, luminosity: 29f32, radius: 4.69f32, temp: 6280f32, color: Color::rgb(0.988235294117647f32, 0.972549019607843f32, 1f32) },
-    StellarClass { name: "F8IV", mass: 2.7f32, luminosity: 10.5f32, radius: 2.83f32, temp: 6280f32, color: Color::rgb(1f32, 0.988235294117647f32, 0.992156862745098f32) },
-    StellarClass { name: "F8V", mass: 1.2f32, luminosity: 2.41f32, radius: 1.35f32, temp: 6280f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.988235294117647f32) },
-    StellarClass { name: "F8VI", mass: 1f32, luminosity: 0.665f32, radius: 0.71f32, temp: 6280f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.988235294117647f32) },
-    StellarClass { name: "F9Ia0", mass: 10f32, luminosity: 473000f32, radius: 654f32, temp: 6011f32, color: Color::rgb(1f32, 0.964705882352941f32, 0.913725490196078f32) },
-    StellarClass { name: "F9Ia", mass: 8.5f32, luminosity: 130000f32, radius: 343f32, temp: 6011f32, color: Color::rgb(1f32, 0.964705882352941f32, 0.913725490196078f32) },
-    StellarClass { name: "F9Ib", mass: 7f32, luminosity: 6840f32, radius: 78.6f32, temp: 6011f32, color: Color::rgb(1f32, 0.964705882352941f32, 0.913725490196078f32) },
-    StellarClass { name: "F9II", mass: 5.6f32, luminosity: 739f32, radius: 24.8f32, temp: 6140f32, color: Color::rgb(1f32, 0.964705882352941f32, 0.937254901960784f32) },
-    StellarClass { name: "F9III", mass: 4.1f32, luminosity: 32.2f32, radius: 5.17f32, temp: 6140f32, color: Color::rgb(1f32, 0.964705882352941f32, 0.96078431372549f32) },
-    StellarClass { name: "F9IV", mass: 2.6f32, luminosity: 8.1f32, radius: 2.59f32, temp: 6140f32, color: Color::rgb(1f32, 0.980392156862745f32, 0.976470588235294f32) },
-    StellarClass { name: "F9V", mass: 1.1f32, luminosity: 2.03f32, radius: 1.3f32, temp: 6140f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.988235294117647f32) },
-    StellarClass { name: "F9VI", mass: 0.9f32, luminosity: 0.614f32, radius: 0.714f32, temp: 6140f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.988235294117647f32) },
-    StellarClass { name: "G0Ia0", mass: 10f32, luminosity: 495000f32, radius: 732f32, temp: 5743f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.858823529411765f32) },
-    StellarClass { name: "G0Ia", mass: 6.3f32, luminosity: 124000f32, radius: 367f32, temp: 5743f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.858823529411765f32) },
-    StellarClass { name: "G0Ib", mass: 2.5f32, luminosity: 7150f32, radius: 88.1f32, temp: 5743f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.858823529411765f32) },
-    StellarClass { name: "G0II", mass: 2.1f32, luminosity: 784f32, radius: 29.2f32, temp: 5743f32, color: Color::rgb(1f32, 0.956862745098039f32, 0.913725490196078f32) },
-    StellarClass { name: "G0III", mass: 1.8f32, luminosity: 37.5f32, radius: 6.38f32, temp: 5743f32, color: Color::rgb(1f32, 0.949019607843137f32, 0.913725490196078f32) },
-    StellarClass { name: "G0IV", mass: 1.4f32, luminosity: 6.25f32, radius: 2.38f32, temp: 6000f32, color: Color::rgb(1f32, 0.972549019607843f32, 0.96078431372549f32) },
-    StellarClass { name: "G0V", mass: 1.1f32, luminosity: 1.72f32, radius: 1.25f32, temp: 6000f32, color: Color::rgb(1f32, 0.972549019607843f32, 0.988235294117647f32) },
-    StellarClass { name: "G0VI", mass: 0.9f32, luminosity: 0.52f32, radius: 0.688f32, temp: 6000f32, color: Color::rgb(1f32, 0.972549019607843f32, 0.988235294117647f32) },
-    StellarClass { name: "G1Ia0", mass: 10.5f32, luminosity: 527000f32, radius: 832f32, temp: 5474f32, color: Color::rgb(1f32, 0.933333333333333f32, 0.831372549019608f32) },
-    StellarClass { name: "G1Ia", mass: 6.6f32, luminosity: 132000f32, radius: 417f32, temp: 5474f32, color: Color::rgb(1f32, 0.933333333333333f32, 0.831372549019608f32) },
-    StellarClass { name: "G1Ib", mass: 2.6f32, luminosity: 7620f32, radius: 100f32, temp: 5474f32, color: Color::rgb(1f32, 0.933333333333333f32, 0.831372549019608f32) },
-    StellarClass { name: "G1II", mass: 2.2f32, luminosity: 835f32, radius: 33.1f32, temp: 5474f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.890196078431372f32) },
-    StellarClass { name: "G1III", mass: 1.8f32, luminosity: 43.8f32, radius: 7.59f32, temp: 5474f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.913725490196078f32) },
-    StellarClass { name: "G1IV", mass: 1.4f32, luminosity: 6.35f32, radius: 2.5f32, temp: 5890f32, color: Color::rgb(1f32, 0.964705882352941f32, 0.956862745098039f32) },
-    StellarClass { name: "G1V", mass: 1f32, luminosity: 1.46f32, radius: 1.19f32, temp: 5890f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.972549019607843f32) },
-    StellarClass { name: "G1VI", mass: 0.9f32, luminosity: 0.44f32, radius: 0.656f32, temp: 5890f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.972549019607843f32) },
-    StellarClass { name: "G2Ia0", mass: 11f32, luminosity: 573000f32, radius: 959f32, temp: 5206f32, color: Color::rgb(1f32, 0.925490196078431f32, 0.803921568627451f32) },
-    StellarClass { name: "G2Ia", mass: 6.9f32, luminosity: 131000f32, radius: 459f32, temp: 5206f32, color: Color::rgb(1f32, 0.925490196078431f32, 0.803921568627451f32) },
-    StellarClass { name: "G2Ib", mass: 2.8f32, luminosity: 8290f32, radius: 115f32, temp: 5206f32, color: Color::rgb(1f32, 0.925490196078431f32, 0.803921568627451f32) },
-    StellarClass { name: "G2II", mass: 2.3f32, luminosity: 909f32, radius: 38.2f32, temp: 5206f32, color: Color::rgb(1f32, 0.945098039215686f32, 0.866666666666667f32) },
-    StellarClass { name: "G2III", mass: 1.9f32, luminosity: 52.3f32, radius: 9.16f32, temp: 5206f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.913725490196078f32) },
-    StellarClass { name: "G2IV", mass: 1.4f32, luminosity: 6.48f32, radius: 2.62f32, temp: 5780f32, color: Color::rgb(1f32, 0.956862745098039f32, 0.949019607843137f32) },
-    StellarClass { name: "G2V", mass: 1f32, luminosity: 1.23f32, radius: 1.14f32, temp: 5780f32, color: Color::rgb(1f32, 0.96078431372549f32, 0.949019607843137f32) },
-    StellarClass { name: "G2VI", mass: 0.9f32, luminosity: 0.373f32, radius: 0.628f32, temp: 5780f32, color: Color::rgb(1f32, 0.96078431372549f32, 0.949019607843137f32) },
-    StellarClass { name: "G3Ia0", mass: 11.5f32, luminosity: 703000f32, radius: 1180f32, temp: 4937f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.796078431372549f32) },
-    StellarClass { name: "G3Ia", mass: 7.2f32, luminosity: 147000f32, radius: 540f32, temp: 4937f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.796078431372549f32) },
-    StellarClass { name: "G3Ib", mass: 2.9f32, luminosity: 8460f32, radius: 130f32, temp: 4937f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.796078431372549f32) },
-    StellarClass { name: "G3II", mass: 2.4f32, luminosity: 1110f32, radius: 47f32, temp: 4937f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.843137254901961f32) },
-    StellarClass { name: "G3III", mass: 1.9f32, luminosity: 70.3f32, radius: 11.8f32, temp: 4937f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.913725490196078f32) },
-    StellarClass { name: "G3IV", mass: 1.5f32, luminosity: 6.04f32, radius: 2.63f32, temp: 5670f32, color: Color::rgb(1f32, 0.933333333333333f32, 0.886274509803922f32) },
-    StellarClass { name: "G3V", mass: 1f32, luminosity: 1.15f32, radius: 1.15f32, temp: 5670f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.925490196078431f32) },
-    StellarClass { name: "G3VI", mass: 0.8f32, luminosity: 0.348f32, radius: 0.63f32, temp: 5670f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.925490196078431f32) },
-    StellarClass { name: "G4Ia0", mass: 12f32, luminosity: 813000f32, radius: 1420f32, temp: 4669f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.756862745098039f32) },
-    StellarClass { name: "G4Ia", mass: 7.5f32, luminosity: 170000f32, radius: 649f32, temp: 4669f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.756862745098039f32) },
-    StellarClass { name: "G4Ib", mass: 3f32, luminosity: 9770f32, radius: 156f32, temp: 4669f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.756862745098039f32) },
-    StellarClass { name: "G4II", mass: 2.5f32, luminosity: 1290f32, radius: 56.5f32, temp: 4669f32, color: Color::rgb(1f32, 0.929411764705882f32, 0.819607843137255f32) },
-    StellarClass { name: "G4III", mass: 2f32, luminosity: 89.1f32, radius: 14.9f32, temp: 4669f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.913725490196078f32) },
-    StellarClass { name: "G4IV", mass: 1.5f32, luminosity: 6.2f32, radius: 2.76f32, temp: 5560f32, color: Color::rgb(1f32, 0.96078431372549f32, 0.933333333333333f32) },
-    StellarClass { name: "G4V", mass: 0.9f32, luminosity: 0.982f32, radius: 1.1f32, temp: 5560f32, color: Color::rgb(1f32, 0.945098039215686f32, 0.898039215686275f32) },
-    StellarClass { name: "G4VI", mass: 0.8f32, luminosity: 0.297f32, radius: 0.605f32, temp: 5560f32, color: Color::rgb(1f32, 0.945098039215686f32, 0.898039215686275f32) },
-    StellarClass { name: "G5Ia0", mass: 12.5f32, luminosity: 1070000f32, radius: 1840f32, temp: 4400f32, color: Color::rgb(1f32, 0.901960784313726f32, 0.717647058823529f32) },
-    StellarClass { name: "G5Ia", mass: 7.8f32, luminosity: 186000f32, radius: 766f32, temp: 4400f32, color: Color::rgb(1f32, 0.901960784313726f32, 0.717647058823529f32) },
-    StellarClass { name: "G5Ib", mass: 3.2f32, luminosity: 11800f32, radius: 192f32, temp: 4400f32, color: Color::rgb(1f32, 0.901960784313726f32, 0.717647058823529f32) },
-    StellarClass { name: "G5II", mass: 2.6f32, luminosity: 1550f32, radius: 69.9f32, temp: 4400f32, color: Color::rgb(1f32, 0.92156862745098f32, 0.796078431372549f32) },
-    StellarClass { name: "G5III", mass: 2f32, luminosity: 118f32, radius: 19.2f32, temp: 4400f32, color: Color::rgb(1f32, 0.925490196078431f32, 0.827450980392157f32) },
-    StellarClass { name: "G5IV", mass: 1.5f32, luminosity: 6.38f32, radius: 2.92f32, temp: 5450f32, color: Color::rgb(1f32, 0.92156862745098f32, 0.835294117647059f32) },
-    StellarClass { name: "G5V", mass: 0.9f32, luminosity: 0.841f32, radius: 1.06f32, temp: 5450f32, color: Color::rgb(1f32, 0.956862745098039f32, 0.917647058823529f32) },
-    StellarClass { name: "G5VI", mass: 0.8f32, luminosity: 0.254f32, radius: 0.583f32, temp: 5450f32, color: Color::rgb(1f32, 0.956862745098039f32, 0.917647058823529f32) },
-    StellarClass { name: "G6Ia0", mass: 12.5f32, luminosity: 1120000f32, radius: 1930f32, temp: 4343f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.698039215686274f32) },
-    StellarClass { name: "G6Ia", mass: 7.9f32, luminosity: 195000f32, radius: 804f32, temp: 4343f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.698039215686274f32) },
-    StellarClass { name: "G6Ib", mass: 3.3f32, luminosity: 12300f32, radius: 202f32, temp: 4343f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.698039215686274f32) },
-    StellarClass { name: "G6II", mass: 2.7f32, luminosity: 1620f32, radius: 73.3f32, temp: 4343f32, color: Color::rgb(1f32, 0.913725490196078f32, 0.776470588235294f32) },
-    StellarClass { name: "G6III", mass: 2.1f32, luminosity: 123f32, radius: 20.2f32, temp: 4343f32, color: Color::rgb(1f32, 0.925490196078431f32, 0.843137254901961f32) },
-    StellarClass { name: "G6IV", mass: 1.5f32, luminosity: 6.59f32, radius: 3.09f32, temp: 5340f32, color: Color::rgb(1f32, 0.949019607843137f32, 0.917647058823529f32) },
-    StellarClass { name: "G6V", mass: 0.9f32, luminosity: 0.792f32, radius: 1.07f32, temp: 5340f32, color: Color::rgb(1f32, 0.956862745098039f32, 0.92156862745098f32) },
-    StellarClass { name: "G6VI", mass: 0.8f32, luminosity: 0.218f32, radius: 0.562f32, temp: 5340f32, color: Color::rgb(1f32, 0.956862745098039f32, 0.92156862745098f32) },
-    StellarClass { name: "G7Ia0", mass: 12.5f32, luminosity: 1180000f32, radius: 2030f32, temp: 4286f32, color: Color::rgb(1f32, 0.874509803921569f32, 0.674509803921569f32) },
-    StellarClass { name: "G7Ia", mass: 8f32, luminosity: 205000f32, radius: 846f32, temp: 4286f32, color: Color::rgb(1f32, 0.874509803921569f32, 0.674509803921569f32) },
-    StellarClass { name: "G7Ib", mass: 3.5f32, luminosity: 12900f32, radius: 212f32, temp: 4286f32, color: Color::rgb(1f32, 0.874509803921569f32, 0.674509803921569f32) },
-    StellarClass { name: "G7II", mass: 2.8f32, luminosity: 1700f32, radius: 77.1f32, temp: 4286f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.76078431372549f32) },
-    StellarClass { name: "G7III", mass: 2.2f32, luminosity: 129f32, radius: 21.2f32, temp: 4286f32, color: Color::rgb(1f32, 0.917647058823529f32, 0.811764705882353f32) },
-    StellarClass { name: "G7IV", mass: 1.5f32, luminosity: 6.84f32, radius: 3.28f32, temp: 5230f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.803921568627451f32) },
-    StellarClass { name: "G7V", mass: 0.9f32, luminosity: 0.684f32, radius: 1.04f32, temp: 5230f32, color: Color::rgb(1f32, 0.956862745098039f32, 0.92156862745098f32) },
-    StellarClass { name: "G7VI", mass: 0.7f32, luminosity: 0.206f32, radius: 0.57f32, temp: 5230f32, color: Color::rgb(1f32, 0.956862745098039f32, 0.92156862745098f32) },
-    StellarClass { name: "G8Ia0", mass: 12.5f32, luminosity: 1130000f32, radius: 2040f32, temp: 4229f32, color: Color::rgb(1f32, 0.862745098039216f32, 0.654901960784314f32) },
-    StellarClass { name: "G8Ia", mass: 8.1f32, luminosity: 196000f32, radius: 851f32, temp: 4229f32, color: Color::rgb(1f32, 0.862745098039216f32, 0.654901960784314f32) },
-    StellarClass { name: "G8Ib", mass: 3.6f32, luminosity: 14900f32, radius: 234f32, temp: 4229f32, color: Color::rgb(1f32, 0.862745098039216f32, 0.654901960784314f32) },
-    StellarClass { name: "G8II", mass: 2.9f32, luminosity: 1960f32, radius: 85.1f32, temp: 4229f32, color: Color::rgb(1f32, 0.898039215686275f32, 0.741176470588235f32) },
-    StellarClass { name: "G8III", mass: 2.2f32, luminosity: 124f32, radius: 21.4f32, temp: 4229f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.780392156862745f32) },
-    StellarClass { name: "G8IV", mass: 1.5f32, luminosity: 6.5f32, radius: 3.34f32, temp: 5120f32, color: Color::rgb(1f32, 0.913725490196078f32, 0.827450980392157f32) },
-    StellarClass { name: "G8V", mass: 0.8f32, luminosity: 0.65f32, radius: 1.06f32, temp: 5120f32, color: Color::rgb(1f32, 0.929411764705882f32, 0.870588235294118f32) },
-    StellarClass { name: "G8VI", mass: 0.7f32, luminosity: 0.179f32, radius: 0.554f32, temp: 5120f32, color: Color::rgb(1f32, 0.929411764705882f32, 0.870588235294118f32) },
-    StellarClass { name: "G9Ia0", mass: 12.5f32, luminosity: 1190000f32, radius: 2160f32, temp: 4171f32, color: Color::rgb(1f32, 0.866666666666667f32, 0.682352941176471f32) },
-    StellarClass { name: "G9Ia", mass: 8.1f32, luminosity: 207000f32, radius: 898f32, temp: 4171f32, color: Color::rgb(1f32, 0.866666666666667f32, 0.682352941176471f32) },
-    StellarClass { name: "G9Ib", mass: 3.8f32, luminosity: 15700f32, radius: 247f32, temp: 4171f32, color: Color::rgb(1f32, 0.866666666666667f32, 0.682352941176471f32) },
-    StellarClass { name: "G9II", mass: 3f32, luminosity: 2070f32, radius: 89.8f32, temp: 4171f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.72156862745098f32) },
-    StellarClass { name: "G9III", mass: 2.3f32, luminosity: 131f32, radius: 22.6f32, temp: 4171f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.768627450980392f32) },
-    StellarClass { name: "G9IV", mass: 1.6f32, luminosity: 6.8f32, radius: 3.57f32, temp: 5010f32, color: Color::rgb(1f32, 0.898039215686275f32, 0.784313725490196f32) },
-    StellarClass { name: "G9V", mass: 0.8f32, luminosity: 0.566f32, radius: 1.03f32, temp: 5010f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.866666666666667f32) },
-    StellarClass { name: "G9VI", mass: 0.7f32, luminosity: 0.171f32, radius: 0.566f32, temp: 5010f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.866666666666667f32) },
-    StellarClass { name: "K0Ia0", mass: 12.5f32, luminosity: 1260000f32, radius: 2280f32, temp: 4114f32, color: Color::rgb(1f32, 0.866666666666667f32, 0.709803921568627f32) },
-    StellarClass { name: "K0Ia", mass: 8.2f32, luminosity: 219000f32, radius: 950f32, temp: 4114f32, color: Color::rgb(1f32, 0.866666666666667f32, 0.709803921568627f32) },
-    StellarClass { name: "K0Ib", mass: 3.9f32, luminosity: 16600f32, radius: 262f32, temp: 4114f32, color: Color::rgb(1f32, 0.866666666666667f32, 0.709803921568627f32) },
-    StellarClass { name: "K0II", mass: 3.1f32, luminosity: 2190f32, radius: 95f32, temp: 4114f32, color: Color::rgb(1f32, 0.886274509803922f32, 0.705882352941177f32) },
-    StellarClass { name: "K0III", mass: 2.3f32, luminosity: 138f32, radius: 23.9f32, temp: 4114f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.745098039215686f32) },
-    StellarClass { name: "K0IV", mass: 1.6f32, luminosity: 7.16f32, radius: 3.83f32, temp: 4900f32, color: Color::rgb(1f32, 0.882352941176471f32, 0.741176470588235f32) },
-    StellarClass { name: "K0V", mass: 0.8f32, luminosity: 0.543f32, radius: 1.05f32, temp: 4900f32, color: Color::rgb(1f32, 0.933333333333333f32, 0.866666666666667f32) },
-    StellarClass { name: "K0VI", mass: 0.7f32, luminosity: 0.15f32, radius: 0.553f32, temp: 4900f32, color: Color::rgb(1f32, 0.933333333333333f32, 0.866666666666667f32) },
-    StellarClass { name: "K1Ia0", mass: 12.5f32, luminosity: 1220000f32, radius: 2300f32, temp: 4057f32, color: Color::rgb(1f32, 0.862745098039216f32, 0.694117647058824f32) },
-    StellarClass { name: "K1Ia", mass: 8.3f32, luminosity: 212000f32, radius: 960f32, temp: 4057f32, color: Color::rgb(1f32, 0.862745098039216f32, 0.694117647058824f32) },
-    StellarClass { name: "K1Ib", mass: 4.1f32, luminosity: 17600f32, radius: 277f32, temp: 4057f32, color: Color::rgb(1f32, 0.862745098039216f32, 0.694117647058824f32) },
-    StellarClass { name: "K1II", mass: 3.3f32, luminosity: 2320f32, radius: 101f32, temp: 4057f32, color: Color::rgb(1f32, 0.87843137254902f32, 0.686274509803922f32) },
-    StellarClass { name: "K1III", mass: 2.4f32, luminosity: 161f32, radius: 26.4f32, temp: 4057f32, color: Color::rgb(1f32, 0.874509803921569f32, 0.709803921568627f32) },
-    StellarClass { name: "K1IV", mass: 1.6f32, luminosity: 7.71f32, radius: 4.21f32, temp: 4760f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.670588235294118f32) },
-    StellarClass { name: "K1V", mass: 0.8f32, luminosity: 0.443f32, radius: 1.01f32, temp: 4760f32, color: Color::rgb(1f32, 0.87843137254902f32, 0.737254901960784f32) },
-    StellarClass { name: "K1VI", mass: 0.6f32, luminosity: 0.134f32, radius: 0.555f32, temp: 4760f32, color: Color::rgb(1f32, 0.87843137254902f32, 0.737254901960784f32) },
-    StellarClass { name: "K2Ia0", mass: 13.3f32, luminosity: 1300000f32, radius: 2440f32, temp: 4000f32, color: Color::rgb(1f32, 0.827450980392157f32, 0.529411764705882f32) },
-    StellarClass { name: "K2Ia", mass: 8.8f32, luminosity: 225000f32, radius: 1020f32, temp: 4000f32, color: Color::rgb(1f32, 0.827450980392157f32, 0.529411764705882f32) },
-    StellarClass { name: "K2Ib", mass: 4.3f32, luminosity: 20600f32, radius: 308f32, temp: 4000f32, color: Color::rgb(1f32, 0.827450980392157f32, 0.529411764705882f32) },
-    StellarClass { name: "K2II", mass: 3.4f32, luminosity: 2470f32, radius: 107f32, temp: 4000f32, color: Color::rgb(1f32, 0.870588235294118f32, 0.666666666666667f32) },
-    StellarClass { name: "K2III", mass: 2.5f32, luminosity: 206f32, radius: 30.8f32, temp: 4000f32, color: Color::rgb(1f32, 0.866666666666667f32, 0.686274509803922f32) },
-    StellarClass { name: "K2IV", mass: 1.6f32, luminosity: 8.38f32, radius: 4.66f32, temp: 4620f32, color: Color::rgb(1f32, 0.898039215686275f32, 0.792156862745098f32) },
-    StellarClass { name: "K2V", mass: 0.7f32, luminosity: 0.401f32, radius: 1.02f32, temp: 4620f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.768627450980392f32) },
-    StellarClass { name: "K2VI", mass: 0.6f32, luminosity: 0.121f32, radius: 0.56f32, temp: 4620f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.768627450980392f32) },
-    StellarClass { name: "K3Ia0", mass: 14.2f32, luminosity: 1460000f32, radius: 2720f32, temp: 3900f32, color: Color::rgb(1f32, 0.8f32, 0.501960784313725f32) },
-    StellarClass { name: "K3Ia", mass: 9.4f32, luminosity: 253000f32, radius: 1140f32, temp: 3900f32, color: Color::rgb(1f32, 0.8f32, 0.501960784313725f32) },
-    StellarClass { name: "K3Ib", mass: 4.6f32, luminosity: 25300f32, radius: 359f32, temp: 3900f32, color: Color::rgb(1f32, 0.8f32, 0.501960784313725f32) },
-    StellarClass { name: "K3II", mass: 3.6f32, luminosity: 2770f32, radius: 119f32, temp: 3900f32, color: Color::rgb(1f32, 0.862745098039216f32, 0.650980392156863f32) },
-    StellarClass { name: "K3III", mass: 2.6f32, luminosity: 253f32, radius: 35.9f32, temp: 3900f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.654901960784314f32) },
-    StellarClass { name: "K3IV", mass: 1.7f32, luminosity: 9.22f32, radius: 5.19f32, temp: 4480f32, color: Color::rgb(1f32, 0.858823529411765f32, 0.654901960784314f32) },
-    StellarClass { name: "K3V", mass: 0.7f32, luminosity: 0.335f32, radius: 0.99f32, temp: 4480f32, color: Color::rgb(1f32, 0.870588235294118f32, 0.764705882352941f32) },
-    StellarClass { name: "K3VI", mass: 0.6f32, luminosity: 0.101f32, radius: 0.544f32, temp: 4480f32, color: Color::rgb(1f32, 0.870588235294118f32, 0.764705882352941f32) },
-    StellarClass { name: "K4Ia0", mass: 15f32, luminosity: 1650000f32, radius: 3060f32, temp: 3800f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.462745098039216f32) },
-    StellarClass { name: "K4Ia", mass: 9.9f32, luminosity: 262000f32, radius: 1220f32, temp: 3800f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.462745098039216f32) },
-    StellarClass { name: "K4Ib", mass: 4.8f32, luminosity: 31500f32, radius: 422f32, temp: 3800f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.462745098039216f32) },
-    StellarClass { name: "K4II", mass: 3.8f32, luminosity: 3150f32, radius: 133f32, temp: 3800f32, color: Color::rgb(1f32, 0.854901960784314f32, 0.631372549019608f32) },
-    StellarClass { name: "K4III", mass: 2.7f32, luminosity: 345f32, radius: 44.2f32, temp: 3800f32, color: Color::rgb(1f32, 0.827450980392157f32, 0.572549019607843f32) },
-    StellarClass { name: "K4IV", mass: 1.7f32, luminosity: 10.3f32, radius: 5.84f32, temp: 4340f32, color: Color::rgb(1f32, 0.854901960784314f32, 0.650980392156863f32) },
-    StellarClass { name: "K4V", mass: 0.7f32, luminosity: 0.31f32, radius: 1.02f32, temp: 4340f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.709803921568627f32) },
-    StellarClass { name: "K4VI", mass: 0.5f32, luminosity: 0.0936f32, radius: 0.558f32, temp: 4340f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.709803921568627f32) },
-    StellarClass { name: "K5Ia0", mass: 15.8f32, luminosity: 1900000f32, radius: 3460f32, temp: 3700f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.603921568627451f32) },
-    StellarClass { name: "K5Ia", mass: 10.4f32, luminosity: 301000f32, radius: 1380f32, temp: 3700f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.603921568627451f32) },
-    StellarClass { name: "K5Ib", mass: 5f32, luminosity: 39600f32, radius: 499f32, temp: 3700f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.603921568627451f32) },
-    StellarClass { name: "K5II", mass: 3.9f32, luminosity: 3620f32, radius: 151f32, temp: 3700f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.611764705882353f32) },
-    StellarClass { name: "K5III", mass: 2.8f32, luminosity: 435f32, radius: 52.3f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.541176470588235f32) },
-    StellarClass { name: "K5IV", mass: 1.8f32, luminosity: 10.6f32, radius: 6.33f32, temp: 4200f32, color: Color::rgb(1f32, 0.850980392156863f32, 0.647058823529412f32) },
-    StellarClass { name: "K5V", mass: 0.7f32, luminosity: 0.266f32, radius: 1f32, temp: 4200f32, color: Color::rgb(1f32, 0.823529411764706f32, 0.631372549019608f32) },
-    StellarClass { name: "K5VI", mass: 0.5f32, luminosity: 0.088f32, radius: 0.578f32, temp: 4200f32, color: Color::rgb(1f32, 0.823529411764706f32, 0.631372549019608f32) },
-    StellarClass { name: "K6Ia0", mass: 15.8f32, luminosity: 1850000f32, radius: 3380f32, temp: 3717f32, color: Color::rgb(1f32, 0.815686274509804f32, 0.596078431372549f32) },
-    StellarClass { name: "K6Ia", mass: 10.6f32, luminosity: 294000f32, radius: 1350f32, temp: 3717f32, color: Color::rgb(1f32, 0.815686274509804f32, 0.596078431372549f32) },
-    StellarClass { name: "K6Ib", mass: 5.3f32, luminosity: 42400f32, radius: 512f32, temp: 3717f32, color: Color::rgb(1f32, 0.815686274509804f32, 0.596078431372549f32) },
-    StellarClass { name: "K6II", mass: 4.2f32, luminosity: 3530f32, radius: 148f32, temp: 3717f32, color: Color::rgb(1f32, 0.83921568627451f32, 0.596078431372549f32) },
-    StellarClass { name: "K6III", mass: 3f32, luminosity: 465f32, radius: 53.6f32, temp: 3717f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.549019607843137f32) },
-    StellarClass { name: "K6IV", mass: 1.8f32, luminosity: 12.2f32, radius: 7.26f32, temp: 4060f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.643137254901961f32) },
-    StellarClass { name: "K6V", mass: 0.6f32, luminosity: 0.211f32, radius: 0.957f32, temp: 4060f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.596078431372549f32) },
-    StellarClass { name: "K6VI", mass: 0.5f32, luminosity: 0.0767f32, radius: 0.577f32, temp: 4060f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.596078431372549f32) },
-    StellarClass { name: "K7Ia0", mass: 15.8f32, luminosity: 1810000f32, radius: 3320f32, temp: 3733f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.588235294117647f32) },
-    StellarClass { name: "K7Ia", mass: 10.7f32, luminosity: 262000f32, radius: 1260f32, temp: 3733f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.588235294117647f32) },
-    StellarClass { name: "K7Ib", mass: 5.7f32, luminosity: 45500f32, radius: 526f32, temp: 3733f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.588235294117647f32) },
-    StellarClass { name: "K7II", mass: 4.4f32, luminosity: 3450f32, radius: 145f32, temp: 3733f32, color: Color::rgb(1f32, 0.831372549019608f32, 0.576470588235294f32) },
-    StellarClass { name: "K7III", mass: 3.1f32, luminosity: 499f32, radius: 55f32, temp: 3733f32, color: Color::rgb(1f32, 0.815686274509804f32, 0.556862745098039f32) },
-    StellarClass { name: "K7IV", mass: 1.9f32, luminosity: 14.2f32, radius: 8.43f32, temp: 3920f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.63921568627451f32) },
-    StellarClass { name: "K7V", mass: 0.6f32, luminosity: 0.187f32, radius: 0.967f32, temp: 3920f32, color: Color::rgb(1f32, 0.780392156862745f32, 0.556862745098039f32) },
-    StellarClass { name: "K7VI", mass: 0.4f32, luminosity: 0.068f32, radius: 0.583f32, temp: 3920f32, color: Color::rgb(1f32, 0.780392156862745f32, 0.556862745098039f32) },
-    StellarClass { name: "K8Ia0", mass: 15.8f32, luminosity: 1770000f32, radius: 3250f32, temp: 3750f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.576470588235294f32) },
-    StellarClass { name: "K8Ia", mass: 10.9f32, luminosity: 256000f32, radius: 1230f32, temp: 3750f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.576470588235294f32) },
-    StellarClass { name: "K8Ib", mass: 6f32, luminosity: 44400f32, radius: 515f32, temp: 3750f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.576470588235294f32) },
-    StellarClass { name: "K8II", mass: 4.6f32, luminosity: 3690f32, radius: 148f32, temp: 3750f32, color: Color::rgb(1f32, 0.823529411764706f32, 0.556862745098039f32) },
-    StellarClass { name: "K8III", mass: 3.3f32, luminosity: 534f32, radius: 56.4f32, temp: 3750f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.545098039215686f32) },
-    StellarClass { name: "K8IV", mass: 1.9f32, luminosity: 17f32, radius: 9.9f32, temp: 3780f32, color: Color::rgb(1f32, 0.843137254901961f32, 0.635294117647059f32) },
-    StellarClass { name: "K8V", mass: 0.6f32, luminosity: 0.155f32, radius: 0.946f32, temp: 3780f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.682352941176471f32) },
-    StellarClass { name: "K8VI", mass: 0.3f32, luminosity: 0.0562f32, radius: 0.57f32, temp: 3780f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.682352941176471f32) },
-    StellarClass { name: "K9Ia0", mass: 15.8f32, luminosity: 1830000f32, radius: 3350f32, temp: 3725f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.568627450980392f32) },
-    StellarClass { name: "K9Ia", mass: 11.1f32, luminosity: 265000f32, radius: 1270f32, temp: 3725f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.568627450980392f32) },
-    StellarClass { name: "K9Ib", mass: 6.3f32, luminosity: 50400f32, radius: 556f32, temp: 3725f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.568627450980392f32) },
-    StellarClass { name: "K9II", mass: 4.9f32, luminosity: 3830f32, radius: 153f32, temp: 3725f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.541176470588235f32) },
-    StellarClass { name: "K9III", mass: 3.4f32, luminosity: 606f32, radius: 60.9f32, temp: 3725f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.529411764705882f32) },
-    StellarClass { name: "K9IV", mass: 2f32, luminosity: 20.7f32, radius: 11.8f32, temp: 3640f32, color: Color::rgb(1f32, 0.83921568627451f32, 0.631372549019608f32) },
-    StellarClass { name: "K9V", mass: 0.5f32, luminosity: 0.144f32, radius: 0.982f32, temp: 3640f32, color: Color::rgb(1f32, 0.792156862745098f32, 0.615686274509804f32) },
-    StellarClass { name: "K9VI", mass: 0.3f32, luminosity: 0.0433f32, radius: 0.54f32, temp: 3640f32, color: Color::rgb(1f32, 0.792156862745098f32, 0.615686274509804f32) },
-    StellarClass { name: "M0Ia0", mass: 15.8f32, luminosity: 1900000f32, radius: 3460f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "M0Ia", mass: 13.3f32, luminosity: 274000f32, radius: 1310f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "M0Ib", mass: 10.7f32, luminosity: 57300f32, radius: 600f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "M0II", mass: 8.2f32, luminosity: 3960f32, radius: 158f32, temp: 3700f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.52156862745098f32) },
-    StellarClass { name: "M0III", mass: 5.6f32, luminosity: 689f32, radius: 65.8f32, temp: 3700f32, color: Color::rgb(1f32, 0.796078431372549f32, 0.517647058823529f32) },
-    StellarClass { name: "M0IV", mass: 3.1f32, luminosity: 26f32, radius: 14.3f32, temp: 3500f32, color: Color::rgb(1f32, 0.835294117647059f32, 0.627450980392157f32) },
-    StellarClass { name: "M0V", mass: 0.5f32, luminosity: 0.125f32, radius: 0.99f32, temp: 3500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.545098039215686f32) },
-    StellarClass { name: "M0VI", mass: 0.2f32, luminosity: 0.0376f32, radius: 0.544f32, temp: 3500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.545098039215686f32) },
-    StellarClass { name: "M1Ia0", mass: 15.1f32, luminosity: 2560000f32, radius: 4460f32, temp: 3510f32, color: Color::rgb(1f32, 0.792156862745098f32, 0.541176470588235f32) },
-    StellarClass { name: "M1Ia", mass: 12.7f32, luminosity: 337000f32, radius: 1620f32, temp: 3510f32, color: Color::rgb(1f32, 0.792156862745098f32, 0.541176470588235f32) },
-    StellarClass { name: "M1Ib", mass: 10.2f32, luminosity: 77300f32, radius: 775f32, temp: 3510f32, color: Color::rgb(1f32, 0.792156862745098f32, 0.541176470588235f32) },
-    StellarClass { name: "M1II", mass: 7.8f32, luminosity: 5860f32, radius: 213f32, temp: 3510f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.501960784313725f32) },
-    StellarClass { name: "M1III", mass: 5.3f32, luminosity: 929f32, radius: 85f32, temp: 3510f32, color: Color::rgb(1f32, 0.784313725490196f32, 0.474509803921569f32) },
-    StellarClass { name: "M1IV", mass: 2.9f32, luminosity: 35.5f32, radius: 18.4f32, temp: 3333f32, color: Color::rgb(1f32, 0.831372549019608f32, 0.623529411764706f32) },
-    StellarClass { name: "M1V", mass: 0.5f32, luminosity: 0.0618f32, radius: 0.768f32, temp: 3333f32, color: Color::rgb(1f32, 0.8f32, 0.556862745098039f32) },
-    StellarClass { name: "M1VI", mass: 0.2f32, luminosity: 0.0186f32, radius: 0.422f32, temp: 3333f32, color: Color::rgb(1f32, 0.8f32, 0.556862745098039f32) },
-    StellarClass { name: "M2Ia0", mass: 14.5f32, luminosity: 3650000f32, radius: 5950f32, temp: 3320f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.407843137254902f32) },
-    StellarClass { name: "M2Ia", mass: 12.1f32, luminosity: 481000f32, radius: 2160f32, temp: 3320f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.407843137254902f32) },
-    StellarClass { name: "M2Ib", mass: 9.8f32, luminosity: 110000f32, radius: 1030f32, temp: 3320f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.407843137254902f32) },
-    StellarClass { name: "M2II", mass: 7.4f32, luminosity: 8360f32, radius: 285f32, temp: 3320f32, color: Color::rgb(1f32, 0.796078431372549f32, 0.486274509803922f32) },
-    StellarClass { name: "M2III", mass: 5.1f32, luminosity: 1210f32, radius: 108f32, temp: 3320f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.462745098039216f32) },
-    StellarClass { name: "M2IV", mass: 2.7f32, luminosity: 50.9f32, radius: 24.4f32, temp: 3167f32, color: Color::rgb(1f32, 0.827450980392157f32, 0.615686274509804f32) },
-    StellarClass { name: "M2V", mass: 0.4f32, luminosity: 0.0321f32, radius: 0.614f32, temp: 3167f32, color: Color::rgb(1f32, 0.768627450980392f32, 0.513725490196078f32) },
-    StellarClass { name: "M2VI", mass: 0.2f32, luminosity: 0.00885f32, radius: 0.322f32, temp: 3167f32, color: Color::rgb(1f32, 0.768627450980392f32, 0.513725490196078f32) },
-    StellarClass { name: "M3Ia0", mass: 13.8f32, luminosity: 5070000f32, radius: 7890f32, temp: 3130f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.462745098039216f32) },
-    StellarClass { name: "M3Ia", mass: 11.6f32, luminosity: 733000f32, radius: 3000f32, temp: 3130f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.462745098039216f32) },
-    StellarClass { name: "M3Ib", mass: 9.3f32, luminosity: 168000f32, radius: 1440f32, temp: 3130f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.462745098039216f32) },
-    StellarClass { name: "M3II", mass: 7.1f32, luminosity: 14000f32, radius: 414f32, temp: 3130f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.466666666666667f32) },
-    StellarClass { name: "M3III", mass: 4.8f32, luminosity: 1840f32, radius: 150f32, temp: 3130f32, color: Color::rgb(1f32, 0.784313725490196f32, 0.466666666666667f32) },
-    StellarClass { name: "M3IV", mass: 2.6f32, luminosity: 77.6f32, radius: 33.6f32, temp: 3000f32, color: Color::rgb(1f32, 0.823529411764706f32, 0.611764705882353f32) },
-    StellarClass { name: "M3V", mass: 0.3f32, luminosity: 0.0178f32, radius: 0.509f32, temp: 3000f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.505882352941176f32) },
-    StellarClass { name: "M3VI", mass: 0.2f32, luminosity: 0.0049f32, radius: 0.267f32, temp: 3000f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.505882352941176f32) },
-    StellarClass { name: "M4Ia0", mass: 13.2f32, luminosity: 8380000f32, radius: 11500f32, temp: 2940f32, color: Color::rgb(1f32, 0.725490196078431f32, 0.407843137254902f32) },
-    StellarClass { name: "M4Ia", mass: 11f32, luminosity: 1100000f32, radius: 4180f32, temp: 2940f32, color: Color::rgb(1f32, 0.725490196078431f32, 0.407843137254902f32) },
-    StellarClass { name: "M4Ib", mass: 8.9f32, luminosity: 277000f32, radius: 2090f32, temp: 2940f32, color: Color::rgb(1f32, 0.725490196078431f32, 0.407843137254902f32) },
-    StellarClass { name: "M4II", mass: 6.7f32, luminosity: 23100f32, radius: 604f32, temp: 2940f32, color: Color::rgb(1f32, 0.780392156862745f32, 0.447058823529412f32) },
-    StellarClass { name: "M4III", mass: 4.6f32, luminosity: 2770f32, radius: 209f32, temp: 2940f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.498039215686275f32) },
-    StellarClass { name: "M4IV", mass: 2.4f32, luminosity: 127f32, radius: 48.3f32, temp: 2833f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.607843137254902f32) },
-    StellarClass { name: "M4V", mass: 0.3f32, luminosity: 0.0106f32, radius: 0.44f32, temp: 2833f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.498039215686275f32) },
-    StellarClass { name: "M4VI", mass: 0.1f32, luminosity: 0.00266f32, radius: 0.221f32, temp: 2833f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.498039215686275f32) },
-    StellarClass { name: "M5Ia0", mass: 12.5f32, luminosity: 15300000f32, radius: 17800f32, temp: 2750f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.305882352941176f32) },
-    StellarClass { name: "M5Ia", mass: 10.5f32, luminosity: 2020000f32, radius: 6450f32, temp: 2750f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.305882352941176f32) },
-    StellarClass { name: "M5Ib", mass: 8.4f32, luminosity: 507000f32, radius: 3230f32, temp: 2750f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.305882352941176f32) },
-    StellarClass { name: "M5II", mass: 6.4f32, luminosity: 46200f32, radius: 976f32, temp: 2750f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.431372549019608f32) },
-    StellarClass { name: "M5III", mass: 4.3f32, luminosity: 5070f32, radius: 323f32, temp: 2750f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.486274509803922f32) },
-    StellarClass { name: "M5IV", mass: 2.3f32, luminosity: 207f32, radius: 69.4f32, temp: 2667f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.603921568627451f32) },
-    StellarClass { name: "M5V", mass: 0.2f32, luminosity: 0.00624f32, radius: 0.381f32, temp: 2667f32, color: Color::rgb(1f32, 0.8f32, 0.435294117647059f32) },
-    StellarClass { name: "M5VI", mass: 0.1f32, luminosity: 0.00172f32, radius: 0.2f32, temp: 2667f32, color: Color::rgb(1f32, 0.8f32, 0.435294117647059f32) },
-    StellarClass { name: "M6Ia0", mass: 12.4f32, luminosity: 28800000f32, radius: 28100f32, temp: 2560f32, color: Color::rgb(1f32, 0.670588235294118f32, 0.203921568627451f32) },
-    StellarClass { name: "M6Ia", mass: 10.3f32, luminosity: 4170000f32, radius: 10700f32, temp: 2560f32, color: Color::rgb(1f32, 0.670588235294118f32, 0.203921568627451f32) },
-    StellarClass { name: "M6Ib", mass: 8.3f32, luminosity: 955000f32, radius: 5120f32, temp: 2560f32, color: Color::rgb(1f32, 0.670588235294118f32, 0.203921568627451f32) },
-    StellarClass { name: "M6II", mass: 6.3f32, luminosity: 95500f32, radius: 1620f32, temp: 2560f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.411764705882353f32) },
-    StellarClass { name: "M6III", mass: 4.2f32, luminosity: 9550f32, radius: 512f32, temp: 2560f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.474509803921569f32) },
-    StellarClass { name: "M6IV", mass: 2.2f32, luminosity: 410f32, radius: 111f32, temp: 2500f32, color: Color::rgb(1f32, 0.815686274509804f32, 0.6f32) },
-    StellarClass { name: "M6V", mass: 0.2f32, luminosity: 0.0045f32, radius: 0.368f32, temp: 2500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.43921568627451f32) },
-    StellarClass { name: "M6VI", mass: 0.1f32, luminosity: 0.00163f32, radius: 0.222f32, temp: 2500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.43921568627451f32) },
-    StellarClass { name: "M7Ia0", mass: 12.3f32, luminosity: 69600000f32, radius: 51000f32, temp: 2370f32, color: Color::rgb(1f32, 0.643137254901961f32, 0.101960784313725f32) },
-    StellarClass { name: "M7Ia", mass: 10.2f32, luminosity: 9180000f32, radius: 18500f32, temp: 2370f32, color: Color::rgb(1f32, 0.643137254901961f32, 0.101960784313725f32) },
-    StellarClass { name: "M7Ib", mass: 8.2f32, luminosity: 2100000f32, radius: 8870f32, temp: 2370f32, color: Color::rgb(1f32, 0.643137254901961f32, 0.101960784313725f32) },
-    StellarClass { name: "M7II", mass: 6.2f32, luminosity: 253000f32, radius: 3070f32, temp: 2370f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.392156862745098f32) },
-    StellarClass { name: "M7III", mass: 4.2f32, luminosity: 21000f32, radius: 887f32, temp: 2370f32, color: Color::rgb(1f32, 0.647058823529412f32, 0.380392156862745f32) },
-    StellarClass { name: "M7IV", mass: 2.1f32, luminosity: 926f32, radius: 192f32, temp: 2333f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.596078431372549f32) },
-    StellarClass { name: "M7V", mass: 0.1f32, luminosity: 0.00369f32, radius: 0.383f32, temp: 2333f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.435294117647059f32) },
-    StellarClass { name: "M7VI", mass: 0.1f32, luminosity: 0.00194f32, radius: 0.278f32, temp: 2333f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.435294117647059f32) },
-    StellarClass { name: "M8Ia0", mass: 12.1f32, luminosity: 205000000f32, radius: 103000f32, temp: 2180f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "M8Ia", mass: 10.1f32, luminosity: 27000000f32, radius: 37600f32, temp: 2180f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "M8Ib", mass: 8.1f32, luminosity: 5150000f32, radius: 16400f32, temp: 2180f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "M8II", mass: 6.1f32, luminosity: 744000f32, radius: 6230f32, temp: 2180f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.376470588235294f32) },
-    StellarClass { name: "M8III", mass: 4.1f32, luminosity: 51500f32, radius: 1640f32, temp: 2180f32, color: Color::rgb(1f32, 0.654901960784314f32, 0.380392156862745f32) },
-    StellarClass { name: "M8IV", mass: 2.1f32, luminosity: 2440f32, radius: 361f32, temp: 2167f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.592156862745098f32) },
-    StellarClass { name: "M8V", mass: 0.1f32, luminosity: 0.00353f32, radius: 0.434f32, temp: 2167f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.427450980392157f32) },
-    StellarClass { name: "M8VI", mass: 0.1f32, luminosity: 0.00244f32, radius: 0.361f32, temp: 2167f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.427450980392157f32) },
-    StellarClass { name: "M9Ia0", mass: 12f32, luminosity: 711000000f32, radius: 231000f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "M9Ia", mass: 10f32, luminosity: 103000000f32, radius: 87900f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "M9Ib", mass: 8f32, luminosity: 17900000f32, radius: 36700f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "M9II", mass: 6f32, luminosity: 2830000f32, radius: 14600f32, temp: 1990f32, color: Color::rgb(1f32, 0.745098039215686f32, 0.356862745098039f32) },
-    StellarClass { name: "M9III", mass: 4.1f32, luminosity: 179000f32, radius: 3670f32, temp: 1990f32, color: Color::rgb(1f32, 0.913725490196078f32, 0.603921568627451f32) },
-    StellarClass { name: "M9IV", mass: 2.1f32, luminosity: 7910f32, radius: 764f32, temp: 2000f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.588235294117647f32) },
-    StellarClass { name: "M9V", mass: 0.1f32, luminosity: 0.00415f32, radius: 0.553f32, temp: 2000f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.423529411764706f32) },
-    StellarClass { name: "M9VI", mass: 0.1f32, luminosity: 0.00415f32, radius: 0.553f32, temp: 2000f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.423529411764706f32) },
-    StellarClass { name: "N0Ia0", mass: 15.8f32, luminosity: 1900000f32, radius: 3460f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "N0Ia", mass: 13.3f32, luminosity: 274000f32, radius: 1310f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "N0Ib", mass: 10.7f32, luminosity: 57300f32, radius: 600f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "N0II", mass: 8.2f32, luminosity: 3960f32, radius: 158f32, temp: 3700f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.52156862745098f32) },
-    StellarClass { name: "N0III", mass: 5.6f32, luminosity: 689f32, radius: 65.8f32, temp: 3700f32, color: Color::rgb(1f32, 0.796078431372549f32, 0.517647058823529f32) },
-    StellarClass { name: "N0IV", mass: 3.1f32, luminosity: 26f32, radius: 14.3f32, temp: 3500f32, color: Color::rgb(1f32, 0.835294117647059f32, 0.627450980392157f32) },
-    StellarClass { name: "N0V", mass: 0.5f32, luminosity: 0.125f32, radius: 0.99f32, temp: 3500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.545098039215686f32) },
-    StellarClass { name: "N0VI", mass: 0.2f32, luminosity: 0.0376f32, radius: 0.544f32, temp: 3500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.545098039215686f32) },
-    StellarClass { name: "N1Ia0", mass: 15.1f32, luminosity: 2560000f32, radius: 4460f32, temp: 3510f32, color: Color::rgb(1f32, 0.792156862745098f32, 0.541176470588235f32) },
-    StellarClass { name: "N1Ia", mass: 12.7f32, luminosity: 337000f32, radius: 1620f32, temp: 3510f32, color: Color::rgb(1f32, 0.792156862745098f32, 0.541176470588235f32) },
-    StellarClass { name: "N1Ib", mass: 10.2f32, luminosity: 77300f32, radius: 775f32, temp: 3510f32, color: Color::rgb(1f32, 0.792156862745098f32, 0.541176470588235f32) },
-    StellarClass { name: "N1II", mass: 7.8f32, luminosity: 5860f32, radius: 213f32, temp: 3510f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.501960784313725f32) },
-    StellarClass { name: "N1III", mass: 5.3f32, luminosity: 929f32, radius: 85f32, temp: 3510f32, color: Color::rgb(1f32, 0.784313725490196f32, 0.474509803921569f32) },
-    StellarClass { name: "N1IV", mass: 2.9f32, luminosity: 35.5f32, radius: 18.4f32, temp: 3333f32, color: Color::rgb(1f32, 0.831372549019608f32, 0.623529411764706f32) },
-    StellarClass { name: "N1V", mass: 0.5f32, luminosity: 0.0618f32, radius: 0.768f32, temp: 3333f32, color: Color::rgb(1f32, 0.8f32, 0.556862745098039f32) },
-    StellarClass { name: "N1VI", mass: 0.2f32, luminosity: 0.0186f32, radius: 0.422f32, temp: 3333f32, color: Color::rgb(1f32, 0.8f32, 0.556862745098039f32) },
-    StellarClass { name: "N2Ia0", mass: 14.5f32, luminosity: 3650000f32, radius: 5950f32, temp: 3320f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.407843137254902f32) },
-    StellarClass { name: "N2Ia", mass: 12.1f32, luminosity: 481000f32, radius: 2160f32, temp: 3320f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.407843137254902f32) },
-    StellarClass { name: "N2Ib", mass: 9.8f32, luminosity: 110000f32, radius: 1030f32, temp: 3320f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.407843137254902f32) },
-    StellarClass { name: "N2II", mass: 7.4f32, luminosity: 8360f32, radius: 285f32, temp: 3320f32, color: Color::rgb(1f32, 0.796078431372549f32, 0.486274509803922f32) },
-    StellarClass { name: "N2III", mass: 5.1f32, luminosity: 1210f32, radius: 108f32, temp: 3320f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.462745098039216f32) },
-    StellarClass { name: "N2IV", mass: 2.7f32, luminosity: 50.9f32, radius: 24.4f32, temp: 3167f32, color: Color::rgb(1f32, 0.827450980392157f32, 0.615686274509804f32) },
-    StellarClass { name: "N2V", mass: 0.4f32, luminosity: 0.0321f32, radius: 0.614f32, temp: 3167f32, color: Color::rgb(1f32, 0.768627450980392f32, 0.513725490196078f32) },
-    StellarClass { name: "N2VI", mass: 0.2f32, luminosity: 0.00885f32, radius: 0.322f32, temp: 3167f32, color: Color::rgb(1f32, 0.768627450980392f32, 0.513725490196078f32) },
-    StellarClass { name: "N3Ia0", mass: 13.8f32, luminosity: 5070000f32, radius: 7890f32, temp: 3130f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.462745098039216f32) },
-    StellarClass { name: "N3Ia", mass: 11.6f32, luminosity: 733000f32, radius: 3000f32, temp: 3130f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.462745098039216f32) },
-    StellarClass { name: "N3Ib", mass: 9.3f32, luminosity: 168000f32, radius: 1440f32, temp: 3130f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.462745098039216f32) },
-    StellarClass { name: "N3II", mass: 7.1f32, luminosity: 14000f32, radius: 414f32, temp: 3130f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.466666666666667f32) },
-    StellarClass { name: "N3III", mass: 4.8f32, luminosity: 1840f32, radius: 150f32, temp: 3130f32, color: Color::rgb(1f32, 0.784313725490196f32, 0.466666666666667f32) },
-    StellarClass { name: "N3IV", mass: 2.6f32, luminosity: 77.6f32, radius: 33.6f32, temp: 3000f32, color: Color::rgb(1f32, 0.823529411764706f32, 0.611764705882353f32) },
-    StellarClass { name: "N3V", mass: 0.3f32, luminosity: 0.0178f32, radius: 0.509f32, temp: 3000f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.505882352941176f32) },
-    StellarClass { name: "N3VI", mass: 0.2f32, luminosity: 0.0049f32, radius: 0.267f32, temp: 3000f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.505882352941176f32) },
-    StellarClass { name: "N4Ia0", mass: 13.2f32, luminosity: 8380000f32, radius: 11500f32, temp: 2940f32, color: Color::rgb(1f32, 0.725490196078431f32, 0.407843137254902f32) },
-    StellarClass { name: "N4Ia", mass: 11f32, luminosity: 1100000f32, radius: 4180f32, temp: 2940f32, color: Color::rgb(1f32, 0.725490196078431f32, 0.407843137254902f32) },
-    StellarClass { name: "N4Ib", mass: 8.9f32, luminosity: 277000f32, radius: 2090f32, temp: 2940f32, color: Color::rgb(1f32, 0.725490196078431f32, 0.407843137254902f32) },
-    StellarClass { name: "N4II", mass: 6.7f32, luminosity: 23100f32, radius: 604f32, temp: 2940f32, color: Color::rgb(1f32, 0.780392156862745f32, 0.447058823529412f32) },
-    StellarClass { name: "N4III", mass: 4.6f32, luminosity: 2770f32, radius: 209f32, temp: 2940f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.498039215686275f32) },
-    StellarClass { name: "N4IV", mass: 2.4f32, luminosity: 127f32, radius: 48.3f32, temp: 2833f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.607843137254902f32) },
-    StellarClass { name: "N4V", mass: 0.3f32, luminosity: 0.0106f32, radius: 0.44f32, temp: 2833f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.498039215686275f32) },
-    StellarClass { name: "N4VI", mass: 0.1f32, luminosity: 0.00266f32, radius: 0.221f32, temp: 2833f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.498039215686275f32) },
-    StellarClass { name: "N5Ia0", mass: 12.5f32, luminosity: 15300000f32, radius: 17800f32, temp: 2750f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.305882352941176f32) },
-    StellarClass { name: "N5Ia", mass: 10.5f32, luminosity: 2020000f32, radius: 6450f32, temp: 2750f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.305882352941176f32) },
-    StellarClass { name: "N5Ib", mass: 8.4f32, luminosity: 507000f32, radius: 3230f32, temp: 2750f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.305882352941176f32) },
-    StellarClass { name: "N5II", mass: 6.4f32, luminosity: 46200f32, radius: 976f32, temp: 2750f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.431372549019608f32) },
-    StellarClass { name: "N5III", mass: 4.3f32, luminosity: 5070f32, radius: 323f32, temp: 2750f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.486274509803922f32) },
-    StellarClass { name: "N5IV", mass: 2.3f32, luminosity: 207f32, radius: 69.4f32, temp: 2667f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.603921568627451f32) },
-    StellarClass { name: "N5V", mass: 0.2f32, luminosity: 0.00624f32, radius: 0.381f32, temp: 2667f32, color: Color::rgb(1f32, 0.8f32, 0.435294117647059f32) },
-    StellarClass { name: "N5VI", mass: 0.1f32, luminosity: 0.00172f32, radius: 0.2f32, temp: 2667f32, color: Color::rgb(1f32, 0.8f32, 0.435294117647059f32) },
-    StellarClass { name: "N6Ia0", mass: 12.4f32, luminosity: 28800000f32, radius: 28100f32, temp: 2560f32, color: Color::rgb(1f32, 0.670588235294118f32, 0.203921568627451f32) },
-    StellarClass { name: "N6Ia", mass: 10.3f32, luminosity: 4170000f32, radius: 10700f32, temp: 2560f32, color: Color::rgb(1f32, 0.670588235294118f32, 0.203921568627451f32) },
-    StellarClass { name: "N6Ib", mass: 8.3f32, luminosity: 955000f32, radius: 5120f32, temp: 2560f32, color: Color::rgb(1f32, 0.670588235294118f32, 0.203921568627451f32) },
-    StellarClass { name: "N6II", mass: 6.3f32, luminosity: 95500f32, radius: 1620f32, temp: 2560f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.411764705882353f32) },
-    StellarClass { name: "N6III", mass: 4.2f32, luminosity: 9550f32, radius: 512f32, temp: 2560f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.474509803921569f32) },
-    StellarClass { name: "N6IV", mass: 2.2f32, luminosity: 410f32, radius: 111f32, temp: 2500f32, color: Color::rgb(1f32, 0.815686274509804f32, 0.6f32) },
-    StellarClass { name: "N6V", mass: 0.2f32, luminosity: 0.0045f32, radius: 0.368f32, temp: 2500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.43921568627451f32) },
-    StellarClass { name: "N6VI", mass: 0.1f32, luminosity: 0.00163f32, radius: 0.222f32, temp: 2500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.43921568627451f32) },
-    StellarClass { name: "N7Ia0", mass: 12.3f32, luminosity: 69600000f32, radius: 51000f32, temp: 2370f32, color: Color::rgb(1f32, 0.643137254901961f32, 0.101960784313725f32) },
-    StellarClass { name: "N7Ia", mass: 10.2f32, luminosity: 9180000f32, radius: 18500f32, temp: 2370f32, color: Color::rgb(1f32, 0.643137254901961f32, 0.101960784313725f32) },
-    StellarClass { name: "N7Ib", mass: 8.2f32, luminosity: 2100000f32, radius: 8870f32, temp: 2370f32, color: Color::rgb(1f32, 0.643137254901961f32, 0.101960784313725f32) },
-    StellarClass { name: "N7II", mass: 6.2f32, luminosity: 253000f32, radius: 3070f32, temp: 2370f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.392156862745098f32) },
-    StellarClass { name: "N7III", mass: 4.2f32, luminosity: 21000f32, radius: 887f32, temp: 2370f32, color: Color::rgb(1f32, 0.647058823529412f32, 0.380392156862745f32) },
-    StellarClass { name: "N7IV", mass: 2.1f32, luminosity: 926f32, radius: 192f32, temp: 2333f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.596078431372549f32) },
-    StellarClass { name: "N7V", mass: 0.1f32, luminosity: 0.00369f32, radius: 0.383f32, temp: 2333f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.435294117647059f32) },
-    StellarClass { name: "N7VI", mass: 0.1f32, luminosity: 0.00194f32, radius: 0.278f32, temp: 2333f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.435294117647059f32) },
-    StellarClass { name: "N8Ia0", mass: 12.1f32, luminosity: 205000000f32, radius: 103000f32, temp: 2180f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "N8Ia", mass: 10.1f32, luminosity: 27000000f32, radius: 37600f32, temp: 2180f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "N8Ib", mass: 8.1f32, luminosity: 5150000f32, radius: 16400f32, temp: 2180f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "N8II", mass: 6.1f32, luminosity: 744000f32, radius: 6230f32, temp: 2180f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.376470588235294f32) },
-    StellarClass { name: "N8III", mass: 4.1f32, luminosity: 51500f32, radius: 1640f32, temp: 2180f32, color: Color::rgb(1f32, 0.654901960784314f32, 0.380392156862745f32) },
-    StellarClass { name: "N8IV", mass: 2.1f32, luminosity: 2440f32, radius: 361f32, temp: 2167f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.592156862745098f32) },
-    StellarClass { name: "N8V", mass: 0.1f32, luminosity: 0.00353f32, radius: 0.434f32, temp: 2167f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.427450980392157f32) },
-    StellarClass { name: "N8VI", mass: 0.1f32, luminosity: 0.00244f32, radius: 0.361f32, temp: 2167f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.427450980392157f32) },
-    StellarClass { name: "N9Ia0", mass: 12f32, luminosity: 711000000f32, radius: 231000f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "N9Ia", mass: 10f32, luminosity: 103000000f32, radius: 87900f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "N9Ib", mass: 8f32, luminosity: 17900000f32, radius: 36700f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "N9II", mass: 6f32, luminosity: 2830000f32, radius: 14600f32, temp: 1990f32, color: Color::rgb(1f32, 0.745098039215686f32, 0.356862745098039f32) },
-    StellarClass { name: "N9III", mass: 4.1f32, luminosity: 179000f32, radius: 3670f32, temp: 1990f32, color: Color::rgb(1f32, 0.913725490196078f32, 0.603921568627451f32) },
-    StellarClass { name: "N9IV", mass: 2.1f32, luminosity: 7910f32, radius: 764f32, temp: 2000f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.588235294117647f32) },
-    StellarClass { name: "N9V", mass: 0.1f32, luminosity: 0.00415f32, radius: 0.553f32, temp: 2000f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.423529411764706f32) },
-    StellarClass { name: "N9VI", mass: 0.1f32, luminosity: 0.00415f32, radius: 0.553f32, temp: 2000f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.423529411764706f32) },
-    StellarClass { name: "WC0Ia0", mass: 160f32, luminosity: 34100000f32, radius: 80.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WC0Ia", mass: 150f32, luminosity: 2590000f32, radius: 22.1f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WC0Ib", mass: 140f32, luminosity: 2150000f32, radius: 20.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WC0II", mass: 130f32, luminosity: 2150000f32, radius: 20.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WC0III", mass: 120f32, luminosity: 2150000f32, radius: 20.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WC0IV", mass: 110f32, luminosity: 1360000f32, radius: 16f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WC0V", mass: 100f32, luminosity: 1240000f32, radius: 15.3f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WC0VI", mass: 60f32, luminosity: 940000f32, radius: 13.3f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WC1Ia0", mass: 159.7f32, luminosity: 27100000f32, radius: 78.8f32, temp: 47600f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WC1Ia", mass: 149.3f32, luminosity: 2250000f32, radius: 22.7f32, temp: 47600f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WC1Ib", mass: 139f32, luminosity: 1870000f32, radius: 20.7f32, temp: 47600f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WC1II", mass: 128.6f32, luminosity: 1730000f32, radius: 19.8f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WC1III", mass: 118.2f32, luminosity: 1580000f32, radius: 18.9f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WC1IV", mass: 107.9f32, luminosity: 1090000f32, radius: 15.7f32, temp: 47800f32, color: Color::rgb(0.568627450980392f32, 0.654901960784314f32, 1f32) },
-    StellarClass { name: "WC1V", mass: 97.5f32, luminosity: 994000f32, radius: 15f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WC1VI", mass: 37f32, luminosity: 754000f32, radius: 13.1f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WC2Ia0", mass: 159.4f32, luminosity: 21400000f32, radius: 77.7f32, temp: 45200f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WC2Ia", mass: 148.6f32, luminosity: 2140000f32, radius: 24.6f32, temp: 45200f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WC2Ib", mass: 137.9f32, luminosity: 1620000f32, radius: 21.4f32, temp: 45200f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WC2II", mass: 127.2f32, luminosity: 1520000f32, radius: 20.3f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WC2III", mass: 116.5f32, luminosity: 1260000f32, radius: 18.5f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.662745098039216f32, 1f32) },
-    StellarClass { name: "WC2IV", mass: 105.7f32, luminosity: 872000f32, radius: 15.4f32, temp: 45600f32, color: Color::rgb(0.572549019607843f32, 0.662745098039216f32, 1f32) },
-    StellarClass { name: "WC2V", mass: 95f32, luminosity: 795000f32, radius: 14.7f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WC2VI", mass: 30f32, luminosity: 603000f32, radius: 12.8f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WC3Ia0", mass: 159f32, luminosity: 16800000f32, radius: 76.9f32, temp: 42800f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WC3Ia", mass: 148f32, luminosity: 1850000f32, radius: 25.5f32, temp: 42800f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WC3Ib", mass: 136.9f32, luminosity: 1400000f32, radius: 22.2f32, temp: 42800f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WC3II", mass: 125.8f32, luminosity: 1210000f32, radius: 20f32, temp: 43400f32, color: Color::rgb(0.584313725490196f32, 0.67843137254902f32, 1f32) },
-    StellarClass { name: "WC3III", mass: 114.7f32, luminosity: 917000f32, radius: 17.5f32, temp: 43400f32, color: Color::rgb(0.588235294117647f32, 0.670588235294118f32, 1f32) },
-    StellarClass { name: "WC3IV", mass: 103.6f32, luminosity: 696000f32, radius: 15.2f32, temp: 43400f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WC3V", mass: 92.5f32, luminosity: 634000f32, radius: 14.5f32, temp: 43400f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WC3VI", mass: 23f32, luminosity: 481000f32, radius: 12.6f32, temp: 43400f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WC4Ia0", mass: 158.7f32, luminosity: 13200000f32, radius: 76.4f32, temp: 40400f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WC4Ia", mass: 147.3f32, luminosity: 1740000f32, radius: 27.7f32, temp: 40400f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WC4Ib", mass: 135.8f32, luminosity: 1200000f32, radius: 23.1f32, temp: 40400f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WC4II", mass: 124.4f32, luminosity: 960000f32, radius: 19.8f32, temp: 41200f32, color: Color::rgb(0.592156862745098f32, 0.686274509803922f32, 1f32) },
-    StellarClass { name: "WC4III", mass: 112.9f32, luminosity: 728000f32, radius: 17.3f32, temp: 41200f32, color: Color::rgb(0.596078431372549f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WC4IV", mass: 101.5f32, luminosity: 552000f32, radius: 15f32, temp: 41200f32, color: Color::rgb(0.584313725490196f32, 0.670588235294118f32, 1f32) },
-    StellarClass { name: "WC4V", mass: 90f32, luminosity: 504000f32, radius: 14.4f32, temp: 41200f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WC4VI", mass: 20f32, luminosity: 382000f32, radius: 12.5f32, temp: 41200f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WC5Ia0", mass: 158.4f32, luminosity: 10300000f32, radius: 76.2f32, temp: 38000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WC5Ia", mass: 142f32, luminosity: 1480000f32, radius: 29f32, temp: 38000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WC5Ib", mass: 125.6f32, luminosity: 1030000f32, radius: 24.1f32, temp: 38000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WC5II", mass: 109.2f32, luminosity: 759000f32, radius: 19.7f32, temp: 39000f32, color: Color::rgb(0.6f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WC5III", mass: 92.8f32, luminosity: 525000f32, radius: 16.4f32, temp: 39000f32, color: Color::rgb(0.603921568627451f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WC5IV", mass: 76.4f32, luminosity: 437000f32, radius: 14.9f32, temp: 39000f32, color: Color::rgb(0.588235294117647f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WC5V", mass: 60f32, luminosity: 398000f32, radius: 14.2f32, temp: 39000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WC5VI", mass: 17.5f32, luminosity: 302000f32, radius: 12.4f32, temp: 39000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WC6Ia0", mass: 136.7f32, luminosity: 7810000f32, radius: 76.6f32, temp: 35400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "WC6Ia", mass: 120.1f32, luminosity: 1360000f32, radius: 31.9f32, temp: 35400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "WC6Ib", mass: 103.5f32, luminosity: 781000f32, radius: 24.2f32, temp: 35400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "WC6II", mass: 86.9f32, luminosity: 654000f32, radius: 20.5f32, temp: 36800f32, color: Color::rgb(0.607843137254902f32, 0.701960784313725f32, 1f32) },
-    StellarClass { name: "WC6III", mass: 70.2f32, luminosity: 376000f32, radius: 15.6f32, temp: 36800f32, color: Color::rgb(0.611764705882353f32, 0.686274509803922f32, 1f32) },
-    StellarClass { name: "WC6IV", mass: 53.6f32, luminosity: 313000f32, radius: 14.2f32, temp: 36800f32, color: Color::rgb(0.592156862745098f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WC6V", mass: 37f32, luminosity: 260000f32, radius: 12.9f32, temp: 36800f32, color: Color::rgb(0.635294117647059f32, 0.72156862745098f32, 1f32) },
-    StellarClass { name: "WC6VI", mass: 14.2f32, luminosity: 180000f32, radius: 10.8f32, temp: 36800f32, color: Color::rgb(0.635294117647059f32, 0.72156862745098f32, 1f32) },
-    StellarClass { name: "WC7Ia0", mass: 115.1f32, luminosity: 5880000f32, radius: 77.4f32, temp: 32800f32, color: Color::rgb(0.627450980392157f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "WC7Ia", mass: 100.9f32, luminosity: 1120000f32, radius: 33.8f32, temp: 32800f32, color: Color::rgb(0.627450980392157f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "WC7Ib", mass: 86.7f32, luminosity: 588000f32, radius: 24.5f32, temp: 32800f32, color: Color::rgb(0.627450980392157f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "WC7II", mass: 72.5f32, luminosity: 510000f32, radius: 20.5f32, temp: 34600f32, color: Color::rgb(0.611764705882353f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "WC7III", mass: 58.4f32, luminosity: 294000f32, radius: 15.5f32, temp: 34600f32, color: Color::rgb(0.619607843137255f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WC7IV", mass: 44.2f32, luminosity: 223000f32, radius: 13.5f32, temp: 34600f32, color: Color::rgb(0.596078431372549f32, 0.686274509803922f32, 1f32) },
-    StellarClass { name: "WC7V", mass: 30f32, luminosity: 154000f32, radius: 11.3f32, temp: 34600f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WC7VI", mass: 10.9f32, luminosity: 107000f32, radius: 9.36f32, temp: 34600f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WC8Ia0", mass: 93.4f32, luminosity: 4370000f32, radius: 78.7f32, temp: 30200f32, color: Color::rgb(0.635294117647059f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "WC8Ia", mass: 81.7f32, luminosity: 913000f32, radius: 36f32, temp: 30200f32, color: Color::rgb(0.635294117647059f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "WC8Ib", mass: 69.9f32, luminosity: 437000f32, radius: 24.9f32, temp: 30200f32, color: Color::rgb(0.635294117647059f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "WC8II", mass: 58.2f32, luminosity: 360000f32, radius: 19.6f32, temp: 32400f32, color: Color::rgb(0.619607843137255f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "WC8III", mass: 46.5f32, luminosity: 207000f32, radius: 14.9f32, temp: 32400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "WC8IV", mass: 34.7f32, luminosity: 157000f32, radius: 13f32, temp: 32400f32, color: Color::rgb(0.6f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WC8V", mass: 23f32, luminosity: 99100f32, radius: 10.3f32, temp: 32400f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WC8VI", mass: 7.6f32, luminosity: 57000f32, radius: 7.81f32, temp: 32400f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WN0VI", mass: 60f32, luminosity: 940000f32, radius: 13.3f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WC9Ia0", mass: 71.8f32, luminosity: 3190000f32, radius: 80.5f32, temp: 27600f32, color: Color::rgb(0.643137254901961f32, 0.725490196078431f32, 1f32) },
-    StellarClass { name: "WC9Ia", mass: 63.1f32, luminosity: 731000f32, radius: 38.5f32, temp: 27600f32, color: Color::rgb(0.643137254901961f32, 0.725490196078431f32, 1f32) },
-    StellarClass { name: "WC9Ib", mass: 54.5f32, luminosity: 319000f32, radius: 25.5f32, temp: 27600f32, color: Color::rgb(0.643137254901961f32, 0.725490196078431f32, 1f32) },
-    StellarClass { name: "WC9II", mass: 45.9f32, luminosity: 276000f32, radius: 19.8f32, temp: 30200f32, color: Color::rgb(0.627450980392157f32, 0.729411764705882f32, 1f32) },
-    StellarClass { name: "WC9III", mass: 37.3f32, luminosity: 159000f32, radius: 15f32, temp: 30200f32, color: Color::rgb(0.619607843137255f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WC9IV", mass: 28.6f32, luminosity: 110000f32, radius: 12.5f32, temp: 30200f32, color: Color::rgb(0.607843137254902f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WC9V", mass: 20f32, luminosity: 57600f32, radius: 9.04f32, temp: 30200f32, color: Color::rgb(0.603921568627451f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "WC9VI", mass: 6.7f32, luminosity: 33100f32, radius: 6.85f32, temp: 30200f32, color: Color::rgb(0.603921568627451f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "WN0Ia0", mass: 160f32, luminosity: 34100000f32, radius: 80.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WN0Ia", mass: 150f32, luminosity: 2590000f32, radius: 22.1f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WN0Ib", mass: 140f32, luminosity: 2150000f32, radius: 20.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WN0II", mass: 130f32, luminosity: 2150000f32, radius: 20.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WN0III", mass: 120f32, luminosity: 2150000f32, radius: 20.2f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WN0IV", mass: 110f32, luminosity: 1360000f32, radius: 16f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WN0V", mass: 100f32, luminosity: 1240000f32, radius: 15.3f32, temp: 50000f32, color: Color::rgb(0.564705882352941f32, 0.650980392156863f32, 1f32) },
-    StellarClass { name: "WN1Ia0", mass: 159.7f32, luminosity: 27100000f32, radius: 78.8f32, temp: 47600f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WN1Ia", mass: 149.3f32, luminosity: 2250000f32, radius: 22.7f32, temp: 47600f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WN1Ib", mass: 139f32, luminosity: 1870000f32, radius: 20.7f32, temp: 47600f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WN1II", mass: 128.6f32, luminosity: 1730000f32, radius: 19.8f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WN1III", mass: 118.2f32, luminosity: 1580000f32, radius: 18.9f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WN1IV", mass: 107.9f32, luminosity: 1090000f32, radius: 15.7f32, temp: 47800f32, color: Color::rgb(0.568627450980392f32, 0.654901960784314f32, 1f32) },
-    StellarClass { name: "WN1V", mass: 97.5f32, luminosity: 994000f32, radius: 15f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WN1VI", mass: 37f32, luminosity: 754000f32, radius: 13.1f32, temp: 47800f32, color: Color::rgb(0.572549019607843f32, 0.658823529411765f32, 1f32) },
-    StellarClass { name: "WN2Ia0", mass: 159.4f32, luminosity: 21400000f32, radius: 77.7f32, temp: 45200f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WN2Ia", mass: 148.6f32, luminosity: 2140000f32, radius: 24.6f32, temp: 45200f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WN2Ib", mass: 137.9f32, luminosity: 1620000f32, radius: 21.4f32, temp: 45200f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WN2II", mass: 127.2f32, luminosity: 1520000f32, radius: 20.3f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WN2III", mass: 116.5f32, luminosity: 1260000f32, radius: 18.5f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.662745098039216f32, 1f32) },
-    StellarClass { name: "WN2IV", mass: 105.7f32, luminosity: 872000f32, radius: 15.4f32, temp: 45600f32, color: Color::rgb(0.572549019607843f32, 0.662745098039216f32, 1f32) },
-    StellarClass { name: "WN2V", mass: 95f32, luminosity: 795000f32, radius: 14.7f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WN2VI", mass: 30f32, luminosity: 603000f32, radius: 12.8f32, temp: 45600f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WN3Ia0", mass: 159f32, luminosity: 16800000f32, radius: 76.9f32, temp: 42800f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WN3Ia", mass: 148f32, luminosity: 1850000f32, radius: 25.5f32, temp: 42800f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WN3Ib", mass: 136.9f32, luminosity: 1400000f32, radius: 22.2f32, temp: 42800f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WN3II", mass: 125.8f32, luminosity: 1210000f32, radius: 20f32, temp: 43400f32, color: Color::rgb(0.584313725490196f32, 0.67843137254902f32, 1f32) },
-    StellarClass { name: "WN3III", mass: 114.7f32, luminosity: 917000f32, radius: 17.5f32, temp: 43400f32, color: Color::rgb(0.588235294117647f32, 0.670588235294118f32, 1f32) },
-    StellarClass { name: "WN3IV", mass: 103.6f32, luminosity: 696000f32, radius: 15.2f32, temp: 43400f32, color: Color::rgb(0.580392156862745f32, 0.666666666666667f32, 1f32) },
-    StellarClass { name: "WN3V", mass: 92.5f32, luminosity: 634000f32, radius: 14.5f32, temp: 43400f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WN3VI", mass: 23f32, luminosity: 481000f32, radius: 12.6f32, temp: 43400f32, color: Color::rgb(0.592156862745098f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WN4Ia0", mass: 158.7f32, luminosity: 13200000f32, radius: 76.4f32, temp: 40400f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WN4Ia", mass: 147.3f32, luminosity: 1740000f32, radius: 27.7f32, temp: 40400f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WN4Ib", mass: 135.8f32, luminosity: 1200000f32, radius: 23.1f32, temp: 40400f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WN4II", mass: 124.4f32, luminosity: 960000f32, radius: 19.8f32, temp: 41200f32, color: Color::rgb(0.592156862745098f32, 0.686274509803922f32, 1f32) },
-    StellarClass { name: "WN4III", mass: 112.9f32, luminosity: 728000f32, radius: 17.3f32, temp: 41200f32, color: Color::rgb(0.596078431372549f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WN4IV", mass: 101.5f32, luminosity: 552000f32, radius: 15f32, temp: 41200f32, color: Color::rgb(0.584313725490196f32, 0.670588235294118f32, 1f32) },
-    StellarClass { name: "WN4V", mass: 90f32, luminosity: 504000f32, radius: 14.4f32, temp: 41200f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WN4VI", mass: 20f32, luminosity: 382000f32, radius: 12.5f32, temp: 41200f32, color: Color::rgb(0.6f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WN5Ia0", mass: 158.4f32, luminosity: 10300000f32, radius: 76.2f32, temp: 38000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WN5Ia", mass: 142f32, luminosity: 1480000f32, radius: 29f32, temp: 38000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WN5Ib", mass: 125.6f32, luminosity: 1030000f32, radius: 24.1f32, temp: 38000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WN5II", mass: 109.2f32, luminosity: 759000f32, radius: 19.7f32, temp: 39000f32, color: Color::rgb(0.6f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WN5III", mass: 92.8f32, luminosity: 525000f32, radius: 16.4f32, temp: 39000f32, color: Color::rgb(0.603921568627451f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WN5IV", mass: 76.4f32, luminosity: 437000f32, radius: 14.9f32, temp: 39000f32, color: Color::rgb(0.588235294117647f32, 0.674509803921569f32, 1f32) },
-    StellarClass { name: "WN5V", mass: 60f32, luminosity: 398000f32, radius: 14.2f32, temp: 39000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WN5VI", mass: 17.5f32, luminosity: 302000f32, radius: 12.4f32, temp: 39000f32, color: Color::rgb(0.607843137254902f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WN6Ia0", mass: 136.7f32, luminosity: 7810000f32, radius: 76.6f32, temp: 35400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "WN6Ia", mass: 120.1f32, luminosity: 1360000f32, radius: 31.9f32, temp: 35400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "WN6Ib", mass: 103.5f32, luminosity: 781000f32, radius: 24.2f32, temp: 35400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "WN6II", mass: 86.9f32, luminosity: 654000f32, radius: 20.5f32, temp: 36800f32, color: Color::rgb(0.607843137254902f32, 0.701960784313725f32, 1f32) },
-    StellarClass { name: "WN6III", mass: 70.2f32, luminosity: 376000f32, radius: 15.6f32, temp: 36800f32, color: Color::rgb(0.611764705882353f32, 0.686274509803922f32, 1f32) },
-    StellarClass { name: "WN6IV", mass: 53.6f32, luminosity: 313000f32, radius: 14.2f32, temp: 36800f32, color: Color::rgb(0.592156862745098f32, 0.682352941176471f32, 1f32) },
-    StellarClass { name: "WN6V", mass: 37f32, luminosity: 260000f32, radius: 12.9f32, temp: 36800f32, color: Color::rgb(0.635294117647059f32, 0.72156862745098f32, 1f32) },
-    StellarClass { name: "WN6VI", mass: 14.2f32, luminosity: 180000f32, radius: 10.8f32, temp: 36800f32, color: Color::rgb(0.635294117647059f32, 0.72156862745098f32, 1f32) },
-    StellarClass { name: "WN7Ia0", mass: 115.1f32, luminosity: 5880000f32, radius: 77.4f32, temp: 32800f32, color: Color::rgb(0.627450980392157f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "WN7Ia", mass: 100.9f32, luminosity: 1120000f32, radius: 33.8f32, temp: 32800f32, color: Color::rgb(0.627450980392157f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "WN7Ib", mass: 86.7f32, luminosity: 588000f32, radius: 24.5f32, temp: 32800f32, color: Color::rgb(0.627450980392157f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "WN7II", mass: 72.5f32, luminosity: 510000f32, radius: 20.5f32, temp: 34600f32, color: Color::rgb(0.611764705882353f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "WN7III", mass: 58.4f32, luminosity: 294000f32, radius: 15.5f32, temp: 34600f32, color: Color::rgb(0.619607843137255f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WN7IV", mass: 44.2f32, luminosity: 223000f32, radius: 13.5f32, temp: 34600f32, color: Color::rgb(0.596078431372549f32, 0.686274509803922f32, 1f32) },
-    StellarClass { name: "WN7V", mass: 30f32, luminosity: 154000f32, radius: 11.3f32, temp: 34600f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WN7VI", mass: 10.9f32, luminosity: 107000f32, radius: 9.36f32, temp: 34600f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WN8Ia0", mass: 93.4f32, luminosity: 4370000f32, radius: 78.7f32, temp: 30200f32, color: Color::rgb(0.635294117647059f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "WN8Ia", mass: 81.7f32, luminosity: 913000f32, radius: 36f32, temp: 30200f32, color: Color::rgb(0.635294117647059f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "WN8Ib", mass: 69.9f32, luminosity: 437000f32, radius: 24.9f32, temp: 30200f32, color: Color::rgb(0.635294117647059f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "WN8II", mass: 58.2f32, luminosity: 360000f32, radius: 19.6f32, temp: 32400f32, color: Color::rgb(0.619607843137255f32, 0.717647058823529f32, 1f32) },
-    StellarClass { name: "WN8III", mass: 46.5f32, luminosity: 207000f32, radius: 14.9f32, temp: 32400f32, color: Color::rgb(0.615686274509804f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "WN8IV", mass: 34.7f32, luminosity: 157000f32, radius: 13f32, temp: 32400f32, color: Color::rgb(0.6f32, 0.690196078431373f32, 1f32) },
-    StellarClass { name: "WN8V", mass: 23f32, luminosity: 99100f32, radius: 10.3f32, temp: 32400f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WN8VI", mass: 7.6f32, luminosity: 57000f32, radius: 7.81f32, temp: 32400f32, color: Color::rgb(0.615686274509804f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WN9Ia0", mass: 71.8f32, luminosity: 3190000f32, radius: 80.5f32, temp: 27600f32, color: Color::rgb(0.643137254901961f32, 0.725490196078431f32, 1f32) },
-    StellarClass { name: "WN9Ia", mass: 63.1f32, luminosity: 731000f32, radius: 38.5f32, temp: 27600f32, color: Color::rgb(0.643137254901961f32, 0.725490196078431f32, 1f32) },
-    StellarClass { name: "WN9Ib", mass: 54.5f32, luminosity: 319000f32, radius: 25.5f32, temp: 27600f32, color: Color::rgb(0.643137254901961f32, 0.725490196078431f32, 1f32) },
-    StellarClass { name: "WN9II", mass: 45.9f32, luminosity: 276000f32, radius: 19.8f32, temp: 30200f32, color: Color::rgb(0.627450980392157f32, 0.729411764705882f32, 1f32) },
-    StellarClass { name: "WN9III", mass: 37.3f32, luminosity: 159000f32, radius: 15f32, temp: 30200f32, color: Color::rgb(0.619607843137255f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WN9IV", mass: 28.6f32, luminosity: 110000f32, radius: 12.5f32, temp: 30200f32, color: Color::rgb(0.607843137254902f32, 0.694117647058824f32, 1f32) },
-    StellarClass { name: "WN9V", mass: 20f32, luminosity: 57600f32, radius: 9.04f32, temp: 30200f32, color: Color::rgb(0.603921568627451f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "WN9VI", mass: 6.7f32, luminosity: 33100f32, radius: 6.85f32, temp: 30200f32, color: Color::rgb(0.603921568627451f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "R0Ia0", mass: 12f32, luminosity: 813000f32, radius: 1420f32, temp: 4669f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.756862745098039f32) },
-    StellarClass { name: "R0Ia", mass: 10.2f32, luminosity: 170000f32, radius: 649f32, temp: 4669f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.756862745098039f32) },
-    StellarClass { name: "R0Ib", mass: 8.3f32, luminosity: 9770f32, radius: 156f32, temp: 4669f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.756862745098039f32) },
-    StellarClass { name: "R0II", mass: 6.5f32, luminosity: 1290f32, radius: 56.5f32, temp: 4669f32, color: Color::rgb(1f32, 0.929411764705882f32, 0.819607843137255f32) },
-    StellarClass { name: "R0III", mass: 4.6f32, luminosity: 89.1f32, radius: 14.9f32, temp: 4669f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.913725490196078f32) },
-    StellarClass { name: "R0IV", mass: 2.8f32, luminosity: 6.2f32, radius: 2.76f32, temp: 5560f32, color: Color::rgb(1f32, 0.96078431372549f32, 0.933333333333333f32) },
-    StellarClass { name: "R0V", mass: 0.9f32, luminosity: 0.982f32, radius: 1.1f32, temp: 5560f32, color: Color::rgb(1f32, 0.945098039215686f32, 0.898039215686275f32) },
-    StellarClass { name: "R0VI", mass: 0.8f32, luminosity: 0.297f32, radius: 0.605f32, temp: 5560f32, color: Color::rgb(1f32, 0.945098039215686f32, 0.898039215686275f32) },
-    StellarClass { name: "R1Ia0", mass: 12.4f32, luminosity: 871000f32, radius: 1540f32, temp: 4561f32, color: Color::rgb(1f32, 0.894117647058824f32, 0.733333333333333f32) },
-    StellarClass { name: "R1Ia", mass: 10.5f32, luminosity: 166000f32, radius: 672f32, temp: 4561f32, color: Color::rgb(1f32, 0.894117647058824f32, 0.733333333333333f32) },
-    StellarClass { name: "R1Ib", mass: 8.6f32, luminosity: 10500f32, radius: 169f32, temp: 4561f32, color: Color::rgb(1f32, 0.894117647058824f32, 0.733333333333333f32) },
-    StellarClass { name: "R1II", mass: 6.7f32, luminosity: 1380f32, radius: 61.3f32, temp: 4561f32, color: Color::rgb(1f32, 0.917647058823529f32, 0.788235294117647f32) },
-    StellarClass { name: "R1III", mass: 4.7f32, luminosity: 105f32, radius: 16.9f32, temp: 4561f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.870588235294118f32) },
-    StellarClass { name: "R1IV", mass: 2.8f32, luminosity: 6.61f32, radius: 3.11f32, temp: 5331f32, color: Color::rgb(1f32, 0.945098039215686f32, 0.898039215686275f32) },
-    StellarClass { name: "R1V", mass: 0.9f32, luminosity: 0.794f32, radius: 1.08f32, temp: 5331f32, color: Color::rgb(1f32, 0.933333333333333f32, 0.870588235294118f32) },
-    StellarClass { name: "R1VI", mass: 0.7f32, luminosity: 0.219f32, radius: 0.565f32, temp: 5331f32, color: Color::rgb(1f32, 0.933333333333333f32, 0.870588235294118f32) },
-    StellarClass { name: "R2Ia0", mass: 12.8f32, luminosity: 940000f32, radius: 1680f32, temp: 4453f32, color: Color::rgb(1f32, 0.882352941176471f32, 0.713725490196078f32) },
-    StellarClass { name: "R2Ia", mass: 10.8f32, luminosity: 179000f32, radius: 733f32, temp: 4453f32, color: Color::rgb(1f32, 0.882352941176471f32, 0.713725490196078f32) },
-    StellarClass { name: "R2Ib", mass: 8.8f32, luminosity: 12400f32, radius: 193f32, temp: 4453f32, color: Color::rgb(1f32, 0.882352941176471f32, 0.713725490196078f32) },
-    StellarClass { name: "R2II", mass: 6.8f32, luminosity: 1490f32, radius: 66.8f32, temp: 4453f32, color: Color::rgb(1f32, 0.901960784313726f32, 0.752941176470588f32) },
-    StellarClass { name: "R2III", mass: 4.8f32, luminosity: 136f32, radius: 20.2f32, temp: 4453f32, color: Color::rgb(1f32, 0.917647058823529f32, 0.827450980392157f32) },
-    StellarClass { name: "R2IV", mass: 2.8f32, luminosity: 7.17f32, radius: 3.53f32, temp: 5102f32, color: Color::rgb(1f32, 0.933333333333333f32, 0.866666666666667f32) },
-    StellarClass { name: "R2V", mass: 0.8f32, luminosity: 0.654f32, radius: 1.07f32, temp: 5102f32, color: Color::rgb(1f32, 0.92156862745098f32, 0.847058823529412f32) },
-    StellarClass { name: "R2VI", mass: 0.7f32, luminosity: 0.18f32, radius: 0.56f32, temp: 5102f32, color: Color::rgb(1f32, 0.92156862745098f32, 0.847058823529412f32) },
-    StellarClass { name: "R3Ia0", mass: 13.3f32, luminosity: 932000f32, radius: 1750f32, temp: 4346f32, color: Color::rgb(1f32, 0.870588235294118f32, 0.690196078431373f32) },
-    StellarClass { name: "R3Ia", mass: 11.2f32, luminosity: 178000f32, radius: 766f32, temp: 4346f32, color: Color::rgb(1f32, 0.870588235294118f32, 0.690196078431373f32) },
-    StellarClass { name: "R3Ib", mass: 9.1f32, luminosity: 13500f32, radius: 211f32, temp: 4346f32, color: Color::rgb(1f32, 0.870588235294118f32, 0.690196078431373f32) },
-    StellarClass { name: "R3II", mass: 7f32, luminosity: 1780f32, radius: 76.6f32, temp: 4346f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.72156862745098f32) },
-    StellarClass { name: "R3III", mass: 5f32, luminosity: 162f32, radius: 23.1f32, temp: 4346f32, color: Color::rgb(1f32, 0.901960784313726f32, 0.780392156862745f32) },
-    StellarClass { name: "R3IV", mass: 2.9f32, luminosity: 7.26f32, radius: 3.9f32, temp: 4873f32, color: Color::rgb(1f32, 0.917647058823529f32, 0.831372549019608f32) },
-    StellarClass { name: "R3V", mass: 0.8f32, luminosity: 0.55f32, radius: 1.07f32, temp: 4873f32, color: Color::rgb(1f32, 0.913725490196078f32, 0.819607843137255f32) },
-    StellarClass { name: "R3VI", mass: 0.6f32, luminosity: 0.152f32, radius: 0.563f32, temp: 4873f32, color: Color::rgb(1f32, 0.913725490196078f32, 0.819607843137255f32) },
-    StellarClass { name: "R4Ia0", mass: 13.7f32, luminosity: 1020000f32, radius: 1930f32, temp: 4238f32, color: Color::rgb(1f32, 0.858823529411765f32, 0.670588235294118f32) },
-    StellarClass { name: "R4Ia", mass: 11.5f32, luminosity: 195000f32, radius: 844f32, temp: 4238f32, color: Color::rgb(1f32, 0.858823529411765f32, 0.670588235294118f32) },
-    StellarClass { name: "R4Ib", mass: 9.4f32, luminosity: 16200f32, radius: 243f32, temp: 4238f32, color: Color::rgb(1f32, 0.858823529411765f32, 0.670588235294118f32) },
-    StellarClass { name: "R4II", mass: 7.2f32, luminosity: 1950f32, radius: 84.4f32, temp: 4238f32, color: Color::rgb(1f32, 0.874509803921569f32, 0.686274509803922f32) },
-    StellarClass { name: "R4III", mass: 5.1f32, luminosity: 195f32, radius: 26.7f32, temp: 4238f32, color: Color::rgb(1f32, 0.882352941176471f32, 0.737254901960784f32) },
-    StellarClass { name: "R4IV", mass: 2.9f32, luminosity: 8.25f32, radius: 4.57f32, temp: 4644f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.796078431372549f32) },
-    StellarClass { name: "R4V", mass: 0.8f32, luminosity: 0.521f32, radius: 1.15f32, temp: 4644f32, color: Color::rgb(1f32, 0.901960784313726f32, 0.796078431372549f32) },
-    StellarClass { name: "R4VI", mass: 0.6f32, luminosity: 0.119f32, radius: 0.55f32, temp: 4644f32, color: Color::rgb(1f32, 0.901960784313726f32, 0.796078431372549f32) },
-    StellarClass { name: "R5Ia0", mass: 14.1f32, luminosity: 1130000f32, radius: 2140f32, temp: 4130f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.647058823529412f32) },
-    StellarClass { name: "R5Ia", mass: 11.9f32, luminosity: 197000f32, radius: 893f32, temp: 4130f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.647058823529412f32) },
-    StellarClass { name: "R5Ib", mass: 9.6f32, luminosity: 21600f32, radius: 296f32, temp: 4130f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.647058823529412f32) },
-    StellarClass { name: "R5II", mass: 7.4f32, luminosity: 2160f32, radius: 93.5f32, temp: 4130f32, color: Color::rgb(1f32, 0.862745098039216f32, 0.654901960784314f32) },
-    StellarClass { name: "R5III", mass: 5.2f32, luminosity: 259f32, radius: 32.4f32, temp: 4130f32, color: Color::rgb(1f32, 0.866666666666667f32, 0.694117647058824f32) },
-    StellarClass { name: "R5IV", mass: 2.9f32, luminosity: 9.67f32, radius: 5.47f32, temp: 4416f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.76078431372549f32) },
-    StellarClass { name: "R5V", mass: 0.7f32, luminosity: 0.463f32, radius: 1.2f32, temp: 4416f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.768627450980392f32) },
-    StellarClass { name: "R5VI", mass: 0.5f32, luminosity: 0.0881f32, radius: 0.523f32, temp: 4416f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.768627450980392f32) },
-    StellarClass { name: "R6Ia0", mass: 14.5f32, luminosity: 1260000f32, radius: 2390f32, temp: 4023f32, color: Color::rgb(1f32, 0.835294117647059f32, 0.627450980392157f32) },
-    StellarClass { name: "R6Ia", mass: 12.2f32, luminosity: 220000f32, radius: 995f32, temp: 4023f32, color: Color::rgb(1f32, 0.835294117647059f32, 0.627450980392157f32) },
-    StellarClass { name: "R6Ib", mass: 9.9f32, luminosity: 31800f32, radius: 378f32, temp: 4023f32, color: Color::rgb(1f32, 0.835294117647059f32, 0.627450980392157f32) },
-    StellarClass { name: "R6II", mass: 7.6f32, luminosity: 2410f32, radius: 104f32, temp: 4023f32, color: Color::rgb(1f32, 0.850980392156863f32, 0.619607843137255f32) },
-    StellarClass { name: "R6III", mass: 5.3f32, luminosity: 318f32, radius: 37.8f32, temp: 4023f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.650980392156863f32) },
-    StellarClass { name: "R6IV", mass: 3f32, luminosity: 11.7f32, radius: 6.71f32, temp: 4187f32, color: Color::rgb(1f32, 0.87843137254902f32, 0.729411764705882f32) },
-    StellarClass { name: "R6V", mass: 0.7f32, luminosity: 0.426f32, radius: 1.28f32, temp: 4187f32, color: Color::rgb(1f32, 0.858823529411765f32, 0.713725490196078f32) },
-    StellarClass { name: "R6VI", mass: 0.4f32, luminosity: 0.0676f32, radius: 0.509f32, temp: 4187f32, color: Color::rgb(1f32, 0.858823529411765f32, 0.713725490196078f32) },
-    StellarClass { name: "R7Ia0", mass: 15f32, luminosity: 1430000f32, radius: 2680f32, temp: 3915f32, color: Color::rgb(1f32, 0.823529411764706f32, 0.603921568627451f32) },
-    StellarClass { name: "R7Ia", mass: 12.6f32, luminosity: 227000f32, radius: 1070f32, temp: 3915f32, color: Color::rgb(1f32, 0.823529411764706f32, 0.603921568627451f32) },
-    StellarClass { name: "R7Ib", mass: 10.2f32, luminosity: 39400f32, radius: 445f32, temp: 3915f32, color: Color::rgb(1f32, 0.823529411764706f32, 0.603921568627451f32) },
-    StellarClass { name: "R7II", mass: 7.8f32, luminosity: 2990f32, radius: 122f32, temp: 3915f32, color: Color::rgb(1f32, 0.835294117647059f32, 0.588235294117647f32) },
-    StellarClass { name: "R7III", mass: 5.4f32, luminosity: 394f32, radius: 44.5f32, temp: 3915f32, color: Color::rgb(1f32, 0.831372549019608f32, 0.603921568627451f32) },
-    StellarClass { name: "R7IV", mass: 3f32, luminosity: 13.6f32, radius: 8.08f32, temp: 3958f32, color: Color::rgb(1f32, 0.862745098039216f32, 0.694117647058824f32) },
-    StellarClass { name: "R7V", mass: 0.6f32, luminosity: 0.259f32, radius: 1.12f32, temp: 3958f32, color: Color::rgb(1f32, 0.827450980392157f32, 0.658823529411765f32) },
-    StellarClass { name: "R7VI", mass: 0.4f32, luminosity: 0.045f32, radius: 0.465f32, temp: 3958f32, color: Color::rgb(1f32, 0.827450980392157f32, 0.658823529411765f32) },
-    StellarClass { name: "R8Ia0", mass: 15.4f32, luminosity: 1640000f32, radius: 3030f32, temp: 3808f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.584313725490196f32) },
-    StellarClass { name: "R8Ia", mass: 12.9f32, luminosity: 259000f32, radius: 1210f32, temp: 3808f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.584313725490196f32) },
-    StellarClass { name: "R8Ib", mass: 10.4f32, luminosity: 45000f32, radius: 503f32, temp: 3808f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.584313725490196f32) },
-    StellarClass { name: "R8II", mass: 8f32, luminosity: 3420f32, radius: 138f32, temp: 3808f32, color: Color::rgb(1f32, 0.823529411764706f32, 0.552941176470588f32) },
-    StellarClass { name: "R8III", mass: 5.5f32, luminosity: 541f32, radius: 55.1f32, temp: 3808f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.56078431372549f32) },
-    StellarClass { name: "R8IV", mass: 3f32, luminosity: 18.2f32, radius: 10.5f32, temp: 3729f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.658823529411765f32) },
-    StellarClass { name: "R8V", mass: 0.6f32, luminosity: 0.182f32, radius: 1.05f32, temp: 3729f32, color: Color::rgb(1f32, 0.796078431372549f32, 0.6f32) },
-    StellarClass { name: "R8VI", mass: 0.3f32, luminosity: 0.038f32, radius: 0.482f32, temp: 3729f32, color: Color::rgb(1f32, 0.796078431372549f32, 0.6f32) },
-    StellarClass { name: "R9Ia0", mass: 15.8f32, luminosity: 1900000f32, radius: 3460f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "R9Ia", mass: 13.3f32, luminosity: 274000f32, radius: 1310f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "R9Ib", mass: 10.7f32, luminosity: 57300f32, radius: 600f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "R9II", mass: 8.2f32, luminosity: 3960f32, radius: 158f32, temp: 3700f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.52156862745098f32) },
-    StellarClass { name: "R9III", mass: 5.6f32, luminosity: 689f32, radius: 65.8f32, temp: 3700f32, color: Color::rgb(1f32, 0.796078431372549f32, 0.517647058823529f32) },
-    StellarClass { name: "R9IV", mass: 3.1f32, luminosity: 26f32, radius: 14.3f32, temp: 3500f32, color: Color::rgb(1f32, 0.835294117647059f32, 0.627450980392157f32) },
-    StellarClass { name: "R9V", mass: 0.5f32, luminosity: 0.125f32, radius: 0.99f32, temp: 3500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.545098039215686f32) },
-    StellarClass { name: "R9VI", mass: 0.2f32, luminosity: 0.0376f32, radius: 0.544f32, temp: 3500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.545098039215686f32) },
-    StellarClass { name: "C0Ia0", mass: 12f32, luminosity: 813000f32, radius: 1420f32, temp: 4669f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.756862745098039f32) },
-    StellarClass { name: "C0Ia", mass: 10.2f32, luminosity: 170000f32, radius: 649f32, temp: 4669f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.756862745098039f32) },
-    StellarClass { name: "C0Ib", mass: 8.3f32, luminosity: 9770f32, radius: 156f32, temp: 4669f32, color: Color::rgb(1f32, 0.905882352941176f32, 0.756862745098039f32) },
-    StellarClass { name: "C0II", mass: 6.5f32, luminosity: 1290f32, radius: 56.5f32, temp: 4669f32, color: Color::rgb(1f32, 0.929411764705882f32, 0.819607843137255f32) },
-    StellarClass { name: "C0III", mass: 4.6f32, luminosity: 89.1f32, radius: 14.9f32, temp: 4669f32, color: Color::rgb(1f32, 0.952941176470588f32, 0.913725490196078f32) },
-    StellarClass { name: "C0IV", mass: 2.8f32, luminosity: 6.2f32, radius: 2.76f32, temp: 5560f32, color: Color::rgb(1f32, 0.96078431372549f32, 0.933333333333333f32) },
-    StellarClass { name: "C0V", mass: 0.9f32, luminosity: 0.982f32, radius: 1.1f32, temp: 5560f32, color: Color::rgb(1f32, 0.945098039215686f32, 0.898039215686275f32) },
-    StellarClass { name: "C0VI", mass: 0.8f32, luminosity: 0.297f32, radius: 0.605f32, temp: 5560f32, color: Color::rgb(1f32, 0.945098039215686f32, 0.898039215686275f32) },
-    StellarClass { name: "C1Ia0", mass: 12.7f32, luminosity: 1100000f32, radius: 1880f32, temp: 4371f32, color: Color::rgb(1f32, 0.870588235294118f32, 0.674509803921569f32) },
-    StellarClass { name: "C1Ia", mass: 10.8f32, luminosity: 191000f32, radius: 785f32, temp: 4371f32, color: Color::rgb(1f32, 0.870588235294118f32, 0.674509803921569f32) },
-    StellarClass { name: "C1Ib", mass: 8.8f32, luminosity: 12000f32, radius: 197f32, temp: 4371f32, color: Color::rgb(1f32, 0.870588235294118f32, 0.674509803921569f32) },
-    StellarClass { name: "C1II", mass: 6.8f32, luminosity: 1590f32, radius: 71.6f32, temp: 4371f32, color: Color::rgb(1f32, 0.909803921568627f32, 0.768627450980392f32) },
-    StellarClass { name: "C1III", mass: 4.8f32, luminosity: 132f32, radius: 20.6f32, temp: 4371f32, color: Color::rgb(1f32, 0.949019607843137f32, 0.87843137254902f32) },
-    StellarClass { name: "C1IV", mass: 2.8f32, luminosity: 7f32, radius: 3.41f32, temp: 5164f32, color: Color::rgb(1f32, 0.945098039215686f32, 0.894117647058824f32) },
-    StellarClass { name: "C1V", mass: 0.8f32, luminosity: 0.639f32, radius: 1.03f32, temp: 5164f32, color: Color::rgb(1f32, 0.92156862745098f32, 0.854901960784314f32) },
-    StellarClass { name: "C1VI", mass: 0.7f32, luminosity: 0.176f32, radius: 0.54f32, temp: 5164f32, color: Color::rgb(1f32, 0.92156862745098f32, 0.854901960784314f32) },
-    StellarClass { name: "C2Ia0", mass: 13.5f32, luminosity: 1310000f32, radius: 2370f32, temp: 4073f32, color: Color::rgb(1f32, 0.83921568627451f32, 0.588235294117647f32) },
-    StellarClass { name: "C2Ia", mass: 11.4f32, luminosity: 228000f32, radius: 989f32, temp: 4073f32, color: Color::rgb(1f32, 0.83921568627451f32, 0.588235294117647f32) },
-    StellarClass { name: "C2Ib", mass: 9.2f32, luminosity: 17300f32, radius: 272f32, temp: 4073f32, color: Color::rgb(1f32, 0.83921568627451f32, 0.588235294117647f32) },
-    StellarClass { name: "C2II", mass: 7.1f32, luminosity: 2280f32, radius: 98.9f32, temp: 4073f32, color: Color::rgb(1f32, 0.886274509803922f32, 0.717647058823529f32) },
-    StellarClass { name: "C2III", mass: 5f32, luminosity: 208f32, radius: 29.9f32, temp: 4073f32, color: Color::rgb(1f32, 0.945098039215686f32, 0.843137254901961f32) },
-    StellarClass { name: "C2IV", mass: 2.9f32, luminosity: 7.67f32, radius: 4.18f32, temp: 4769f32, color: Color::rgb(1f32, 0.925490196078431f32, 0.854901960784314f32) },
-    StellarClass { name: "C2V", mass: 0.8f32, luminosity: 0.278f32, radius: 0.797f32, temp: 4769f32, color: Color::rgb(1f32, 0.894117647058824f32, 0.811764705882353f32) },
-    StellarClass { name: "C2VI", mass: 0.7f32, luminosity: 0.133f32, radius: 0.551f32, temp: 4769f32, color: Color::rgb(1f32, 0.894117647058824f32, 0.811764705882353f32) },
-    StellarClass { name: "C3Ia0", mass: 14.2f32, luminosity: 1710000f32, radius: 3150f32, temp: 3776f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.505882352941176f32) },
-    StellarClass { name: "C3Ia", mass: 12f32, luminosity: 297000f32, radius: 1310f32, temp: 3776f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.505882352941176f32) },
-    StellarClass { name: "C3Ib", mass: 9.7f32, luminosity: 24700f32, radius: 378f32, temp: 3776f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.505882352941176f32) },
-    StellarClass { name: "C3II", mass: 7.4f32, luminosity: 3570f32, radius: 144f32, temp: 3776f32, color: Color::rgb(1f32, 0.866666666666667f32, 0.666666666666667f32) },
-    StellarClass { name: "C3III", mass: 5.2f32, luminosity: 357f32, radius: 45.5f32, temp: 3776f32, color: Color::rgb(1f32, 0.941176470588235f32, 0.811764705882353f32) },
-    StellarClass { name: "C3IV", mass: 2.9f32, luminosity: 10f32, radius: 5.68f32, temp: 4373f32, color: Color::rgb(1f32, 0.909803921568627f32, 0.819607843137255f32) },
-    StellarClass { name: "C3V", mass: 0.7f32, luminosity: 0.12f32, radius: 0.623f32, temp: 4373f32, color: Color::rgb(1f32, 0.870588235294118f32, 0.768627450980392f32) },
-    StellarClass { name: "C3VI", mass: 0.6f32, luminosity: 0.0912f32, radius: 0.542f32, temp: 4373f32, color: Color::rgb(1f32, 0.870588235294118f32, 0.768627450980392f32) },
-    StellarClass { name: "C4Ia0", mass: 15f32, luminosity: 2710000f32, radius: 4670f32, temp: 3478f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.419607843137255f32) },
-    StellarClass { name: "C4Ia", mass: 12.6f32, luminosity: 429000f32, radius: 1860f32, temp: 3478f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.419607843137255f32) },
-    StellarClass { name: "C4Ib", mass: 10.2f32, luminosity: 42900f32, radius: 588f32, temp: 3478f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.419607843137255f32) },
-    StellarClass { name: "C4II", mass: 7.8f32, luminosity: 6200f32, radius: 223f32, temp: 3478f32, color: Color::rgb(1f32, 0.847058823529412f32, 0.615686274509804f32) },
-    StellarClass { name: "C4III", mass: 5.4f32, luminosity: 680f32, radius: 74f32, temp: 3478f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.776470588235294f32) },
-    StellarClass { name: "C4IV", mass: 3f32, luminosity: 14.6f32, radius: 8.28f32, temp: 3978f32, color: Color::rgb(1f32, 0.890196078431372f32, 0.780392156862745f32) },
-    StellarClass { name: "C4V", mass: 0.6f32, luminosity: 0.0636f32, radius: 0.547f32, temp: 3978f32, color: Color::rgb(1f32, 0.843137254901961f32, 0.725490196078431f32) },
-    StellarClass { name: "C4VI", mass: 0.5f32, luminosity: 0.0636f32, radius: 0.547f32, temp: 3978f32, color: Color::rgb(1f32, 0.843137254901961f32, 0.725490196078431f32) },
-    StellarClass { name: "C5Ia0", mass: 15.7f32, luminosity: 4940000f32, radius: 7550f32, temp: 3180f32, color: Color::rgb(1f32, 0.745098039215686f32, 0.337254901960784f32) },
-    StellarClass { name: "C5Ia", mass: 13.2f32, luminosity: 783000f32, radius: 3000f32, temp: 3180f32, color: Color::rgb(1f32, 0.745098039215686f32, 0.337254901960784f32) },
-    StellarClass { name: "C5Ib", mass: 10.6f32, luminosity: 94100f32, radius: 1040f32, temp: 3180f32, color: Color::rgb(1f32, 0.745098039215686f32, 0.337254901960784f32) },
-    StellarClass { name: "C5II", mass: 8.1f32, luminosity: 11300f32, radius: 361f32, temp: 3180f32, color: Color::rgb(1f32, 0.827450980392157f32, 0.56078431372549f32) },
-    StellarClass { name: "C5III", mass: 5.5f32, luminosity: 1490f32, radius: 131f32, temp: 3180f32, color: Color::rgb(1f32, 0.929411764705882f32, 0.741176470588235f32) },
-    StellarClass { name: "C5IV", mass: 3f32, luminosity: 22.7f32, radius: 12.8f32, temp: 3582f32, color: Color::rgb(1f32, 0.874509803921569f32, 0.741176470588235f32) },
-    StellarClass { name: "C5V", mass: 0.5f32, luminosity: 0.0052f32, radius: 0.193f32, temp: 3582f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.682352941176471f32) },
-    StellarClass { name: "C5VI", mass: 0.4f32, luminosity: 0.0328f32, radius: 0.485f32, temp: 3582f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.682352941176471f32) },
-    StellarClass { name: "C6Ia0", mass: 14.8f32, luminosity: 10900000f32, radius: 13600f32, temp: 2883f32, color: Color::rgb(1f32, 0.713725490196078f32, 0.250980392156863f32) },
-    StellarClass { name: "C6Ia", mass: 12.4f32, luminosity: 1570000f32, radius: 5180f32, temp: 2883f32, color: Color::rgb(1f32, 0.713725490196078f32, 0.250980392156863f32) },
-    StellarClass { name: "C6Ib", mass: 10f32, luminosity: 273000f32, radius: 2160f32, temp: 2883f32, color: Color::rgb(1f32, 0.713725490196078f32, 0.250980392156863f32) },
-    StellarClass { name: "C6II", mass: 7.6f32, luminosity: 27300f32, radius: 683f32, temp: 2883f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.509803921568627f32) },
-    StellarClass { name: "C6III", mass: 5.2f32, luminosity: 3950f32, radius: 260f32, temp: 2883f32, color: Color::rgb(1f32, 0.925490196078431f32, 0.705882352941177f32) },
-    StellarClass { name: "C6IV", mass: 2.8f32, luminosity: 48.6f32, radius: 23.6f32, temp: 3187f32, color: Color::rgb(1f32, 0.854901960784314f32, 0.701960784313725f32) },
-    StellarClass { name: "C6V", mass: 0.4f32, luminosity: 0.00134f32, radius: 0.124f32, temp: 3187f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.619607843137255f32) },
-    StellarClass { name: "C6VI", mass: 0.3f32, luminosity: 0.00307f32, radius: 0.187f32, temp: 3187f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.619607843137255f32) },
-    StellarClass { name: "C7Ia0", mass: 13.9f32, luminosity: 28500000f32, radius: 27400f32, temp: 2585f32, color: Color::rgb(1f32, 0.67843137254902f32, 0.168627450980392f32) },
-    StellarClass { name: "C7Ia", mass: 11.6f32, luminosity: 4120000f32, radius: 10400f32, temp: 2585f32, color: Color::rgb(1f32, 0.67843137254902f32, 0.168627450980392f32) },
-    StellarClass { name: "C7Ib", mass: 9.3f32, luminosity: 861000f32, radius: 4770f32, temp: 2585f32, color: Color::rgb(1f32, 0.67843137254902f32, 0.168627450980392f32) },
-    StellarClass { name: "C7II", mass: 7.1f32, luminosity: 86100f32, radius: 1510f32, temp: 2585f32, color: Color::rgb(1f32, 0.784313725490196f32, 0.458823529411765f32) },
-    StellarClass { name: "C7III", mass: 4.8f32, luminosity: 9440f32, radius: 499f32, temp: 2585f32, color: Color::rgb(1f32, 0.92156862745098f32, 0.674509803921569f32) },
-    StellarClass { name: "C7IV", mass: 2.5f32, luminosity: 146f32, radius: 53.3f32, temp: 2791f32, color: Color::rgb(1f32, 0.83921568627451f32, 0.666666666666667f32) },
-    StellarClass { name: "C7V", mass: 0.3f32, luminosity: 0.00053f32, radius: 0.101f32, temp: 2791f32, color: Color::rgb(1f32, 0.8f32, 0.552941176470588f32) },
-    StellarClass { name: "C7VI", mass: 0.3f32, luminosity: 0.00053f32, radius: 0.101f32, temp: 2791f32, color: Color::rgb(1f32, 0.8f32, 0.552941176470588f32) },
-    StellarClass { name: "C8Ia0", mass: 12.9f32, luminosity: 108000000f32, radius: 68200f32, temp: 2288f32, color: Color::rgb(1f32, 0.647058823529412f32, 0.0823529411764706f32) },
-    StellarClass { name: "C8Ia", mass: 10.8f32, luminosity: 15600000f32, radius: 25900f32, temp: 2288f32, color: Color::rgb(1f32, 0.647058823529412f32, 0.0823529411764706f32) },
-    StellarClass { name: "C8Ib", mass: 8.7f32, luminosity: 3260000f32, radius: 11800f32, temp: 2288f32, color: Color::rgb(1f32, 0.647058823529412f32, 0.0823529411764706f32) },
-    StellarClass { name: "C8II", mass: 6.6f32, luminosity: 358000f32, radius: 3920f32, temp: 2288f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.407843137254902f32) },
-    StellarClass { name: "C8III", mass: 4.4f32, luminosity: 29700f32, radius: 1130f32, temp: 2288f32, color: Color::rgb(1f32, 0.917647058823529f32, 0.63921568627451f32) },
-    StellarClass { name: "C8IV", mass: 2.3f32, luminosity: 670f32, radius: 155f32, temp: 2396f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.627450980392157f32) },
-    StellarClass { name: "C8V", mass: 0.2f32, luminosity: 0.000968f32, radius: 0.186f32, temp: 2396f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.490196078431373f32) },
-    StellarClass { name: "C8VI", mass: 0.2f32, luminosity: 0.00067f32, radius: 0.155f32, temp: 2396f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.490196078431373f32) },
-    StellarClass { name: "C9Ia0", mass: 12f32, luminosity: 711000000f32, radius: 231000f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "C9Ia", mass: 10f32, luminosity: 103000000f32, radius: 87900f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "C9Ib", mass: 8f32, luminosity: 21500000f32, radius: 40200f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "C9II", mass: 6f32, luminosity: 2830000f32, radius: 14600f32, temp: 1990f32, color: Color::rgb(1f32, 0.745098039215686f32, 0.356862745098039f32) },
-    StellarClass { name: "C9III", mass: 4.1f32, luminosity: 179000f32, radius: 3670f32, temp: 1990f32, color: Color::rgb(1f32, 0.913725490196078f32, 0.603921568627451f32) },
-    StellarClass { name: "C9IV", mass: 2.1f32, luminosity: 7910f32, radius: 764f32, temp: 2000f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.588235294117647f32) },
-    StellarClass { name: "C9V", mass: 0.1f32, luminosity: 0.00415f32, radius: 0.553f32, temp: 2000f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.423529411764706f32) },
-    StellarClass { name: "C9VI", mass: 0.1f32, luminosity: 0.00415f32, radius: 0.553f32, temp: 2000f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.423529411764706f32) },
-    StellarClass { name: "S0Ia0", mass: 15.8f32, luminosity: 1900000f32, radius: 3460f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "S0Ia", mass: 13.3f32, luminosity: 274000f32, radius: 1310f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "S0Ib", mass: 10.7f32, luminosity: 57300f32, radius: 600f32, temp: 3700f32, color: Color::rgb(1f32, 0.8f32, 0.56078431372549f32) },
-    StellarClass { name: "S0II", mass: 8.2f32, luminosity: 3960f32, radius: 158f32, temp: 3700f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.52156862745098f32) },
-    StellarClass { name: "S0III", mass: 5.6f32, luminosity: 689f32, radius: 65.8f32, temp: 3700f32, color: Color::rgb(1f32, 0.796078431372549f32, 0.517647058823529f32) },
-    StellarClass { name: "S0IV", mass: 3.1f32, luminosity: 26f32, radius: 14.3f32, temp: 3500f32, color: Color::rgb(1f32, 0.835294117647059f32, 0.627450980392157f32) },
-    StellarClass { name: "S0V", mass: 0.5f32, luminosity: 0.125f32, radius: 0.99f32, temp: 3500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.545098039215686f32) },
-    StellarClass { name: "S0VI", mass: 0.2f32, luminosity: 0.0376f32, radius: 0.544f32, temp: 3500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.545098039215686f32) },
-    StellarClass { name: "S1Ia0", mass: 15.1f32, luminosity: 2560000f32, radius: 4460f32, temp: 3510f32, color: Color::rgb(1f32, 0.792156862745098f32, 0.541176470588235f32) },
-    StellarClass { name: "S1Ia", mass: 12.7f32, luminosity: 337000f32, radius: 1620f32, temp: 3510f32, color: Color::rgb(1f32, 0.792156862745098f32, 0.541176470588235f32) },
-    StellarClass { name: "S1Ib", mass: 10.2f32, luminosity: 77300f32, radius: 775f32, temp: 3510f32, color: Color::rgb(1f32, 0.792156862745098f32, 0.541176470588235f32) },
-    StellarClass { name: "S1II", mass: 7.8f32, luminosity: 5860f32, radius: 213f32, temp: 3510f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.501960784313725f32) },
-    StellarClass { name: "S1III", mass: 5.3f32, luminosity: 929f32, radius: 85f32, temp: 3510f32, color: Color::rgb(1f32, 0.784313725490196f32, 0.474509803921569f32) },
-    StellarClass { name: "S1IV", mass: 2.9f32, luminosity: 35.5f32, radius: 18.4f32, temp: 3333f32, color: Color::rgb(1f32, 0.831372549019608f32, 0.623529411764706f32) },
-    StellarClass { name: "S1V", mass: 0.5f32, luminosity: 0.0618f32, radius: 0.768f32, temp: 3333f32, color: Color::rgb(1f32, 0.8f32, 0.556862745098039f32) },
-    StellarClass { name: "S1VI", mass: 0.2f32, luminosity: 0.0186f32, radius: 0.422f32, temp: 3333f32, color: Color::rgb(1f32, 0.8f32, 0.556862745098039f32) },
-    StellarClass { name: "S2Ia0", mass: 14.5f32, luminosity: 3650000f32, radius: 5950f32, temp: 3320f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.407843137254902f32) },
-    StellarClass { name: "S2Ia", mass: 12.1f32, luminosity: 481000f32, radius: 2160f32, temp: 3320f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.407843137254902f32) },
-    StellarClass { name: "S2Ib", mass: 9.8f32, luminosity: 110000f32, radius: 1030f32, temp: 3320f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.407843137254902f32) },
-    StellarClass { name: "S2II", mass: 7.4f32, luminosity: 8360f32, radius: 285f32, temp: 3320f32, color: Color::rgb(1f32, 0.796078431372549f32, 0.486274509803922f32) },
-    StellarClass { name: "S2III", mass: 5.1f32, luminosity: 1210f32, radius: 108f32, temp: 3320f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.462745098039216f32) },
-    StellarClass { name: "S2IV", mass: 2.7f32, luminosity: 50.9f32, radius: 24.4f32, temp: 3167f32, color: Color::rgb(1f32, 0.827450980392157f32, 0.615686274509804f32) },
-    StellarClass { name: "S2V", mass: 0.4f32, luminosity: 0.0321f32, radius: 0.614f32, temp: 3167f32, color: Color::rgb(1f32, 0.768627450980392f32, 0.513725490196078f32) },
-    StellarClass { name: "S2VI", mass: 0.2f32, luminosity: 0.00885f32, radius: 0.322f32, temp: 3167f32, color: Color::rgb(1f32, 0.768627450980392f32, 0.513725490196078f32) },
-    StellarClass { name: "S3Ia0", mass: 13.8f32, luminosity: 5070000f32, radius: 7890f32, temp: 3130f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.462745098039216f32) },
-    StellarClass { name: "S3Ia", mass: 11.6f32, luminosity: 733000f32, radius: 3000f32, temp: 3130f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.462745098039216f32) },
-    StellarClass { name: "S3Ib", mass: 9.3f32, luminosity: 168000f32, radius: 1440f32, temp: 3130f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.462745098039216f32) },
-    StellarClass { name: "S3II", mass: 7.1f32, luminosity: 14000f32, radius: 414f32, temp: 3130f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.466666666666667f32) },
-    StellarClass { name: "S3III", mass: 4.8f32, luminosity: 1840f32, radius: 150f32, temp: 3130f32, color: Color::rgb(1f32, 0.784313725490196f32, 0.466666666666667f32) },
-    StellarClass { name: "S3IV", mass: 2.6f32, luminosity: 77.6f32, radius: 33.6f32, temp: 3000f32, color: Color::rgb(1f32, 0.823529411764706f32, 0.611764705882353f32) },
-    StellarClass { name: "S3V", mass: 0.3f32, luminosity: 0.0178f32, radius: 0.509f32, temp: 3000f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.505882352941176f32) },
-    StellarClass { name: "S3VI", mass: 0.2f32, luminosity: 0.0049f32, radius: 0.267f32, temp: 3000f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.505882352941176f32) },
-    StellarClass { name: "S4Ia0", mass: 13.2f32, luminosity: 8380000f32, radius: 11500f32, temp: 2940f32, color: Color::rgb(1f32, 0.725490196078431f32, 0.407843137254902f32) },
-    StellarClass { name: "S4Ia", mass: 11f32, luminosity: 1100000f32, radius: 4180f32, temp: 2940f32, color: Color::rgb(1f32, 0.725490196078431f32, 0.407843137254902f32) },
-    StellarClass { name: "S4Ib", mass: 8.9f32, luminosity: 277000f32, radius: 2090f32, temp: 2940f32, color: Color::rgb(1f32, 0.725490196078431f32, 0.407843137254902f32) },
-    StellarClass { name: "S4II", mass: 6.7f32, luminosity: 23100f32, radius: 604f32, temp: 2940f32, color: Color::rgb(1f32, 0.780392156862745f32, 0.447058823529412f32) },
-    StellarClass { name: "S4III", mass: 4.6f32, luminosity: 2770f32, radius: 209f32, temp: 2940f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.498039215686275f32) },
-    StellarClass { name: "S4IV", mass: 2.4f32, luminosity: 127f32, radius: 48.3f32, temp: 2833f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.607843137254902f32) },
-    StellarClass { name: "S4V", mass: 0.3f32, luminosity: 0.0106f32, radius: 0.44f32, temp: 2833f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.498039215686275f32) },
-    StellarClass { name: "S4VI", mass: 0.1f32, luminosity: 0.00266f32, radius: 0.221f32, temp: 2833f32, color: Color::rgb(1f32, 0.788235294117647f32, 0.498039215686275f32) },
-    StellarClass { name: "S5Ia0", mass: 12.5f32, luminosity: 15300000f32, radius: 17800f32, temp: 2750f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.305882352941176f32) },
-    StellarClass { name: "S5Ia", mass: 10.5f32, luminosity: 2020000f32, radius: 6450f32, temp: 2750f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.305882352941176f32) },
-    StellarClass { name: "S5Ib", mass: 8.4f32, luminosity: 507000f32, radius: 3230f32, temp: 2750f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.305882352941176f32) },
-    StellarClass { name: "S5II", mass: 6.4f32, luminosity: 46200f32, radius: 976f32, temp: 2750f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.431372549019608f32) },
-    StellarClass { name: "S5III", mass: 4.3f32, luminosity: 5070f32, radius: 323f32, temp: 2750f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.486274509803922f32) },
-    StellarClass { name: "S5IV", mass: 2.3f32, luminosity: 207f32, radius: 69.4f32, temp: 2667f32, color: Color::rgb(1f32, 0.819607843137255f32, 0.603921568627451f32) },
-    StellarClass { name: "S5V", mass: 0.2f32, luminosity: 0.00624f32, radius: 0.381f32, temp: 2667f32, color: Color::rgb(1f32, 0.8f32, 0.435294117647059f32) },
-    StellarClass { name: "S5VI", mass: 0.1f32, luminosity: 0.00172f32, radius: 0.2f32, temp: 2667f32, color: Color::rgb(1f32, 0.8f32, 0.435294117647059f32) },
-    StellarClass { name: "S6Ia0", mass: 12.4f32, luminosity: 28800000f32, radius: 28100f32, temp: 2560f32, color: Color::rgb(1f32, 0.670588235294118f32, 0.203921568627451f32) },
-    StellarClass { name: "S6Ia", mass: 10.3f32, luminosity: 4170000f32, radius: 10700f32, temp: 2560f32, color: Color::rgb(1f32, 0.670588235294118f32, 0.203921568627451f32) },
-    StellarClass { name: "S6Ib", mass: 8.3f32, luminosity: 955000f32, radius: 5120f32, temp: 2560f32, color: Color::rgb(1f32, 0.670588235294118f32, 0.203921568627451f32) },
-    StellarClass { name: "S6II", mass: 6.3f32, luminosity: 95500f32, radius: 1620f32, temp: 2560f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.411764705882353f32) },
-    StellarClass { name: "S6III", mass: 4.2f32, luminosity: 9550f32, radius: 512f32, temp: 2560f32, color: Color::rgb(1f32, 0.698039215686274f32, 0.474509803921569f32) },
-    StellarClass { name: "S6IV", mass: 2.2f32, luminosity: 410f32, radius: 111f32, temp: 2500f32, color: Color::rgb(1f32, 0.815686274509804f32, 0.6f32) },
-    StellarClass { name: "S6V", mass: 0.2f32, luminosity: 0.0045f32, radius: 0.368f32, temp: 2500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.43921568627451f32) },
-    StellarClass { name: "S6VI", mass: 0.1f32, luminosity: 0.00163f32, radius: 0.222f32, temp: 2500f32, color: Color::rgb(1f32, 0.764705882352941f32, 0.43921568627451f32) },
-    StellarClass { name: "S7Ia0", mass: 12.3f32, luminosity: 69600000f32, radius: 51000f32, temp: 2370f32, color: Color::rgb(1f32, 0.643137254901961f32, 0.101960784313725f32) },
-    StellarClass { name: "S7Ia", mass: 10.2f32, luminosity: 9180000f32, radius: 18500f32, temp: 2370f32, color: Color::rgb(1f32, 0.643137254901961f32, 0.101960784313725f32) },
-    StellarClass { name: "S7Ib", mass: 8.2f32, luminosity: 2100000f32, radius: 8870f32, temp: 2370f32, color: Color::rgb(1f32, 0.643137254901961f32, 0.101960784313725f32) },
-    StellarClass { name: "S7II", mass: 6.2f32, luminosity: 253000f32, radius: 3070f32, temp: 2370f32, color: Color::rgb(1f32, 0.756862745098039f32, 0.392156862745098f32) },
-    StellarClass { name: "S7III", mass: 4.2f32, luminosity: 21000f32, radius: 887f32, temp: 2370f32, color: Color::rgb(1f32, 0.647058823529412f32, 0.380392156862745f32) },
-    StellarClass { name: "S7IV", mass: 2.1f32, luminosity: 926f32, radius: 192f32, temp: 2333f32, color: Color::rgb(1f32, 0.811764705882353f32, 0.596078431372549f32) },
-    StellarClass { name: "S7V", mass: 0.1f32, luminosity: 0.00369f32, radius: 0.383f32, temp: 2333f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.435294117647059f32) },
-    StellarClass { name: "S7VI", mass: 0.1f32, luminosity: 0.00194f32, radius: 0.278f32, temp: 2333f32, color: Color::rgb(1f32, 0.772549019607843f32, 0.435294117647059f32) },
-    StellarClass { name: "S8Ia0", mass: 12.1f32, luminosity: 205000000f32, radius: 103000f32, temp: 2180f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "S8Ia", mass: 10.1f32, luminosity: 27000000f32, radius: 37600f32, temp: 2180f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "S8Ib", mass: 8.1f32, luminosity: 5150000f32, radius: 16400f32, temp: 2180f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "S8II", mass: 6.1f32, luminosity: 744000f32, radius: 6230f32, temp: 2180f32, color: Color::rgb(1f32, 0.752941176470588f32, 0.376470588235294f32) },
-    StellarClass { name: "S8III", mass: 4.1f32, luminosity: 51500f32, radius: 1640f32, temp: 2180f32, color: Color::rgb(1f32, 0.654901960784314f32, 0.380392156862745f32) },
-    StellarClass { name: "S8IV", mass: 2.1f32, luminosity: 2440f32, radius: 361f32, temp: 2167f32, color: Color::rgb(1f32, 0.807843137254902f32, 0.592156862745098f32) },
-    StellarClass { name: "S8V", mass: 0.1f32, luminosity: 0.00353f32, radius: 0.434f32, temp: 2167f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.427450980392157f32) },
-    StellarClass { name: "S8VI", mass: 0.1f32, luminosity: 0.00244f32, radius: 0.361f32, temp: 2167f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.427450980392157f32) },
-    StellarClass { name: "S9Ia0", mass: 12f32, luminosity: 711000000f32, radius: 231000f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "S9Ia", mass: 10f32, luminosity: 103000000f32, radius: 87900f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "S9Ib", mass: 8f32, luminosity: 17900000f32, radius: 36700f32, temp: 1990f32, color: Color::rgb(1f32, 0.615686274509804f32, 0f32) },
-    StellarClass { name: "S9II", mass: 6f32, luminosity: 2830000f32, radius: 14600f32, temp: 1990f32, color: Color::rgb(1f32, 0.745098039215686f32, 0.356862745098039f32) },
-    StellarClass { name: "S9III", mass: 4.1f32, luminosity: 179000f32, radius: 3670f32, temp: 1990f32, color: Color::rgb(1f32, 0.913725490196078f32, 0.603921568627451f32) },
-    StellarClass { name: "S9IV", mass: 2.1f32, luminosity: 7910f32, radius: 764f32, temp: 2000f32, color: Color::rgb(1f32, 0.803921568627451f32, 0.588235294117647f32) },
-    StellarClass { name: "S9V", mass: 0.1f32, luminosity: 0.00415f32, radius: 0.553f32, temp: 2000f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.423529411764706f32) },
-    StellarClass { name: "S9VI", mass: 0.1f32, luminosity: 0.00415f32, radius: 0.553f32, temp: 2000f32, color: Color::rgb(1f32, 0.776470588235294f32, 0.423529411764706f32) },
-    StellarClass { name: "DA0", mass: 1.1f32, luminosity: 6.91f32, radius: 0.00902f32, temp: 100000f32, color: Color::rgb(0.607843137254902f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "DA1", mass: 0.9f32, luminosity: 0.265f32, radius: 0.00696f32, temp: 50400f32, color: Color::rgb(0.623529411764706f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "DA2", mass: 0.8f32, luminosity: 0.0255f32, radius: 0.00864f32, temp: 25200f32, color: Color::rgb(0.658823529411765f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "DA3", mass: 0.7f32, luminosity: 0.00602f32, radius: 0.00944f32, temp: 16800f32, color: Color::rgb(0.701960784313725f32, 0.772549019607843f32, 1f32) },
-    StellarClass { name: "DA4", mass: 0.6f32, luminosity: 0.00182f32, radius: 0.00922f32, temp: 12600f32, color: Color::rgb(0.752941176470588f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "DA5", mass: 0.5f32, luminosity: 0.000693f32, radius: 0.0089f32, temp: 10080f32, color: Color::rgb(0.811764705882353f32, 0.854901960784314f32, 1f32) },
-    StellarClass { name: "DA6", mass: 0.4f32, luminosity: 0.000315f32, radius: 0.00864f32, temp: 8400f32, color: Color::rgb(0.87843137254902f32, 0.901960784313726f32, 1f32) },
-    StellarClass { name: "DA7", mass: 0.3f32, luminosity: 0.00018f32, radius: 0.00889f32, temp: 7200f32, color: Color::rgb(0.952941176470588f32, 0.952941176470588f32, 1f32) },
-    StellarClass { name: "DA8", mass: 0.2f32, luminosity: 0.000105f32, radius: 0.00887f32, temp: 6300f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.96078431372549f32) },
-    StellarClass { name: "DA9", mass: 0.1f32, luminosity: 0.0000673f32, radius: 0.00898f32, temp: 5600f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.882352941176471f32) },
-    StellarClass { name: "DB0", mass: 1.1f32, luminosity: 6.91f32, radius: 0.00902f32, temp: 100000f32, color: Color::rgb(0.607843137254902f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "DB1", mass: 0.9f32, luminosity: 0.265f32, radius: 0.00696f32, temp: 50400f32, color: Color::rgb(0.623529411764706f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "DB2", mass: 0.8f32, luminosity: 0.0255f32, radius: 0.00864f32, temp: 25200f32, color: Color::rgb(0.658823529411765f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "DB3", mass: 0.7f32, luminosity: 0.00602f32, radius: 0.00944f32, temp: 16800f32, color: Color::rgb(0.701960784313725f32, 0.772549019607843f32, 1f32) },
-    StellarClass { name: "DB4", mass: 0.6f32, luminosity: 0.00182f32, radius: 0.00922f32, temp: 12600f32, color: Color::rgb(0.752941176470588f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "DB5", mass: 0.5f32, luminosity: 0.000693f32, radius: 0.0089f32, temp: 10080f32, color: Color::rgb(0.811764705882353f32, 0.854901960784314f32, 1f32) },
-    StellarClass { name: "DB6", mass: 0.4f32, luminosity: 0.000315f32, radius: 0.00864f32, temp: 8400f32, color: Color::rgb(0.87843137254902f32, 0.901960784313726f32, 1f32) },
-    StellarClass { name: "DB7", mass: 0.3f32, luminosity: 0.00018f32, radius: 0.00889f32, temp: 7200f32, color: Color::rgb(0.952941176470588f32, 0.952941176470588f32, 1f32) },
-    StellarClass { name: "DB8", mass: 0.2f32, luminosity: 0.000105f32, radius: 0.00887f32, temp: 6300f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.96078431372549f32) },
-    StellarClass { name: "DB9", mass: 0.1f32, luminosity: 0.0000673f32, radius: 0.00898f32, temp: 5600f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.882352941176471f32) },
-    StellarClass { name: "DC0", mass: 1.1f32, luminosity: 6.91f32, radius: 0.00902f32, temp: 100000f32, color: Color::rgb(0.607843137254902f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "DC1", mass: 0.9f32, luminosity: 0.265f32, radius: 0.00696f32, temp: 50400f32, color: Color::rgb(0.623529411764706f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "DC2", mass: 0.8f32, luminosity: 0.0255f32, radius: 0.00864f32, temp: 25200f32, color: Color::rgb(0.658823529411765f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "DC3", mass: 0.7f32, luminosity: 0.00602f32, radius: 0.00944f32, temp: 16800f32, color: Color::rgb(0.701960784313725f32, 0.772549019607843f32, 1f32) },
-    StellarClass { name: "DC4", mass: 0.6f32, luminosity: 0.00182f32, radius: 0.00922f32, temp: 12600f32, color: Color::rgb(0.752941176470588f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "DC5", mass: 0.5f32, luminosity: 0.000693f32, radius: 0.0089f32, temp: 10080f32, color: Color::rgb(0.811764705882353f32, 0.854901960784314f32, 1f32) },
-    StellarClass { name: "DC6", mass: 0.4f32, luminosity: 0.000315f32, radius: 0.00864f32, temp: 8400f32, color: Color::rgb(0.87843137254902f32, 0.901960784313726f32, 1f32) },
-    StellarClass { name: "DC7", mass: 0.3f32, luminosity: 0.00018f32, radius: 0.00889f32, temp: 7200f32, color: Color::rgb(0.952941176470588f32, 0.952941176470588f32, 1f32) },
-    StellarClass { name: "DC8", mass: 0.2f32, luminosity: 0.000105f32, radius: 0.00887f32, temp: 6300f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.96078431372549f32) },
-    StellarClass { name: "DC9", mass: 0.1f32, luminosity: 0.0000673f32, radius: 0.00898f32, temp: 5600f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.882352941176471f32) },
-    StellarClass { name: "DO0", mass: 1.1f32, luminosity: 6.91f32, radius: 0.00902f32, temp: 100000f32, color: Color::rgb(0.607843137254902f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "DO1", mass: 0.9f32, luminosity: 0.265f32, radius: 0.00696f32, temp: 50400f32, color: Color::rgb(0.623529411764706f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "DO2", mass: 0.8f32, luminosity: 0.0255f32, radius: 0.00864f32, temp: 25200f32, color: Color::rgb(0.658823529411765f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "DO3", mass: 0.7f32, luminosity: 0.00602f32, radius: 0.00944f32, temp: 16800f32, color: Color::rgb(0.701960784313725f32, 0.772549019607843f32, 1f32) },
-    StellarClass { name: "DO4", mass: 0.6f32, luminosity: 0.00182f32, radius: 0.00922f32, temp: 12600f32, color: Color::rgb(0.752941176470588f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "DO5", mass: 0.5f32, luminosity: 0.000693f32, radius: 0.0089f32, temp: 10080f32, color: Color::rgb(0.811764705882353f32, 0.854901960784314f32, 1f32) },
-    StellarClass { name: "DO6", mass: 0.4f32, luminosity: 0.000315f32, radius: 0.00864f32, temp: 8400f32, color: Color::rgb(0.87843137254902f32, 0.901960784313726f32, 1f32) },
-    StellarClass { name: "DO7", mass: 0.3f32, luminosity: 0.00018f32, radius: 0.00889f32, temp: 7200f32, color: Color::rgb(0.952941176470588f32, 0.952941176470588f32, 1f32) },
-    StellarClass { name: "DO8", mass: 0.2f32, luminosity: 0.000105f32, radius: 0.00887f32, temp: 6300f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.96078431372549f32) },
-    StellarClass { name: "DO9", mass: 0.1f32, luminosity: 0.0000673f32, radius: 0.00898f32, temp: 5600f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.882352941176471f32) },
-    StellarClass { name: "DQ0", mass: 1.1f32, luminosity: 6.91f32, radius: 0.00902f32, temp: 100000f32, color: Color::rgb(0.607843137254902f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "DQ1", mass: 0.9f32, luminosity: 0.265f32, radius: 0.00696f32, temp: 50400f32, color: Color::rgb(0.623529411764706f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "DQ2", mass: 0.8f32, luminosity: 0.0255f32, radius: 0.00864f32, temp: 25200f32, color: Color::rgb(0.658823529411765f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "DQ3", mass: 0.7f32, luminosity: 0.00602f32, radius: 0.00944f32, temp: 16800f32, color: Color::rgb(0.701960784313725f32, 0.772549019607843f32, 1f32) },
-    StellarClass { name: "DQ4", mass: 0.6f32, luminosity: 0.00182f32, radius: 0.00922f32, temp: 12600f32, color: Color::rgb(0.752941176470588f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "DQ5", mass: 0.5f32, luminosity: 0.000693f32, radius: 0.0089f32, temp: 10080f32, color: Color::rgb(0.811764705882353f32, 0.854901960784314f32, 1f32) },
-    StellarClass { name: "DQ6", mass: 0.4f32, luminosity: 0.000315f32, radius: 0.00864f32, temp: 8400f32, color: Color::rgb(0.87843137254902f32, 0.901960784313726f32, 1f32) },
-    StellarClass { name: "DQ7", mass: 0.3f32, luminosity: 0.00018f32, radius: 0.00889f32, temp: 7200f32, color: Color::rgb(0.952941176470588f32, 0.952941176470588f32, 1f32) },
-    StellarClass { name: "DQ8", mass: 0.2f32, luminosity: 0.000105f32, radius: 0.00887f32, temp: 6300f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.96078431372549f32) },
-    StellarClass { name: "DQ9", mass: 0.1f32, luminosity: 0.0000673f32, radius: 0.00898f32, temp: 5600f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.882352941176471f32) },
-    StellarClass { name: "DZ0", mass: 1.1f32, luminosity: 6.91f32, radius: 0.00902f32, temp: 100000f32, color: Color::rgb(0.607843137254902f32, 0.698039215686274f32, 1f32) },
-    StellarClass { name: "DZ1", mass: 0.9f32, luminosity: 0.265f32, radius: 0.00696f32, temp: 50400f32, color: Color::rgb(0.623529411764706f32, 0.709803921568627f32, 1f32) },
-    StellarClass { name: "DZ2", mass: 0.8f32, luminosity: 0.0255f32, radius: 0.00864f32, temp: 25200f32, color: Color::rgb(0.658823529411765f32, 0.741176470588235f32, 1f32) },
-    StellarClass { name: "DZ3", mass: 0.7f32, luminosity: 0.00602f32, radius: 0.00944f32, temp: 16800f32, color: Color::rgb(0.701960784313725f32, 0.772549019607843f32, 1f32) },
-    StellarClass { name: "DZ4", mass: 0.6f32, luminosity: 0.00182f32, radius: 0.00922f32, temp: 12600f32, color: Color::rgb(0.752941176470588f32, 0.811764705882353f32, 1f32) },
-    StellarClass { name: "DZ5", mass: 0.5f32, luminosity: 0.000693f32, radius: 0.0089f32, temp: 10080f32, color: Color::rgb(0.811764705882353f32, 0.854901960784314f32, 1f32) },
-    StellarClass { name: "DZ6", mass: 0.4f32, luminosity: 0.000315f32, radius: 0.00864f32, temp: 8400f32, color: Color::rgb(0.87843137254902f32, 0.901960784313726f32, 1f32) },
-    StellarClass { name: "DZ7", mass: 0.3f32, luminosity: 0.00018f32, radius: 0.00889f32, temp: 7200f32, color: Color::rgb(0.952941176470588f32, 0.952941176470588f32, 1f32) },
-    StellarClass { name: "DZ8", mass: 0.2f32, luminosity: 0.000105f32, radius: 0.00887f32, temp: 6300f32, color: Color::rgb(1f32, 0.968627450980392f32, 0.96078431372549f32) },
-    StellarClass { name: "DZ9", mass: 0.1f32, luminosity: 0.0000673f32, radius: 0.00898f32, temp: 5600f32, color: Color::rgb(1f32, 0.937254901960784f32, 0.882352941176471f32) },
-];
@@ -0,0 +1,521 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Stellar Classification table used to generate stars.
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::prelude::*;
+use bevy::reflect::TypeUuid;
+use bevy::utils::{HashMap, HashSet};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Spectral Type, the letter part of a classification.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum SpectralType {
+    WN,
+    WC,
+    O,
+    B,
+    A,
+    F,
+    G,
+    K,
+    M,
+    R,
+    N,
+    S,
+    C,
+    DA,
+    DB,
+    DC,
+    DO,
+    DQ,
+    DZ,
+}
+
+impl SpectralType {
+    /// Every Spectral Type, longest prefix first so parsing is greedy.
+    const PREFIXES: [(&'static str, SpectralType); 19] = [
+        ("WN", SpectralType::WN),
+        ("WC", SpectralType::WC),
+        ("DA", SpectralType::DA),
+        ("DB", SpectralType::DB),
+        ("DC", SpectralType::DC),
+        ("DO", SpectralType::DO),
+        ("DQ", SpectralType::DQ),
+        ("DZ", SpectralType::DZ),
+        ("O", SpectralType::O),
+        ("B", SpectralType::B),
+        ("A", SpectralType::A),
+        ("F", SpectralType::F),
+        ("G", SpectralType::G),
+        ("K", SpectralType::K),
+        ("M", SpectralType::M),
+        ("R", SpectralType::R),
+        ("N", SpectralType::N),
+        ("S", SpectralType::S),
+        ("C", SpectralType::C),
+    ];
+    /// White Dwarfs have no luminosity class of their own.
+    pub fn is_white_dwarf(&self) -> bool {
+        matches!(
+            self,
+            SpectralType::DA
+                | SpectralType::DB
+                | SpectralType::DC
+                | SpectralType::DO
+                | SpectralType::DQ
+                | SpectralType::DZ
+        )
+    }
+    pub fn label(&self) -> &'static str {
+        SpectralType::PREFIXES
+            .iter()
+            .find(|(_, kind)| kind == self)
+            .map_or("", |(label, _)| label)
+    }
+}
+
+/// Luminosity Class, the roman numeral part of a classification.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum LuminosityClass {
+    /// Hypergiant
+    Ia0,
+    /// Luminous Supergiant
+    Ia,
+    /// Supergiant
+    Ib,
+    /// Bright Giant
+    II,
+    /// Giant
+    III,
+    /// Subgiant
+    IV,
+    /// Main Sequence
+    V,
+    /// Subdwarf
+    VI,
+    /// White Dwarf
+    VII,
+}
+
+impl LuminosityClass {
+    /// Luminosity Classes expected for every non White Dwarf type.
+    pub const GIANTS_AND_DWARFS: [LuminosityClass; 8] = [
+        LuminosityClass::Ia0,
+        LuminosityClass::Ia,
+        LuminosityClass::Ib,
+        LuminosityClass::II,
+        LuminosityClass::III,
+        LuminosityClass::IV,
+        LuminosityClass::V,
+        LuminosityClass::VI,
+    ];
+    pub fn label(&self) -> &'static str {
+        match self {
+            LuminosityClass::Ia0 => "Ia0",
+            LuminosityClass::Ia => "Ia",
+            LuminosityClass::Ib => "Ib",
+            LuminosityClass::II => "II",
+            LuminosityClass::III => "III",
+            LuminosityClass::IV => "IV",
+            LuminosityClass::V => "V",
+            LuminosityClass::VI => "VI",
+            LuminosityClass::VII => "VII",
+        }
+    }
+}
+
+impl FromStr for LuminosityClass {
+    type Err = SpectralParseError;
+    fn from_str(label: &str) -> Result<LuminosityClass, SpectralParseError> {
+        LuminosityClass::GIANTS_AND_DWARFS
+            .iter()
+            .chain(std::iter::once(&LuminosityClass::VII))
+            .find(|class| class.label() == label)
+            .copied()
+            .ok_or_else(|| SpectralParseError::UnknownLuminosityClass(label.to_owned()))
+    }
+}
+
+/// Full Spectral Classification such as `G2V`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct SpectralClass {
+    pub kind: SpectralType,
+    /// Subtype from `0` (hottest) to `9` (coolest)
+    pub subtype: u8,
+    pub luminosity: LuminosityClass,
+}
+
+impl fmt::Display for SpectralClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.kind.is_white_dwarf() {
+            write!(f, "{}{}", self.kind.label(), self.subtype)
+        } else {
+            write!(
+                f,
+                "{}{}{}",
+                self.kind.label(),
+                self.subtype,
+                self.luminosity.label()
+            )
+        }
+    }
+}
+
+/// Reasons a Spectral Classification couldn't be parsed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SpectralParseError {
+    UnknownSpectralType(String),
+    MissingSubtype(String),
+    UnknownLuminosityClass(String),
+}
+
+impl fmt::Display for SpectralParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpectralParseError::UnknownSpectralType(label) => {
+                write!(f, "unknown spectral type in {:?}", label)
+            }
+            SpectralParseError::MissingSubtype(label) => {
+                write!(f, "missing subtype in {:?}", label)
+            }
+            SpectralParseError::UnknownLuminosityClass(label) => {
+                write!(f, "unknown luminosity class {:?}", label)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SpectralParseError {}
+
+impl FromStr for SpectralClass {
+    type Err = SpectralParseError;
+    fn from_str(label: &str) -> Result<SpectralClass, SpectralParseError> {
+        let (prefix, kind) = SpectralType::PREFIXES
+            .iter()
+            .find(|(prefix, _)| label.starts_with(prefix))
+            .ok_or_else(|| SpectralParseError::UnknownSpectralType(label.to_owned()))?;
+        let rest = &label[prefix.len()..];
+        let subtype = rest
+            .chars()
+            .next()
+            .and_then(|digit| digit.to_digit(10))
+            .ok_or_else(|| SpectralParseError::MissingSubtype(label.to_owned()))?;
+        let rest = &rest[1..];
+        let luminosity = if kind.is_white_dwarf() && rest.is_empty() {
+            LuminosityClass::VII
+        } else {
+            rest.parse()?
+        };
+        Ok(SpectralClass {
+            kind: *kind,
+            subtype: subtype as u8,
+            luminosity,
+        })
+    }
+}
+
+/// Physical properties of a class of star.
+#[derive(Debug, Clone)]
+pub struct StellarClass {
+    pub class: SpectralClass,
+    /// Solar Masses
+    pub mass: f32,
+    /// Solar Luminosities
+    pub luminosity: f32,
+    /// Solar Radii
+    pub radius: f32,
+    /// Kelvin
+    pub temp: f32,
+    pub color: Color,
+}
+
+impl StellarClass {
+    /// Blend between two classes by `t`, luminosity is blended logarithmically.
+    fn lerp(&self, other: &StellarClass, t: f32) -> StellarClass {
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        let [r0, g0, b0, _] = self.color.as_rgba_f32();
+        let [r1, g1, b1, _] = other.color.as_rgba_f32();
+        StellarClass {
+            class: if t < 0.5 { self.class } else { other.class },
+            mass: mix(self.mass, other.mass),
+            luminosity: 10f32.powf(mix(self.luminosity.log10(), other.luminosity.log10())),
+            radius: mix(self.radius, other.radius),
+            temp: mix(self.temp, other.temp),
+            color: Color::rgb(mix(r0, r1), mix(g0, g1), mix(b0, b1)),
+        }
+    }
+}
+
+/// Row of the catalog as written in the asset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StellarRecord {
+    name: String,
+    mass: f32,
+    luminosity: f32,
+    radius: f32,
+    temp: f32,
+    color: (f32, f32, f32),
+}
+
+/// Problems found while validating a catalog.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CatalogIssue {
+    /// Name couldn't be parsed
+    Unparseable(String, SpectralParseError),
+    /// Same classification listed more than once
+    Duplicate(SpectralClass),
+    /// Temperature rises from one subtype to the next
+    TemperatureNotMonotonic(SpectralClass),
+    /// Spectral Type lacks a Luminosity Class every other type has
+    MissingLuminosityClass(SpectralType, LuminosityClass),
+}
+
+impl CatalogIssue {
+    /// Fatal issues make the catalog ambiguous, the rest are worth a warning.
+    pub fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            CatalogIssue::Unparseable(..) | CatalogIssue::Duplicate(_)
+        )
+    }
+}
+
+impl fmt::Display for CatalogIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogIssue::Unparseable(name, error) => write!(f, "{}: {}", name, error),
+            CatalogIssue::Duplicate(class) => write!(f, "{} is listed more than once", class),
+            CatalogIssue::TemperatureNotMonotonic(class) => {
+                write!(f, "{} is hotter than the subtype before it", class)
+            }
+            CatalogIssue::MissingLuminosityClass(kind, luminosity) => write!(
+                f,
+                "{} has no luminosity class {}",
+                kind.label(),
+                luminosity.label()
+            ),
+        }
+    }
+}
+
+/// Catalog of Stellar Classifications, loaded from `*.catalog` RON files.
+#[derive(Debug, Clone, Default, TypeUuid)]
+#[uuid = "b3a1f3c2-6d0e-4c53-93b4-0b8f4a8d2e71"]
+pub struct StellarCatalog {
+    classes: Vec<StellarClass>,
+    index: HashMap<SpectralClass, usize>,
+}
+
+impl StellarCatalog {
+    /// Parse a catalog from RON, returning every issue found alongside it.
+    pub fn from_ron(data: &str) -> Result<(StellarCatalog, Vec<CatalogIssue>), ron::Error> {
+        let records: Vec<StellarRecord> = ron::from_str(data)?;
+        Ok(StellarCatalog::from_records(records))
+    }
+    fn from_records(records: Vec<StellarRecord>) -> (StellarCatalog, Vec<CatalogIssue>) {
+        let mut catalog = StellarCatalog::default();
+        let mut issues = Vec::new();
+        for record in records {
+            let class = match record.name.parse::<SpectralClass>() {
+                Ok(class) => class,
+                Err(error) => {
+                    issues.push(CatalogIssue::Unparseable(record.name, error));
+                    continue;
+                }
+            };
+            if catalog.index.contains_key(&class) {
+                issues.push(CatalogIssue::Duplicate(class));
+                continue;
+            }
+            let (r, g, b) = record.color;
+            catalog.index.insert(class, catalog.classes.len());
+            catalog.classes.push(StellarClass {
+                class,
+                mass: record.mass,
+                luminosity: record.luminosity,
+                radius: record.radius,
+                temp: record.temp,
+                color: Color::rgb(r, g, b),
+            });
+        }
+        issues.extend(catalog.validate());
+        (catalog, issues)
+    }
+    /// Check temperatures fall with subtype and every type has every luminosity class.
+    pub fn validate(&self) -> Vec<CatalogIssue> {
+        let mut issues = Vec::new();
+        for class in self.classes.iter().map(|class| class.class) {
+            if class.subtype == 0 {
+                continue;
+            }
+            let hotter = SpectralClass {
+                subtype: class.subtype - 1,
+                ..class
+            };
+            if let (Some(this), Some(hotter)) = (self.get(class), self.get(hotter)) {
+                if this.temp > hotter.temp {
+                    issues.push(CatalogIssue::TemperatureNotMonotonic(class));
+                }
+            }
+        }
+        let present: HashSet<(SpectralType, LuminosityClass)> = self
+            .classes
+            .iter()
+            .map(|class| (class.class.kind, class.class.luminosity))
+            .collect();
+        let mut kinds: Vec<SpectralType> = present.iter().map(|(kind, _)| *kind).collect();
+        kinds.sort();
+        kinds.dedup();
+        for kind in kinds.into_iter().filter(|kind| !kind.is_white_dwarf()) {
+            for luminosity in LuminosityClass::GIANTS_AND_DWARFS.iter() {
+                if !present.contains(&(kind, *luminosity)) {
+                    issues.push(CatalogIssue::MissingLuminosityClass(kind, *luminosity));
+                }
+            }
+        }
+        issues
+    }
+    pub fn len(&self) -> usize {
+        self.classes.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &StellarClass> {
+        self.classes.iter()
+    }
+    pub fn get(&self, class: SpectralClass) -> Option<&StellarClass> {
+        self.classes.get(*self.index.get(&class)?)
+    }
+    /// Pick a class uniformly at random.
+    pub fn choose<R: Rng>(&self, rng: &mut R) -> Option<&StellarClass> {
+        if self.classes.is_empty() {
+            None
+        } else {
+            self.classes.get(rng.gen_range(0..self.classes.len()))
+        }
+    }
+    /// Properties at a fractional subtype such as `G2.5V`, interpolated between table rows.
+    pub fn interpolate(
+        &self,
+        kind: SpectralType,
+        subtype: f32,
+        luminosity: LuminosityClass,
+    ) -> Option<StellarClass> {
+        let subtype = subtype.clamp(0.0, 9.0);
+        let class = |subtype: f32| SpectralClass {
+            kind,
+            subtype: subtype as u8,
+            luminosity,
+        };
+        let lower = self.get(class(subtype.floor()))?;
+        match self.get(class(subtype.ceil())) {
+            Some(upper) => Some(lower.lerp(upper, subtype.fract())),
+            None => Some(lower.clone()),
+        }
+    }
+}
+
+/// Loads [StellarCatalog] from RON, refusing catalogs with duplicate or unparseable entries.
+#[derive(Default)]
+pub struct StellarCatalogLoader;
+
+impl AssetLoader for StellarCatalogLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let records: Vec<StellarRecord> = ron::de::from_bytes(bytes)?;
+            let (catalog, issues) = StellarCatalog::from_records(records);
+            for issue in issues.iter() {
+                warn!("Stellar Catalog {:?}: {}", load_context.path(), issue);
+            }
+            if let Some(issue) = issues.iter().find(|issue| issue.is_fatal()) {
+                anyhow::bail!("Invalid Stellar Catalog: {}", issue);
+            }
+            load_context.set_default_asset(LoadedAsset::new(catalog));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["catalog"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_spectral_class() {
+        let class: SpectralClass = "O5V".parse().unwrap();
+        assert_eq!(class.kind, SpectralType::O);
+        assert_eq!(class.subtype, 5);
+        assert_eq!(class.luminosity, LuminosityClass::V);
+        assert_eq!(
+            "WN3Ia0".parse::<SpectralClass>().unwrap().kind,
+            SpectralType::WN
+        );
+        assert_eq!(
+            "DA4".parse::<SpectralClass>().unwrap().luminosity,
+            LuminosityClass::VII
+        );
+        assert_eq!("G2V".parse::<SpectralClass>().unwrap().to_string(), "G2V");
+        assert!("X2V".parse::<SpectralClass>().is_err());
+        assert!("G2X".parse::<SpectralClass>().is_err());
+    }
+
+    #[test]
+    fn test_catalog_validation() {
+        let data = r#"[
+            (name: "G2V", mass: 1.0, luminosity: 1.0, radius: 1.0, temp: 5800.0, color: (1.0, 1.0, 0.9)),
+            (name: "G3V", mass: 0.9, luminosity: 0.8, radius: 0.9, temp: 5900.0, color: (1.0, 1.0, 0.8)),
+            (name: "G3V", mass: 0.9, luminosity: 0.8, radius: 0.9, temp: 5700.0, color: (1.0, 1.0, 0.8)),
+        ]"#;
+        let (catalog, issues) = StellarCatalog::from_ron(data).unwrap();
+        assert_eq!(catalog.len(), 2);
+        let g3v = "G3V".parse().unwrap();
+        assert!(issues.contains(&CatalogIssue::Duplicate(g3v)));
+        assert!(issues.contains(&CatalogIssue::TemperatureNotMonotonic(g3v)));
+        assert!(issues.contains(&CatalogIssue::MissingLuminosityClass(
+            SpectralType::G,
+            LuminosityClass::Ia0
+        )));
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let data = r#"[
+            (name: "G2V", mass: 1.0, luminosity: 1.0, radius: 1.0, temp: 6000.0, color: (1.0, 1.0, 1.0)),
+            (name: "G3V", mass: 0.8, luminosity: 100.0, radius: 0.8, temp: 5000.0, color: (1.0, 1.0, 1.0)),
+        ]"#;
+        let (catalog, _) = StellarCatalog::from_ron(data).unwrap();
+        let star = catalog
+            .interpolate(SpectralType::G, 2.5, LuminosityClass::V)
+            .unwrap();
+        assert!((star.temp - 5500.0).abs() < 1e-3);
+        assert!((star.luminosity - 10.0).abs() < 1e-3);
+    }
+}
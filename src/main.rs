@@ -24,8 +24,8 @@
 #![allow(dead_code)]
 #![allow(unused_mut)]
 
-//use crate::plugin::parallax::ParallaxBackgroundPlugin;
 use self::generation::GenerationContext;
+use crate::plugin::parallax::ParallaxBackgroundPlugin;
 use bevy::diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin};
 use bevy::log::{LogPlugin, LogSettings};
 use bevy::prelude::*;
@@ -57,7 +57,7 @@ fn main() {
     let mut builder = App::build();
     builder
         .add_plugins(DefaultPlugins)
-        //.add_plugin(ParallaxBackgroundPlugin)
+        .add_plugin(ParallaxBackgroundPlugin)
         .add_plugin(FrameTimeDiagnosticsPlugin)
         .add_plugin(LogDiagnosticsPlugin::default())
         .add_plugins(TilemapDefaultPlugins)
@@ -75,6 +75,7 @@ fn main() {
     // Pause Menu States
     self::menus::bootstrap_menus(&mut builder);
     self::generation::bootstrap_worldgeneration(&mut builder);
+    self::gameplay::bootstrap_gameplay(&mut builder);
 
     builder.run();
 }
@@ -17,8 +17,12 @@
 //
 
 mod bundle;
+mod config;
+mod layer;
 mod plugin;
 mod render;
 
 pub use self::bundle::ParallaxBackgroundBundle;
+pub use self::config::{ParallaxConfig, ParallaxConfigLoader, ParallaxLayerConfig, ParallaxSource};
+pub use self::layer::{NebulaLayer, ParallaxBackground, ParallaxLayer};
 pub use self::plugin::ParallaxBackgroundPlugin;
@@ -0,0 +1,78 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::reflect::TypeUuid;
+use serde::{Deserialize, Serialize};
+
+/// Where the image for a Parallax Layer comes from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ParallaxSource {
+    /// Image asset loaded from disk
+    Texture(String),
+    /// Nebula generated by [crate::utility::CloudTextureGenerator], seeded from the background
+    /// seed mixed with `seed`.
+    Nebula {
+        seed: u32,
+        width: usize,
+        height: usize,
+    },
+}
+
+/// Single Layer of a Parallax Background
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParallaxLayerConfig {
+    /// Image for this layer
+    pub source: ParallaxSource,
+    /// Z depth of the layer, further layers are drawn behind nearer ones.
+    pub depth: f32,
+    /// How much the layer moves with the world, `0.0` is fixed to the camera and `1.0` moves with
+    /// the world.
+    pub scroll: f32,
+    /// Color the layer is multiplied by as RGBA.
+    pub tint: (f32, f32, f32, f32),
+}
+
+/// Parallax Background described as a list of layers, loaded from `*.parallax` RON files.
+#[derive(Debug, Clone, Serialize, Deserialize, TypeUuid)]
+#[uuid = "5e3c6f1a-8f0b-4d39-9f3e-2b7c1d4a6e90"]
+pub struct ParallaxConfig {
+    pub layers: Vec<ParallaxLayerConfig>,
+}
+
+/// Loads [ParallaxConfig] from RON.
+#[derive(Default)]
+pub struct ParallaxConfigLoader;
+
+impl AssetLoader for ParallaxConfigLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let config: ParallaxConfig = ron::de::from_bytes(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(config));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["parallax"]
+    }
+}
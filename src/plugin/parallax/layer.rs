@@ -0,0 +1,271 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::bundle::ParallaxBackgroundBundle;
+use super::config::{ParallaxConfig, ParallaxLayerConfig, ParallaxSource};
+use crate::utility::CloudTextureGenerator;
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use bevy::render::texture::AddressMode;
+use bevy::tasks::AsyncComputeTaskPool;
+use std::sync::{Arc, Mutex};
+
+/// Layers are sized to this multiple of the window so scrolling never shows an edge.
+///
+/// `parallax.vert` repeats the texture this many times across the layer, keeping one texture
+/// period per window.
+const LAYER_COVERAGE: f32 = 3.0;
+
+/// Parallax Background built from a [ParallaxConfig].
+///
+/// Layers are spawned as children once the config is loaded. Changing `seed` regenerates any
+/// nebula layers. Layers follow this entity's [Visible] once their textures are ready.
+pub struct ParallaxBackground {
+    /// Layer list to build from
+    pub config: Handle<ParallaxConfig>,
+    /// Seed for procedurally generated layers
+    pub seed: u32,
+    /// Have the layers been spawned
+    pub spawned: bool,
+}
+
+impl ParallaxBackground {
+    pub fn new(config: Handle<ParallaxConfig>, seed: u32) -> ParallaxBackground {
+        ParallaxBackground {
+            config,
+            seed,
+            spawned: false,
+        }
+    }
+}
+
+/// Single layer of a [ParallaxBackground]
+pub struct ParallaxLayer {
+    /// How much the layer moves with the world
+    pub scroll: f32,
+    /// Texture shown once loaded or generated
+    pub texture: Option<Handle<Texture>>,
+    /// Has the texture been prepared for display
+    pub ready: bool,
+}
+
+/// Procedurally generated layer, regenerated whenever the background seed changes.
+pub struct NebulaLayer {
+    /// Mixed with the background seed to give each layer its own nebula
+    pub seed: u32,
+    pub width: usize,
+    pub height: usize,
+    /// Seed the current texture was generated from
+    pub generated: Option<u32>,
+}
+
+/// Nebula texture being generated off the main thread, filled in once it's done.
+pub struct NebulaTask(Arc<Mutex<Option<Texture>>>);
+
+/// Spawn layers for backgrounds whose config has finished loading.
+pub fn spawn_parallax_layers(
+    mut commands: Commands,
+    configs: Res<Assets<ParallaxConfig>>,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    windows: Res<Windows>,
+    mut background_query: Query<(Entity, &mut ParallaxBackground)>,
+) {
+    let (width, height) = windows
+        .get_primary()
+        .map_or((800.0, 600.0), |window| (window.width(), window.height()));
+    for (entity, mut background) in background_query.iter_mut() {
+        if background.spawned {
+            continue;
+        }
+        let config = match configs.get(&background.config) {
+            Some(config) => config,
+            None => {
+                if asset_server.get_load_state(&background.config) == LoadState::Failed {
+                    warn!("Unable to load Parallax Background");
+                    background.spawned = true;
+                }
+                continue;
+            }
+        };
+        trace!("Spawning {} Parallax Layers", config.layers.len());
+        background.spawned = true;
+        commands.entity(entity).with_children(|parent| {
+            for layer in &config.layers {
+                spawn_layer(parent, layer, &asset_server, &mut materials, width, height);
+            }
+        });
+    }
+}
+
+fn spawn_layer(
+    parent: &mut ChildBuilder,
+    layer: &ParallaxLayerConfig,
+    asset_server: &AssetServer,
+    materials: &mut Assets<ColorMaterial>,
+    width: f32,
+    height: f32,
+) {
+    let (r, g, b, a) = layer.tint;
+    let texture = match &layer.source {
+        ParallaxSource::Texture(path) => Some(asset_server.load(path.as_str())),
+        ParallaxSource::Nebula { .. } => None,
+    };
+    let mut transform = Transform::from_xyz(0.0, 0.0, layer.depth);
+    transform.scale = Vec3::new(width * LAYER_COVERAGE, height * LAYER_COVERAGE, 1.0);
+    let mut entity = parent.spawn_bundle(ParallaxBackgroundBundle {
+        material: materials.add(ColorMaterial {
+            color: Color::rgba(r, g, b, a),
+            texture: texture.clone(),
+        }),
+        transform,
+        visible: Visible {
+            is_visible: false,
+            is_transparent: true,
+        },
+        ..Default::default()
+    });
+    entity.insert(ParallaxLayer {
+        scroll: layer.scroll,
+        texture,
+        ready: false,
+    });
+    if let ParallaxSource::Nebula {
+        seed,
+        width,
+        height,
+    } = layer.source
+    {
+        entity.insert(NebulaLayer {
+            seed,
+            width,
+            height,
+            generated: None,
+        });
+    }
+}
+
+/// Start generating nebula textures for new or reseeded backgrounds.
+pub fn generate_nebula_layers(
+    mut commands: Commands,
+    task_pool: Res<AsyncComputeTaskPool>,
+    background_query: Query<&ParallaxBackground>,
+    mut layer_query: Query<(Entity, &Parent, &mut NebulaLayer), Without<NebulaTask>>,
+) {
+    for (entity, parent, mut nebula) in layer_query.iter_mut() {
+        let seed = match background_query.get(parent.0) {
+            Ok(background) => background.seed ^ nebula.seed,
+            Err(_) => continue,
+        };
+        if nebula.generated == Some(seed) {
+            continue;
+        }
+        trace!("Generating Nebula {}", seed);
+        let (width, height) = (nebula.width, nebula.height);
+        let slot = Arc::new(Mutex::new(None));
+        let result = slot.clone();
+        task_pool
+            .spawn(async move {
+                let texture = CloudTextureGenerator::new(seed).texture(width, height, 0);
+                if let Ok(mut result) = result.lock() {
+                    *result = Some(texture);
+                }
+            })
+            .detach();
+        nebula.generated = Some(seed);
+        commands.entity(entity).insert(NebulaTask(slot));
+    }
+}
+
+/// Collect finished nebula textures.
+pub fn collect_nebula_layers(
+    mut commands: Commands,
+    mut textures: ResMut<Assets<Texture>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut layer_query: Query<(
+        Entity,
+        &NebulaTask,
+        &mut ParallaxLayer,
+        &Handle<ColorMaterial>,
+    )>,
+) {
+    for (entity, task, mut layer, material) in layer_query.iter_mut() {
+        let texture = task.0.lock().ok().and_then(|mut slot| slot.take());
+        if let Some(texture) = texture {
+            let handle = textures.add(texture);
+            layer.ready = false;
+            if let Some(material) = materials.get_mut(material) {
+                material.texture = Some(handle.clone());
+            }
+            if let Some(old) = layer.texture.replace(handle) {
+                textures.remove(old);
+            }
+            commands.entity(entity).remove::<NebulaTask>();
+        }
+    }
+}
+
+/// Prepare textures once they're available and show layers with their background.
+pub fn update_parallax_visibility(
+    mut textures: ResMut<Assets<Texture>>,
+    background_query: Query<(&Visible, &Children), With<ParallaxBackground>>,
+    mut layer_query: Query<(&mut ParallaxLayer, &mut Visible), Without<ParallaxBackground>>,
+) {
+    for (background_visible, children) in background_query.iter() {
+        for child in children.iter() {
+            if let Ok((mut layer, mut visible)) = layer_query.get_mut(*child) {
+                if !layer.ready {
+                    let texture = layer
+                        .texture
+                        .as_ref()
+                        .and_then(|handle| textures.get_mut(handle));
+                    if let Some(texture) = texture {
+                        texture.sampler.set_address_mode(AddressMode::Repeat);
+                        layer.ready = true;
+                    }
+                }
+                let show = layer.ready && background_visible.is_visible;
+                if visible.is_visible != show {
+                    visible.is_visible = show;
+                }
+            }
+        }
+    }
+}
+
+/// Shift layers against the camera's movement by their scroll factor, wrapping by the texture's
+/// on-screen period of one window so the oversized layer always covers the view.
+pub fn scroll_parallax_layers(
+    windows: Res<Windows>,
+    background_query: Query<(&GlobalTransform, &Children), With<ParallaxBackground>>,
+    mut layer_query: Query<(&ParallaxLayer, &mut Transform)>,
+) {
+    let (width, height) = windows
+        .get_primary()
+        .map_or((800.0, 600.0), |window| (window.width(), window.height()));
+    for (global, children) in background_query.iter() {
+        for child in children.iter() {
+            if let Ok((layer, mut transform)) = layer_query.get_mut(*child) {
+                let x = -(global.translation.x * layer.scroll).rem_euclid(width);
+                let y = -(global.translation.y * layer.scroll).rem_euclid(height);
+                transform.translation.x = x + width / 2.0;
+                transform.translation.y = y + height / 2.0;
+            }
+        }
+    }
+}
@@ -1,7 +1,6 @@
 #version 450
 
 layout(location = 0) in vec2 v_Uv;
-layout(location = 1) in vec4 v_Color;
 
 layout(location = 0) out vec4 o_Target;
 
@@ -6,6 +6,9 @@ layout(location = 2) in vec2 Vertex_Uv;
 
 layout(location = 0) out vec2 v_Uv;
 
+// Must match LAYER_COVERAGE in layer.rs so the texture repeats once per window
+const float Coverage = 3.0;
+
 layout(set = 0, binding = 0) uniform CameraViewProj {
     mat4 ViewProj;
 };
@@ -16,7 +19,5 @@ layout(set = 2, binding = 0) uniform Transform {
 
 void main() {
     gl_Position = ViewProj * Model * vec4(Vertex_Position, 1.0);
-    //v_Uv /= vec2(gl_Position.x / Vertex_Position.z, gl_Position.y / Vertex_Position.z);
-    v_Uv = Vertex_Uv / vec2(gl_Position.x / Vertex_Position.z, gl_Position.y / Vertex_Position.z);
-    v_Uv = Vertex_Uv;
+    v_Uv = Vertex_Uv * Coverage;
 }
@@ -16,9 +16,15 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
 
+use super::config::{ParallaxConfig, ParallaxConfigLoader};
+use super::layer::{
+    collect_nebula_layers, generate_nebula_layers, scroll_parallax_layers, spawn_parallax_layers,
+    update_parallax_visibility,
+};
 use super::render::{build_parallax_background_pipeline, PARALLAX_BACKGROUND_PIPELINE_HANDLE};
 use bevy::app::{AppBuilder, Plugin};
-use bevy::asset::Assets;
+use bevy::asset::{AddAsset, Assets};
+use bevy::ecs::system::IntoSystem;
 use bevy::render::pipeline::{PipelineDescriptor, RenderPipeline};
 use bevy::render::prelude::RenderPipelines;
 use bevy::render::render_graph::RenderGraph;
@@ -31,6 +37,13 @@ pub struct ParallaxBackgroundPlugin;
 
 impl Plugin for ParallaxBackgroundPlugin {
     fn build(&self, app: &mut AppBuilder) {
+        app.add_asset::<ParallaxConfig>()
+            .init_asset_loader::<ParallaxConfigLoader>()
+            .add_system(spawn_parallax_layers.system())
+            .add_system(generate_nebula_layers.system())
+            .add_system(collect_nebula_layers.system())
+            .add_system(update_parallax_visibility.system())
+            .add_system(scroll_parallax_layers.system());
         let world = app.world_mut();
         let world_cell = world.cell();
         let mut pipelines = world_cell
@@ -23,5 +23,8 @@ mod worldclock;
 
 pub use self::dice::Dice;
 pub use self::poisson::PoissonDiscSampler;
-pub use self::texgen::CloudTextureGenerator;
-pub use self::worldclock::{world_clock_update, WorldClock};
+pub use self::texgen::{
+    preset, CloudTextureGenerator, ColorRamp, FractalNoise, FractalSettings, Pattern,
+    TextureSynthesizer,
+};
+pub use self::worldclock::{world_clock_update, WorldClock, SECONDS_PER_DAY, WARP_LEVELS};
@@ -21,16 +21,18 @@ pub trait Dice: rand::Rng {
     /// Roll `count` dice with `sides` and count the total above `target`.
     fn roll_target(&mut self, count: usize, sides: usize, target: usize) -> usize {
         (0..count)
-            .map(|_| self.gen_range(1..sides))
+            .map(|_| self.gen_range(1..=sides))
             .filter(|v| *v >= target)
             .count()
     }
     /// Roll `count` dice with `pips` and sum the total pips.
     fn roll_sum(&mut self, count: usize, pips: usize) -> usize {
-        (0..count).map(|_| self.gen_range(1..pips)).sum()
+        (0..count).map(|_| self.gen_range(1..=pips)).sum()
     }
     /// Roll percentile dice and return the value between 0 and 100.
     fn roll_pct(&mut self) -> f32 {
         self.gen_range(0.0..100.0)
     }
 }
+
+impl<R: rand::Rng> Dice for R {}
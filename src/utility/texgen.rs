@@ -16,78 +16,88 @@
 //  along with this program.  If not, see <http://www.gnu.org/licenses/>.
 //
 
+//! Seeded procedural texture synthesis.
+mod fractal;
+pub mod preset;
+mod ramp;
+mod synth;
+
+pub use self::fractal::{FractalNoise, FractalSettings};
+pub use self::ramp::{lerp_color, ColorRamp};
+pub use self::synth::{pixels_to_texture, Pattern, TextureSynthesizer};
 use bevy::prelude::*;
-use bevy::render::texture::{Extent3d, TextureDimension, TextureFormat};
-use noise::{NoiseFn, OpenSimplex, Perlin, Seedable};
 
+/// Red, tileable clouds.
 pub struct CloudTextureGenerator {
-    noise1: OpenSimplex,
-    noise2: OpenSimplex,
-    noise3: OpenSimplex,
-    noise4: OpenSimplex,
-    noise5: OpenSimplex,
+    synthesizer: TextureSynthesizer,
 }
 
 impl CloudTextureGenerator {
     pub fn new(seed: u32) -> CloudTextureGenerator {
+        let settings = FractalSettings {
+            frequency: 10.0,
+            ..Default::default()
+        };
+        let ramp = ColorRamp::new(
+            Color::rgba(0.0, 0.0, 0.0, 0.0),
+            Color::rgba(1.0, 0.0, 0.0, 1.0),
+        );
         CloudTextureGenerator {
-            noise1: OpenSimplex::new().set_seed(seed ^ 679125833),
-            noise2: OpenSimplex::new().set_seed(seed ^ 3274989671),
-            noise3: OpenSimplex::new().set_seed(seed ^ 2776948319),
-            noise4: OpenSimplex::new().set_seed(seed ^ 4054792873),
-            noise5: OpenSimplex::new().set_seed(seed ^ 2100345001),
+            synthesizer: TextureSynthesizer::new(seed, settings, ramp),
         }
     }
     pub fn texture(&mut self, width: usize, height: usize, frame: usize) -> Texture {
-        let mut data = Vec::with_capacity(width * height * 4);
-        for x in 0..width {
-            for y in 0..height {
-                let nx = x as f64 / (width as f64 * 0.1);
-                let ny = y as f64 / (width as f64 * 0.1);
-                let nz = frame as f64 * 0.1;
-                let e = 16.0 * (self.noise1.get([01.0 * nx, 01.0 * ny, 01.0 * nz]) + 1.0)
-                    + 08.0 * (self.noise2.get([02.0 * nx, 02.0 * ny, 02.0 * nz]) + 1.0)
-                    + 04.0 * (self.noise3.get([04.0 * nx, 04.0 * ny, 04.0 * nz]) + 1.0)
-                    + 02.0 * (self.noise4.get([08.0 * nx, 08.0 * ny, 08.0 * nz]) + 1.0)
-                    + 01.0 * (self.noise5.get([16.0 * nx, 16.0 * ny, 16.0 * nz]) + 1.0);
-                let val = ((e / 64.0) * 255.0) as u8;
-                //trace!("{},{} => {} -> {}", x, y, e, val);
-                data.push(val);
-                data.push(0);
-                data.push(0);
-                data.push(val);
-            }
-        }
-        Texture::new(
-            Extent3d::new(width as u32, height as u32, 1),
-            TextureDimension::D2,
-            data,
-            TextureFormat::Rgba8UnormSrgb,
-        )
+        self.synthesizer.texture(width, height, frame as f64 * 0.1)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::CloudTextureGenerator;
-    use std::fs::File;
-    use std::io::BufWriter;
-    use std::path::Path;
+    use super::{preset, CloudTextureGenerator, ColorRamp, FractalNoise, FractalSettings};
+    use bevy::prelude::Color;
+    use bevy::render::texture::TextureFormat;
 
     #[test]
     fn test_texture() {
-        const WIDTH: usize = 1024;
-        const HEIGHT: usize = 768;
-        let path = Path::new(r"target/perlin_test.png");
-        let file = File::create(path).unwrap();
-        let ref mut w = BufWriter::new(file);
+        const WIDTH: usize = 64;
+        const HEIGHT: usize = 48;
+        let texture = CloudTextureGenerator::new(0).texture(WIDTH, HEIGHT, 0);
+        assert_eq!(texture.size.width, WIDTH as u32);
+        assert_eq!(texture.size.height, HEIGHT as u32);
+        assert_eq!(texture.format, TextureFormat::Rgba8UnormSrgb);
+        assert_eq!(texture.data.len(), WIDTH * HEIGHT * 4);
 
-        let mut encoder = png::Encoder::new(w, WIDTH as u32, HEIGHT as u32);
-        encoder.set_color(png::ColorType::Rgba);
-        encoder.set_depth(png::BitDepth::Eight);
-        let mut writer = encoder.write_header().unwrap();
+        // The same seed always makes the same clouds, and a different one doesn't.
+        let again = CloudTextureGenerator::new(0).texture(WIDTH, HEIGHT, 0);
+        assert_eq!(texture.data, again.data);
+        let other = CloudTextureGenerator::new(1).texture(WIDTH, HEIGHT, 0);
+        assert_ne!(texture.data, other.data);
+    }
 
-        let texture = CloudTextureGenerator::new(0).texture(WIDTH, HEIGHT, 0);
-        writer.write_image_data(&texture.data).unwrap();
+    #[test]
+    fn test_tileable() {
+        let noise = FractalNoise::new(7, FractalSettings::default());
+        for step in 0..16 {
+            let v = step as f64 / 16.0;
+            let left = noise.sample_tileable(0.0, v, 0.75, 0.0);
+            let right = noise.sample_tileable(1.0, v, 0.75, 0.0);
+            assert!((left - right).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_ramp() {
+        let ramp = ColorRamp::new(Color::BLACK, Color::WHITE).with_stop(0.5, Color::RED);
+        assert_eq!(ramp.sample(-1.0), Color::BLACK);
+        assert_eq!(ramp.sample(0.5), Color::RED);
+        assert_eq!(ramp.sample(2.0), Color::WHITE);
+    }
+
+    #[test]
+    fn test_temperature_color() {
+        let [r, _, b, _] = preset::temperature_color(3000.0).as_rgba_f32();
+        assert!(r > b);
+        let [r, _, b, _] = preset::temperature_color(30000.0).as_rgba_f32();
+        assert!(b > r);
     }
 }
@@ -0,0 +1,134 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use noise::{NoiseFn, OpenSimplex, Seedable};
+use std::f64::consts::TAU;
+
+/// Salts mixed into the seed so every octave and warp axis gets its own noise.
+const OCTAVE_SALTS: [u32; 8] = [
+    679125833, 3274989671, 2776948319, 4054792873, 2100345001, 1512302407, 3917269331, 2415919103,
+];
+const WARP_SALTS: [u32; 4] = [1103515245, 2654435761, 2246822519, 3266489917];
+
+/// Shape of fractal Brownian motion noise.
+#[derive(Debug, Copy, Clone)]
+pub struct FractalSettings {
+    /// Number of noise layers summed together, at most 8
+    pub octaves: usize,
+    /// Features across the texture in the first octave
+    pub frequency: f64,
+    /// Frequency multiplier between octaves
+    pub lacunarity: f64,
+    /// Amplitude multiplier between octaves
+    pub gain: f64,
+    /// How far the domain is pushed around before sampling, `0.0` disables warping.
+    pub warp: f64,
+    /// Frequency of the warping noise
+    pub warp_frequency: f64,
+}
+
+impl Default for FractalSettings {
+    fn default() -> FractalSettings {
+        FractalSettings {
+            octaves: 5,
+            frequency: 4.0,
+            lacunarity: 2.0,
+            gain: 0.5,
+            warp: 0.0,
+            warp_frequency: 2.0,
+        }
+    }
+}
+
+/// Seeded, domain warped fractal noise sampled in 4D so it can wrap seamlessly.
+pub struct FractalNoise {
+    settings: FractalSettings,
+    octaves: Vec<OpenSimplex>,
+    warp: Vec<OpenSimplex>,
+}
+
+impl FractalNoise {
+    pub fn new(seed: u32, settings: FractalSettings) -> FractalNoise {
+        FractalNoise {
+            settings,
+            octaves: OCTAVE_SALTS
+                .iter()
+                .take(settings.octaves.max(1))
+                .map(|salt| OpenSimplex::new().set_seed(seed ^ salt))
+                .collect(),
+            warp: WARP_SALTS
+                .iter()
+                .map(|salt| OpenSimplex::new().set_seed(seed ^ salt))
+                .collect(),
+        }
+    }
+    pub fn settings(&self) -> &FractalSettings {
+        &self.settings
+    }
+    /// Noise at `point` between `0.0` and `1.0`, after domain warping.
+    pub fn sample(&self, point: [f64; 4]) -> f64 {
+        if self.settings.warp <= 0.0 {
+            return self.fbm(point);
+        }
+        let at = scale(point, self.settings.warp_frequency);
+        let mut warped = point;
+        for (axis, noise) in self.warp.iter().enumerate() {
+            warped[axis] += self.settings.warp * noise.get(at);
+        }
+        self.fbm(warped)
+    }
+    /// Noise at texture coordinates `u`, `v` in `0.0..1.0` that wraps seamlessly at the edges.
+    ///
+    /// Each axis is mapped to a circle so the texture is sampled off the surface of a torus,
+    /// `aspect` is height over width and keeps features square, `time` moves through the noise.
+    pub fn sample_tileable(&self, u: f64, v: f64, aspect: f64, time: f64) -> f64 {
+        let (a, b) = (u * TAU, v * TAU);
+        let (ru, rv) = (1.0 / TAU, aspect / TAU);
+        self.sample([
+            a.cos() * ru + time,
+            a.sin() * ru,
+            b.cos() * rv,
+            b.sin() * rv,
+        ])
+    }
+    /// Noise at texture coordinates `u`, `v` without wrapping.
+    pub fn sample_plane(&self, u: f64, v: f64, aspect: f64, time: f64) -> f64 {
+        self.sample([u, v * aspect, time, 0.0])
+    }
+    fn fbm(&self, point: [f64; 4]) -> f64 {
+        let (mut sum, mut norm) = (0.0, 0.0);
+        let mut amplitude = 1.0;
+        let mut frequency = self.settings.frequency;
+        for noise in &self.octaves {
+            sum += amplitude * noise.get(scale(point, frequency));
+            norm += amplitude;
+            amplitude *= self.settings.gain;
+            frequency *= self.settings.lacunarity;
+        }
+        (sum / norm * 0.5 + 0.5).clamp(0.0, 1.0)
+    }
+}
+
+fn scale(point: [f64; 4], factor: f64) -> [f64; 4] {
+    [
+        point[0] * factor,
+        point[1] * factor,
+        point[2] * factor,
+        point[3] * factor,
+    ]
+}
@@ -0,0 +1,224 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+//! Ready made synthesizers for the bodies generated in [crate::generation].
+use super::synth::{pixels_to_texture, to_byte};
+use super::{lerp_color, ColorRamp, FractalSettings, Pattern, TextureSynthesizer};
+use crate::generation::{PlanetData, StarData};
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro128PlusPlus;
+
+/// Share of a planet's makeup that's gas above which it's rendered as a gas giant.
+const GAS_GIANT_GASES: f32 = 0.4;
+/// Surface temperature in Celsius below which water freezes over.
+const FREEZING: f64 = 0.0;
+/// Surface temperature in Celsius above which no water remains.
+const BOILING: f64 = 100.0;
+
+/// Wispy, warped cloud of gas lit in `tint`, transparent where thin.
+pub fn nebula(seed: u32, tint: Color) -> TextureSynthesizer {
+    let [r, g, b, _] = tint.as_rgba_f32();
+    let ramp = ColorRamp::default()
+        .with_stop(0.35, Color::rgba(r, g, b, 0.0))
+        .with_stop(0.7, Color::rgba(r, g, b, 0.6))
+        .with_stop(
+            1.0,
+            Color::rgba(0.5 + r * 0.5, 0.5 + g * 0.5, 0.5 + b * 0.5, 0.9),
+        );
+    let settings = FractalSettings {
+        octaves: 6,
+        frequency: 3.0,
+        warp: 0.4,
+        ..Default::default()
+    };
+    TextureSynthesizer::new(seed, settings, ramp)
+}
+
+/// Surface of a planet, gas giants get bands and everything else terrain.
+pub fn planet(seed: u32, planet: &PlanetData) -> TextureSynthesizer {
+    if planet.gases > GAS_GIANT_GASES {
+        gas_giant(seed, planet)
+    } else {
+        rocky_planet(seed, planet)
+    }
+}
+
+/// Turbulent bands in shades of the planet's colour.
+pub fn gas_giant(seed: u32, planet: &PlanetData) -> TextureSynthesizer {
+    let base = planet.color;
+    let ramp = ColorRamp::default()
+        .with_stop(0.0, shade(base, 0.5))
+        .with_stop(0.45, base)
+        .with_stop(0.7, shade(base, 1.3))
+        .with_stop(1.0, shade(base, 0.8));
+    let settings = FractalSettings {
+        octaves: 4,
+        frequency: 2.0,
+        warp: 0.15,
+        ..Default::default()
+    };
+    let count = 4 + seed % 8;
+    TextureSynthesizer::new(seed, settings, ramp).with_pattern(Pattern::Bands {
+        count,
+        turbulence: 0.1 + f64::from(planet.gases) * 0.2,
+    })
+}
+
+/// Oceans, land and mountains, with sea level from the planet's water and ice or scorched rock
+/// by temperature.
+pub fn rocky_planet(seed: u32, planet: &PlanetData) -> TextureSynthesizer {
+    let land = lerp_color(
+        Color::rgb(0.45, 0.35, 0.25),
+        Color::rgb(0.2, 0.45, 0.15),
+        (planet.foliage / (planet.foliage + planet.minerals).max(f32::EPSILON)).clamp(0.0, 1.0),
+    );
+    let sea_level = if planet.temp > BOILING {
+        0.0
+    } else {
+        (planet.water * 1.5).clamp(0.0, 0.8)
+    };
+    let (deep, shallow) = if planet.temp < FREEZING {
+        (Color::rgb(0.7, 0.8, 0.9), Color::rgb(0.85, 0.9, 0.95))
+    } else {
+        (Color::rgb(0.02, 0.08, 0.3), Color::rgb(0.1, 0.3, 0.6))
+    };
+    let peaks = if planet.temp < FREEZING + 20.0 {
+        Color::WHITE
+    } else {
+        shade(land, 0.6)
+    };
+    let mut ramp = ColorRamp::default();
+    if sea_level > 0.0 {
+        ramp = ramp
+            .with_stop(0.0, deep)
+            .with_stop(sea_level, shallow)
+            .with_stop(
+                sea_level + 0.01,
+                lerp_color(land, Color::rgb(0.8, 0.75, 0.55), 0.5),
+            );
+    }
+    ramp = ramp
+        .with_stop(sea_level + 0.05, lerp_color(land, planet.color, 0.3))
+        .with_stop(0.85, shade(land, 0.8))
+        .with_stop(1.0, peaks);
+    let settings = FractalSettings {
+        octaves: 7,
+        frequency: 3.0,
+        gain: 0.55,
+        warp: 0.1,
+        ..Default::default()
+    };
+    TextureSynthesizer::new(seed, settings, ramp)
+}
+
+/// Granulated photosphere coloured by the star's temperature.
+pub fn star_surface(seed: u32, star: &StarData) -> TextureSynthesizer {
+    let color = lerp_color(temperature_color(star.temp), star.color, 0.5);
+    let ramp = ColorRamp::default()
+        .with_stop(0.0, shade(color, 0.6))
+        .with_stop(0.6, color)
+        .with_stop(1.0, lerp_color(color, Color::WHITE, 0.6));
+    let settings = FractalSettings {
+        octaves: 5,
+        frequency: 12.0,
+        gain: 0.45,
+        ..Default::default()
+    };
+    TextureSynthesizer::new(seed, settings, ramp).with_pattern(Pattern::Ridged)
+}
+
+/// Tileable field of stars on a transparent background, `density` is stars per pixel.
+pub fn starfield(seed: u32, width: usize, height: usize, density: f32) -> Texture {
+    let mut rng = Xoshiro128PlusPlus::seed_from_u64(u64::from(seed));
+    let mut data = vec![0u8; width * height * 4];
+    let count = (width * height) as f32 * density;
+    for _ in 0..count as usize {
+        let (x, y) = (rng.gen_range(0..width), rng.gen_range(0..height));
+        let brightness: f32 = rng.gen_range(0.2f32..1.0).powi(3);
+        let [r, g, b, _] = temperature_color(rng.gen_range(2500.0..30000.0)).as_rgba_f32();
+        // Bright stars bleed into their neighbours, wrapping so the field stays seamless.
+        let spread = if brightness > 0.6 { 1 } else { 0 };
+        for dy in -spread..=spread {
+            for dx in -spread..=spread {
+                let falloff = if dx == 0 && dy == 0 { 1.0 } else { 0.3 };
+                let px = (x as isize + dx).rem_euclid(width as isize) as usize;
+                let py = (y as isize + dy).rem_euclid(height as isize) as usize;
+                let pixel = &mut data[(py * width + px) * 4..][..4];
+                let alpha = brightness * falloff;
+                pixel[0] = pixel[0].max(to_byte(r * alpha));
+                pixel[1] = pixel[1].max(to_byte(g * alpha));
+                pixel[2] = pixel[2].max(to_byte(b * alpha));
+                pixel[3] = pixel[3].max(to_byte(alpha));
+            }
+        }
+    }
+    pixels_to_texture(width, height, data)
+}
+
+/// Approximate colour of a black body at `kelvin`.
+pub fn temperature_color(kelvin: f64) -> Color {
+    let t = (kelvin / 100.0).clamp(10.0, 400.0);
+    let r = if t <= 66.0 {
+        255.0
+    } else {
+        329.698727446 * (t - 60.0).powf(-0.1332047592)
+    };
+    let g = if t <= 66.0 {
+        99.4708025861 * t.ln() - 161.1195681661
+    } else {
+        288.1221695283 * (t - 60.0).powf(-0.0755148492)
+    };
+    let b = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.5177312231 * (t - 10.0).ln() - 305.0447927307
+    };
+    Color::rgb(
+        (r / 255.0).clamp(0.0, 1.0) as f32,
+        (g / 255.0).clamp(0.0, 1.0) as f32,
+        (b / 255.0).clamp(0.0, 1.0) as f32,
+    )
+}
+
+fn shade(color: Color, factor: f32) -> Color {
+    let [r, g, b, a] = color.as_rgba_f32();
+    Color::rgba(
+        (r * factor).min(1.0),
+        (g * factor).min(1.0),
+        (b * factor).min(1.0),
+        a,
+    )
+}
+
+/// White radial glow fading to transparent at the edge, tint it through the material.
+pub fn radial_glow(size: usize) -> Texture {
+    let mut data = Vec::with_capacity(size * size * 4);
+    let center = size as f32 / 2.0;
+    for y in 0..size {
+        for x in 0..size {
+            let dx = (x as f32 + 0.5 - center) / center;
+            let dy = (y as f32 + 0.5 - center) / center;
+            let falloff = (1.0 - (dx * dx + dy * dy).sqrt()).max(0.0).powi(2);
+            data.extend_from_slice(&[255, 255, 255, to_byte(falloff)]);
+        }
+    }
+    pixels_to_texture(size, size, data)
+}
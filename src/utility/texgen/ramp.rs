@@ -0,0 +1,60 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use bevy::prelude::Color;
+
+/// Gradient mapping values between `0.0` and `1.0` to colours.
+#[derive(Debug, Clone, Default)]
+pub struct ColorRamp {
+    stops: Vec<(f32, Color)>,
+}
+
+impl ColorRamp {
+    /// Ramp fading from `from` to `to`
+    pub fn new(from: Color, to: Color) -> ColorRamp {
+        ColorRamp::default().with_stop(0.0, from).with_stop(1.0, to)
+    }
+    /// Add a colour stop at `position`
+    pub fn with_stop(mut self, position: f32, color: Color) -> ColorRamp {
+        let position = position.clamp(0.0, 1.0);
+        let index = self.stops.partition_point(|(stop, _)| *stop <= position);
+        self.stops.insert(index, (position, color));
+        self
+    }
+    /// Colour at `value`, interpolated between the surrounding stops.
+    pub fn sample(&self, value: f32) -> Color {
+        let index = self.stops.partition_point(|(stop, _)| *stop <= value);
+        match (self.stops.get(index.wrapping_sub(1)), self.stops.get(index)) {
+            (Some((p0, c0)), Some((p1, c1))) => lerp_color(*c0, *c1, (value - p0) / (p1 - p0)),
+            (Some((_, color)), None) | (None, Some((_, color))) => *color,
+            (None, None) => Color::NONE,
+        }
+    }
+}
+
+/// Blend between two colours in their RGBA components.
+pub fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    let (a, b) = (from.as_rgba_f32(), to.as_rgba_f32());
+    let t = t.clamp(0.0, 1.0);
+    Color::rgba(
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    )
+}
@@ -0,0 +1,113 @@
+//
+//  Roguestar - An experimental Roguelike Adventure across the stars.
+//  Copyright (C) 2021 Hans W. Uhlig
+//
+//  This program is free software: you can redistribute it and/or modify
+//  it under the terms of the GNU General Public License as published by
+//  the Free Software Foundation, either version 3 of the License, or
+//  (at your option) any later version.
+//
+//  This program is distributed in the hope that it will be useful,
+//  but WITHOUT ANY WARRANTY; without even the implied warranty of
+//  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//  GNU General Public License for more details.
+//
+//  You should have received a copy of the GNU General Public License
+//  along with this program.  If not, see <http://www.gnu.org/licenses/>.
+//
+
+use super::{ColorRamp, FractalNoise, FractalSettings};
+use bevy::prelude::*;
+use bevy::render::texture::{Extent3d, TextureDimension, TextureFormat};
+use std::f64::consts::TAU;
+
+/// How noise is shaped before it's coloured.
+#[derive(Debug, Copy, Clone)]
+pub enum Pattern {
+    /// Plain fractal noise, clouds and nebulae
+    Fractal,
+    /// Turbulent latitude bands, gas giants
+    Bands { count: u32, turbulence: f64 },
+    /// Sharp creases where the noise crosses its midpoint, mountain ranges
+    Ridged,
+}
+
+/// Seeded texture synthesizer colouring shaped fractal noise through a [ColorRamp].
+pub struct TextureSynthesizer {
+    noise: FractalNoise,
+    ramp: ColorRamp,
+    pattern: Pattern,
+    tileable: bool,
+}
+
+impl TextureSynthesizer {
+    pub fn new(seed: u32, settings: FractalSettings, ramp: ColorRamp) -> TextureSynthesizer {
+        TextureSynthesizer {
+            noise: FractalNoise::new(seed, settings),
+            ramp,
+            pattern: Pattern::Fractal,
+            tileable: true,
+        }
+    }
+    pub fn with_pattern(mut self, pattern: Pattern) -> TextureSynthesizer {
+        self.pattern = pattern;
+        self
+    }
+    /// Should the output wrap seamlessly at its edges, on by default.
+    pub fn with_tileable(mut self, tileable: bool) -> TextureSynthesizer {
+        self.tileable = tileable;
+        self
+    }
+    /// Shaped value at texture coordinates `u`, `v` between `0.0` and `1.0`.
+    pub fn value(&self, u: f64, v: f64, aspect: f64, time: f64) -> f64 {
+        let noise = if self.tileable {
+            self.noise.sample_tileable(u, v, aspect, time)
+        } else {
+            self.noise.sample_plane(u, v, aspect, time)
+        };
+        match self.pattern {
+            Pattern::Fractal => noise,
+            Pattern::Bands { count, turbulence } => {
+                let latitude = v + turbulence * (noise - 0.5);
+                let band = 0.5 + 0.5 * (latitude * count as f64 * TAU).sin();
+                0.75 * band + 0.25 * noise
+            }
+            Pattern::Ridged => 1.0 - (2.0 * noise - 1.0).abs(),
+        }
+    }
+    /// Colour at texture coordinates `u`, `v`.
+    pub fn color(&self, u: f64, v: f64, aspect: f64, time: f64) -> Color {
+        self.ramp.sample(self.value(u, v, aspect, time) as f32)
+    }
+    /// Row major RGBA8 pixels.
+    pub fn pixels(&self, width: usize, height: usize, time: f64) -> Vec<u8> {
+        let aspect = height as f64 / width.max(1) as f64;
+        let mut data = Vec::with_capacity(width * height * 4);
+        for y in 0..height {
+            for x in 0..width {
+                let u = x as f64 / width as f64;
+                let v = y as f64 / height as f64;
+                let [r, g, b, a] = self.color(u, v, aspect, time).as_rgba_f32();
+                data.extend_from_slice(&[to_byte(r), to_byte(g), to_byte(b), to_byte(a)]);
+            }
+        }
+        data
+    }
+    pub fn texture(&self, width: usize, height: usize, time: f64) -> Texture {
+        pixels_to_texture(width, height, self.pixels(width, height, time))
+    }
+}
+
+/// Wrap row major RGBA8 pixels in a [Texture].
+pub fn pixels_to_texture(width: usize, height: usize, data: Vec<u8>) -> Texture {
+    Texture::new(
+        Extent3d::new(width as u32, height as u32, 1),
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+pub(super) fn to_byte(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
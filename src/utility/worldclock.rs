@@ -19,23 +19,32 @@
 use bevy::prelude::*;
 use bevy::utils::Duration;
 
+/// Seconds in a standard day, Orbital periods and travel times are measured in days.
+pub const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// Time Warp multipliers available to the player.
+pub const WARP_LEVELS: [f32; 6] = [1.0, 10.0, 100.0, 1000.0, 10000.0, 100000.0];
+
 pub struct WorldClock {
     duration: Duration,
-    multiplier: f32,
+    delta: Duration,
+    warp: usize,
+    /// Rate held outside of the Time Warp levels
+    held: Option<f32>,
     paused: bool,
 }
 
 impl WorldClock {
     pub fn new() -> WorldClock {
-        WorldClock {
-            duration: Duration::default(),
-            multiplier: 1.0,
-            paused: false,
-        }
+        WorldClock::default()
     }
     pub fn time(&self) -> Duration {
         self.duration
     }
+    /// World time elapsed during the last tick.
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
     pub fn seconds_since_epoch(&self) -> f64 {
         self.duration.as_secs_f64()
     }
@@ -48,10 +57,45 @@ impl WorldClock {
     pub fn unpause(&mut self) {
         self.paused = false;
     }
+    /// Current rate world time passes relative to real time, a held rate wins over Time Warp.
+    pub fn multiplier(&self) -> f32 {
+        self.held.unwrap_or(WARP_LEVELS[self.warp])
+    }
+    /// Hold world time at an arbitrary rate outside of the Time Warp levels, such as while in
+    /// Hyperspace transit. Changing or dropping Time Warp only changes the level returned to once
+    /// the rate is released.
+    pub fn hold_multiplier(&mut self, multiplier: f32) {
+        self.held = Some(multiplier.max(0.0));
+    }
+    /// Release a held rate, returning to the current Time Warp level.
+    pub fn release_multiplier(&mut self) {
+        self.held = None;
+    }
+    /// Index into [WARP_LEVELS] of the current Time Warp.
+    pub fn warp_level(&self) -> usize {
+        self.warp
+    }
+    /// Set Time Warp level, clamped to the available [WARP_LEVELS].
+    pub fn set_warp_level(&mut self, level: usize) {
+        self.warp = usize::min(level, WARP_LEVELS.len() - 1);
+    }
+    pub fn increase_warp(&mut self) {
+        self.set_warp_level(self.warp + 1);
+    }
+    pub fn decrease_warp(&mut self) {
+        self.set_warp_level(self.warp.saturating_sub(1));
+    }
+    /// Drop immediately out of Time Warp back to real time.
+    pub fn drop_warp(&mut self) {
+        self.set_warp_level(0);
+    }
     pub fn tick(&mut self, delta: Duration) {
-        if !&self.paused {
-            self.duration += delta.mul_f32(self.multiplier);
-        }
+        self.delta = if self.paused {
+            Duration::default()
+        } else {
+            delta.mul_f32(self.multiplier())
+        };
+        self.duration += self.delta;
     }
 }
 
@@ -59,7 +103,9 @@ impl Default for WorldClock {
     fn default() -> WorldClock {
         WorldClock {
             duration: Duration::default(),
-            multiplier: 1.0,
+            delta: Duration::default(),
+            warp: 0,
+            held: None,
             paused: false,
         }
     }
@@ -68,3 +114,54 @@ impl Default for WorldClock {
 pub fn world_clock_update(time: Res<Time>, mut world_time: ResMut<WorldClock>) {
     world_time.tick(time.delta())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warp_levels() {
+        let mut clock = WorldClock::new();
+        for _ in 0..WARP_LEVELS.len() + 2 {
+            clock.increase_warp();
+        }
+        assert_eq!(clock.warp_level(), WARP_LEVELS.len() - 1);
+        assert_eq!(clock.multiplier(), 100000.0);
+        clock.decrease_warp();
+        assert_eq!(clock.multiplier(), 10000.0);
+        clock.drop_warp();
+        clock.decrease_warp();
+        assert_eq!(clock.warp_level(), 0);
+        assert_eq!(clock.multiplier(), 1.0);
+    }
+
+    #[test]
+    fn test_tick() {
+        let mut clock = WorldClock::new();
+        clock.set_warp_level(2);
+        clock.tick(Duration::from_secs(2));
+        assert_eq!(clock.delta(), Duration::from_secs(200));
+        clock.pause();
+        clock.tick(Duration::from_secs(2));
+        assert_eq!(clock.delta(), Duration::default());
+        assert_eq!(clock.time(), Duration::from_secs(200));
+        clock.unpause();
+        clock.tick(Duration::from_secs(1));
+        assert_eq!(clock.time(), Duration::from_secs(300));
+    }
+
+    #[test]
+    fn test_held_multiplier() {
+        let mut clock = WorldClock::new();
+        clock.hold_multiplier(5000.0);
+        clock.increase_warp();
+        assert_eq!(clock.multiplier(), 5000.0);
+        clock.drop_warp();
+        assert_eq!(clock.multiplier(), 5000.0);
+        clock.tick(Duration::from_secs(1));
+        assert_eq!(clock.delta(), Duration::from_secs(5000));
+        clock.set_warp_level(1);
+        clock.release_multiplier();
+        assert_eq!(clock.multiplier(), 10.0);
+    }
+}